@@ -0,0 +1,161 @@
+use crate::{
+    client::OneInchClient,
+    consts::{AGGREGATION_ROUTER_V6_ADDRESS, AGGREGATION_ROUTER_V6_ZKSYNC_ADDRESS},
+};
+use std::{collections::HashMap, fmt, sync::Arc};
+
+/// Kind of the labeled address.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum AddressKind {
+    Router,
+    OwnWallet,
+    Counterparty,
+    Other,
+}
+
+/// Label of the address.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct AddressLabel {
+    pub name: String,
+    pub kind: AddressKind,
+}
+
+/// Backend of the address labels, like a database or a config service.
+/// Lookups are synchronous, since they happen while formatting, so remote
+/// backends are expected to cache labels in memory.
+pub trait AddressLabels: Send + Sync {
+    /// Returns label of the address, in any case.
+    fn label(&self, address: &str) -> Option<AddressLabel>;
+}
+
+/// In-memory address book, case insensitive. Knows 1inch routers out of the
+/// box.
+///
+/// ```
+/// use one_inch::address_book::{AddressBook, AddressKind, AddressLabels};
+///
+/// let book = AddressBook::new().with_label("0xAbC0000000000000000000000000000000000001", "treasury", AddressKind::OwnWallet);
+/// assert_eq!(book.label("0xabc0000000000000000000000000000000000001").unwrap().name, "treasury");
+/// assert_eq!(
+///     book.display("0x111111125421ca6dc452d289314280a0f8842a65").to_string(),
+///     "1inch router v6 (0x1111…2a65)"
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct AddressBook {
+    labels: HashMap<String, AddressLabel>,
+}
+
+impl AddressBook {
+    pub fn new() -> AddressBook {
+        AddressBook { labels: HashMap::new() }
+            .with_label(AGGREGATION_ROUTER_V6_ADDRESS, "1inch router v6", AddressKind::Router)
+            .with_label(AGGREGATION_ROUTER_V6_ZKSYNC_ADDRESS, "1inch router v6 (zkSync)", AddressKind::Router)
+            .with_label("0x1111111254eeb25477b68fb85ed929f73a960582", "1inch router v5", AddressKind::Router)
+    }
+
+    /// Labels the address, replacing its previous label.
+    pub fn with_label(mut self, address: &str, name: impl Into<String>, kind: AddressKind) -> AddressBook {
+        self.insert(address, name, kind);
+        self
+    }
+
+    /// Labels the address, replacing its previous label.
+    pub fn insert(&mut self, address: &str, name: impl Into<String>, kind: AddressKind) {
+        self.labels.insert(address.to_lowercase(), AddressLabel { name: name.into(), kind });
+    }
+
+    /// Returns formatter of the address with its label.
+    pub fn display<'a>(&'a self, address: &'a str) -> LabeledAddress<'a> {
+        LabeledAddress { address, label: self.label(address) }
+    }
+}
+
+impl Default for AddressBook {
+    fn default() -> Self {
+        AddressBook::new()
+    }
+}
+
+impl AddressLabels for AddressBook {
+    fn label(&self, address: &str) -> Option<AddressLabel> {
+        self.labels.get(&address.to_lowercase()).cloned()
+    }
+}
+
+/// Address formatted with its label, like `treasury (0xabc0…0001)`, or as is
+/// if it has no label.
+#[derive(Debug, Clone)]
+pub struct LabeledAddress<'a> {
+    address: &'a str,
+    label: Option<AddressLabel>,
+}
+
+impl fmt::Display for LabeledAddress<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.label {
+            Some(label) if self.address.len() > 10 && self.address.is_ascii() => {
+                let tail = &self.address[self.address.len() - 4..];
+                write!(f, "{} ({}…{})", label.name, &self.address[..6], tail)
+            }
+            Some(label) => write!(f, "{} ({})", label.name, self.address),
+            None => f.write_str(self.address),
+        }
+    }
+}
+
+impl OneInchClient {
+    /// Sets address book the client labels addresses with in logs and
+    /// formatting.
+    pub fn with_address_book(mut self, address_book: impl AddressLabels + 'static) -> OneInchClient {
+        self.address_book = Some(Arc::new(address_book));
+        self
+    }
+
+    /// Returns label of the address, if the client has an address book.
+    pub fn label(&self, address: &str) -> Option<AddressLabel> {
+        self.address_book.as_ref().and_then(|book| book.label(address))
+    }
+
+    /// Returns formatter of the address with its label from the client's
+    /// address book.
+    pub fn display_address<'a>(&self, address: &'a str) -> LabeledAddress<'a> {
+        LabeledAddress { address, label: self.label(address) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::{new_with_default_http, SupportedNetworks};
+
+    struct Counterparties;
+
+    impl AddressLabels for Counterparties {
+        fn label(&self, address: &str) -> Option<AddressLabel> {
+            address
+                .eq_ignore_ascii_case("0x2222222222222222222222222222222222222222")
+                .then(|| AddressLabel { name: "market maker".into(), kind: AddressKind::Counterparty })
+        }
+    }
+
+    #[test]
+    fn test_client_labels_addresses_with_its_backend() {
+        let client = new_with_default_http("token".to_string(), SupportedNetworks::Ethereum);
+        assert_eq!(
+            client.display_address("0x2222222222222222222222222222222222222222").to_string(),
+            "0x2222222222222222222222222222222222222222"
+        );
+
+        let client = client.with_address_book(Counterparties);
+        assert_eq!(
+            client.label("0x2222222222222222222222222222222222222222").unwrap().kind,
+            AddressKind::Counterparty
+        );
+        assert_eq!(
+            client.display_address("0x2222222222222222222222222222222222222222").to_string(),
+            "market maker (0x2222…2222)"
+        );
+        assert_eq!(client.display_address("0x3333").to_string(), "0x3333");
+    }
+}
@@ -0,0 +1,128 @@
+use crate::common::token::TokenInfo;
+use num_bigint::BigUint;
+use thiserror::Error;
+
+/// Rounding applied when an amount is formatted with fewer fractional digits
+/// than the token has.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum RoundingPolicy {
+    /// Towards zero, so amounts the user receives are never overstated.
+    Floor,
+    /// Away from zero, so amounts the user pays are never understated.
+    Ceil,
+    /// To the nearest value, ties to even (banker's rounding), so rounding
+    /// errors don't accumulate in reports.
+    HalfEven,
+}
+
+/// Role of the displayed amount, which determines its rounding.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum AmountRole {
+    /// Amount the user receives, like `dstAmount` of a quote.
+    Receive,
+    /// Amount the user pays, like the swapped amount or fees.
+    Pay,
+    /// Amount in reports and analytics.
+    Report,
+}
+
+impl AmountRole {
+    /// Returns the conservative rounding policy of the role: floor for
+    /// receive amounts, ceil for pay amounts and banker's for reporting.
+    pub fn rounding(&self) -> RoundingPolicy {
+        match self {
+            AmountRole::Receive => RoundingPolicy::Floor,
+            AmountRole::Pay => RoundingPolicy::Ceil,
+            AmountRole::Report => RoundingPolicy::HalfEven,
+        }
+    }
+}
+
+/// Represents errors that can occur while formatting amounts.
+#[derive(Error, Debug, Eq, PartialEq)]
+pub enum AmountError {
+    /// Amount isn't a non-negative integer in minimal units.
+    #[error("Invalid amount {0}, it should be an integer in minimal units")]
+    InvalidAmount(String),
+}
+
+/// Formats the amount given in minimal units of the token with `decimals` as a
+/// decimal number with exactly `precision` fractional digits, rounded by the
+/// policy.
+///
+/// ```
+/// use one_inch::amount::{format_amount, RoundingPolicy};
+///
+/// assert_eq!(format_amount("1234567", 6, 2, RoundingPolicy::Floor).unwrap(), "1.23");
+/// assert_eq!(format_amount("1234567", 6, 2, RoundingPolicy::Ceil).unwrap(), "1.24");
+/// ```
+pub fn format_amount(amount: &str, decimals: u8, precision: u8, policy: RoundingPolicy) -> Result<String, AmountError> {
+    let raw: BigUint = amount.parse().map_err(|_| AmountError::InvalidAmount(amount.to_string()))?;
+
+    let scaled = match decimals.checked_sub(precision) {
+        // Token has no more digits than requested, so nothing is rounded
+        None | Some(0) => raw * BigUint::from(10u8).pow(u32::from(precision - decimals)),
+        Some(dropped) => {
+            let divisor = BigUint::from(10u8).pow(u32::from(dropped));
+            let (quotient, remainder) = (&raw / &divisor, &raw % &divisor);
+
+            let round_up = match policy {
+                RoundingPolicy::Floor => false,
+                RoundingPolicy::Ceil => remainder != BigUint::default(),
+                RoundingPolicy::HalfEven => {
+                    let doubled = &remainder * 2u8;
+                    doubled > divisor || (doubled == divisor && &quotient % 2u8 == BigUint::from(1u8))
+                }
+            };
+
+            if round_up {
+                quotient + 1u8
+            } else {
+                quotient
+            }
+        }
+    };
+
+    let digits = format!("{:0>width$}", scaled.to_string(), width = usize::from(precision) + 1);
+    let (integer, fraction) = digits.split_at(digits.len() - usize::from(precision));
+
+    match precision {
+        0 => Ok(integer.to_string()),
+        _ => Ok(format!("{}.{}", integer, fraction)),
+    }
+}
+
+/// Formats the amount by the rounding policy of its role. See
+/// [`format_amount`].
+pub fn format_amount_as(amount: &str, decimals: u8, precision: u8, role: AmountRole) -> Result<String, AmountError> {
+    format_amount(amount, decimals, precision, role.rounding())
+}
+
+impl TokenInfo {
+    /// Formats the amount of the token given in minimal units by the rounding
+    /// policy of its role, appending the token symbol.
+    pub fn format_amount(&self, amount: &str, precision: u8, role: AmountRole) -> Result<String, AmountError> {
+        Ok(format!("{} {}", format_amount_as(amount, self.decimals, precision, role)?, self.symbol))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rounding_policies() {
+        assert_eq!(format_amount("1235000", 6, 2, RoundingPolicy::HalfEven).unwrap(), "1.24");
+        assert_eq!(format_amount("1225000", 6, 2, RoundingPolicy::HalfEven).unwrap(), "1.22");
+        assert_eq!(format_amount("1225001", 6, 2, RoundingPolicy::HalfEven).unwrap(), "1.23");
+        assert_eq!(format_amount("1220000", 6, 2, RoundingPolicy::Ceil).unwrap(), "1.22");
+        assert_eq!(format_amount("999", 6, 2, RoundingPolicy::Ceil).unwrap(), "0.01");
+        assert_eq!(format_amount("999", 6, 2, RoundingPolicy::Floor).unwrap(), "0.00");
+        assert_eq!(format_amount("15", 1, 3, RoundingPolicy::Floor).unwrap(), "1.500");
+        assert_eq!(format_amount("1500000", 6, 0, RoundingPolicy::Floor).unwrap(), "1");
+
+        assert_eq!(format_amount_as("1239999", 6, 2, AmountRole::Receive).unwrap(), "1.23");
+        assert_eq!(format_amount_as("1230001", 6, 2, AmountRole::Pay).unwrap(), "1.24");
+        assert!(format_amount("-1", 6, 2, RoundingPolicy::Floor).is_err());
+    }
+}
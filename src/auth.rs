@@ -0,0 +1,384 @@
+use crate::{rate_limit::parse_retry_after, utils::runtime::Instant};
+use futures::future::BoxFuture;
+use reqwest::{Response, StatusCode};
+use serde::{Deserialize, Deserializer};
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+use zeroize::Zeroizing;
+
+/// Error of fetching the API key.
+pub type ApiKeyError = Box<dyn Error + Send + Sync>;
+
+/// String holding a secret, like the API key. It's zeroized on drop, and its
+/// `Debug` and `Display` print `[REDACTED]`, so the secret can't leak into logs
+/// or error messages by accident. It's only readable via
+/// [`expose_secret`](SecretString::expose_secret).
+///
+/// ```
+/// use one_inch::auth::SecretString;
+///
+/// let key = SecretString::new("my-api-key".to_string());
+/// assert_eq!(format!("{:?}", key), "[REDACTED]");
+/// assert_eq!(key.expose_secret(), "my-api-key");
+/// ```
+#[derive(Clone)]
+pub struct SecretString(Zeroizing<String>);
+
+impl SecretString {
+    pub fn new(secret: String) -> SecretString {
+        SecretString(Zeroizing::new(secret))
+    }
+
+    /// Returns the secret itself.
+    pub fn expose_secret(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for SecretString {
+    fn from(secret: String) -> Self {
+        SecretString::new(secret)
+    }
+}
+
+impl From<Zeroizing<String>> for SecretString {
+    fn from(secret: Zeroizing<String>) -> Self {
+        SecretString(secret)
+    }
+}
+
+impl<'de> Deserialize<'de> for SecretString {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(SecretString::new(String::deserialize(deserializer)?))
+    }
+}
+
+impl fmt::Debug for SecretString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
+
+impl fmt::Display for SecretString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
+
+/// Source of the API key for clients which shouldn't keep the plaintext key in
+/// memory for their whole lifetime.
+///
+/// Client requests the key right before every request and zeroizes its copy
+/// once the request is built. Implementations fetching the key from a remote
+/// secret store are expected to cache it in a sealed form.
+pub trait ApiKeyProvider: Send + Sync {
+    /// Returns the current API key.
+    fn api_key(&self) -> BoxFuture<'_, Result<Zeroizing<String>, ApiKeyError>>;
+
+    /// Called when the API rejects the key, e.g. because it has been rotated,
+    /// so provider can drop its cached copy and fetch a fresh one next time.
+    fn invalidate(&self) {}
+}
+
+/// Provider reading the API key from the environment variable on every
+/// request, so the key never stays resident in the client.
+#[derive(Debug, Clone)]
+pub struct EnvApiKeyProvider {
+    var: String,
+}
+
+impl EnvApiKeyProvider {
+    pub fn new(var: impl Into<String>) -> EnvApiKeyProvider {
+        EnvApiKeyProvider { var: var.into() }
+    }
+}
+
+impl ApiKeyProvider for EnvApiKeyProvider {
+    fn api_key(&self) -> BoxFuture<'_, Result<Zeroizing<String>, ApiKeyError>> {
+        Box::pin(async move {
+            let key = std::env::var(&self.var).map_err(|e| format!("Can't read {}: {}", self.var, e))?;
+            Ok(Zeroizing::new(key))
+        })
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+enum KeyState {
+    Active,
+    RateLimited { until: Instant },
+    Rejected { until: Instant },
+}
+
+/// Pool of API keys requests are spread across round-robin, for users running
+/// several keys. Key the API rejects (401) is skipped for the rejection
+/// cooldown (10 minutes by default), so a spurious 401 doesn't take it out
+/// for good, and rate limited key (429) is skipped until its `Retry-After`,
+/// or the cooldown (60s by default) if the API doesn't say.
+///
+/// When every usable key is rate limited, the one released the soonest is
+/// used anyway, so the request gets a 429 of its own rather than an error.
+pub struct ApiKeyPool {
+    keys: Vec<SecretString>,
+    states: Mutex<Vec<KeyState>>,
+    next: AtomicUsize,
+    cooldown: Duration,
+    rejection_cooldown: Duration,
+}
+
+impl ApiKeyPool {
+    pub fn new(keys: impl IntoIterator<Item = String>) -> ApiKeyPool {
+        let keys: Vec<SecretString> = keys.into_iter().map(SecretString::new).collect();
+        let states = Mutex::new(vec![KeyState::Active; keys.len()]);
+
+        ApiKeyPool {
+            keys,
+            states,
+            next: AtomicUsize::new(0),
+            cooldown: Duration::from_secs(60),
+            rejection_cooldown: Duration::from_secs(600),
+        }
+    }
+
+    /// Sets how long rate limited key is skipped when the API doesn't return
+    /// `Retry-After`.
+    pub fn with_cooldown(mut self, cooldown: Duration) -> ApiKeyPool {
+        self.cooldown = cooldown;
+        self
+    }
+
+    /// Sets how long key rejected by the API is skipped before it's tried
+    /// again.
+    pub fn with_rejection_cooldown(mut self, cooldown: Duration) -> ApiKeyPool {
+        self.rejection_cooldown = cooldown;
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Returns amount of keys which are neither rejected nor rate limited.
+    pub fn available(&self) -> usize {
+        let now = Instant::now();
+        self.states.lock().unwrap().iter().filter(|state| is_usable(state, now)).count()
+    }
+
+    // Picks the next usable key round-robin, returning its index too.
+    fn pick(&self) -> Result<(usize, SecretString), ApiKeyError> {
+        let states = self.states.lock().unwrap();
+        let now = Instant::now();
+        let start = self.next.fetch_add(1, Ordering::Relaxed);
+
+        let index = (0..self.keys.len())
+            .map(|offset| (start + offset) % self.keys.len())
+            .find(|index| is_usable(&states[*index], now))
+            .or_else(|| {
+                // Every key is either rejected or rate limited
+                (0..self.keys.len())
+                    .filter_map(|index| match states[index] {
+                        KeyState::RateLimited { until } => Some((until, index)),
+                        _ => None,
+                    })
+                    .min()
+                    .map(|(_, index)| index)
+            })
+            .ok_or("Every API key of the pool was rejected")?;
+
+        Ok((index, self.keys[index].clone()))
+    }
+
+    fn report(&self, index: usize, response: &Response) {
+        let state = match response.status() {
+            StatusCode::UNAUTHORIZED => KeyState::Rejected { until: Instant::now() + self.rejection_cooldown },
+            StatusCode::TOO_MANY_REQUESTS => {
+                let cooldown = parse_retry_after(response.headers()).unwrap_or(self.cooldown);
+                KeyState::RateLimited { until: Instant::now() + cooldown }
+            }
+            _ => return,
+        };
+
+        tracing::warn!(key = index, ?state, "API key of the pool is skipped");
+        self.states.lock().unwrap()[index] = state;
+    }
+}
+
+impl fmt::Debug for ApiKeyPool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ApiKeyPool")
+            .field("keys", &self.keys)
+            .field("states", &self.states)
+            .field("cooldown", &self.cooldown)
+            .field("rejection_cooldown", &self.rejection_cooldown)
+            .finish()
+    }
+}
+
+fn is_usable(state: &KeyState, now: Instant) -> bool {
+    match state {
+        KeyState::Active => true,
+        KeyState::RateLimited { until } | KeyState::Rejected { until } => *until <= now,
+    }
+}
+
+// API key the client authorizes requests with.
+#[derive(Clone)]
+pub(crate) enum Credentials {
+    /// Key provided at construction, zeroized once the last clone of the
+    /// client is dropped.
+    Static(Arc<SecretString>),
+    /// Key fetched from the provider before every request.
+    Provider(Arc<dyn ApiKeyProvider>),
+    /// Keys rotated round-robin.
+    Pool(Arc<ApiKeyPool>),
+}
+
+// Key picked for the request, with its index in the pool if it's from one.
+pub(crate) struct KeyLease {
+    pub(crate) key: SecretString,
+    pub(crate) slot: Option<usize>,
+}
+
+impl Credentials {
+    pub(crate) async fn lease(&self) -> Result<KeyLease, ApiKeyError> {
+        match self {
+            Credentials::Static(key) => Ok(KeyLease { key: SecretString::clone(key), slot: None }),
+            Credentials::Provider(provider) => Ok(KeyLease { key: provider.api_key().await?.into(), slot: None }),
+            Credentials::Pool(pool) => pool.pick().map(|(slot, key)| KeyLease { key, slot: Some(slot) }),
+        }
+    }
+
+    pub(crate) fn invalidate(&self) {
+        if let Credentials::Provider(provider) = self {
+            provider.invalidate();
+        }
+    }
+
+    // Lets the credentials react on the response to the request authorized
+    // with the key of the lease slot: provider drops rejected key, pool skips
+    // rejected and rate limited ones.
+    pub(crate) fn report(&self, slot: Option<usize>, response: &Response) {
+        if response.status() == StatusCode::UNAUTHORIZED {
+            self.invalidate();
+        }
+        if let (Credentials::Pool(pool), Some(slot)) = (self, slot) {
+            pool.report(slot, response);
+        }
+    }
+}
+
+impl fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Credentials::Static(key) => f.debug_tuple("Static").field(key).finish(),
+            Credentials::Provider(_) => f.debug_tuple("Provider").finish_non_exhaustive(),
+            Credentials::Pool(pool) => f.debug_tuple("Pool").field(pool).finish(),
+        }
+    }
+}
+
+// API keys of the client: the default one and overrides for the products
+// whose key entitlements differ. Product is the first segment of the endpoint
+// name, like `swap` in `swap/quote`.
+#[derive(Clone, Debug)]
+pub(crate) struct KeyRouting {
+    default: Credentials,
+    products: HashMap<String, Credentials>,
+}
+
+impl KeyRouting {
+    pub(crate) fn new(default: Credentials) -> KeyRouting {
+        KeyRouting { default, products: HashMap::new() }
+    }
+
+    pub(crate) fn set_product(&mut self, product: String, credentials: Credentials) {
+        self.products.insert(product, credentials);
+    }
+
+    pub(crate) fn for_endpoint(&self, endpoint: &str) -> &Credentials {
+        let product = endpoint.split('/').next().unwrap_or(endpoint);
+        self.products.get(product).unwrap_or(&self.default)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct CountingProvider {
+        invalidations: AtomicUsize,
+    }
+
+    impl ApiKeyProvider for CountingProvider {
+        fn api_key(&self) -> BoxFuture<'_, Result<Zeroizing<String>, ApiKeyError>> {
+            let invalidations = self.invalidations.load(Ordering::SeqCst);
+            Box::pin(async move { Ok(Zeroizing::new(format!("key-{}", invalidations))) })
+        }
+
+        fn invalidate(&self) {
+            self.invalidations.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_provider_key_is_refetched_after_invalidation() {
+        let credentials = Credentials::Provider(Arc::new(CountingProvider { invalidations: AtomicUsize::new(0) }));
+
+        assert_eq!(credentials.lease().await.unwrap().key.expose_secret(), "key-0");
+        credentials.invalidate();
+        assert_eq!(credentials.lease().await.unwrap().key.expose_secret(), "key-1");
+
+        let missing = Credentials::Provider(Arc::new(EnvApiKeyProvider::new("ONE_INCH_TEST_MISSING_KEY")));
+        assert!(missing.lease().await.is_err());
+    }
+
+    #[test]
+    fn test_pool_rotates_and_skips_rejected_keys() {
+        let pool = ApiKeyPool::new(["a".to_string(), "b".to_string(), "c".to_string()]);
+        let keys: Vec<String> = (0..4).map(|_| pool.pick().unwrap().1.expose_secret().to_string()).collect();
+        assert_eq!(keys, vec!["a", "b", "c", "a"]);
+
+        let rejected = KeyState::Rejected { until: Instant::now() + Duration::from_secs(600) };
+        pool.states.lock().unwrap()[1] = rejected;
+        pool.states.lock().unwrap()[2] = KeyState::RateLimited { until: Instant::now() + Duration::from_secs(60) };
+        assert_eq!(pool.available(), 1);
+        assert!((0..3).all(|_| pool.pick().unwrap().1.expose_secret() == "a"));
+
+        // Rate limited key is still better than none
+        pool.states.lock().unwrap()[0] = rejected;
+        assert_eq!(pool.pick().unwrap().1.expose_secret(), "c");
+        pool.states.lock().unwrap()[2] = rejected;
+        assert!(pool.pick().is_err());
+
+        // Rejected key is tried again after the cooldown
+        pool.states.lock().unwrap()[1] = KeyState::Rejected { until: Instant::now() };
+        assert_eq!(pool.pick().unwrap().1.expose_secret(), "b");
+    }
+
+    #[tokio::test]
+    async fn test_key_is_routed_by_product() {
+        let key = |key: &str| Credentials::Static(Arc::new(SecretString::new(key.to_string())));
+
+        let mut routing = KeyRouting::new(key("default"));
+        routing.set_product("portfolio".to_string(), key("portfolio"));
+
+        assert_eq!(
+            routing.for_endpoint("portfolio/overview").lease().await.unwrap().key.expose_secret(),
+            "portfolio"
+        );
+        assert_eq!(routing.for_endpoint("swap/quote").lease().await.unwrap().key.expose_secret(), "default");
+        assert_eq!(routing.for_endpoint("web3").lease().await.unwrap().key.expose_secret(), "default");
+    }
+}
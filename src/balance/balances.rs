@@ -0,0 +1,18 @@
+use crate::{balance::WalletBalancesResponse, client::OneInchClient, consts::BALANCE_API_VERSION, error::OneInchError};
+
+impl OneInchClient {
+    /// Performs request to get balances of all the tokens of the wallet.
+    pub async fn get_wallet_balances(&self, address: String) -> Result<WalletBalancesResponse, OneInchError> {
+        let url = format!(
+            "{}/balance/{}/{}/balances/{}",
+            self.base_url(),
+            BALANCE_API_VERSION,
+            self.network_id,
+            address
+        );
+
+        let balances: WalletBalancesResponse = self.request_json("balance/balances", self.http_client.get(url)).await?;
+
+        Ok(balances)
+    }
+}
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Represents the struct we receive after requesting balances of the wallet.
+/// Response is a hashmap where key is token's address (lowercase) and value is
+/// its balance in minimal units.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct WalletBalancesResponse {
+    #[serde(flatten)]
+    pub balances: HashMap<String, String>,
+}
+
+impl WalletBalancesResponse {
+    /// Returns balances which aren't zero.
+    pub fn non_zero(&self) -> impl Iterator<Item = (&String, &String)> {
+        self.balances.iter().filter(|(_, balance)| balance.as_str() != "0")
+    }
+}
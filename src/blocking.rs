@@ -0,0 +1,100 @@
+use crate::{
+    client::OneInchClient,
+    error::OneInchError,
+    swap::{
+        approve::{AllowanceDetails, AllowanceResponse, ApproveCallData, ApproveTranactionDetails, RouterAddress},
+        QuoteDetails, QuoteResponse, SwapDetails, SwapDetailsV6, SwapResponse, SwapV6Response,
+    },
+};
+use std::future::Future;
+use tokio::runtime::{Builder, Runtime};
+
+/// Synchronous wrapper of [`OneInchClient`] for CLI tools and scripts, which
+/// don't want to run an async runtime themselves. It drives the async client
+/// on its own single-threaded runtime, so every call blocks the current
+/// thread until the response is received.
+///
+/// Calls panic if made from within an async runtime, use [`OneInchClient`]
+/// there instead.
+///
+/// ```no_run
+/// use one_inch::{blocking::OneInchBlockingClient, client::{new_with_default_http, SupportedNetworks}};
+///
+/// let client = OneInchBlockingClient::new(new_with_default_http("token".to_string(), SupportedNetworks::Ethereum)).unwrap();
+/// let spender = client.get_router_address().unwrap();
+/// ```
+pub struct OneInchBlockingClient {
+    inner: OneInchClient,
+    runtime: Runtime,
+}
+
+impl OneInchBlockingClient {
+    /// Wraps the client, configured as usual, into the blocking one.
+    pub fn new(inner: OneInchClient) -> Result<OneInchBlockingClient, std::io::Error> {
+        let runtime = Builder::new_current_thread().enable_all().build()?;
+        Ok(OneInchBlockingClient { inner, runtime })
+    }
+
+    /// Returns the wrapped async client.
+    pub fn inner(&self) -> &OneInchClient {
+        &self.inner
+    }
+
+    fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// Performs `quote` request, see [`OneInchClient::quote`].
+    pub fn quote(&self, details: QuoteDetails) -> Result<QuoteResponse, OneInchError> {
+        self.block_on(self.inner.quote(details))
+    }
+
+    /// Performs swap request, see [`OneInchClient::swap`].
+    pub fn swap(&self, details: SwapDetails) -> Result<SwapResponse, OneInchError> {
+        self.block_on(self.inner.swap(details))
+    }
+
+    /// Performs swap request, see [`OneInchClient::swap_v6`].
+    pub fn swap_v6(&self, details: SwapDetailsV6) -> Result<SwapV6Response, OneInchError> {
+        self.block_on(self.inner.swap_v6(details))
+    }
+
+    /// Retrieves approve transaction, see [`OneInchClient::approve`].
+    pub fn approve(&self, details: ApproveTranactionDetails) -> Result<ApproveCallData, OneInchError> {
+        self.block_on(self.inner.approve(details))
+    }
+
+    /// Retrieves allowance, see [`OneInchClient::get_allowance`].
+    pub fn get_allowance(&self, details: AllowanceDetails) -> Result<AllowanceResponse, OneInchError> {
+        self.block_on(self.inner.get_allowance(details))
+    }
+
+    /// Retrieves the router address, see
+    /// [`OneInchClient::get_router_address`].
+    pub fn get_router_address(&self) -> Result<RouterAddress, OneInchError> {
+        self.block_on(self.inner.get_router_address())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::{new_with_base_url, SupportedNetworks},
+        utils::mock_server::MockServer,
+    };
+
+    #[test]
+    fn test_blocking_client_without_caller_runtime() {
+        // Mock server needs a runtime of its own, the client doesn't
+        let server_runtime = Builder::new_multi_thread().worker_threads(1).enable_all().build().unwrap();
+        let server = server_runtime.block_on(MockServer::start(r#"{"address": "0x1111111111111111111111111111111111111111"}"#));
+
+        let client =
+            OneInchBlockingClient::new(new_with_base_url("token".to_string(), SupportedNetworks::Ethereum, &server.base_url))
+                .unwrap();
+
+        assert_eq!(client.get_router_address().unwrap().address, "0x1111111111111111111111111111111111111111");
+        assert!(server.requests()[0].starts_with("/swap/v5.2/1/approve/spender"));
+    }
+}
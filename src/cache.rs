@@ -0,0 +1,229 @@
+use crate::utils::runtime::Instant;
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+// Cached body and the time it was received at.
+type Entry = (Instant, Arc<Vec<u8>>);
+
+/// In-memory cache of the responses of slow-changing endpoints, shared by all
+/// clones of the client, so many tasks asking for the same data don't hammer
+/// the API.
+///
+/// Only endpoints with a TTL are cached. Responses are keyed by the endpoint
+/// and the full request URL, which includes the chain and the parameters.
+/// Error responses are never cached.
+///
+/// Endpoints can be cached in stale-while-revalidate mode: for the max
+/// staleness after the TTL expires, the stale response is served immediately
+/// while a single background request refreshes it, so callers with latency
+/// budgets never wait for the API, and bursts of calls don't turn into bursts
+/// of requests.
+///
+/// ```
+/// use one_inch::cache::ResponseCache;
+/// use std::time::Duration;
+///
+/// let cache = ResponseCache::slow_endpoints(Duration::from_secs(300))
+///     .endpoint_ttl("swap/approve/spender", Duration::from_secs(3600))
+///     .endpoint_ttl("price/prices", Duration::from_secs(5))
+///     .stale_while_revalidate("price/prices", Duration::from_secs(30));
+/// assert_eq!(cache.ttl_for("swap/tokens"), Some(Duration::from_secs(300)));
+/// assert_eq!(cache.ttl_for("swap/quote"), None);
+/// assert_eq!(cache.max_staleness_for("price/prices"), Some(Duration::from_secs(30)));
+/// ```
+#[derive(Debug, Default)]
+pub struct ResponseCache {
+    ttls: HashMap<String, Duration>,
+    max_staleness: HashMap<String, Duration>,
+    entries: Mutex<HashMap<String, Entry>>,
+    // Keys of the stale entries being refreshed
+    revalidating: Mutex<HashSet<String>>,
+}
+
+// Cached response found for the request.
+pub(crate) enum CacheLookup {
+    Fresh(Arc<Vec<u8>>),
+    // Stale response to serve, and whether the caller should refresh it
+    Stale(Arc<Vec<u8>>, bool),
+    Miss,
+}
+
+impl ResponseCache {
+    /// Creates cache with no endpoints cached.
+    pub fn new() -> ResponseCache {
+        ResponseCache::default()
+    }
+
+    /// Creates cache of the endpoints whose data rarely changes: tokens list,
+    /// liquidity sources and the router address.
+    pub fn slow_endpoints(ttl: Duration) -> ResponseCache {
+        ["swap/tokens", "swap/liquidity-sources", "swap/approve/spender"]
+            .into_iter()
+            .fold(ResponseCache::new(), |cache, endpoint| cache.endpoint_ttl(endpoint, ttl))
+    }
+
+    /// Caches responses of the endpoint for the TTL.
+    pub fn endpoint_ttl(mut self, endpoint: impl Into<String>, ttl: Duration) -> ResponseCache {
+        self.ttls.insert(endpoint.into(), ttl);
+        self
+    }
+
+    /// Serves responses of the endpoint for up to `max_staleness` after
+    /// their TTL expires, refreshing them in the background. Endpoint needs a
+    /// TTL to be cached at all.
+    pub fn stale_while_revalidate(mut self, endpoint: impl Into<String>, max_staleness: Duration) -> ResponseCache {
+        self.max_staleness.insert(endpoint.into(), max_staleness);
+        self
+    }
+
+    /// Returns TTL of the endpoint's responses, `None` if they aren't cached.
+    pub fn ttl_for(&self, endpoint: &str) -> Option<Duration> {
+        self.ttls.get(endpoint).copied()
+    }
+
+    /// Returns how long the endpoint's responses are served after their TTL,
+    /// `None` if they aren't served stale.
+    pub fn max_staleness_for(&self, endpoint: &str) -> Option<Duration> {
+        self.max_staleness.get(endpoint).copied()
+    }
+
+    /// Removes all the cached responses.
+    pub fn clear(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+
+    /// Removes expired responses, including the stale ones past their max
+    /// staleness.
+    pub fn purge_expired(&self) {
+        self.entries.lock().unwrap().retain(|key, (cached_at, _)| {
+            let endpoint = key.split_once(' ').map_or(key.as_str(), |(endpoint, _)| endpoint);
+            self.ttl_for(endpoint).is_some_and(|ttl| cached_at.elapsed() < ttl + self.staleness(endpoint))
+        });
+    }
+
+    pub(crate) fn get(&self, endpoint: &str, url: &str) -> CacheLookup {
+        let Some(ttl) = self.ttl_for(endpoint) else {
+            return CacheLookup::Miss;
+        };
+        let key = key(endpoint, url);
+        let Some((cached_at, body)) = self.entries.lock().unwrap().get(&key).cloned() else {
+            return CacheLookup::Miss;
+        };
+
+        let age = cached_at.elapsed();
+        if age < ttl {
+            CacheLookup::Fresh(body)
+        } else if age < ttl + self.staleness(endpoint) {
+            let revalidate = self.revalidating.lock().unwrap().insert(key);
+            CacheLookup::Stale(body, revalidate)
+        } else {
+            CacheLookup::Miss
+        }
+    }
+
+    pub(crate) fn insert(&self, endpoint: &str, url: &str, body: Arc<Vec<u8>>) {
+        if self.ttls.contains_key(endpoint) {
+            self.entries.lock().unwrap().insert(key(endpoint, url), (Instant::now(), body));
+        }
+    }
+
+    // Returns guard marking refresh of the stale response as finished,
+    // successfully or not, once dropped. Dropping it along with the task
+    // covers tasks which are cancelled or never run.
+    pub(crate) fn revalidation(self: &Arc<Self>, endpoint: &str, url: &str) -> Revalidation {
+        Revalidation { cache: self.clone(), key: key(endpoint, url) }
+    }
+
+    fn staleness(&self, endpoint: &str) -> Duration {
+        self.max_staleness_for(endpoint).unwrap_or_default()
+    }
+}
+
+// Refresh of the stale response in progress, see `ResponseCache::revalidation`.
+pub(crate) struct Revalidation {
+    cache: Arc<ResponseCache>,
+    key: String,
+}
+
+impl Drop for Revalidation {
+    fn drop(&mut self) {
+        self.cache.revalidating.lock().unwrap().remove(&self.key);
+    }
+}
+
+fn key(endpoint: &str, url: &str) -> String {
+    format!("{} {}", endpoint, url)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::{new_with_base_url, SupportedNetworks},
+        utils::mock_server::MockServer,
+    };
+
+    #[tokio::test]
+    async fn test_slow_endpoints_are_cached_per_chain() {
+        let server = MockServer::start(r#"{"address": "0x1111111111111111111111111111111111111111"}"#).await;
+        let client = new_with_base_url("token".to_string(), SupportedNetworks::Ethereum, &server.base_url)
+            .with_response_cache(ResponseCache::slow_endpoints(Duration::from_secs(60)));
+
+        client.get_router_address().await.unwrap();
+        client.get_router_address().await.unwrap();
+        client.with_chain(SupportedNetworks::Base).get_router_address().await.unwrap();
+        assert_eq!(server.requests().len(), 2);
+
+        client.response_cache().unwrap().clear();
+        client.get_router_address().await.unwrap();
+        assert_eq!(server.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_stale_responses_are_revalidated_in_background() {
+        let server = MockServer::start(r#"{"address": "0x1111111111111111111111111111111111111111"}"#).await;
+        let cache = ResponseCache::new()
+            .endpoint_ttl("swap/approve/spender", Duration::ZERO)
+            .stale_while_revalidate("swap/approve/spender", Duration::from_secs(60));
+        let client =
+            new_with_base_url("token".to_string(), SupportedNetworks::Ethereum, &server.base_url).with_response_cache(cache);
+
+        client.get_router_address().await.unwrap();
+        assert_eq!(server.requests().len(), 1);
+
+        // Burst of calls is served stale, with a single refresh
+        for _ in 0..5 {
+            client.get_router_address().await.unwrap();
+        }
+        for _ in 0..100 {
+            if client.response_cache().unwrap().revalidating.lock().unwrap().is_empty() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert_eq!(server.requests().len(), 2);
+
+        let cache = client.response_cache().unwrap();
+        assert!(matches!(cache.get("swap/approve/spender", "unknown"), CacheLookup::Miss));
+    }
+
+    #[test]
+    fn test_revalidation_which_never_ran_is_released() {
+        let cache = Arc::new(
+            ResponseCache::new()
+                .endpoint_ttl("swap/approve/spender", Duration::ZERO)
+                .stale_while_revalidate("swap/approve/spender", Duration::from_secs(60)),
+        );
+        cache.insert("swap/approve/spender", "url", Arc::new(b"{}".to_vec()));
+
+        assert!(matches!(cache.get("swap/approve/spender", "url"), CacheLookup::Stale(_, true)));
+        assert!(matches!(cache.get("swap/approve/spender", "url"), CacheLookup::Stale(_, false)));
+
+        // Task dropped without running, like when there is no runtime
+        drop(cache.revalidation("swap/approve/spender", "url"));
+        assert!(matches!(cache.get("swap/approve/spender", "url"), CacheLookup::Stale(_, true)));
+    }
+}
@@ -0,0 +1,196 @@
+use crate::{
+    client::OneInchClient,
+    error::OneInchError,
+    swap::{QuoteDetails, QuoteResponse, SwapDetailsV6, SwapV6Response},
+    utils::runtime::{self, Instant},
+};
+use futures::{
+    channel::oneshot,
+    future::{select, Either, FutureExt, Shared},
+};
+use std::{
+    fmt,
+    future::Future,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+/// Point in time after which the result of a call isn't needed anymore.
+/// Unlike [`Timeouts`](crate::timeout::Timeouts), deadline covers the whole
+/// call, including retries and waiting for the rate limiter.
+///
+/// ```
+/// use one_inch::cancel::Deadline;
+/// use std::time::Duration;
+///
+/// let deadline = Deadline::after(Duration::from_millis(300));
+/// assert!(!deadline.is_expired());
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Deadline(Instant);
+
+impl Deadline {
+    /// Returns deadline the duration from now.
+    pub fn after(duration: Duration) -> Deadline {
+        Deadline(Instant::now() + duration)
+    }
+
+    /// Returns time left until the deadline, zero if it has passed.
+    pub fn remaining(&self) -> Duration {
+        self.0.saturating_duration_since(Instant::now())
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.remaining().is_zero()
+    }
+
+    /// Runs the call until the deadline, dropping it (and aborting its HTTP
+    /// request) with [`OneInchError::DeadlineExceeded`] once it passes.
+    pub async fn run<T, E, F>(&self, call: F) -> Result<T, E>
+    where
+        F: Future<Output = Result<T, E>>,
+        E: From<OneInchError>,
+    {
+        if self.is_expired() {
+            return Err(OneInchError::DeadlineExceeded.into());
+        }
+        runtime::timeout(self.remaining(), call).await.map_err(|_| OneInchError::DeadlineExceeded)?
+    }
+}
+
+/// Token a trading loop cancels to abandon the calls which are no longer
+/// relevant, like the quote of a stale price. Clones share the state, so
+/// cancelling any of them cancels the calls run with all of them.
+#[derive(Clone)]
+pub struct CancellationToken {
+    sender: Arc<Mutex<Option<oneshot::Sender<()>>>>,
+    cancelled: Shared<oneshot::Receiver<()>>,
+}
+
+impl CancellationToken {
+    pub fn new() -> CancellationToken {
+        let (sender, receiver) = oneshot::channel();
+        CancellationToken { sender: Arc::new(Mutex::new(Some(sender))), cancelled: receiver.shared() }
+    }
+
+    /// Cancels the calls run with the token, now and in the future.
+    pub fn cancel(&self) {
+        // Dropped sender completes the receiver
+        self.sender.lock().unwrap().take();
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.sender.lock().unwrap().is_none()
+    }
+
+    /// Completes once the token is cancelled.
+    pub async fn cancelled(&self) {
+        let _ = self.cancelled.clone().await;
+    }
+
+    /// Runs the call until the token is cancelled, dropping it (and aborting
+    /// its HTTP request) with [`OneInchError::Cancelled`] then.
+    pub async fn run<T, E, F>(&self, call: F) -> Result<T, E>
+    where
+        F: Future<Output = Result<T, E>>,
+        E: From<OneInchError>,
+    {
+        if self.is_cancelled() {
+            return Err(OneInchError::Cancelled.into());
+        }
+        match select(Box::pin(call), Box::pin(self.cancelled())).await {
+            Either::Left((result, _)) => result,
+            Either::Right(_) => Err(OneInchError::Cancelled.into()),
+        }
+    }
+}
+
+impl Default for CancellationToken {
+    fn default() -> Self {
+        CancellationToken::new()
+    }
+}
+
+impl fmt::Debug for CancellationToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CancellationToken").field("cancelled", &self.is_cancelled()).finish()
+    }
+}
+
+impl OneInchClient {
+    /// Performs `quote` request, abandoning it once the deadline passes.
+    pub async fn quote_with_deadline(&self, details: QuoteDetails, deadline: Deadline) -> Result<QuoteResponse, OneInchError> {
+        deadline.run(self.quote(details)).await
+    }
+
+    /// Performs `swap` request of the v6 API, abandoning it once the deadline
+    /// passes.
+    pub async fn swap_with_deadline(&self, details: SwapDetailsV6, deadline: Deadline) -> Result<SwapV6Response, OneInchError> {
+        deadline.run(self.swap_v6(details)).await
+    }
+
+    /// Performs `quote` request, abandoning it once the token is cancelled.
+    pub async fn quote_cancellable(
+        &self,
+        details: QuoteDetails,
+        token: &CancellationToken,
+    ) -> Result<QuoteResponse, OneInchError> {
+        token.run(self.quote(details)).await
+    }
+
+    /// Performs `swap` request of the v6 API, abandoning it once the token is
+    /// cancelled.
+    pub async fn swap_cancellable(
+        &self,
+        details: SwapDetailsV6,
+        token: &CancellationToken,
+    ) -> Result<SwapV6Response, OneInchError> {
+        token.run(self.swap_v6(details)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::{new_with_base_url, new_with_default_http, SupportedNetworks},
+        swap::QuoteDetailsBuilder,
+        utils::mock_server::MockServer,
+    };
+
+    fn details() -> QuoteDetails {
+        QuoteDetailsBuilder::new().src("0xa".into()).dst("0xb".into()).amount("1000".into()).build().unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_calls_are_abandoned() {
+        let client = new_with_default_http("token".into(), SupportedNetworks::Ethereum);
+
+        let token = CancellationToken::new();
+        token.clone().cancel();
+        assert!(matches!(client.quote_cancellable(details(), &token).await, Err(OneInchError::Cancelled)));
+
+        let expired = Deadline::after(Duration::ZERO);
+        assert!(matches!(
+            client.quote_with_deadline(details(), expired).await,
+            Err(OneInchError::DeadlineExceeded)
+        ));
+
+        let token = CancellationToken::new();
+        let pending = token.run(async {
+            futures::future::pending::<()>().await;
+            Ok::<_, OneInchError>(())
+        });
+        let (result, _) = futures::join!(pending, async { token.cancel() });
+        assert!(matches!(result, Err(OneInchError::Cancelled)));
+
+        let server = MockServer::start(r#"{"toAmount": "42"}"#).await;
+        let client = new_with_base_url("token".into(), SupportedNetworks::Ethereum, &server.base_url);
+        let deadline = Deadline::after(Duration::from_secs(10));
+        assert_eq!(client.quote_with_deadline(details(), deadline).await.unwrap().to_amount, "42");
+        assert_eq!(
+            client.quote_cancellable(details(), &CancellationToken::new()).await.unwrap().to_amount,
+            "42"
+        );
+    }
+}
@@ -0,0 +1,280 @@
+use crate::{
+    client::{OneInchClient, SupportedNetworks},
+    consts::{
+        BALANCE_API_VERSION, CHARTS_API_VERSION, DOMAINS_API_VERSION, FUSION_API_VERSION, FUSION_PLUS_API_VERSION,
+        GAS_PRICE_API_VERSION, HISTORY_API_VERSION, NFT_API_VERSION, ORDERBOOK_API_VERSION, PORTFOLIO_API_VERSION,
+        SPOT_PRICE_API_VERSION, SWAP_API_VERSION, TOKENS_API_VERSION, TOKEN_DETAILS_API_VERSION, TRACES_API_VERSION,
+    },
+    swap::NATIVE_TOKEN_ADDRESS,
+    utils::runtime::Instant,
+};
+use reqwest::StatusCode;
+use std::{collections::HashMap, sync::Mutex, time::Duration};
+
+// Address used in probe requests which need one.
+const PROBE_ADDRESS: &str = "0x0000000000000000000000000000000000000000";
+
+// How long failed probes are remembered before being retried.
+const PROBE_FAILURE_TTL: Duration = Duration::from_secs(60);
+
+/// Products (first segments of the endpoint paths) the crate has modules for.
+pub const COMPILED_PRODUCTS: &[&str] = &[
+    "swap",
+    "price",
+    "gas-price",
+    "orderbook",
+    "fusion",
+    "fusion-plus",
+    "balance",
+    "history",
+    "traces",
+    "nft",
+    "domains",
+    "token",
+    "token-details",
+    "tx-gateway",
+    "charts",
+    "portfolio",
+    "web3",
+];
+
+/// Access of the API key to the product.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ProductAccess {
+    /// API key is accepted by the product.
+    Granted,
+    /// API key is rejected by the product.
+    Denied,
+    /// Product isn't available in the client's environment, so it hasn't
+    /// been probed.
+    Unavailable,
+    /// Product isn't known to the crate, or can't be probed without side
+    /// effects.
+    Unknown,
+    /// Probe failed for a reason other than authorization, so access is
+    /// unknown.
+    ProbeFailed(String),
+}
+
+/// Description of what the client is able to do: products compiled into the
+/// crate, chains it's configured for and products its API key has access to.
+///
+/// Access is probed lazily, with a cheap request to the product on the first
+/// [`access`](Capabilities::access) call, and remembered afterwards. Failed
+/// probes are remembered for a minute, so they aren't repeated on every call.
+pub struct Capabilities {
+    client: OneInchClient,
+    // Access along with the time it was probed at
+    access: Mutex<HashMap<&'static str, (ProductAccess, Instant)>>,
+}
+
+impl OneInchClient {
+    /// Returns capabilities of the client. See
+    /// [`Capabilities`](crate::capabilities::Capabilities).
+    pub fn capabilities(&self) -> Capabilities {
+        Capabilities { client: self.clone(), access: Mutex::new(HashMap::new()) }
+    }
+}
+
+impl Capabilities {
+    /// Returns products the crate has modules for.
+    pub fn compiled_products(&self) -> &'static [&'static str] {
+        COMPILED_PRODUCTS
+    }
+
+    /// Returns chains the client is configured for.
+    pub fn chains(&self) -> Vec<SupportedNetworks> {
+        vec![self.client.network_id]
+    }
+
+    /// Returns name of the environment the client works with.
+    pub fn environment(&self) -> &str {
+        self.client.environment().name()
+    }
+
+    /// Returns access of the API key to the product, probing it on the first
+    /// call.
+    pub async fn access(&self, product: &str) -> ProductAccess {
+        let Some(product) = COMPILED_PRODUCTS.iter().copied().find(|p| *p == product) else {
+            return ProductAccess::Unknown;
+        };
+
+        if let Some((access, probed_at)) = self.access.lock().unwrap().get(product) {
+            if !matches!(access, ProductAccess::ProbeFailed(_)) || probed_at.elapsed() < PROBE_FAILURE_TTL {
+                return access.clone();
+            }
+        }
+
+        let access = self.probe(product).await;
+        self.access.lock().unwrap().insert(product, (access.clone(), Instant::now()));
+        access
+    }
+
+    /// Probes access to every compiled product.
+    pub async fn access_all(&self) -> HashMap<&'static str, ProductAccess> {
+        let mut access = HashMap::new();
+        for product in COMPILED_PRODUCTS {
+            access.insert(*product, self.access(product).await);
+        }
+        access
+    }
+
+    async fn probe(&self, product: &'static str) -> ProductAccess {
+        let client = &self.client;
+        if !client.environment().is_available(product) {
+            return ProductAccess::Unavailable;
+        }
+
+        let base_url = client.base_url();
+        let chain = client.network_id;
+        let request = match product {
+            "swap" => client.http_client.get(format!("{}/swap/{}/{}/liquidity-sources", base_url, SWAP_API_VERSION, chain)),
+            "price" => client.http_client.get(format!("{}/price/{}/{}/currencies", base_url, SPOT_PRICE_API_VERSION, chain)),
+            "gas-price" => client.http_client.get(format!("{}/gas-price/{}/{}", base_url, GAS_PRICE_API_VERSION, chain)),
+            "orderbook" => {
+                client.http_client.get(format!("{}/orderbook/{}/{}/all?limit=1", base_url, ORDERBOOK_API_VERSION, chain))
+            }
+            "fusion" => client.http_client.get(format!(
+                "{}/fusion/orders/{}/{}/order/active?limit=1",
+                base_url, FUSION_API_VERSION, chain
+            )),
+            "fusion-plus" => client.http_client.get(format!(
+                "{}/fusion-plus/orders/{}/order/active?limit=1",
+                base_url, FUSION_PLUS_API_VERSION
+            )),
+            "balance" => client.http_client.get(format!(
+                "{}/balance/{}/{}/balances/{}",
+                base_url, BALANCE_API_VERSION, chain, PROBE_ADDRESS
+            )),
+            "history" => client.http_client.get(format!(
+                "{}/history/{}/history/{}/events?chainId={}&limit=1",
+                base_url, HISTORY_API_VERSION, PROBE_ADDRESS, chain
+            )),
+            "traces" => {
+                client.http_client.get(format!("{}/traces/{}/chain/{}/synced-interval", base_url, TRACES_API_VERSION, chain))
+            }
+            "nft" => client.http_client.get(format!(
+                "{}/nft/{}/byaddress?chainIds={}&address={}&limit=1",
+                base_url, NFT_API_VERSION, chain, PROBE_ADDRESS
+            )),
+            "domains" => client.http_client.get(format!(
+                "{}/domains/{}/reverse-lookup?address={}",
+                base_url, DOMAINS_API_VERSION, PROBE_ADDRESS
+            )),
+            "token" => {
+                client.http_client.get(format!("{}/token/{}/{}/search?query=1inch&limit=1", base_url, TOKENS_API_VERSION, chain))
+            }
+            "token-details" => client.http_client.get(format!(
+                "{}/token-details/{}/details/{}/{}",
+                base_url,
+                TOKEN_DETAILS_API_VERSION,
+                chain,
+                wrapped_native(chain)
+            )),
+            // Only endpoint of the product broadcasts transactions, so it
+            // can't be probed
+            "tx-gateway" => return ProductAccess::Unknown,
+            "charts" => client.http_client.get(format!(
+                "{}/charts/{}/chart/aggregated/candle/{}/{}/86400/{}",
+                base_url,
+                CHARTS_API_VERSION,
+                wrapped_native(chain),
+                NATIVE_TOKEN_ADDRESS,
+                chain
+            )),
+            "portfolio" => client.http_client.get(format!(
+                "{}/portfolio/portfolio/{}/general/supported_chains",
+                base_url, PORTFOLIO_API_VERSION
+            )),
+            "web3" => client.http_client.post(format!("{}/web3/{}", base_url, chain)).json(&serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "method": "eth_blockNumber",
+                "params": []
+            })),
+            _ => return ProductAccess::Unknown,
+        };
+
+        // Probe is sent as the product's endpoint, so the product's key is used
+        match client.send(product, request).await {
+            Ok(response) => access_by_status(response.status()),
+            Err(e) => ProductAccess::ProbeFailed(e.to_string()),
+        }
+    }
+}
+
+// Returns address of the wrapped native token of the chain, which is known
+// to the APIs probed with a token.
+fn wrapped_native(chain: SupportedNetworks) -> &'static str {
+    match chain {
+        SupportedNetworks::Ethereum => "0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2",
+        SupportedNetworks::Optimism | SupportedNetworks::Base => "0x4200000000000000000000000000000000000006",
+        SupportedNetworks::BSC => "0xbb4cdb9cbd36b01bd1cbaebf2de08d9173bc095c",
+        SupportedNetworks::Gnosis => "0xe91d153e0b41518a2ce8dd3d7944fa863463a97d",
+        SupportedNetworks::Polygon => "0x0d500b1d8e8ef31e21c99d1db9a6444d3adf1270",
+        SupportedNetworks::Fantom => "0x21be370d5312f44cb42ce377bc9b8a0cef1a4c83",
+        SupportedNetworks::ZkSync => "0x5aea5775959fbc2557cc8789bc1bf90a239d9a91",
+        SupportedNetworks::Klaytn => "0xe4f05a66ec68b54a58b17c22107b02e0232cc817",
+        SupportedNetworks::Arbitrum => "0x82af49447d8a07e3bd95bd0d56f35241523fbab1",
+        SupportedNetworks::Avalanche => "0xb31f66aa3c1e785363f0875a1b74e27b85fd66c7",
+        SupportedNetworks::Aurora => "0xc9bdeed33cd01541e1eed10f90519d2c06fe3feb",
+    }
+}
+
+// Only successful probe proves the product is there and accepts the key: 404
+// may mean the product is missing, and other rejections say nothing about
+// the key.
+fn access_by_status(status: StatusCode) -> ProductAccess {
+    match status {
+        StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => ProductAccess::Denied,
+        status if status.is_success() => ProductAccess::Granted,
+        status => ProductAccess::ProbeFailed(format!("Probe responded with {}", status)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::{new_with_base_url, new_with_environment},
+        environment::Environment,
+        utils::mock_server::MockServer,
+    };
+
+    #[tokio::test]
+    async fn test_capabilities_without_probing() {
+        let environment = Environment::custom("staging", "http://127.0.0.1:1").without_product("nft");
+        let capabilities = new_with_environment("key".to_string(), SupportedNetworks::Base, environment).capabilities();
+
+        assert_eq!(capabilities.environment(), "staging");
+        assert!(capabilities.compiled_products().contains(&"fusion-plus"));
+        assert_eq!(capabilities.access("nft").await, ProductAccess::Unavailable);
+        assert_eq!(capabilities.access("wallets").await, ProductAccess::Unknown);
+        for product in ["token", "tx-gateway", "charts", "portfolio"] {
+            assert!(capabilities.compiled_products().contains(&product));
+        }
+
+        assert_eq!(access_by_status(StatusCode::OK), ProductAccess::Granted);
+        assert_eq!(access_by_status(StatusCode::FORBIDDEN), ProductAccess::Denied);
+        for status in [StatusCode::BAD_REQUEST, StatusCode::NOT_FOUND, StatusCode::BAD_GATEWAY] {
+            assert!(matches!(access_by_status(status), ProductAccess::ProbeFailed(_)));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_probes_are_read_only_and_failures_remembered() {
+        let server = MockServer::start_with_status(404, "").await;
+        let capabilities = new_with_base_url("key".to_string(), SupportedNetworks::Base, &server.base_url).capabilities();
+
+        assert_eq!(capabilities.access("tx-gateway").await, ProductAccess::Unknown);
+        assert!(server.requests().is_empty());
+
+        for _ in 0..2 {
+            assert!(matches!(capabilities.access("charts").await, ProductAccess::ProbeFailed(_)));
+        }
+        assert_eq!(server.requests().len(), 1);
+        assert!(server.requests()[0].starts_with(
+            "/charts/v1.0/chart/aggregated/candle/0x4200000000000000000000000000000000000006/0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee/"
+        ));
+    }
+}
@@ -0,0 +1,107 @@
+use crate::{
+    client::{OneInchClient, SupportedNetworks},
+    consts::PORTFOLIO_API_VERSION,
+    error::OneInchError,
+};
+use serde::Deserialize;
+
+/// Chain as the Portfolio API describes it.
+#[derive(Deserialize, Debug, Clone)]
+pub struct ApiChain {
+    #[serde(rename = "chain_id")]
+    pub id: u32,
+    #[serde(default, rename = "chain_name")]
+    pub name: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct SupportedChainsResponse {
+    result: Vec<ApiChain>,
+}
+
+/// Chain supported by the API, mapped onto
+/// [`SupportedNetworks`](crate::client::SupportedNetworks).
+#[derive(Debug, Clone)]
+pub struct DiscoveredChain {
+    pub id: u32,
+    pub name: Option<String>,
+    /// Network of the chain, `None` if this version of the crate doesn't know
+    /// the chain.
+    pub network: Option<SupportedNetworks>,
+}
+
+impl DiscoveredChain {
+    /// Returns true if this version of the crate doesn't know the chain.
+    pub fn is_unknown(&self) -> bool {
+        self.network.is_none()
+    }
+}
+
+impl From<ApiChain> for DiscoveredChain {
+    fn from(chain: ApiChain) -> Self {
+        DiscoveredChain { id: chain.id, name: chain.name, network: SupportedNetworks::from_repr(chain.id) }
+    }
+}
+
+impl OneInchClient {
+    /// Performs request to get chains supported by the API, mapping them onto
+    /// `SupportedNetworks`. Chains the crate doesn't know about yet are kept
+    /// and flagged, so services can notice 1inch adding networks.
+    pub async fn get_supported_chains(&self) -> Result<Vec<DiscoveredChain>, OneInchError> {
+        let url = format!(
+            "{}/portfolio/portfolio/{}/general/supported_chains",
+            self.base_url(),
+            PORTFOLIO_API_VERSION
+        );
+
+        let chains: SupportedChainsResponse = self.request_json("portfolio/supported-chains", self.http_client.get(url)).await?;
+
+        Ok(chains.result.into_iter().map(DiscoveredChain::from).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{client::new_with_base_url, utils::mock_server::MockServer};
+
+    #[tokio::test]
+    async fn test_supported_chains_mapping() {
+        let server = MockServer::start(
+            r#"{"result": [
+                {
+                    "chain_id": 1,
+                    "chain_name": "Ethereum",
+                    "chain_icon": "https://portfolio.1inch.io/assets/images/chains/ethereum.svg",
+                    "native_token": {
+                        "chain_id": 1,
+                        "address": "0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee",
+                        "decimals": 18,
+                        "symbol": "ETH",
+                        "name": "Ether"
+                    }
+                },
+                {
+                    "chain_id": 59144,
+                    "chain_name": "Linea",
+                    "chain_icon": "https://portfolio.1inch.io/assets/images/chains/linea.svg",
+                    "native_token": {
+                        "chain_id": 59144,
+                        "address": "0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee",
+                        "decimals": 18,
+                        "symbol": "ETH",
+                        "name": "Ether"
+                    }
+                }
+            ]}"#,
+        )
+        .await;
+        let client = new_with_base_url("token".into(), SupportedNetworks::Ethereum, &server.base_url);
+
+        let chains = client.get_supported_chains().await.unwrap();
+        assert_eq!(server.requests()[0], "/portfolio/portfolio/v3/general/supported_chains");
+        assert!(matches!(chains[0].network, Some(SupportedNetworks::Ethereum)));
+        assert!(chains[1].is_unknown());
+        assert_eq!(chains[1].name.as_deref(), Some("Linea"));
+    }
+}
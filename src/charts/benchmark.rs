@@ -0,0 +1,164 @@
+use crate::{
+    charts::{Candle, CandleInterval},
+    client::OneInchClient,
+    error::OneInchError,
+};
+use std::ops::Range;
+
+/// Trade of the pair with its volume, e.g. a fill of own orders or a market
+/// trade from an external feed. Charts data has no volumes, so VWAP is
+/// computed from trades provided by the caller.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct VolumePoint {
+    /// Unix timestamp in seconds.
+    pub time: u64,
+    pub price: f64,
+    /// Volume of the trade, in units of the first token.
+    pub volume: f64,
+}
+
+/// Side of the execution relative to the first token of the pair.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum TradeSide {
+    /// First token was sold, so higher price is better.
+    Sell,
+    /// First token was bought, so lower price is better.
+    Buy,
+}
+
+/// Execution price compared with the benchmark price.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct BenchmarkComparison {
+    pub execution_price: f64,
+    pub benchmark_price: f64,
+    /// Slippage against the benchmark in basis points, positive if the
+    /// execution is worse than the benchmark.
+    pub slippage_bps: f64,
+}
+
+impl BenchmarkComparison {
+    /// Compares the execution price of the trade side with the benchmark.
+    /// `None` if the benchmark price isn't positive, since slippage against
+    /// it is undefined.
+    pub fn new(side: TradeSide, execution_price: f64, benchmark_price: f64) -> Option<BenchmarkComparison> {
+        if !(benchmark_price > 0.0 && benchmark_price.is_finite()) {
+            return None;
+        }
+
+        let difference = match side {
+            TradeSide::Sell => benchmark_price - execution_price,
+            TradeSide::Buy => execution_price - benchmark_price,
+        };
+
+        Some(BenchmarkComparison { execution_price, benchmark_price, slippage_bps: difference / benchmark_price * 10_000.0 })
+    }
+
+    /// Returns true if the execution is at least as good as the benchmark.
+    pub fn beats_benchmark(&self) -> bool {
+        self.slippage_bps <= 0.0
+    }
+}
+
+/// Returns time-weighted average price over the window of unix timestamps.
+/// Every candle contributes its mean OHLC price, weighted by the time it
+/// overlaps the window. `None` if no candle overlaps the window. Candles
+/// whose end doesn't fit into a timestamp are skipped.
+pub fn twap(candles: &[Candle], interval: CandleInterval, window: Range<u64>) -> Option<f64> {
+    let (weighted, weight) = candles.iter().fold((0.0, 0u64), |(weighted, weight), candle| {
+        let Some(candle_end) = candle.time.checked_add(interval.seconds()) else {
+            return (weighted, weight);
+        };
+        let start = candle.time.max(window.start);
+        let end = candle_end.min(window.end);
+        let overlap = end.saturating_sub(start);
+        let price = (candle.open + candle.high + candle.low + candle.close) / 4.0;
+
+        (weighted + price * overlap as f64, weight + overlap)
+    });
+
+    (weight > 0).then(|| weighted / weight as f64)
+}
+
+/// Returns volume-weighted average price of the trades within the window of
+/// unix timestamps. `None` if there is no volume in the window.
+pub fn vwap(trades: &[VolumePoint], window: Range<u64>) -> Option<f64> {
+    let (notional, volume) =
+        trades.iter().filter(|trade| window.contains(&trade.time)).fold((0.0, 0.0), |(notional, volume), trade| {
+            (notional + trade.price * trade.volume, volume + trade.volume)
+        });
+
+    (volume > 0.0).then(|| notional / volume)
+}
+
+impl OneInchClient {
+    /// Returns TWAP of the `token0`/`token1` pair price over the window of
+    /// unix timestamps, from candles of the client's network.
+    pub async fn get_twap(
+        &self,
+        token0: String,
+        token1: String,
+        interval: CandleInterval,
+        window: Range<u64>,
+    ) -> Result<Option<f64>, OneInchError> {
+        let candles = self.get_candles(token0, token1, interval).await?;
+        Ok(twap(&candles.data, interval, window))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::{new_with_base_url, SupportedNetworks},
+        utils::mock_server::MockServer,
+    };
+
+    fn candle(time: u64, price: f64) -> Candle {
+        Candle { time, open: price, high: price, low: price, close: price }
+    }
+
+    #[test]
+    fn test_benchmarks() {
+        let candles = vec![candle(0, 100.0), candle(3600, 110.0), candle(7200, 130.0)];
+
+        // Second half of the first candle and the whole second one
+        let average = twap(&candles, CandleInterval::Hour, 1800..7200).unwrap();
+        assert!((average - (100.0 * 1800.0 + 110.0 * 3600.0) / 5400.0).abs() < 1e-9);
+        assert_eq!(twap(&candles, CandleInterval::Hour, 20000..30000), None);
+
+        let trades = [
+            VolumePoint { time: 10, price: 100.0, volume: 3.0 },
+            VolumePoint { time: 20, price: 120.0, volume: 1.0 },
+            VolumePoint { time: 99, price: 1000.0, volume: 1.0 },
+        ];
+        assert_eq!(vwap(&trades, 0..50), Some(105.0));
+
+        let sold = BenchmarkComparison::new(TradeSide::Sell, 99.0, 100.0).unwrap();
+        assert!((sold.slippage_bps - 100.0).abs() < 1e-9);
+        assert!(!sold.beats_benchmark());
+        assert!(BenchmarkComparison::new(TradeSide::Buy, 99.0, 100.0).unwrap().beats_benchmark());
+    }
+
+    #[test]
+    fn test_degenerate_benchmarks() {
+        assert_eq!(BenchmarkComparison::new(TradeSide::Sell, 99.0, 0.0), None);
+        assert_eq!(BenchmarkComparison::new(TradeSide::Buy, 99.0, f64::NAN), None);
+
+        let candles = vec![candle(u64::MAX - 10, 100.0)];
+        assert_eq!(twap(&candles, CandleInterval::Hour, 0..u64::MAX), None);
+    }
+
+    #[tokio::test]
+    async fn test_twap_keeps_api_errors() {
+        let server = MockServer::start_with_status(
+            400,
+            r#"{"error": "Bad Request", "description": "pair not found", "statusCode": 400, "requestId": "id"}"#,
+        )
+        .await;
+        let client = new_with_base_url("token".to_string(), SupportedNetworks::Ethereum, &server.base_url);
+
+        let error = client.get_twap("0xa".into(), "0xb".into(), CandleInterval::Hour, 0..3600).await.unwrap_err();
+        assert!(matches!(&error, OneInchError::Api { description: Some(description), .. } if description == "pair not found"));
+        assert!(server.requests()[0].starts_with("/charts/v1.0/chart/aggregated/candle/0xa/0xb/3600/1"));
+    }
+}
@@ -0,0 +1,29 @@
+use crate::{
+    charts::{CandleInterval, CandlesResponse},
+    client::OneInchClient,
+    consts::CHARTS_API_VERSION,
+    error::OneInchError,
+};
+
+impl OneInchClient {
+    /// Performs request to get candles of the `token0`/`token1` pair price on
+    /// the client's network.
+    pub async fn get_candles(
+        &self,
+        token0: String,
+        token1: String,
+        interval: CandleInterval,
+    ) -> Result<CandlesResponse, OneInchError> {
+        let url = format!(
+            "{}/charts/{}/chart/aggregated/candle/{}/{}/{}/{}",
+            self.base_url(),
+            CHARTS_API_VERSION,
+            token0,
+            token1,
+            interval.seconds(),
+            self.network_id
+        );
+
+        self.request_json("charts/candle", self.http_client.get(url)).await
+    }
+}
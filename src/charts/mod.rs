@@ -0,0 +1,6 @@
+mod benchmark;
+mod candles;
+mod types;
+
+pub use benchmark::*;
+pub use types::*;
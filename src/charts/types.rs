@@ -0,0 +1,72 @@
+use crate::swap::PricePoint;
+use serde::Deserialize;
+
+/// Interval of the candle.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum CandleInterval {
+    FiveMinutes,
+    FifteenMinutes,
+    Hour,
+    FourHours,
+    Day,
+    Week,
+}
+
+impl CandleInterval {
+    /// Returns length of the interval in seconds, as the API accepts it.
+    pub fn seconds(&self) -> u64 {
+        match self {
+            CandleInterval::FiveMinutes => 300,
+            CandleInterval::FifteenMinutes => 900,
+            CandleInterval::Hour => 3600,
+            CandleInterval::FourHours => 14400,
+            CandleInterval::Day => 86400,
+            CandleInterval::Week => 604800,
+        }
+    }
+}
+
+/// OHLC candle of the pair price: amount of the second token per one first
+/// token.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct Candle {
+    /// Unix timestamp of the candle start, in seconds.
+    pub time: u64,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+}
+
+/// Represents the struct we receive after requesting candles.
+#[derive(Deserialize, Debug, Clone)]
+pub struct CandlesResponse {
+    pub data: Vec<Candle>,
+}
+
+impl CandlesResponse {
+    /// Returns close prices of the candles, e.g. to estimate volatility for
+    /// [`simulate_slippage`](crate::swap::simulate_slippage).
+    pub fn close_prices(&self) -> Vec<PricePoint> {
+        self.data.iter().map(|c| PricePoint { time: c.time, price: c.close }).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_candles_deserialization() {
+        let body = r#"{"data": [
+            {"time": 1717200000, "open": 3800.5, "high": 3812.0, "low": 3795.1, "close": 3810.2},
+            {"time": 1717203600, "open": 3810.2, "high": 3820.0, "low": 3801.7, "close": 3805.0}
+        ]}"#;
+
+        let response: CandlesResponse = serde_json::from_str(body).unwrap();
+
+        assert_eq!(response.data[0].high, 3812.0);
+        assert_eq!(response.close_prices()[1], PricePoint { time: 1717203600, price: 3805.0 });
+        assert_eq!(CandleInterval::Hour.seconds(), 3600);
+    }
+}
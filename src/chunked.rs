@@ -0,0 +1,116 @@
+use crate::error::OneInchError;
+use futures::future::join_all;
+use std::{fmt, future::Future};
+use thiserror::Error;
+
+/// Failed request of one chunk of the addresses.
+#[derive(Debug)]
+pub struct ChunkError {
+    /// Addresses of the chunk, whose results are missing.
+    pub addresses: Vec<String>,
+    pub error: OneInchError,
+}
+
+/// Error of the request split into chunks of addresses, some of which failed.
+/// Results of the succeeded chunks are merged into `partial`, so callers can
+/// use them and retry only the failed addresses.
+#[derive(Error, Debug)]
+#[error("{} of {total} chunks failed: {}", .failed.len(), describe(.failed))]
+pub struct ChunkedRequestError<T: fmt::Debug> {
+    pub partial: T,
+    pub failed: Vec<ChunkError>,
+    /// Amount of the chunks the request was split into.
+    pub total: usize,
+}
+
+fn describe(failed: &[ChunkError]) -> String {
+    failed.iter().map(|chunk| format!("{} addresses ({})", chunk.addresses.len(), chunk.error)).collect::<Vec<_>>().join("; ")
+}
+
+// Requests results for the addresses in chunks of at most `limit` of them
+// and merges the results. Chunks are requested concurrently, their pace is
+// kept by the client's rate limiter.
+pub(crate) async fn fetch_chunked<T, F, Fut>(
+    addresses: Vec<String>,
+    limit: usize,
+    fetch: F,
+    merge: impl Fn(&mut T, T),
+) -> Result<T, ChunkedRequestError<T>>
+where
+    T: Default + fmt::Debug,
+    F: Fn(Vec<String>) -> Fut,
+    Fut: Future<Output = Result<T, OneInchError>>,
+{
+    let chunks: Vec<Vec<String>> = addresses.chunks(limit.max(1)).map(|chunk| chunk.to_vec()).collect();
+    let total = chunks.len();
+
+    let results = join_all(chunks.iter().map(|chunk| fetch(chunk.clone()))).await;
+
+    let mut merged = T::default();
+    let mut failed = vec![];
+    for (addresses, result) in chunks.into_iter().zip(results) {
+        match result {
+            Ok(result) => merge(&mut merged, result),
+            Err(error) => failed.push(ChunkError { addresses, error }),
+        }
+    }
+
+    if failed.is_empty() {
+        Ok(merged)
+    } else {
+        Err(ChunkedRequestError { partial: merged, failed, total })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::{new_with_base_url, SupportedNetworks},
+        tokens::tokens_price::TokensPricesRequestDetails,
+        utils::{assert_send, mock_server::MockServer},
+    };
+
+    #[test]
+    fn test_tokens_price_future_is_send() {
+        let client = new_with_base_url("token".to_string(), SupportedNetworks::Ethereum, "http://localhost");
+        assert_send(client.get_tokens_price(TokensPricesRequestDetails { addresses: vec![], currency: None }));
+    }
+
+    #[tokio::test]
+    async fn test_long_address_lists_are_chunked() {
+        let addresses: Vec<String> = (0..5).map(|i| format!("0x{}", i)).collect();
+        let result = fetch_chunked(
+            addresses,
+            2,
+            |chunk| async move {
+                if chunk.contains(&"0x2".to_string()) {
+                    Err(OneInchError::Validation("unavailable".into()))
+                } else {
+                    Ok(chunk)
+                }
+            },
+            |merged: &mut Vec<String>, chunk| merged.extend(chunk),
+        )
+        .await;
+
+        let error = result.unwrap_err();
+        assert_eq!(error.partial, vec!["0x0", "0x1", "0x4"]);
+        assert_eq!(error.failed[0].addresses, vec!["0x2", "0x3"]);
+        assert_eq!(error.to_string(), "1 of 3 chunks failed: 2 addresses (Validation error: unavailable)");
+
+        let server = MockServer::start(r#"{"0x0": "1"}"#).await;
+        let client = new_with_base_url("token".to_string(), SupportedNetworks::Ethereum, &server.base_url);
+        let details = TokensPricesRequestDetails {
+            addresses: (0..250).chain(0..10).map(|i| format!("0x{:040X}", i)).collect(),
+            currency: None,
+        };
+        let prices = client.get_tokens_price(details).await.unwrap();
+        assert_eq!(prices.prices.len(), 1);
+        let requests = server.requests();
+        let mut sizes: Vec<usize> = requests.iter().map(|target| target.split(',').count()).collect();
+        sizes.sort();
+        assert_eq!(sizes, vec![50, 100, 100]);
+        assert!(requests.iter().all(|target| target == &target.to_lowercase()));
+    }
+}
@@ -0,0 +1,146 @@
+use crate::{client::OneInchClient, utils::runtime::Instant};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+/// Circuit breaker of the endpoints, shared by all clones of the client: after
+/// `failure_threshold` consecutive failures of the endpoint (network errors
+/// and 5xx responses) its circuit opens, and requests to it fail fast with
+/// [`CircuitOpen`](crate::error::OneInchError::CircuitOpen) without being
+/// sent, so bots don't burn their rate limit during 1inch outages.
+///
+/// Once the cool-down passes, a single trial request is let through: its
+/// success closes the circuit, and its failure opens it for another
+/// cool-down.
+///
+/// ```
+/// use one_inch::circuit_breaker::CircuitBreaker;
+/// use std::time::Duration;
+///
+/// let breaker = CircuitBreaker::new(5, Duration::from_secs(30));
+/// assert_eq!(breaker.retry_in("swap/quote"), None);
+/// ```
+#[derive(Debug)]
+pub struct CircuitBreaker {
+    failure_threshold: u32,
+    cool_down: Duration,
+    circuits: Mutex<HashMap<String, Circuit>>,
+}
+
+#[derive(Debug, Default)]
+struct Circuit {
+    failures: u32,
+    // Time the circuit was opened, or the last trial request was let through
+    opened_at: Option<Instant>,
+}
+
+impl CircuitBreaker {
+    /// Creates circuit breaker opening after `failure_threshold` consecutive
+    /// failures for the cool-down.
+    pub fn new(failure_threshold: u32, cool_down: Duration) -> CircuitBreaker {
+        CircuitBreaker { failure_threshold: failure_threshold.max(1), cool_down, circuits: Mutex::new(HashMap::new()) }
+    }
+
+    /// Returns time until the endpoint's open circuit lets a trial request
+    /// through, `None` if the circuit is closed.
+    pub fn retry_in(&self, endpoint: &str) -> Option<Duration> {
+        let circuits = self.circuits.lock().unwrap();
+        let opened_at = circuits.get(endpoint)?.opened_at?;
+        Some(self.cool_down.saturating_sub(opened_at.elapsed()))
+    }
+
+    /// Closes circuits of all the endpoints.
+    pub fn reset(&self) {
+        self.circuits.lock().unwrap().clear();
+    }
+
+    // Checks whether the request to the endpoint may be sent. If the circuit
+    // is open, returns time until the next trial request.
+    pub(crate) fn acquire(&self, endpoint: &str) -> Result<(), Duration> {
+        let mut circuits = self.circuits.lock().unwrap();
+        let Some(circuit) = circuits.get_mut(endpoint) else {
+            return Ok(());
+        };
+
+        match circuit.opened_at {
+            Some(opened_at) if opened_at.elapsed() < self.cool_down => Err(self.cool_down.saturating_sub(opened_at.elapsed())),
+            Some(_) => {
+                // Trial request, the rest wait for its outcome or another
+                // cool-down if it never comes
+                circuit.opened_at = Some(Instant::now());
+                Ok(())
+            }
+            None => Ok(()),
+        }
+    }
+
+    pub(crate) fn record(&self, endpoint: &str, success: bool) {
+        let mut circuits = self.circuits.lock().unwrap();
+        if success {
+            circuits.remove(endpoint);
+            return;
+        }
+
+        let circuit = circuits.entry(endpoint.to_string()).or_default();
+        circuit.failures += 1;
+        if circuit.failures >= self.failure_threshold {
+            if circuit.opened_at.is_none() {
+                tracing::warn!(endpoint, failures = circuit.failures, "circuit opened after consecutive failures");
+            }
+            circuit.opened_at = Some(Instant::now());
+        }
+    }
+}
+
+impl OneInchClient {
+    /// Sets circuit breaker which all requests of the client (and its clones)
+    /// go through.
+    pub fn with_circuit_breaker(mut self, circuit_breaker: CircuitBreaker) -> OneInchClient {
+        self.circuit_breaker = Some(Arc::new(circuit_breaker));
+        self
+    }
+
+    pub fn circuit_breaker(&self) -> Option<&CircuitBreaker> {
+        self.circuit_breaker.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::{new_with_base_url, SupportedNetworks},
+        error::OneInchError,
+        utils::mock_server::MockServer,
+    };
+
+    #[tokio::test]
+    async fn test_circuit_opens_after_consecutive_failures() {
+        let server = MockServer::start_with_status(503, r#"{"statusCode": 503, "description": "down"}"#).await;
+        let client = new_with_base_url("token".to_string(), SupportedNetworks::Ethereum, &server.base_url)
+            .with_circuit_breaker(CircuitBreaker::new(2, Duration::from_secs(60)));
+
+        for _ in 0..2 {
+            assert!(matches!(
+                client.get_router_address().await,
+                Err(OneInchError::Api { status_code: 503, .. })
+            ));
+        }
+        assert!(matches!(
+            client.get_router_address().await,
+            Err(OneInchError::CircuitOpen { endpoint, .. }) if endpoint == "swap/approve/spender"
+        ));
+        assert_eq!(server.requests().len(), 2);
+        assert!(client.circuit_breaker().unwrap().retry_in("swap/approve/spender").is_some());
+        assert_eq!(client.circuit_breaker().unwrap().retry_in("swap/quote"), None);
+
+        // Trial request after the cool-down
+        let breaker = CircuitBreaker::new(1, Duration::ZERO);
+        breaker.record("swap/quote", false);
+        assert!(breaker.acquire("swap/quote").is_ok());
+        breaker.record("swap/quote", true);
+        assert_eq!(breaker.retry_in("swap/quote"), None);
+    }
+}
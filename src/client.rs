@@ -1,24 +1,916 @@
+#[cfg(all(feature = "test-util", not(target_arch = "wasm32")))]
+use crate::test_util::{Fixtures, MockTransport};
+use crate::{
+    address_book::AddressLabels,
+    auth::{ApiKeyError, ApiKeyPool, ApiKeyProvider, Credentials, KeyRouting, SecretString},
+    cache::ResponseCache,
+    circuit_breaker::CircuitBreaker,
+    environment::Environment,
+    events::EventSink,
+    jitter::JitterConfig,
+    journal::RequestJournal,
+    price_feed::NativePriceFeed,
+    rate_limit::{parse_retry_after, Quota, QuotaTracker, RateLimitError, RateLimiter},
+    response::{request_id, ResponseLimits},
+    retry::{is_retryable_request, RetryPolicy},
+    swap::{IncludeDefaults, PriceImpactPolicy},
+    timeout::Timeouts,
+    utils::{deprecation::warn_if_deprecated_response, metrics, runtime},
+    web3::{EmittedFinality, FinalityConfig, Web3Policy},
+};
 use core::fmt;
+#[cfg(not(target_arch = "wasm32"))]
+use reqwest::Proxy;
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue, AUTHORIZATION, USER_AGENT},
+    RequestBuilder, Response, StatusCode,
+};
+use std::{sync::Arc, time::Duration};
 use strum_macros::{Display, FromRepr};
+use thiserror::Error;
 
 /// Struct to work with 1inch api
+#[derive(Clone)]
 pub struct OneInchClient {
     /// reqwest::Client does not need to be Rc/Arc because it already uses an
     /// Arc internally.
     pub http_client: reqwest::Client,
 
-    /// An authorization token for interacting with API.
+    /// Settings the HTTP client is built with, unless it was provided
+    /// externally.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) http_settings: HttpSettings,
+
+    /// An authorization token for interacting with API, along with tokens of
+    /// the products which should use another one.
     /// There you can get your own token : <https://portal.1inch.dev/applications>
-    pub(crate) token: String,
+    pub(crate) credentials: KeyRouting,
 
     /// The ID of the network on which you want to work.
     /// You can interact only with 1 specified network with your client.
     pub(crate) network_id: SupportedNetworks,
+
+    /// Optional client-side rate limiter, shared by all clones of the client.
+    pub(crate) rate_limiter: Option<Arc<RateLimiter>>,
+
+    /// Optional circuit breaker of the endpoints, shared by all clones of the
+    /// client.
+    pub(crate) circuit_breaker: Option<Arc<CircuitBreaker>>,
+
+    /// USD prices of the native tokens, shared by all clones of the client.
+    pub(crate) native_prices: Arc<NativePriceFeed>,
+
+    /// Name of the tenant whose share of the rate limit budget is used by
+    /// requests of this client.
+    pub(crate) tenant: Option<String>,
+
+    /// Limits of the response body size, per endpoint.
+    pub(crate) response_limits: ResponseLimits,
+
+    /// Defaults of the `include*` flags of quote and swap requests.
+    pub(crate) include_defaults: IncludeDefaults,
+
+    /// Environment profile the client works with, including base URL of the
+    /// API.
+    pub(crate) environment: Environment,
+
+    /// Jitter windows of the background tasks polling the API.
+    pub(crate) jitter: JitterConfig,
+
+    /// Confirmations considered final, per chain.
+    pub(crate) finality: FinalityConfig,
+
+    /// Optional policy of retrying idempotent requests on transient failures.
+    pub(crate) retry_policy: Option<RetryPolicy>,
+
+    /// Optional cache of slow-changing endpoints, shared by all clones of the
+    /// client.
+    pub(crate) response_cache: Option<Arc<ResponseCache>>,
+
+    /// Optional tracker of the API key quota reported by the responses,
+    /// shared by all clones of the client.
+    pub(crate) quota: Option<Arc<QuotaTracker>>,
+
+    /// Whether balance and allowance are checked before swap requests.
+    pub(crate) swap_preflight: bool,
+
+    /// Whether swap addresses given as domains are resolved via Domains API.
+    pub(crate) resolve_domains: bool,
+
+    /// Optional policy refusing swaps whose price impact is too high.
+    pub(crate) price_impact_policy: Option<PriceImpactPolicy>,
+
+    /// Headers added to every request, like partner identification.
+    pub(crate) default_headers: HeaderMap,
+
+    /// Optional journal of the requests, shared by all clones of the client.
+    pub(crate) journal: Option<Arc<RequestJournal>>,
+
+    /// Optional labels of the addresses, for logs and formatting.
+    pub(crate) address_book: Option<Arc<dyn AddressLabels>>,
+
+    /// Whether receivers of prepared swaps are checked for being contracts.
+    pub(crate) verify_receivers: bool,
+
+    /// Optional sink of the execution events.
+    pub(crate) event_sink: Option<Arc<dyn EventSink>>,
+
+    /// Final transactions whose events were emitted, shared by all clones of
+    /// the client.
+    pub(crate) emitted_finality: Arc<EmittedFinality>,
+
+    /// Timeouts of the requests, per endpoint.
+    pub(crate) timeouts: Timeouts,
+
+    /// Timeouts and retries of the JSON-RPC calls.
+    pub(crate) web3_policy: Web3Policy,
+
+    /// Optional mock transport answering the requests instead of sending
+    /// them.
+    #[cfg(all(feature = "test-util", not(target_arch = "wasm32")))]
+    pub(crate) transport: Option<Arc<MockTransport>>,
+
+    /// Optional fixtures the responses are recorded to or replayed from.
+    #[cfg(all(feature = "test-util", not(target_arch = "wasm32")))]
+    pub(crate) fixtures: Option<Arc<Fixtures>>,
 }
 
-/// Function creates a OneInchClient instance with default http settings.
+// Settings of the HTTP client built by the crate. Every setter changing them
+// rebuilds the client from all of them, so they don't override each other.
+// Browser manages connections itself, so there are no settings there.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug, Clone)]
+pub(crate) struct HttpSettings {
+    pub(crate) proxy: Option<Proxy>,
+    pub(crate) connect_timeout: Option<Duration>,
+    pub(crate) compression: bool,
+    pub(crate) pool: PoolSettings,
+}
+
+// Tuning of the connection pool, `None` keeps defaults of reqwest.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug, Clone)]
+pub(crate) struct PoolSettings {
+    pub(crate) max_idle_per_host: Option<usize>,
+    pub(crate) idle_timeout: Option<Duration>,
+    pub(crate) tcp_keepalive: Option<Duration>,
+    pub(crate) tcp_nodelay: bool,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Default for HttpSettings {
+    fn default() -> Self {
+        HttpSettings {
+            proxy: None,
+            connect_timeout: None,
+            compression: true,
+            pool: PoolSettings { max_idle_per_host: None, idle_timeout: None, tcp_keepalive: None, tcp_nodelay: true },
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl HttpSettings {
+    pub(crate) fn build(&self) -> Result<reqwest::Client, reqwest::Error> {
+        let mut builder = reqwest::Client::builder().gzip(self.compression).brotli(self.compression);
+        if let Some(proxy) = &self.proxy {
+            builder = builder.proxy(proxy.clone());
+        }
+        if let Some(connect_timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(connect_timeout);
+        }
+        if let Some(max_idle) = self.pool.max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max_idle);
+        }
+        if let Some(idle_timeout) = self.pool.idle_timeout {
+            builder = builder.pool_idle_timeout(idle_timeout);
+        }
+        builder.tcp_keepalive(self.pool.tcp_keepalive).tcp_nodelay(self.pool.tcp_nodelay).build()
+    }
+}
+
+/// Function creates a OneInchClient instance with default http settings,
+/// working with the production environment unless overridden via environment
+/// variables. For anything beyond that, use [`OneInchClientBuilder`].
 pub fn new_with_default_http(token: String, network_id: SupportedNetworks) -> OneInchClient {
-    OneInchClient { http_client: reqwest::Client::default(), token, network_id }
+    new_with_environment(token, network_id, Environment::production())
+}
+
+/// Function creates a OneInchClient instance with default http settings,
+/// working with the specified environment profile. Profile values can be
+/// overridden via environment variables, see
+/// [`Environment`](crate::environment::Environment).
+pub fn new_with_environment(token: String, network_id: SupportedNetworks, environment: Environment) -> OneInchClient {
+    new_with_credentials(Credentials::Static(Arc::new(SecretString::new(token))), network_id, environment)
+}
+
+/// Function creates a OneInchClient instance using externally built HTTP
+/// client, e.g. one shared across the whole service with its own middleware,
+/// certificates and connection pools. Authorization and base URL are still
+/// applied by the OneInchClient to every request.
+///
+/// ```
+/// use one_inch::client::{new_with_http_client, SupportedNetworks};
+/// use std::time::Duration;
+///
+/// let http_client = reqwest::Client::builder().pool_max_idle_per_host(16).timeout(Duration::from_secs(10)).build().unwrap();
+/// let client = new_with_http_client("token".to_string(), SupportedNetworks::Ethereum, http_client);
+/// ```
+pub fn new_with_http_client(token: String, network_id: SupportedNetworks, http_client: reqwest::Client) -> OneInchClient {
+    new_with_default_http(token, network_id).with_http_client(http_client)
+}
+
+/// Function creates a OneInchClient instance with default http settings,
+/// sending requests to the specified base URL instead of the public API, e.g.
+/// a proxy, an enterprise endpoint or a mock server in tests. Explicit base URL
+/// takes precedence over the `ONE_INCH_BASE_URL` environment variable.
+pub fn new_with_base_url(token: String, network_id: SupportedNetworks, base_url: impl Into<String>) -> OneInchClient {
+    new_with_default_http(token, network_id).with_base_url(base_url)
+}
+
+/// Function creates a OneInchClient instance sending requests through the
+/// proxy, e.g. `http://proxy.corp:3128`. SOCKS proxies (`socks5://` and
+/// `socks5h://` URLs, like Tor) require `socks` feature. Not available in the
+/// browser.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn new_with_proxy(token: String, network_id: SupportedNetworks, proxy_url: &str) -> Result<OneInchClient, reqwest::Error> {
+    new_with_default_http(token, network_id).with_proxy(Proxy::all(proxy_url)?)
+}
+
+/// Function creates a OneInchClient instance with default http settings, which
+/// fetches the API key from the provider before every request instead of
+/// holding it in memory.
+pub fn new_with_key_provider(provider: impl ApiKeyProvider + 'static, network_id: SupportedNetworks) -> OneInchClient {
+    new_with_credentials(Credentials::Provider(Arc::new(provider)), network_id, Environment::production())
+}
+
+/// Function creates a OneInchClient instance with default http settings, which
+/// spreads requests across the keys of the pool round-robin, skipping keys
+/// the API rejects or rate limits.
+pub fn new_with_key_pool(pool: ApiKeyPool, network_id: SupportedNetworks) -> OneInchClient {
+    new_with_credentials(Credentials::Pool(Arc::new(pool)), network_id, Environment::production())
+}
+
+fn new_with_credentials(credentials: Credentials, network_id: SupportedNetworks, environment: Environment) -> OneInchClient {
+    let environment = environment.with_env_overrides();
+
+    OneInchClient {
+        http_client: reqwest::Client::default(),
+        #[cfg(not(target_arch = "wasm32"))]
+        http_settings: HttpSettings::default(),
+        credentials: KeyRouting::new(credentials),
+        network_id,
+        // RPS of the environment is already validated
+        rate_limiter: environment.rps().and_then(|rps| RateLimiter::new(rps).ok()).map(Arc::new),
+        circuit_breaker: None,
+        native_prices: Arc::default(),
+        tenant: None,
+        response_limits: ResponseLimits::default(),
+        include_defaults: IncludeDefaults::default(),
+        environment,
+        jitter: JitterConfig::default(),
+        finality: FinalityConfig::default(),
+        retry_policy: None,
+        response_cache: None,
+        quota: None,
+        swap_preflight: false,
+        resolve_domains: false,
+        price_impact_policy: None,
+        default_headers: HeaderMap::new(),
+        journal: None,
+        address_book: None,
+        verify_receivers: false,
+        event_sink: None,
+        emitted_finality: Arc::default(),
+        timeouts: Timeouts::default(),
+        web3_policy: Web3Policy::default(),
+        #[cfg(all(feature = "test-util", not(target_arch = "wasm32")))]
+        transport: None,
+        #[cfg(all(feature = "test-util", not(target_arch = "wasm32")))]
+        fixtures: None,
+    }
+}
+
+impl fmt::Debug for OneInchClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OneInchClient")
+            .field("credentials", &self.credentials)
+            .field("network_id", &self.network_id)
+            .field("base_url", &self.base_url())
+            .field("tenant", &self.tenant)
+            .field("retry_policy", &self.retry_policy)
+            .field("timeouts", &self.timeouts)
+            .finish_non_exhaustive()
+    }
+}
+
+impl OneInchClient {
+    /// Sets API key used for requests of the product (first segment of the
+    /// endpoint path, like `swap` or `portfolio`) instead of the default one,
+    /// for accounts whose keys are entitled to different products.
+    pub fn with_product_key(mut self, product: impl Into<String>, token: String) -> OneInchClient {
+        self.credentials.set_product(product.into(), Credentials::Static(Arc::new(SecretString::new(token))));
+        self
+    }
+
+    /// Sets API key provider used for requests of the product instead of the
+    /// default key. See [`with_product_key`](OneInchClient::with_product_key).
+    pub fn with_product_key_provider(
+        mut self,
+        product: impl Into<String>,
+        provider: impl ApiKeyProvider + 'static,
+    ) -> OneInchClient {
+        self.credentials.set_product(product.into(), Credentials::Provider(Arc::new(provider)));
+        self
+    }
+
+    /// Limits requests of the client (and its clones) to `rps` requests per
+    /// second across all endpoints, shorthand for
+    /// [`with_rate_limiter`](OneInchClient::with_rate_limiter). Fails if
+    /// `rps` isn't a positive finite number.
+    pub fn with_rps(self, rps: f64) -> Result<OneInchClient, RateLimitError> {
+        Ok(self.with_rate_limiter(RateLimiter::new(rps)?))
+    }
+
+    /// Returns the rate limiter of the client, if it's configured.
+    pub fn rate_limiter(&self) -> Option<&RateLimiter> {
+        self.rate_limiter.as_deref()
+    }
+
+    /// Sets HTTP client the requests are sent with, replacing the default one.
+    /// Clones of `reqwest::Client` share the connection pool, so the same
+    /// client can be passed to any amount of OneInchClients.
+    pub fn with_http_client(mut self, http_client: reqwest::Client) -> OneInchClient {
+        self.http_client = http_client;
+        self
+    }
+
+    /// Sets proxy all requests of the client are sent through. Proxy can be
+    /// restricted to some schemes or hosts, or carry credentials, see
+    /// [`reqwest::Proxy`]. HTTP client is rebuilt, so it replaces the client
+    /// set with [`with_http_client`](OneInchClient::with_http_client), and
+    /// clones made before don't use the proxy. Not available in the browser.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn with_proxy(mut self, proxy: Proxy) -> Result<OneInchClient, reqwest::Error> {
+        self.http_settings.proxy = Some(proxy);
+        self.http_client = self.http_settings.build()?;
+        Ok(self)
+    }
+
+    /// Sets timeout of establishing connections. HTTP client is rebuilt, like
+    /// with [`with_proxy`](OneInchClient::with_proxy). Not available in the
+    /// browser.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn with_connect_timeout(mut self, connect_timeout: Duration) -> Result<OneInchClient, reqwest::Error> {
+        self.http_settings.connect_timeout = Some(connect_timeout);
+        self.http_client = self.http_settings.build()?;
+        Ok(self)
+    }
+
+    /// Enables or disables gzip and brotli compression of the responses,
+    /// which is enabled by default and noticeably cuts latency of large
+    /// responses, like tokens list. HTTP client is rebuilt, like with
+    /// [`with_proxy`](OneInchClient::with_proxy). Not available in the
+    /// browser, which handles compression itself.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn with_compression(mut self, compression: bool) -> Result<OneInchClient, reqwest::Error> {
+        self.http_settings.compression = compression;
+        self.http_client = self.http_settings.build()?;
+        Ok(self)
+    }
+
+    /// Sets timeouts of the requests, per endpoint.
+    pub fn with_timeouts(mut self, timeouts: Timeouts) -> OneInchClient {
+        self.timeouts = timeouts;
+        self
+    }
+
+    /// Returns a client sharing everything with this one, whose requests time
+    /// out after `timeout` regardless of the endpoint. Handy for a single
+    /// call with a tighter deadline:
+    /// `client.with_call_timeout(Duration::from_secs(1)).quote(details)`.
+    pub fn with_call_timeout(&self, timeout: Duration) -> OneInchClient {
+        OneInchClient { timeouts: self.timeouts.clone().with_call_timeout(timeout), ..self.clone() }
+    }
+
+    /// Sets rate limiter which all requests of the client (and its clones) go
+    /// through.
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> OneInchClient {
+        self.rate_limiter = Some(Arc::new(rate_limiter));
+        self
+    }
+
+    /// Sets policy of retrying idempotent (GET) requests, like quotes or
+    /// tokens list, on timeouts and 5xx responses.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> OneInchClient {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Sets `User-Agent` of every request, e.g. the name and version of the
+    /// application.
+    pub fn with_user_agent(self, user_agent: HeaderValue) -> OneInchClient {
+        self.with_default_header(USER_AGENT, user_agent)
+    }
+
+    /// Adds header to every request, like partner identification headers some
+    /// plans require, replacing the previous value of the same header.
+    /// `Authorization` is always set by the client, so it's ignored.
+    pub fn with_default_header(mut self, name: HeaderName, value: HeaderValue) -> OneInchClient {
+        if name != AUTHORIZATION {
+            self.default_headers.insert(name, value);
+        }
+        self
+    }
+
+    /// Enables journaling the requests of the client and its clones, see
+    /// [`RequestJournal`].
+    pub fn with_journal(mut self, journal: RequestJournal) -> OneInchClient {
+        self.journal = Some(Arc::new(journal));
+        self
+    }
+
+    /// Returns the request journal, if journaling is enabled.
+    pub fn journal(&self) -> Option<&RequestJournal> {
+        self.journal.as_deref()
+    }
+
+    /// Enables caching responses of the endpoints configured in the cache, so
+    /// tasks sharing the client don't request the same slow-changing data
+    /// again until its TTL expires.
+    pub fn with_response_cache(mut self, response_cache: ResponseCache) -> OneInchClient {
+        self.response_cache = Some(Arc::new(response_cache));
+        self
+    }
+
+    /// Returns the response cache, if caching is enabled.
+    pub fn response_cache(&self) -> Option<&ResponseCache> {
+        self.response_cache.as_deref()
+    }
+
+    /// Enables tracking of the API key quota reported by the rate limit
+    /// headers of the responses, see [`quota`](OneInchClient::quota).
+    pub fn with_quota_tracking(mut self) -> OneInchClient {
+        self.quota = Some(Arc::new(QuotaTracker::default()));
+        self
+    }
+
+    /// Returns quota reported by the latest response which had rate limit
+    /// headers, if quota tracking is enabled.
+    pub fn quota(&self) -> Option<Quota> {
+        self.quota.as_ref().and_then(|quota| quota.last())
+    }
+
+    /// Enables checking balance and allowance of the source token before swap
+    /// requests whose estimation isn't disabled, so shortfalls are reported as
+    /// [`PreflightError`](crate::swap::PreflightError) with needed and
+    /// available amounts instead of the API's estimation error.
+    pub fn with_swap_preflight(mut self, swap_preflight: bool) -> OneInchClient {
+        self.swap_preflight = swap_preflight;
+        self
+    }
+
+    /// Sets limits of the response body size.
+    pub fn with_response_limits(mut self, response_limits: ResponseLimits) -> OneInchClient {
+        self.response_limits = response_limits;
+        self
+    }
+
+    /// Sets defaults of the `include*` flags applied to quote and swap requests
+    /// which don't set them explicitly.
+    pub fn with_include_defaults(mut self, include_defaults: IncludeDefaults) -> OneInchClient {
+        self.include_defaults = include_defaults;
+        self
+    }
+
+    /// Sets jitter windows of the background tasks (wallet watching, order
+    /// monitoring, etc) started by the client.
+    pub fn with_jitter(mut self, jitter: JitterConfig) -> OneInchClient {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Sets confirmations considered final per chain, used by transaction
+    /// status helpers.
+    pub fn with_finality(mut self, finality: FinalityConfig) -> OneInchClient {
+        self.finality = finality;
+        self
+    }
+
+    /// Sets base URL the requests of the client are sent to, keeping the rest
+    /// of the environment profile.
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> OneInchClient {
+        self.environment = self.environment.with_base_url(base_url);
+        self
+    }
+
+    /// Returns the environment profile the client works with.
+    pub fn environment(&self) -> &Environment {
+        &self.environment
+    }
+
+    // Base URL of the API in the client's environment.
+    pub(crate) fn base_url(&self) -> &str {
+        self.environment.base_url()
+    }
+
+    /// Returns a client sharing connection pool and rate limiter with this
+    /// one, whose requests are accounted to the specified tenant's partition
+    /// of the rate limiter.
+    pub fn for_tenant(&self, tenant: impl Into<String>) -> OneInchClient {
+        OneInchClient { tenant: Some(tenant.into()), ..self.clone() }
+    }
+
+    /// Returns a client sharing everything with this one, whose requests go
+    /// to the specified network. One client can serve many networks this way:
+    /// `client.with_chain(SupportedNetworks::Polygon).quote(details)`.
+    pub fn with_chain(&self, chain: SupportedNetworks) -> OneInchClient {
+        OneInchClient { network_id: chain, ..self.clone() }
+    }
+
+    /// Returns the network requests of the client go to.
+    pub fn network(&self) -> SupportedNetworks {
+        self.network_id
+    }
+
+    /// Sends request to the endpoint with authorization header, waiting for
+    /// the rate limiter first if it's configured. All requests to the API
+    /// should go through it.
+    #[cfg_attr(feature = "tracing", tracing::instrument(name = "request", skip_all, fields(endpoint = endpoint)))]
+    pub(crate) async fn send(&self, endpoint: &'static str, mut request: RequestBuilder) -> Result<Response, SendError> {
+        let product = endpoint.split('/').next().unwrap_or(endpoint);
+        if !self.environment.is_available(product) {
+            return Err(SendError::ProductUnavailable { product, environment: self.environment.name().to_string() });
+        }
+
+        let retry_policy = match &self.retry_policy {
+            Some(retry_policy) if is_retryable_request(&request) => retry_policy,
+            _ => return self.send_once(endpoint, request).await,
+        };
+
+        let mut attempt = 0;
+        loop {
+            let retry = request.try_clone();
+            let result = self.send_once(endpoint, request).await;
+
+            match retry {
+                Some(retry) if attempt < retry_policy.max_retries() && retry_policy.is_transient(&result) => {
+                    let delay = match &result {
+                        // Waiting longer than the policy allows is up to the
+                        // caller, so such rate limit is returned as is
+                        Err(SendError::RateLimited { retry_after: Some(retry_after), .. })
+                            if *retry_after > retry_policy.max_delay() =>
+                        {
+                            return result
+                        }
+                        Err(SendError::RateLimited { retry_after: Some(retry_after), .. }) => *retry_after,
+                        _ => retry_policy.delay(attempt),
+                    };
+                    tracing::debug!(endpoint, attempt, ?delay, "retrying request after transient failure");
+                    runtime::sleep(delay).await;
+
+                    request = retry;
+                    attempt += 1;
+                }
+                _ => return result,
+            }
+        }
+    }
+
+    // Sends request once, without retries.
+    async fn send_once(&self, endpoint: &'static str, request: RequestBuilder) -> Result<Response, SendError> {
+        // Open circuit fails before the rate limit budget is spent
+        if let Some(circuit_breaker) = &self.circuit_breaker {
+            circuit_breaker.acquire(endpoint).map_err(|retry_in| SendError::CircuitOpen { endpoint, retry_in })?;
+        }
+
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.acquire(self.tenant.as_deref()).await;
+        }
+
+        let credentials = self.credentials.for_endpoint(endpoint);
+
+        // Leased key copy is zeroized as soon as the header is built, only its
+        // slot in the pool is kept for reporting the response
+        let (authorization, slot) = {
+            let lease = credentials.lease().await.map_err(SendError::ApiKey)?;
+            let mut value = HeaderValue::from_str(lease.key.expose_secret()).map_err(|e| SendError::ApiKey(e.into()))?;
+            value.set_sensitive(true);
+            (value, lease.slot)
+        };
+
+        // Browser fetch has no timeouts, so requests there run until the
+        // browser gives up
+        #[cfg(not(target_arch = "wasm32"))]
+        let request = match self.timeouts.timeout_for(endpoint) {
+            Some(timeout) => request.timeout(timeout),
+            None => request,
+        };
+
+        let request = match self.default_headers.is_empty() {
+            true => request,
+            false => request.headers(self.default_headers.clone()),
+        };
+
+        let request = request.header(AUTHORIZATION, authorization);
+        let pending = self.journal.as_ref().and_then(|journal| {
+            let built = request.try_clone()?.build().ok()?;
+            Some(journal.start(endpoint, built.method().clone(), built.url().to_string()))
+        });
+
+        let started = runtime::Instant::now();
+        let result = self.dispatch(endpoint, request).await;
+        metrics::record_response(endpoint, &result, started.elapsed());
+        if let (Some(journal), Some(pending)) = (&self.journal, pending) {
+            journal.complete(pending, result.as_ref().ok().map(|response| response.status().as_u16()));
+        }
+        if let Some(circuit_breaker) = &self.circuit_breaker {
+            let success = result.as_ref().is_ok_and(|response| !response.status().is_server_error());
+            circuit_breaker.record(endpoint, success);
+        }
+
+        let response = result?;
+        credentials.report(slot, &response);
+        warn_if_deprecated_response(endpoint, &response);
+        if let Some(quota) = &self.quota {
+            quota.update(response.headers());
+        }
+
+        if response.status() == StatusCode::TOO_MANY_REQUESTS {
+            return Err(SendError::RateLimited {
+                retry_after: parse_retry_after(response.headers()),
+                request_id: request_id(response.headers()),
+            });
+        }
+
+        Ok(response)
+    }
+
+    // Sends the request, unless the mock transport or fixtures answer it.
+    #[cfg_attr(not(all(feature = "test-util", not(target_arch = "wasm32"))), allow(unused_variables))]
+    async fn dispatch(&self, endpoint: &'static str, request: RequestBuilder) -> Result<Response, reqwest::Error> {
+        #[cfg(all(feature = "test-util", not(target_arch = "wasm32")))]
+        if let Some(transport) = &self.transport {
+            return request.build().map(|request| transport.answer(endpoint, request));
+        }
+        #[cfg(all(feature = "test-util", not(target_arch = "wasm32")))]
+        if let Some(fixtures) = &self.fixtures {
+            return fixtures.dispatch(endpoint, request).await;
+        }
+
+        request.send().await
+    }
+}
+
+/// Builder of [`OneInchClient`] covering its whole configuration, so new
+/// settings don't change signatures of the constructors. API key (or its
+/// provider, or pool) and network are required, everything else defaults to
+/// the same values as [`new_with_default_http`] uses.
+///
+/// ```
+/// use one_inch::{
+///     client::{OneInchClientBuilder, SupportedNetworks},
+///     retry::RetryPolicy,
+///     timeout::Timeouts,
+/// };
+/// use reqwest::header::{HeaderName, HeaderValue};
+/// use std::time::Duration;
+///
+/// let client = OneInchClientBuilder::new()
+///     .api_key("token".to_string())
+///     .network(SupportedNetworks::Arbitrum)
+///     .base_url("https://enterprise.example.com")
+///     .timeouts(Timeouts::new().default_timeout(Duration::from_secs(10)))
+///     .retry_policy(RetryPolicy::new(3))
+///     .header(HeaderName::from_static("x-partner-id"), HeaderValue::from_static("acme"))
+///     .build()
+///     .unwrap();
+/// assert_eq!(client.environment().base_url(), "https://enterprise.example.com");
+/// ```
+#[derive(Default)]
+pub struct OneInchClientBuilder {
+    credentials: Option<Credentials>,
+    network_id: Option<SupportedNetworks>,
+    environment: Option<Environment>,
+    base_url: Option<String>,
+    timeouts: Option<Timeouts>,
+    retry_policy: Option<RetryPolicy>,
+    rate_limiter: Option<RateLimiter>,
+    default_headers: HeaderMap,
+    http_client: Option<reqwest::Client>,
+    #[cfg(not(target_arch = "wasm32"))]
+    http_settings: HttpSettings,
+}
+
+/// Enumerates errors of building the client.
+#[derive(Error, Debug)]
+pub enum ClientBuilderError {
+    #[error("API key, its provider or pool is required")]
+    MissingApiKey,
+
+    #[error("Network is required")]
+    MissingNetwork,
+
+    /// HTTP client couldn't be built with the settings, e.g. the proxy.
+    #[error("HTTP client error: {0}")]
+    Http(#[from] reqwest::Error),
+}
+
+impl OneInchClientBuilder {
+    pub fn new() -> OneInchClientBuilder {
+        OneInchClientBuilder::default()
+    }
+
+    /// Sets API key the requests are authorized with.
+    pub fn api_key(mut self, token: String) -> OneInchClientBuilder {
+        self.credentials = Some(Credentials::Static(Arc::new(SecretString::new(token))));
+        self
+    }
+
+    /// Sets provider the API key is fetched from before every request.
+    pub fn key_provider(mut self, provider: impl ApiKeyProvider + 'static) -> OneInchClientBuilder {
+        self.credentials = Some(Credentials::Provider(Arc::new(provider)));
+        self
+    }
+
+    /// Sets pool of API keys the requests are spread across.
+    pub fn key_pool(mut self, pool: ApiKeyPool) -> OneInchClientBuilder {
+        self.credentials = Some(Credentials::Pool(Arc::new(pool)));
+        self
+    }
+
+    pub fn network(mut self, network_id: SupportedNetworks) -> OneInchClientBuilder {
+        self.network_id = Some(network_id);
+        self
+    }
+
+    /// Sets environment profile, production by default.
+    pub fn environment(mut self, environment: Environment) -> OneInchClientBuilder {
+        self.environment = Some(environment);
+        self
+    }
+
+    /// Sets base URL, taking precedence over the environment profile.
+    pub fn base_url(mut self, base_url: impl Into<String>) -> OneInchClientBuilder {
+        self.base_url = Some(base_url.into());
+        self
+    }
+
+    pub fn timeouts(mut self, timeouts: Timeouts) -> OneInchClientBuilder {
+        self.timeouts = Some(timeouts);
+        self
+    }
+
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> OneInchClientBuilder {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Sets rate limiter, taking precedence over the RPS limit of the
+    /// environment profile. See
+    /// [`with_rate_limiter`](OneInchClient::with_rate_limiter).
+    pub fn rate_limiter(mut self, rate_limiter: RateLimiter) -> OneInchClientBuilder {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Adds header to every request, replacing the previous value of the
+    /// same header. `Authorization` is always set by the client, so it's
+    /// ignored.
+    pub fn header(mut self, name: HeaderName, value: HeaderValue) -> OneInchClientBuilder {
+        self.default_headers.insert(name, value);
+        self
+    }
+
+    /// Sets `User-Agent` of every request.
+    pub fn user_agent(self, user_agent: HeaderValue) -> OneInchClientBuilder {
+        self.header(USER_AGENT, user_agent)
+    }
+
+    /// Sets externally built HTTP client, see [`new_with_http_client`]. It
+    /// takes precedence over the proxy and connect timeout.
+    pub fn http_client(mut self, http_client: reqwest::Client) -> OneInchClientBuilder {
+        self.http_client = Some(http_client);
+        self
+    }
+
+    /// Sets proxy all requests are sent through. Not available in the browser.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn proxy(mut self, proxy: Proxy) -> OneInchClientBuilder {
+        self.http_settings.proxy = Some(proxy);
+        self
+    }
+
+    /// Sets timeout of establishing connections. Not available in the
+    /// browser.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn connect_timeout(mut self, connect_timeout: Duration) -> OneInchClientBuilder {
+        self.http_settings.connect_timeout = Some(connect_timeout);
+        self
+    }
+
+    /// Enables or disables gzip and brotli compression of the responses,
+    /// enabled by default. Not available in the browser.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn compression(mut self, compression: bool) -> OneInchClientBuilder {
+        self.http_settings.compression = compression;
+        self
+    }
+
+    /// Sets how many idle connections per host are kept in the pool. Not
+    /// available in the browser.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn pool_max_idle_per_host(mut self, max_idle: usize) -> OneInchClientBuilder {
+        self.http_settings.pool.max_idle_per_host = Some(max_idle);
+        self
+    }
+
+    /// Sets how long idle connections are kept in the pool, 90 seconds by
+    /// default. Loops quoting in bursts should keep them longer than the
+    /// pause between bursts, so bursts don't start with new TLS handshakes.
+    /// Not available in the browser.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn pool_idle_timeout(mut self, idle_timeout: Duration) -> OneInchClientBuilder {
+        self.http_settings.pool.idle_timeout = Some(idle_timeout);
+        self
+    }
+
+    /// Sets interval of TCP keep-alive probes, keeping idle connections from
+    /// being dropped by NATs and load balancers. Disabled by default. Not
+    /// available in the browser.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn tcp_keepalive(mut self, interval: Duration) -> OneInchClientBuilder {
+        self.http_settings.pool.tcp_keepalive = Some(interval);
+        self
+    }
+
+    /// Enables or disables `TCP_NODELAY`, enabled by default. Not available
+    /// in the browser.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn tcp_nodelay(mut self, enabled: bool) -> OneInchClientBuilder {
+        self.http_settings.pool.tcp_nodelay = enabled;
+        self
+    }
+
+    pub fn build(self) -> Result<OneInchClient, ClientBuilderError> {
+        let credentials = self.credentials.ok_or(ClientBuilderError::MissingApiKey)?;
+        let network_id = self.network_id.ok_or(ClientBuilderError::MissingNetwork)?;
+
+        let mut client = new_with_credentials(credentials, network_id, self.environment.unwrap_or_else(Environment::production));
+        if let Some(base_url) = self.base_url {
+            client = client.with_base_url(base_url);
+        }
+        if let Some(timeouts) = self.timeouts {
+            client = client.with_timeouts(timeouts);
+        }
+        client.retry_policy = self.retry_policy;
+        if let Some(rate_limiter) = self.rate_limiter {
+            client = client.with_rate_limiter(rate_limiter);
+        }
+        for (name, value) in self.default_headers {
+            if let Some(name) = name {
+                client = client.with_default_header(name, value);
+            }
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            client.http_client = self.http_settings.build()?;
+            client.http_settings = self.http_settings;
+        }
+        if let Some(http_client) = self.http_client {
+            client.http_client = http_client;
+        }
+
+        Ok(client)
+    }
+}
+
+/// Represents errors that can occur while sending request.
+#[derive(Error, Debug)]
+pub enum SendError {
+    /// API key couldn't be obtained, so the request hasn't been sent.
+    #[error("API key error: {0}")]
+    ApiKey(ApiKeyError),
+
+    /// Request failed because of a network error.
+    #[error(transparent)]
+    Network(#[from] reqwest::Error),
+
+    /// API rejected the request because of the rate limit. `retry_after` is
+    /// taken from the `Retry-After` header if the API provided it.
+    #[error("Rate limited, retry after {retry_after:?}")]
+    RateLimited { retry_after: Option<Duration>, request_id: Option<String> },
+
+    /// Circuit of the endpoint is open after consecutive failures, so the
+    /// request hasn't been sent.
+    #[error("Circuit of {endpoint} is open, retry in {retry_in:?}")]
+    CircuitOpen { endpoint: &'static str, retry_in: Duration },
+
+    /// Product of the endpoint is unavailable in the client's environment, so
+    /// the request hasn't been sent.
+    #[error("{product} is unavailable in the {environment} environment")]
+    ProductUnavailable { product: &'static str, environment: String },
 }
 
 /// List of all supported Networks/Chains with their IDs.
@@ -94,3 +986,126 @@ pub enum SupportedCurrencies {
     VND,
     ZAR,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{error::OneInchError, utils::mock_server::MockServer};
+
+    #[test]
+    fn test_proxy_configuration() {
+        let token = "token".to_string();
+        assert!(new_with_proxy(token.clone(), SupportedNetworks::Ethereum, "http://127.0.0.1:3128").is_ok());
+        assert!(new_with_proxy(token, SupportedNetworks::Ethereum, "not a proxy url").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_chain_override_is_scoped() {
+        let server = MockServer::start(r#"{"address": "0x1111111111111111111111111111111111111111"}"#).await;
+        let client = new_with_base_url("token".to_string(), SupportedNetworks::Ethereum, &server.base_url);
+
+        client.with_chain(SupportedNetworks::Polygon).get_router_address().await.unwrap();
+        client.get_router_address().await.unwrap();
+
+        let requests = server.requests();
+        assert!(requests[0].starts_with("/swap/v5.2/137/"));
+        assert!(requests[1].starts_with("/swap/v5.2/1/"));
+        assert_eq!(client.network() as u32, SupportedNetworks::Ethereum as u32);
+    }
+
+    #[test]
+    fn test_clones_share_rate_limiter() {
+        let client = new_with_default_http("token".to_string(), SupportedNetworks::Ethereum)
+            .with_rate_limiter(RateLimiter::new(1.0).unwrap().with_burst(1).unwrap());
+        let clone = client.for_tenant("analytics");
+
+        assert!(client.rate_limiter().unwrap().try_acquire(None).is_ok());
+        assert!(clone.rate_limiter().unwrap().try_acquire(None).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_long_retry_after_is_not_slept() {
+        let server = MockServer::start_with_headers(429, &[("Retry-After", "86400")], "{}").await;
+        let client = new_with_base_url("token".to_string(), SupportedNetworks::Ethereum, &server.base_url)
+            .with_retry_policy(RetryPolicy::new(3));
+
+        assert!(matches!(
+            client.get_router_address().await,
+            Err(OneInchError::RateLimited { retry_after: Some(retry_after), .. }) if retry_after.as_secs() == 86400
+        ));
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[test]
+    fn test_api_key_is_redacted_in_debug_output() {
+        let client = new_with_default_http("secret-api-key".to_string(), SupportedNetworks::Ethereum)
+            .with_product_key("portfolio", "secret-portfolio-key".to_string());
+        let pool = new_with_key_pool(ApiKeyPool::new(["secret-pooled-key".to_string()]), SupportedNetworks::Base);
+
+        let output = format!("{:?} {:?}", client, pool);
+        assert!(!output.contains("secret"));
+        assert!(output.contains("[REDACTED]"));
+    }
+
+    #[tokio::test]
+    async fn test_builder_applies_configuration() {
+        assert!(matches!(
+            OneInchClientBuilder::new().network(SupportedNetworks::Ethereum).build(),
+            Err(ClientBuilderError::MissingApiKey)
+        ));
+        assert!(matches!(
+            OneInchClientBuilder::new().api_key("token".to_string()).build(),
+            Err(ClientBuilderError::MissingNetwork)
+        ));
+
+        let server = MockServer::start(r#"{"address": "0x1111111111111111111111111111111111111111"}"#).await;
+        let client = OneInchClientBuilder::new()
+            .api_key("token".to_string())
+            .network(SupportedNetworks::Polygon)
+            .base_url(&server.base_url)
+            .connect_timeout(Duration::from_secs(1))
+            .pool_max_idle_per_host(4)
+            .pool_idle_timeout(Duration::from_secs(300))
+            .tcp_keepalive(Duration::from_secs(30))
+            .retry_policy(RetryPolicy::new(3))
+            .rate_limiter(RateLimiter::new(10.0).unwrap())
+            .header(HeaderName::from_static("x-partner-id"), HeaderValue::from_static("acme"))
+            .build()
+            .unwrap();
+
+        client.get_router_address().await.unwrap();
+        assert!(server.requests()[0].starts_with("/swap/v5.2/137/"));
+        assert!(client.retry_policy.is_some());
+        assert!(client.rate_limiter().is_some());
+        assert_eq!(client.default_headers["x-partner-id"], "acme");
+        assert_eq!(client.http_settings.pool.idle_timeout, Some(Duration::from_secs(300)));
+        assert!(client.http_settings.pool.tcp_nodelay);
+    }
+
+    #[tokio::test]
+    async fn test_default_headers_are_sent() {
+        let server = MockServer::start(r#"{"address": "0x1111111111111111111111111111111111111111"}"#).await;
+        let client = new_with_base_url("token".to_string(), SupportedNetworks::Ethereum, &server.base_url)
+            .with_user_agent(HeaderValue::from_static("my-app/1.0"))
+            .with_default_header(HeaderName::from_static("x-partner-id"), HeaderValue::from_static("acme"))
+            .with_default_header(AUTHORIZATION, HeaderValue::from_static("spoofed"));
+
+        client.get_router_address().await.unwrap();
+        assert_eq!(server.last_header("User-Agent").as_deref(), Some("my-app/1.0"));
+        assert_eq!(server.last_header("X-Partner-Id").as_deref(), Some("acme"));
+        assert_eq!(server.last_header("Authorization").as_deref(), Some("token"));
+    }
+
+    #[tokio::test]
+    async fn test_compression_is_negotiated_by_default() {
+        let server = MockServer::start(r#"{"address": "0x1111111111111111111111111111111111111111"}"#).await;
+        let client = new_with_base_url("token".to_string(), SupportedNetworks::Ethereum, &server.base_url);
+
+        client.get_router_address().await.unwrap();
+        let accepted = server.last_header("Accept-Encoding").unwrap();
+        assert!(accepted.contains("gzip") && accepted.contains("br"));
+
+        client.with_compression(false).unwrap().get_router_address().await.unwrap();
+        assert_eq!(server.last_header("Accept-Encoding"), None);
+    }
+}
@@ -0,0 +1,143 @@
+use num_bigint::BigUint;
+use serde::Serialize;
+use std::error::Error;
+use thiserror::Error;
+use tiny_keccak::{Hasher, Keccak};
+
+/// Enumerates potential errors when encoding values for EIP-712 hashing.
+#[derive(Error, Debug, Eq, PartialEq)]
+pub enum Eip712Error {
+    /// Indicates the value is not a valid hex encoded 20 bytes address.
+    #[error("Invalid address: {0}")]
+    InvalidAddress(String),
+
+    /// Indicates the value is not a decimal number which fits into uint256.
+    #[error("Invalid uint256 value: {0}")]
+    InvalidUint(String),
+}
+
+/// Trait for anything able to sign EIP-712 typed data. Implement it for your
+/// wallet/signer to sign orders built by this crate. Both the typed data (for
+/// signers working with `eth_signTypedData_v4`) and the final hash (for signers
+/// working with raw digests) are provided.
+pub trait TypedDataSigner {
+    /// Signs typed data and returns hex encoded 65 bytes signature.
+    fn sign_typed_data(&self, typed_data: &serde_json::Value, hash: [u8; 32]) -> Result<String, Box<dyn Error>>;
+}
+
+/// Struct represents EIP-712 domain of a signing contract.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Eip712Domain {
+    pub name: String,
+    pub version: String,
+    pub chain_id: u64,
+    pub verifying_contract: String,
+}
+
+impl Eip712Domain {
+    const TYPE: &'static str = "EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)";
+
+    /// Computes the domain separator.
+    pub fn separator(&self) -> Result<[u8; 32], Eip712Error> {
+        let mut encoded = Vec::with_capacity(5 * 32);
+        encoded.extend_from_slice(&keccak256(Self::TYPE.as_bytes()));
+        encoded.extend_from_slice(&keccak256(self.name.as_bytes()));
+        encoded.extend_from_slice(&keccak256(self.version.as_bytes()));
+        encoded.extend_from_slice(&encode_uint(&self.chain_id.to_string())?);
+        encoded.extend_from_slice(&encode_address(&self.verifying_contract)?);
+
+        Ok(keccak256(&encoded))
+    }
+
+    /// Returns the `EIP712Domain` type definition in the typed data format.
+    pub fn type_definition() -> serde_json::Value {
+        serde_json::json!([
+            { "name": "name", "type": "string" },
+            { "name": "version", "type": "string" },
+            { "name": "chainId", "type": "uint256" },
+            { "name": "verifyingContract", "type": "address" },
+        ])
+    }
+}
+
+/// Computes keccak256 hash of the data.
+pub fn keccak256(data: &[u8]) -> [u8; 32] {
+    let mut hasher = Keccak::v256();
+    let mut output = [0u8; 32];
+    hasher.update(data);
+    hasher.finalize(&mut output);
+    output
+}
+
+/// Computes final EIP-712 hash to be signed from domain separator and hash of
+/// the struct.
+pub fn typed_data_hash(domain_separator: &[u8; 32], struct_hash: &[u8; 32]) -> [u8; 32] {
+    let mut encoded = Vec::with_capacity(2 + 2 * 32);
+    encoded.extend_from_slice(&[0x19, 0x01]);
+    encoded.extend_from_slice(domain_separator);
+    encoded.extend_from_slice(struct_hash);
+    keccak256(&encoded)
+}
+
+/// Encodes hex address (with or without `0x` prefix) as 32 bytes word.
+pub fn encode_address(address: &str) -> Result<[u8; 32], Eip712Error> {
+    let raw = address.strip_prefix("0x").unwrap_or(address);
+    let bytes = hex::decode(raw).map_err(|_| Eip712Error::InvalidAddress(address.to_string()))?;
+    if bytes.len() != 20 {
+        return Err(Eip712Error::InvalidAddress(address.to_string()));
+    }
+
+    let mut word = [0u8; 32];
+    word[12..].copy_from_slice(&bytes);
+    Ok(word)
+}
+
+/// Encodes decimal number as 32 bytes big-endian word.
+pub fn encode_uint(value: &str) -> Result<[u8; 32], Eip712Error> {
+    let number = value.parse::<BigUint>().map_err(|_| Eip712Error::InvalidUint(value.to_string()))?;
+    let bytes = number.to_bytes_be();
+    if bytes.len() > 32 {
+        return Err(Eip712Error::InvalidUint(value.to_string()));
+    }
+
+    let mut word = [0u8; 32];
+    word[32 - bytes.len()..].copy_from_slice(&bytes);
+    Ok(word)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keccak256_of_empty_input() {
+        assert_eq!(
+            hex::encode(keccak256(&[])),
+            "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+        );
+    }
+
+    // Values are taken from the example in EIP-712 specification.
+    #[test]
+    fn test_domain_separator_matches_specification_example() {
+        let domain = Eip712Domain {
+            name: "Ether Mail".into(),
+            version: "1".into(),
+            chain_id: 1,
+            verifying_contract: "0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC".into(),
+        };
+
+        assert_eq!(
+            hex::encode(domain.separator().unwrap()),
+            "f2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f"
+        );
+    }
+
+    #[test]
+    fn test_encoding_rejects_invalid_values() {
+        assert_eq!(encode_address("0x1234"), Err(Eip712Error::InvalidAddress("0x1234".into())));
+        assert_eq!(encode_uint("-1"), Err(Eip712Error::InvalidUint("-1".into())));
+        assert_eq!(encode_uint("1").unwrap()[31], 1);
+    }
+}
@@ -0,0 +1,71 @@
+use std::fmt;
+
+/// How composite calls treat failures of the enrichment data (prices, token
+/// info, history) fetched alongside their core data.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub enum EnrichmentMode {
+    /// Any failure fails the whole call.
+    #[default]
+    Strict,
+    /// Failed enrichment is left out and reported as an
+    /// [`EnrichmentWarning`], only failures of the core data fail the call.
+    SoftFail,
+}
+
+/// Enrichment left out of the composite response because its source failed.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct EnrichmentWarning {
+    /// Source of the enrichment, like `prices` or `history`.
+    pub source: &'static str,
+    pub error: String,
+}
+
+impl fmt::Display for EnrichmentWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} unavailable: {}", self.source, self.error)
+    }
+}
+
+impl EnrichmentMode {
+    // Returns the enrichment, or `None` with the warning recorded if it failed
+    // in soft-fail mode.
+    pub(crate) fn enrich<T, E: fmt::Display>(
+        self,
+        source: &'static str,
+        result: Result<T, E>,
+        warnings: &mut Vec<EnrichmentWarning>,
+    ) -> Result<Option<T>, E> {
+        match (result, self) {
+            (Ok(value), _) => Ok(Some(value)),
+            (Err(e), EnrichmentMode::Strict) => Err(e),
+            (Err(e), EnrichmentMode::SoftFail) => {
+                tracing::warn!(source, "enrichment failed: {}", e);
+                warnings.push(EnrichmentWarning { source, error: e.to_string() });
+                Ok(None)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    #[test]
+    fn test_soft_fail_degrades_to_warning() {
+        let mut warnings = vec![];
+        let failed = || Err::<u8, Box<dyn Error>>("timed out".into());
+
+        assert!(EnrichmentMode::Strict.enrich("prices", failed(), &mut warnings).is_err());
+        assert!(warnings.is_empty());
+
+        assert_eq!(EnrichmentMode::SoftFail.enrich("prices", failed(), &mut warnings).unwrap(), None);
+        assert_eq!(
+            EnrichmentMode::SoftFail.enrich("history", Ok::<_, Box<dyn Error>>(1), &mut warnings).unwrap(),
+            Some(1)
+        );
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].to_string(), "prices unavailable: timed out");
+    }
+}
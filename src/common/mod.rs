@@ -1 +1,6 @@
+pub mod eip712;
+pub mod enrichment;
+pub mod page;
+pub mod permit;
+pub mod tags;
 pub mod token;
@@ -0,0 +1,249 @@
+use futures::{stream, Stream};
+use serde::Deserialize;
+use std::{collections::VecDeque, error::Error, future::Future};
+
+/// Position of the next page, to pass back into the list endpoint. Endpoints
+/// paginate either by page number (starting from 1) or by offset.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Cursor {
+    Page(u32),
+    Offset(u32),
+}
+
+impl Cursor {
+    /// Returns number of the page, if the endpoint paginates by page number.
+    pub fn page(self) -> Option<u32> {
+        match self {
+            Cursor::Page(page) => Some(page),
+            Cursor::Offset(_) => None,
+        }
+    }
+
+    /// Returns offset of the page, if the endpoint paginates by offset.
+    pub fn offset(self) -> Option<u32> {
+        match self {
+            Cursor::Offset(offset) => Some(offset),
+            Cursor::Page(_) => None,
+        }
+    }
+}
+
+/// Page of items returned by the list endpoints, in one shape regardless of
+/// how the endpoint paginates.
+///
+/// `next_cursor` is `None` on the last page, `total` is the number of items on
+/// all the pages if the API reports it.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(from = "MetaPage<T>", bound(deserialize = "T: Deserialize<'de>"))]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub next_cursor: Option<Cursor>,
+    pub total: Option<u64>,
+}
+
+impl<T> Page<T> {
+    /// Creates the last page of the items.
+    pub fn last(items: Vec<T>) -> Page<T> {
+        Page { items, next_cursor: None, total: None }
+    }
+
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+
+    pub fn into_items(self) -> Vec<T> {
+        self.items
+    }
+
+    pub fn next_cursor(&self) -> Option<Cursor> {
+        self.next_cursor
+    }
+
+    pub fn total(&self) -> Option<u64> {
+        self.total
+    }
+
+    /// Returns true if there are more pages after this one.
+    pub fn has_next(&self) -> bool {
+        self.next_cursor.is_some()
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Converts items of the page, keeping its pagination.
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> Page<U> {
+        Page { items: self.items.into_iter().map(f).collect(), next_cursor: self.next_cursor, total: self.total }
+    }
+
+    // Creates page of the endpoint paginated by page number, which doesn't
+    // report the total. Page is full if it has `limit` items, so the next one
+    // may exist.
+    pub(crate) fn numbered(items: Vec<T>, page: Option<u32>, limit: Option<u32>) -> Page<T> {
+        let next_cursor =
+            limit.filter(|limit| *limit as usize == items.len() && *limit > 0).map(|_| Cursor::Page(page.unwrap_or(1) + 1));
+        Page { items, next_cursor, total: None }
+    }
+
+    // Creates page of the endpoint paginated by offset, which doesn't report
+    // the total.
+    pub(crate) fn offset(items: Vec<T>, offset: Option<u32>, limit: Option<u32>) -> Page<T> {
+        let next_cursor = limit
+            .filter(|limit| *limit as usize == items.len() && *limit > 0)
+            .map(|limit| Cursor::Offset(offset.unwrap_or_default() + limit));
+        Page { items, next_cursor, total: None }
+    }
+}
+
+impl<T> IntoIterator for Page<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Page<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
+
+/// Returns a stream of the items of all the pages, fetching the next page
+/// with `fetch` once the items of the previous one are consumed. The first
+/// page is fetched with the `start` cursor. Failed fetch is yielded as an
+/// error and ends the stream.
+///
+/// The client's `stream_*` methods are built with it, and it can be used
+/// with any other paginated call:
+///
+/// ```no_run
+/// use futures::StreamExt;
+/// use one_inch::{
+///     client::{new_with_default_http, SupportedNetworks},
+///     common::page::{paginate, Cursor},
+/// };
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let client = new_with_default_http("token".to_string(), SupportedNetworks::Ethereum);
+/// let orders = paginate(None, |cursor: Option<Cursor>| {
+///     let client = client.clone();
+///     async move { client.get_fusion_active_orders(cursor.and_then(Cursor::page), Some(100)).await }
+/// });
+/// futures::pin_mut!(orders);
+/// while let Some(order) = orders.next().await {
+///     println!("{:?}", order.unwrap());
+/// }
+/// # }
+/// ```
+pub fn paginate<T, F, Fut>(start: Option<Cursor>, fetch: F) -> impl Stream<Item = Result<T, Box<dyn Error>>>
+where
+    F: FnMut(Option<Cursor>) -> Fut,
+    Fut: Future<Output = Result<Page<T>, Box<dyn Error>>>,
+{
+    // Cursor of the next page, `None` after the last one
+    let next: Option<Option<Cursor>> = Some(start);
+
+    stream::unfold((fetch, next, VecDeque::new()), |(mut fetch, mut next, mut items)| async move {
+        loop {
+            if let Some(item) = items.pop_front() {
+                return Some((Ok(item), (fetch, next, items)));
+            }
+
+            let cursor = next?;
+            match fetch(cursor).await {
+                Ok(page) => {
+                    next = page.next_cursor.map(Some);
+                    items.extend(page.items);
+                }
+                Err(e) => return Some((Err(e), (fetch, None, items))),
+            }
+        }
+    })
+}
+
+/// Pagination metadata of the page, as the Orderbook and Fusion APIs return
+/// it.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct PageMeta {
+    total_items: u64,
+    total_pages: u64,
+    current_page: u64,
+}
+
+// Page as the Orderbook and Fusion APIs return it.
+#[derive(Deserialize)]
+pub(crate) struct MetaPage<T> {
+    meta: PageMeta,
+    items: Vec<T>,
+}
+
+impl<T> From<MetaPage<T>> for Page<T> {
+    fn from(page: MetaPage<T>) -> Self {
+        let next_cursor = (page.meta.current_page < page.meta.total_pages)
+            .then(|| u32::try_from(page.meta.current_page + 1).ok())
+            .flatten()
+            .map(Cursor::Page);
+
+        Page { items: page.items, next_cursor, total: Some(page.meta.total_items) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_page_cursors() {
+        let body = r#"{"meta": {"totalItems": 3, "itemsPerPage": 2, "totalPages": 2, "currentPage": 1}, "items": [1, 2]}"#;
+        let page: Page<u32> = serde_json::from_str(body).unwrap();
+        assert_eq!(page.next_cursor(), Some(Cursor::Page(2)));
+        assert_eq!(page.total(), Some(3));
+
+        let body = r#"{"meta": {"totalItems": 3, "itemsPerPage": 2, "totalPages": 2, "currentPage": 2}, "items": [3]}"#;
+        let page: Page<u32> = serde_json::from_str(body).unwrap();
+        assert!(!page.has_next());
+        assert_eq!(page.map(|n| n * 10).into_items(), vec![30]);
+
+        assert_eq!(Page::offset(vec![1, 2], Some(4), Some(2)).next_cursor(), Some(Cursor::Offset(6)));
+        assert_eq!(Page::offset(vec![1], Some(4), Some(2)).next_cursor(), None);
+        assert_eq!(Page::numbered(vec![1, 2], None, Some(2)).next_cursor(), Some(Cursor::Page(2)));
+        assert_eq!(Page::numbered(vec![1, 2], Some(3), None).next_cursor(), None);
+    }
+
+    #[tokio::test]
+    async fn test_pages_are_streamed() {
+        use futures::StreamExt;
+
+        let items: Vec<u32> = paginate(Some(Cursor::Offset(0)), |cursor| async move {
+            let offset = cursor.and_then(Cursor::offset).unwrap();
+            let items = (offset..(offset + 2).min(5)).collect();
+            Ok(Page::offset(items, Some(offset), Some(2)))
+        })
+        .map(|item| item.unwrap())
+        .collect()
+        .await;
+        assert_eq!(items, vec![0, 1, 2, 3, 4]);
+
+        let mut fetches = 0;
+        let results: Vec<_> = paginate(None, |_| {
+            fetches += 1;
+            async { Err::<Page<u32>, _>("unavailable".into()) }
+        })
+        .collect()
+        .await;
+        assert_eq!(results.len(), 1);
+        assert_eq!(fetches, 1);
+    }
+}
@@ -0,0 +1,337 @@
+use crate::{
+    common::eip712::{encode_address, encode_uint, keccak256, typed_data_hash, Eip712Domain, Eip712Error, TypedDataSigner},
+    consts::PERMIT2_ADDRESS,
+    utils::runtime::{SystemTime, UNIX_EPOCH},
+};
+use num_bigint::BigUint;
+use serde::Serialize;
+use std::{error::Error, sync::Mutex};
+use thiserror::Error;
+
+/// Kind of the permit, since nonces of EIP-2612 and Permit2 are independent.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum PermitKind {
+    Eip2612,
+    Permit2,
+}
+
+/// Values of the permit which matter for allowance accounting.
+///
+/// Permit2 permits are signed as `PermitSingle`, with `value` as the amount
+/// and `deadline` as both the allowance expiration and the signature
+/// deadline.
+#[derive(Debug, Clone, Serialize)]
+pub struct PermitDetails {
+    #[serde(skip)]
+    pub kind: PermitKind,
+    #[serde(skip)]
+    pub token: String,
+
+    pub owner: String,
+    pub spender: String,
+    pub value: String,
+    pub nonce: String,
+    pub deadline: u64,
+}
+
+impl PermitDetails {
+    const TYPE: &'static str = "Permit(address owner,address spender,uint256 value,uint256 nonce,uint256 deadline)";
+    const PERMIT2_DETAILS_TYPE: &'static str = "PermitDetails(address token,uint160 amount,uint48 expiration,uint48 nonce)";
+    const PERMIT2_SINGLE_TYPE: &'static str = "PermitSingle(PermitDetails details,address spender,uint256 sigDeadline)PermitDetails(address token,uint160 amount,uint48 expiration,uint48 nonce)";
+    const PERMIT2_DOMAIN_TYPE: &'static str = "EIP712Domain(string name,uint256 chainId,address verifyingContract)";
+
+    /// Computes EIP-712 hash of the EIP-2612 `Permit` or Permit2
+    /// `PermitSingle` struct (without domain).
+    pub fn struct_hash(&self) -> Result<[u8; 32], Eip712Error> {
+        if self.kind == PermitKind::Permit2 {
+            return self.permit2_struct_hash();
+        }
+
+        let mut encoded = Vec::with_capacity(6 * 32);
+        encoded.extend_from_slice(&keccak256(Self::TYPE.as_bytes()));
+        encoded.extend_from_slice(&encode_address(&self.owner)?);
+        encoded.extend_from_slice(&encode_address(&self.spender)?);
+        encoded.extend_from_slice(&encode_uint(&self.value)?);
+        encoded.extend_from_slice(&encode_uint(&self.nonce)?);
+        encoded.extend_from_slice(&encode_uint(&self.deadline.to_string())?);
+
+        Ok(keccak256(&encoded))
+    }
+
+    fn permit2_struct_hash(&self) -> Result<[u8; 32], Eip712Error> {
+        let mut details = Vec::with_capacity(5 * 32);
+        details.extend_from_slice(&keccak256(Self::PERMIT2_DETAILS_TYPE.as_bytes()));
+        details.extend_from_slice(&encode_address(&self.token)?);
+        details.extend_from_slice(&encode_uint(&self.value)?);
+        details.extend_from_slice(&encode_uint(&self.deadline.to_string())?);
+        details.extend_from_slice(&encode_uint(&self.nonce)?);
+
+        let mut encoded = Vec::with_capacity(4 * 32);
+        encoded.extend_from_slice(&keccak256(Self::PERMIT2_SINGLE_TYPE.as_bytes()));
+        encoded.extend_from_slice(&keccak256(&details));
+        encoded.extend_from_slice(&encode_address(&self.spender)?);
+        encoded.extend_from_slice(&encode_uint(&self.deadline.to_string())?);
+
+        Ok(keccak256(&encoded))
+    }
+
+    // Separator of the Permit2 domain, which has no version.
+    fn permit2_domain_separator(chain_id: u64) -> Result<[u8; 32], Eip712Error> {
+        let mut encoded = Vec::with_capacity(4 * 32);
+        encoded.extend_from_slice(&keccak256(Self::PERMIT2_DOMAIN_TYPE.as_bytes()));
+        encoded.extend_from_slice(&keccak256(b"Permit2"));
+        encoded.extend_from_slice(&encode_uint(&chain_id.to_string())?);
+        encoded.extend_from_slice(&encode_address(PERMIT2_ADDRESS)?);
+
+        Ok(keccak256(&encoded))
+    }
+
+    /// Returns EIP-712 typed data of the permit. EIP-2612 permits use the
+    /// token domain, while Permit2 ones use the Permit2 domain and take only
+    /// the chain id from the provided one.
+    pub fn typed_data(&self, domain: &Eip712Domain) -> serde_json::Value {
+        if self.kind == PermitKind::Permit2 {
+            return serde_json::json!({
+                "types": {
+                    "EIP712Domain": [
+                        { "name": "name", "type": "string" },
+                        { "name": "chainId", "type": "uint256" },
+                        { "name": "verifyingContract", "type": "address" },
+                    ],
+                    "PermitSingle": [
+                        { "name": "details", "type": "PermitDetails" },
+                        { "name": "spender", "type": "address" },
+                        { "name": "sigDeadline", "type": "uint256" },
+                    ],
+                    "PermitDetails": [
+                        { "name": "token", "type": "address" },
+                        { "name": "amount", "type": "uint160" },
+                        { "name": "expiration", "type": "uint48" },
+                        { "name": "nonce", "type": "uint48" },
+                    ],
+                },
+                "primaryType": "PermitSingle",
+                "domain": {
+                    "name": "Permit2",
+                    "chainId": domain.chain_id,
+                    "verifyingContract": PERMIT2_ADDRESS,
+                },
+                "message": {
+                    "details": {
+                        "token": self.token,
+                        "amount": self.value,
+                        "expiration": self.deadline,
+                        "nonce": self.nonce,
+                    },
+                    "spender": self.spender,
+                    "sigDeadline": self.deadline,
+                },
+            });
+        }
+
+        serde_json::json!({
+            "types": {
+                "EIP712Domain": Eip712Domain::type_definition(),
+                "Permit": [
+                    { "name": "owner", "type": "address" },
+                    { "name": "spender", "type": "address" },
+                    { "name": "value", "type": "uint256" },
+                    { "name": "nonce", "type": "uint256" },
+                    { "name": "deadline", "type": "uint256" },
+                ],
+            },
+            "primaryType": "Permit",
+            "domain": domain,
+            "message": self,
+        })
+    }
+
+    /// Computes EIP-712 hash of the permit, in the same domain as
+    /// [`typed_data`](PermitDetails::typed_data).
+    pub fn hash(&self, domain: &Eip712Domain) -> Result<[u8; 32], Eip712Error> {
+        let separator = match self.kind {
+            PermitKind::Eip2612 => domain.separator()?,
+            PermitKind::Permit2 => Self::permit2_domain_separator(domain.chain_id)?,
+        };
+        Ok(typed_data_hash(&separator, &self.struct_hash()?))
+    }
+}
+
+/// Enumerates situations in which signing a permit may lead to double
+/// spending of the allowance.
+#[derive(Error, Debug, Eq, PartialEq)]
+pub enum PermitReplayError {
+    /// Permit with the same nonce has already been issued for the token and
+    /// owner, so only one of them can ever be used.
+    #[error("Permit with nonce {nonce} has already been issued")]
+    NonceReused { nonce: String },
+
+    /// Unexpired permit for the same token, owner and spender has already been
+    /// issued, so both of them may end up being used.
+    #[error("Permit for spender {spender} is already issued and valid until {deadline}")]
+    Overlapping { spender: String, deadline: u64 },
+}
+
+/// What registry should do when a new permit conflicts with already issued
+/// ones.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub enum ReplayPolicy {
+    /// Refuse to register (and sign) the permit.
+    #[default]
+    Reject,
+    /// Emit a warning and register the permit anyway.
+    Warn,
+}
+
+/// Registry of permits issued by the crate. Signing helpers consult it to avoid
+/// issuing a second permit which overlaps with an unexpired one and could
+/// double-spend the allowance.
+#[derive(Debug, Default)]
+pub struct PermitRegistry {
+    policy: ReplayPolicy,
+    issued: Mutex<Vec<PermitDetails>>,
+}
+
+impl PermitRegistry {
+    pub fn new(policy: ReplayPolicy) -> PermitRegistry {
+        PermitRegistry { policy, issued: Mutex::new(vec![]) }
+    }
+
+    /// Registers the permit as issued, checking it against unexpired permits
+    /// issued before.
+    pub fn register(&self, permit: &PermitDetails) -> Result<(), PermitReplayError> {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or_default();
+        let mut issued = self.issued.lock().unwrap();
+        issued.retain(|p| p.deadline >= now);
+
+        if let Some(conflict) = issued.iter().find_map(|p| conflict(p, permit)) {
+            match self.policy {
+                ReplayPolicy::Reject => return Err(conflict),
+                ReplayPolicy::Warn => tracing::warn!(token = %permit.token, owner = %permit.owner, "{}", conflict),
+            }
+        }
+
+        issued.push(permit.clone());
+        Ok(())
+    }
+
+    /// Forgets the permit, e.g. after it has been consumed on-chain or the
+    /// signature was discarded.
+    pub fn release(&self, permit: &PermitDetails) {
+        self.issued.lock().unwrap().retain(|p| !(same_source(p, permit) && p.nonce == permit.nonce));
+    }
+
+    /// Registers EIP-2612 or Permit2 permit and signs it with provided
+    /// signer. Returns hex encoded signature.
+    pub fn sign_permit(
+        &self,
+        permit: &PermitDetails,
+        domain: &Eip712Domain,
+        signer: &impl TypedDataSigner,
+    ) -> Result<String, Box<dyn Error>> {
+        let hash = permit.hash(domain)?;
+        self.register(permit)?;
+
+        signer.sign_typed_data(&permit.typed_data(domain), hash).inspect_err(|_| self.release(permit))
+    }
+}
+
+fn same_source(a: &PermitDetails, b: &PermitDetails) -> bool {
+    a.kind == b.kind && a.token.eq_ignore_ascii_case(&b.token) && a.owner.eq_ignore_ascii_case(&b.owner)
+}
+
+fn conflict(issued: &PermitDetails, new: &PermitDetails) -> Option<PermitReplayError> {
+    if !same_source(issued, new) {
+        return None;
+    }
+
+    if issued.nonce.parse::<BigUint>().ok() == new.nonce.parse::<BigUint>().ok() {
+        return Some(PermitReplayError::NonceReused { nonce: new.nonce.clone() });
+    }
+
+    if issued.spender.eq_ignore_ascii_case(&new.spender) {
+        return Some(PermitReplayError::Overlapping { spender: issued.spender.clone(), deadline: issued.deadline });
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn permit(spender: &str, nonce: &str) -> PermitDetails {
+        PermitDetails {
+            kind: PermitKind::Eip2612,
+            token: "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48".into(),
+            owner: "0x30A557351eab496FD69F537BE1F8c744A18F94Fd".into(),
+            spender: spender.into(),
+            value: "1000".into(),
+            nonce: nonce.into(),
+            deadline: u64::MAX,
+        }
+    }
+
+    #[test]
+    fn test_registry_rejects_overlapping_permits() {
+        let registry = PermitRegistry::default();
+        let router = "0x111111125421ca6dc452d289314280a0f8842a65";
+
+        assert!(registry.register(&permit(router, "0")).is_ok());
+        assert_eq!(
+            registry.register(&permit("0x01", "0")),
+            Err(PermitReplayError::NonceReused { nonce: "0".into() })
+        );
+        assert_eq!(
+            registry.register(&permit(router, "1")),
+            Err(PermitReplayError::Overlapping { spender: router.into(), deadline: u64::MAX })
+        );
+
+        registry.release(&permit(router, "0"));
+        assert!(registry.register(&permit(router, "1")).is_ok());
+    }
+
+    #[test]
+    fn test_permit2_hash() {
+        // Type hashes of the Permit2 contract
+        assert_eq!(
+            hex::encode(keccak256(PermitDetails::PERMIT2_DETAILS_TYPE.as_bytes())),
+            "65626cad6cb96493bf6f5ebea28756c966f023ab9e8a83a7101849d5573b3678"
+        );
+        assert_eq!(
+            hex::encode(keccak256(PermitDetails::PERMIT2_SINGLE_TYPE.as_bytes())),
+            "f3841cd1ff0085026a6327b620b67997ce40f282c88a8e905a7a5626e310f3d0"
+        );
+
+        let mut permit = permit("0x111111125421ca6dc452d289314280a0f8842a65", "0");
+        permit.kind = PermitKind::Permit2;
+        permit.deadline = 1_700_000_000;
+        let domain =
+            Eip712Domain { name: "USD Coin".into(), version: "2".into(), chain_id: 1, verifying_contract: permit.token.clone() };
+
+        let typed_data = permit.typed_data(&domain);
+        assert_eq!(typed_data["primaryType"], "PermitSingle");
+        assert_eq!(typed_data["domain"]["name"], "Permit2");
+        assert_eq!(typed_data["message"]["details"]["amount"], "1000");
+        // Domain separator of the Permit2 contract on Ethereum
+        assert_eq!(
+            hex::encode(PermitDetails::permit2_domain_separator(1).unwrap()),
+            "866a5aba21966af95d6c7ab78eb2b2fc913915c28be3b9aa07cc04ff903e3f28"
+        );
+        assert_eq!(
+            hex::encode(permit.hash(&domain).unwrap()),
+            "ee24ec256a55b80dc12e04df37e47c140ed55cbb47fcc4d5fbfeca03204b18fc"
+        );
+    }
+
+    #[test]
+    fn test_registry_ignores_expired_permits_and_warn_policy() {
+        let registry = PermitRegistry::new(ReplayPolicy::Warn);
+        let mut expired = permit("0x01", "0");
+        expired.deadline = 0;
+
+        assert!(registry.register(&expired).is_ok());
+        assert!(registry.register(&permit("0x01", "0")).is_ok());
+        assert!(registry.register(&permit("0x01", "0")).is_ok());
+    }
+}
@@ -0,0 +1,149 @@
+use crate::{common::token::TokenInfo, swap::TokensListResponse};
+use std::{convert::Infallible, fmt, str::FromStr};
+
+/// Tag 1inch assigns to tokens, parsed from its raw form like `PEG:USD` or
+/// `RISK:availability`. Tags the crate doesn't know are kept as `Other`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TokenTag {
+    /// Native currency of the network.
+    Native,
+    /// Regular ERC-20 token.
+    Tokens,
+    /// Staking derivative, like stETH.
+    Staking,
+    /// Token pegged to the asset, like `USD` or `ETH`.
+    Peg(String),
+    /// Risk flag, like `availability`.
+    Risk(String),
+    Other(String),
+}
+
+impl From<&str> for TokenTag {
+    fn from(tag: &str) -> Self {
+        match tag.split_once(':') {
+            Some(("PEG", asset)) => TokenTag::Peg(asset.to_string()),
+            Some(("RISK", flag)) => TokenTag::Risk(flag.to_string()),
+            _ => match tag {
+                "native" => TokenTag::Native,
+                "tokens" => TokenTag::Tokens,
+                "staking" => TokenTag::Staking,
+                other => TokenTag::Other(other.to_string()),
+            },
+        }
+    }
+}
+
+impl FromStr for TokenTag {
+    type Err = Infallible;
+
+    fn from_str(tag: &str) -> Result<Self, Self::Err> {
+        Ok(TokenTag::from(tag))
+    }
+}
+
+impl fmt::Display for TokenTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TokenTag::Native => write!(f, "native"),
+            TokenTag::Tokens => write!(f, "tokens"),
+            TokenTag::Staking => write!(f, "staking"),
+            TokenTag::Peg(asset) => write!(f, "PEG:{}", asset),
+            TokenTag::Risk(flag) => write!(f, "RISK:{}", flag),
+            TokenTag::Other(tag) => write!(f, "{}", tag),
+        }
+    }
+}
+
+impl TokenInfo {
+    /// Returns tags of the token in typed form.
+    pub fn typed_tags(&self) -> Vec<TokenTag> {
+        self.tags.iter().map(|tag| TokenTag::from(tag.as_str())).collect()
+    }
+
+    /// Returns true if the token has the tag.
+    pub fn has_tag(&self, tag: &TokenTag) -> bool {
+        self.typed_tags().contains(tag)
+    }
+
+    /// Returns true if the token is pegged to the asset, like `USD`.
+    pub fn is_pegged_to(&self, asset: &str) -> bool {
+        self.typed_tags().iter().any(|tag| matches!(tag, TokenTag::Peg(pegged) if pegged.eq_ignore_ascii_case(asset)))
+    }
+
+    /// Returns risk flags of the token, like `availability`.
+    pub fn risk_flags(&self) -> Vec<String> {
+        self.typed_tags()
+            .into_iter()
+            .filter_map(|tag| match tag {
+                TokenTag::Risk(flag) => Some(flag),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+/// Filters tokens having the tag.
+pub fn filter_by_tag<'a>(
+    tokens: impl IntoIterator<Item = &'a TokenInfo>,
+    tag: &'a TokenTag,
+) -> impl Iterator<Item = &'a TokenInfo> {
+    tokens.into_iter().filter(move |token| token.has_tag(tag))
+}
+
+/// Filters tokens without any risk flags.
+pub fn without_risk_flags<'a>(tokens: impl IntoIterator<Item = &'a TokenInfo>) -> impl Iterator<Item = &'a TokenInfo> {
+    tokens.into_iter().filter(|token| token.risk_flags().is_empty())
+}
+
+impl TokensListResponse {
+    /// Returns tokens of the list having the tag.
+    pub fn with_tag<'a>(&'a self, tag: &'a TokenTag) -> impl Iterator<Item = &'a TokenInfo> {
+        filter_by_tag(self.tokens.values(), tag)
+    }
+
+    /// Returns tokens of the list pegged to the asset, like `USD`.
+    pub fn pegged_to<'a>(&'a self, asset: &'a str) -> impl Iterator<Item = &'a TokenInfo> {
+        self.tokens.values().filter(move |token| token.is_pegged_to(asset))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn token(symbol: &str, tags: &[&str]) -> TokenInfo {
+        TokenInfo {
+            address: format!("0x{}", symbol.to_lowercase()),
+            symbol: symbol.to_string(),
+            name: symbol.to_string(),
+            decimals: 18,
+            logo_uri: String::new(),
+            domain_version: None,
+            eip2612: None,
+            is_fot: None,
+            tags: tags.iter().map(|tag| tag.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_tags_parsing_and_filtering() {
+        for raw in ["native", "PEG:USD", "RISK:availability", "GROUP:ETH"] {
+            assert_eq!(raw.parse::<TokenTag>().unwrap().to_string(), raw);
+        }
+        assert_eq!("GROUP:ETH".parse::<TokenTag>().unwrap(), TokenTag::Other("GROUP:ETH".into()));
+
+        let tokens = vec![
+            token("USDC", &["tokens", "PEG:USD"]),
+            token("SCAM", &["tokens", "PEG:USD", "RISK:availability"]),
+            token("WETH", &["tokens", "PEG:ETH"]),
+        ];
+
+        let usd = TokenTag::Peg("USD".into());
+        let pegged: Vec<&str> = filter_by_tag(&tokens, &usd).map(|token| token.symbol.as_str()).collect();
+        assert_eq!(pegged, vec!["USDC", "SCAM"]);
+
+        let safe: Vec<&str> = without_risk_flags(&tokens).map(|token| token.symbol.as_str()).collect();
+        assert_eq!(safe, vec!["USDC", "WETH"]);
+        assert!(tokens[2].is_pegged_to("eth"));
+    }
+}
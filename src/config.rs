@@ -0,0 +1,317 @@
+use crate::{
+    auth::{ApiKeyPool, EnvApiKeyProvider, SecretString},
+    capabilities::COMPILED_PRODUCTS,
+    client::{ClientBuilderError, OneInchClient, OneInchClientBuilder, SupportedNetworks},
+    consts::BASIC_URL,
+    environment::{is_valid_rps, Environment},
+    multichain::MultiChainClient,
+    rate_limit::{RateLimitError, RateLimiter},
+    retry::RetryPolicy,
+    swap::{SwapDetailsBuilderError, SwapDetailsV6Builder},
+    timeout::Timeouts,
+};
+use serde::Deserialize;
+use std::{collections::HashMap, path::Path, time::Duration};
+use thiserror::Error;
+
+/// Configuration of the clients, loaded from a TOML file, so services don't
+/// have to mirror options of the crate in their own config structs.
+///
+/// ```
+/// use one_inch::config::Config;
+///
+/// let config = Config::from_toml(
+///     r#"
+///     api_key_env = "ONE_INCH_API_TOKEN"
+///     networks = [1, 137]
+///     default_slippage = 1
+///
+///     [rate_limit]
+///     rps = 1.0
+///
+///     [retry]
+///     max_retries = 3
+///
+///     [timeouts]
+///     default_ms = 10000
+///     endpoints = { "swap/quote" = 2000 }
+///
+///     [modules]
+///     nft = false
+///     "#,
+/// )
+/// .unwrap();
+///
+/// let clients = config.multichain_client().unwrap();
+/// assert_eq!(clients.networks().count(), 2);
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// API key. Exactly one of `api_key`, `api_keys` and `api_key_env` should
+    /// be set.
+    pub api_key: Option<SecretString>,
+    /// Keys of the pool the requests are spread across.
+    #[serde(default)]
+    pub api_keys: Vec<SecretString>,
+    /// Name of the environment variable holding the API key, read before
+    /// every request.
+    pub api_key_env: Option<String>,
+    /// Keys of the products which should use another one, by product name
+    /// like `fusion`.
+    #[serde(default)]
+    pub product_keys: HashMap<String, SecretString>,
+    /// Chain ids of the networks, the first one is the default network.
+    #[serde(default)]
+    pub networks: Vec<u32>,
+    /// Name of the environment, `production` by default. Other names
+    /// require `base_url` of the environment.
+    pub environment: Option<String>,
+    pub base_url: Option<String>,
+    /// Slippage of the swaps, in percents.
+    pub default_slippage: Option<usize>,
+    pub rate_limit: Option<RateLimitConfig>,
+    pub retry: Option<RetryConfig>,
+    pub timeouts: Option<TimeoutsConfig>,
+    /// Products (first segments of the endpoint paths) switched on or off,
+    /// all of them are on by default. Client refuses requests to the products
+    /// switched off.
+    #[serde(default)]
+    pub modules: HashMap<String, bool>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RateLimitConfig {
+    pub rps: f64,
+    pub burst: Option<u32>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RetryConfig {
+    pub max_retries: u32,
+    pub base_delay_ms: Option<u64>,
+    pub max_delay_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TimeoutsConfig {
+    pub default_ms: Option<u64>,
+    /// Timeouts by endpoint, like `swap/quote`.
+    #[serde(default)]
+    pub endpoints: HashMap<String, u64>,
+}
+
+/// Enumerates errors of loading the configuration.
+#[derive(Error, Debug)]
+pub enum ConfigError {
+    #[error("Can't read config file: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Can't parse config: {0}")]
+    Parse(#[from] toml::de::Error),
+
+    #[error("Invalid config: {0}")]
+    Invalid(String),
+
+    #[error("Can't create client: {0}")]
+    Client(#[from] ClientBuilderError),
+}
+
+impl Config {
+    /// Loads and validates configuration from the TOML file.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Config, ConfigError> {
+        Config::from_toml(&std::fs::read_to_string(path)?)
+    }
+
+    /// Parses and validates configuration from the TOML string.
+    pub fn from_toml(toml: &str) -> Result<Config, ConfigError> {
+        let config: Config = toml::from_str(toml)?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    fn validate(&self) -> Result<(), ConfigError> {
+        let keys = [self.api_key.is_some(), !self.api_keys.is_empty(), self.api_key_env.is_some()];
+        if keys.iter().filter(|&&set| set).count() != 1 {
+            return Err(ConfigError::Invalid(
+                "exactly one of api_key, api_keys and api_key_env should be set".into(),
+            ));
+        }
+
+        self.network_ids()?;
+        if let Some(slippage) = self.default_slippage.filter(|&slippage| slippage > 50) {
+            return Err(ConfigError::Invalid(format!("default_slippage {} is above 50", slippage)));
+        }
+        if let Some(rate_limit) = &self.rate_limit {
+            if !is_valid_rps(rate_limit.rps) {
+                return Err(ConfigError::Invalid(format!("rate_limit.rps {} should be positive", rate_limit.rps)));
+            }
+            if rate_limit.burst == Some(0) {
+                return Err(ConfigError::Invalid("rate_limit.burst should be positive".into()));
+            }
+        }
+        match self.environment.as_deref() {
+            None | Some("production") => {}
+            Some(_) if self.base_url.is_some() => {}
+            Some(name) => {
+                return Err(ConfigError::Invalid(format!("unknown environment {}, set base_url of custom ones", name)));
+            }
+        }
+        if let Some(module) = self.modules.keys().find(|module| !COMPILED_PRODUCTS.contains(&module.as_str())) {
+            return Err(ConfigError::Invalid(format!("unknown module {}", module)));
+        }
+
+        Ok(())
+    }
+
+    /// Returns the configured networks, the first one is the default network.
+    pub fn network_ids(&self) -> Result<Vec<SupportedNetworks>, ConfigError> {
+        if self.networks.is_empty() {
+            return Err(ConfigError::Invalid("at least one network should be set".into()));
+        }
+
+        self.networks
+            .iter()
+            .map(|&id| {
+                SupportedNetworks::from_repr(id).ok_or_else(|| ConfigError::Invalid(format!("unsupported network {}", id)))
+            })
+            .collect()
+    }
+
+    /// Creates the client of the default network.
+    pub fn client(&self) -> Result<OneInchClient, ConfigError> {
+        let network_id = self.network_ids()?[0];
+
+        let mut builder = OneInchClientBuilder::new().network(network_id).environment(self.environment_profile());
+        builder = match (&self.api_key, &self.api_key_env) {
+            (Some(api_key), _) => builder.api_key(api_key.expose_secret().to_string()),
+            (None, Some(var)) => builder.key_provider(EnvApiKeyProvider::new(var)),
+            (None, None) => builder.key_pool(ApiKeyPool::new(self.api_keys.iter().map(|key| key.expose_secret().to_string()))),
+        };
+        if let Some(timeouts) = &self.timeouts {
+            let mut configured = Timeouts::new();
+            if let Some(default_ms) = timeouts.default_ms {
+                configured = configured.default_timeout(Duration::from_millis(default_ms));
+            }
+            for (endpoint, timeout_ms) in &timeouts.endpoints {
+                configured = configured.endpoint_timeout(endpoint.clone(), Duration::from_millis(*timeout_ms));
+            }
+            builder = builder.timeouts(configured);
+        }
+        if let Some(retry) = &self.retry {
+            let mut policy = RetryPolicy::new(retry.max_retries);
+            if let Some(base_delay_ms) = retry.base_delay_ms {
+                policy = policy.with_base_delay(Duration::from_millis(base_delay_ms));
+            }
+            if let Some(max_delay_ms) = retry.max_delay_ms {
+                policy = policy.with_max_delay(Duration::from_millis(max_delay_ms));
+            }
+            builder = builder.retry_policy(policy);
+        }
+        if let Some(rate_limit) = &self.rate_limit {
+            let invalid = |e: RateLimitError| ConfigError::Invalid(e.to_string());
+            let mut limiter = RateLimiter::new(rate_limit.rps).map_err(invalid)?;
+            if let Some(burst) = rate_limit.burst {
+                limiter = limiter.with_burst(burst).map_err(invalid)?;
+            }
+            builder = builder.rate_limiter(limiter);
+        }
+
+        let mut client = builder.build()?;
+        for (product, key) in &self.product_keys {
+            client = client.with_product_key(product.clone(), key.expose_secret().to_string());
+        }
+
+        Ok(client)
+    }
+
+    /// Creates clients of all the configured networks, sharing the API key,
+    /// connection pool and rate limiter.
+    pub fn multichain_client(&self) -> Result<MultiChainClient, ConfigError> {
+        Ok(MultiChainClient::new(self.client()?, self.network_ids()?))
+    }
+
+    /// Returns builder of the swap details with the default slippage, if
+    /// it's configured.
+    pub fn swap_details_builder(&self) -> Result<SwapDetailsV6Builder, SwapDetailsBuilderError> {
+        match self.default_slippage {
+            Some(slippage) => SwapDetailsV6Builder::new().slippage(slippage),
+            None => Ok(SwapDetailsV6Builder::new()),
+        }
+    }
+
+    fn environment_profile(&self) -> Environment {
+        let name = self.environment.clone().unwrap_or_else(|| "production".to_string());
+        let mut environment = Environment::custom(name, self.base_url.as_deref().unwrap_or(BASIC_URL));
+        for (module, _) in self.modules.iter().filter(|(_, enabled)| !**enabled) {
+            environment = environment.without_product(module.clone());
+        }
+        environment
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_produces_clients() {
+        let config = Config::from_toml(
+            r#"
+            api_keys = ["first", "second"]
+            networks = [137, 1]
+            base_url = "https://enterprise.example.com/"
+            default_slippage = 2
+            product_keys = { fusion = "fusion-key" }
+
+            [retry]
+            max_retries = 2
+
+            [timeouts]
+            endpoints = { "swap/quote" = 1500 }
+
+            [modules]
+            nft = false
+            fusion = true
+            portfolio = true
+            tx-gateway = false
+            "#,
+        )
+        .unwrap();
+        assert!(!format!("{:?}", config).contains("second"));
+
+        let client = config.client().unwrap();
+        assert_eq!(client.network() as u32, 137);
+        assert_eq!(client.base_url(), "https://enterprise.example.com");
+        assert!(!client.environment().is_available("nft"));
+        assert!(client.environment().is_available("fusion"));
+        assert!(!client.environment().is_available("tx-gateway"));
+        assert_eq!(client.retry_policy.as_ref().unwrap().max_retries(), 2);
+        assert_eq!(client.timeouts.timeout_for("swap/quote"), Some(Duration::from_millis(1500)));
+        assert!(config.swap_details_builder().is_ok());
+
+        for (toml, error) in [
+            ("networks = [1]", "exactly one of"),
+            ("api_key = \"key\"\nnetworks = [2]", "unsupported network 2"),
+            ("api_key = \"key\"\nnetworks = [1]\n[modules]\nwallets = true", "unknown module wallets"),
+            ("api_key = \"key\"\nnetworks = [1]\ndefault_slippage = 51", "above 50"),
+            (
+                "api_key = \"key\"\nnetworks = [1]\n[rate_limit]\nrps = 1.0\nburst = 0",
+                "burst should be positive",
+            ),
+            ("api_key = \"key\"\nnetworks = [1]\n[rate_limit]\nrps = nan", "should be positive"),
+            ("api_key = \"key\"\nnetworks = [1]\n[rate_limit]\nrps = inf", "should be positive"),
+            (
+                "api_key = \"key\"\nnetworks = [1]\nenvironment = \"staging\"",
+                "unknown environment staging",
+            ),
+        ] {
+            let message = Config::from_toml(toml).unwrap_err().to_string();
+            assert!(message.contains(error), "{}", message);
+        }
+        assert!(matches!(Config::from_toml("api_key = \"key\"\nnetwork = 1"), Err(ConfigError::Parse(_))));
+    }
+}
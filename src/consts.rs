@@ -5,8 +5,9 @@ pub const BASIC_URL: &str = "https://api.1inch.dev";
 
 pub const SWAP_V6_API_VERSION: &str = "v6.0";
 pub const SWAP_API_VERSION: &str = "v5.2";
-pub const ORDERBOOK_API_VERSION: &str = "v3.0";
-pub const FUSION_API_VERSION: &str = "v1.0";
+pub const ORDERBOOK_API_VERSION: &str = "v4.0";
+pub const FUSION_API_VERSION: &str = "v2.0";
+pub const FUSION_PLUS_API_VERSION: &str = "v1.0";
 pub const HISTORY_API_VERSION: &str = "v2.0";
 pub const TRACES_API_VERSION: &str = "v1.0";
 pub const PORTFOLIO_API_VERSION: &str = "v3";
@@ -14,4 +15,16 @@ pub const BALANCE_API_VERSION: &str = "v1.2";
 pub const GAS_PRICE_API_VERSION: &str = "v1.4";
 pub const SPOT_PRICE_API_VERSION: &str = "v1.1";
 pub const TOKENS_API_VERSION: &str = "v1.2";
-pub const NFT_API_VERSION: &str = "v1";
+pub const NFT_API_VERSION: &str = "v2";
+pub const DOMAINS_API_VERSION: &str = "v2.0";
+pub const TOKEN_DETAILS_API_VERSION: &str = "v1.0";
+pub const TX_GATEWAY_API_VERSION: &str = "v1.1";
+pub const CHARTS_API_VERSION: &str = "v1.0";
+
+/// Maximum amount of the addresses in a single spot price request.
+pub const SPOT_PRICE_MAX_ADDRESSES: usize = 100;
+
+pub const AGGREGATION_ROUTER_V6_ADDRESS: &str = "0x111111125421ca6dc452d289314280a0f8842a65";
+pub const AGGREGATION_ROUTER_V6_ZKSYNC_ADDRESS: &str = "0x6fd4383cb451173d5f9304f041c7bcbf27d561ff";
+/// Address of the Permit2 contract, the same on all the supported networks.
+pub const PERMIT2_ADDRESS: &str = "0x000000000022d473030f116ddee9f6b43ac78ba3";
@@ -0,0 +1,107 @@
+use crate::{
+    client::OneInchClient,
+    consts::DOMAINS_API_VERSION,
+    domains::{is_address, DomainLookupResponse, ReverseLookupResponse},
+    error::OneInchError,
+};
+use reqwest::Url;
+
+impl OneInchClient {
+    /// Performs request to resolve the domain (like ENS name) to an address.
+    pub async fn lookup_domain(&self, name: String) -> Result<DomainLookupResponse, OneInchError> {
+        let url = format!("{}/domains/{}/lookup", self.base_url(), DOMAINS_API_VERSION);
+
+        let url_with_params =
+            Url::parse_with_params(&url, [("name", name)]).map_err(|e| OneInchError::Validation(e.to_string()))?;
+
+        let lookup: DomainLookupResponse = self.request_json("domains/lookup", self.http_client.get(url_with_params)).await?;
+
+        Ok(lookup)
+    }
+
+    /// Performs request to get the primary domain of the address.
+    pub async fn reverse_lookup(&self, address: String) -> Result<ReverseLookupResponse, OneInchError> {
+        let url = format!("{}/domains/{}/reverse-lookup", self.base_url(), DOMAINS_API_VERSION);
+
+        let url_with_params =
+            Url::parse_with_params(&url, [("address", address)]).map_err(|e| OneInchError::Validation(e.to_string()))?;
+
+        let lookup: ReverseLookupResponse =
+            self.request_json("domains/reverse-lookup", self.http_client.get(url_with_params)).await?;
+
+        Ok(lookup)
+    }
+
+    /// Resolves address which should be sent with request. Addresses are
+    /// returned as is, anything else is treated as a domain and resolved via
+    /// Domains API. Domain resolving to anything but a 20 bytes address is an
+    /// error.
+    pub async fn resolve_address(&self, address_or_domain: String) -> Result<String, OneInchError> {
+        if is_address(&address_or_domain) {
+            return Ok(address_or_domain);
+        }
+
+        let lookup = self.lookup_domain(address_or_domain.clone()).await?;
+        match lookup.result {
+            Some(result) if is_address(&result.address) => {
+                tracing::info!(domain = %address_or_domain, address = %result.address, "resolved domain");
+                Ok(result.address)
+            }
+            Some(result) => Err(OneInchError::Validation(format!(
+                "Domain {} resolves to invalid address {}",
+                address_or_domain, result.address
+            ))),
+            None => Err(OneInchError::Validation(format!(
+                "Domain {} doesn't resolve to an address",
+                address_or_domain
+            ))),
+        }
+    }
+
+    /// Enables resolving `from` and `receiver` of swaps given as domains (like
+    /// ENS names) via Domains API. Disabled by default, so swap addresses are
+    /// sent as they are, and a domain never ends up in the calldata without
+    /// the caller asking for it. Resolutions are logged at info level.
+    pub fn with_domain_resolution(mut self, enabled: bool) -> OneInchClient {
+        self.resolve_domains = enabled;
+        self
+    }
+
+    // Resolves swap address if domain resolution is enabled.
+    pub(crate) async fn resolve_swap_address(&self, address_or_domain: String) -> Result<String, OneInchError> {
+        if !self.resolve_domains {
+            return Ok(address_or_domain);
+        }
+        self.resolve_address(address_or_domain).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        client::{new_with_base_url, SupportedNetworks},
+        error::OneInchError,
+        utils::mock_server::MockServer,
+    };
+
+    #[tokio::test]
+    async fn test_swap_addresses_are_resolved_only_when_enabled() {
+        let server = MockServer::start(r#"{"result": {"protocol": "ENS", "address": "0x1234", "checkUrl": ""}}"#).await;
+        let client = new_with_base_url("token".into(), SupportedNetworks::Ethereum, &server.base_url);
+
+        assert_eq!(client.resolve_swap_address("vitalik.eth".into()).await.unwrap(), "vitalik.eth");
+        assert!(server.requests().is_empty());
+
+        let client = client.with_domain_resolution(true);
+        let address = "0xd8da6bf26964af9d7eed9e03e53415d37aa96045";
+        assert_eq!(client.resolve_swap_address(address.into()).await.unwrap(), address);
+        assert!(server.requests().is_empty());
+
+        // Resolved value isn't an address
+        assert!(matches!(
+            client.resolve_swap_address("vitalik.eth".into()).await,
+            Err(OneInchError::Validation(_))
+        ));
+        assert_eq!(server.requests().len(), 1);
+    }
+}
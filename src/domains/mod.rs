@@ -0,0 +1,4 @@
+mod lookup;
+mod types;
+
+pub use types::*;
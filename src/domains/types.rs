@@ -0,0 +1,59 @@
+use serde::Deserialize;
+
+/// Address the domain resolves to.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DomainLookupResult {
+    /// Naming protocol the domain belongs to, like `ENS`.
+    pub protocol: String,
+    pub address: String,
+    pub check_url: Option<String>,
+}
+
+/// Represents the struct we receive after requesting domain lookup. Result is
+/// missing if the domain doesn't resolve to any address.
+#[derive(Deserialize, Debug, Clone)]
+pub struct DomainLookupResponse {
+    pub result: Option<DomainLookupResult>,
+}
+
+/// Primary domain of the address.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ReverseLookupResult {
+    /// Naming protocol the domain belongs to, like `ENS`.
+    pub protocol: String,
+    pub domain: String,
+    pub check_url: Option<String>,
+}
+
+/// Represents the struct we receive after requesting reverse lookup. Result is
+/// missing if the address has no primary domain.
+#[derive(Deserialize, Debug, Clone)]
+pub struct ReverseLookupResponse {
+    pub result: Option<ReverseLookupResult>,
+}
+
+/// Returns true if the value is a `0x` prefixed hex encoded address, so it
+/// doesn't need to be resolved.
+pub(crate) fn is_address(value: &str) -> bool {
+    value.len() == 42 && value.starts_with("0x") && value[2..].chars().all(|c| c.is_ascii_hexdigit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_domain_lookup_deserialization() {
+        let body = r#"{"result": {"protocol": "ENS", "address": "0xd8da6bf26964af9d7eed9e03e53415d37aa96045", "checkUrl": "https://app.ens.domains/name/vitalik.eth"}}"#;
+        let response: DomainLookupResponse = serde_json::from_str(body).unwrap();
+        assert_eq!(response.result.unwrap().address, "0xd8da6bf26964af9d7eed9e03e53415d37aa96045");
+
+        let response: ReverseLookupResponse = serde_json::from_str(r#"{"result": null}"#).unwrap();
+        assert!(response.result.is_none());
+
+        assert!(is_address("0xd8da6bf26964af9d7eed9e03e53415d37aa96045"));
+        assert!(!is_address("vitalik.eth"));
+    }
+}
@@ -0,0 +1,183 @@
+use crate::consts::BASIC_URL;
+use std::collections::HashSet;
+use thiserror::Error;
+
+/// Name of the environment variable which overrides the environment name.
+pub const ENVIRONMENT_VAR: &str = "ONE_INCH_ENVIRONMENT";
+/// Name of the environment variable which overrides the base URL.
+pub const BASE_URL_VAR: &str = "ONE_INCH_BASE_URL";
+/// Name of the environment variable which overrides the RPS limit.
+pub const RPS_VAR: &str = "ONE_INCH_RPS";
+
+/// Named environment profile the client works with: base URL of the API, its
+/// rate limit and products available in it.
+///
+/// Profile is selected at client construction, and its values can be
+/// overridden via `ONE_INCH_ENVIRONMENT`, `ONE_INCH_BASE_URL` and
+/// `ONE_INCH_RPS` environment variables, so switching to a staging deployment
+/// doesn't require code changes.
+///
+/// ```
+/// use one_inch::environment::Environment;
+///
+/// let staging =
+///     Environment::custom("staging", "https://staging.example.com").with_rps(5.0).unwrap().without_product("nft");
+/// assert!(!staging.is_available("nft"));
+/// assert!(Environment::production().with_rps(f64::NAN).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Environment {
+    name: String,
+    base_url: String,
+    rps: Option<f64>,
+    unavailable: HashSet<String>,
+}
+
+/// Enumerates errors of the environment configuration.
+#[derive(Error, Debug, PartialEq)]
+pub enum EnvironmentError {
+    /// Indicates the RPS limit isn't a positive finite number.
+    #[error("RPS limit should be a positive number, got {0}")]
+    InvalidRps(f64),
+}
+
+impl Environment {
+    /// Public 1inch API with all products available and no client-side rate
+    /// limit.
+    pub fn production() -> Environment {
+        Environment::custom("production", BASIC_URL)
+    }
+
+    /// Environment with custom base URL, e.g. an enterprise staging
+    /// deployment.
+    pub fn custom(name: impl Into<String>, base_url: impl Into<String>) -> Environment {
+        Environment {
+            name: name.into(),
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            rps: None,
+            unavailable: HashSet::new(),
+        }
+    }
+
+    /// Sets base URL of the environment, e.g. a proxy in front of the API or a
+    /// mock server in tests.
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Environment {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Sets RPS limit of the environment, which the client enforces with a
+    /// rate limiter.
+    pub fn with_rps(mut self, rps: f64) -> Result<Environment, EnvironmentError> {
+        if !is_valid_rps(rps) {
+            return Err(EnvironmentError::InvalidRps(rps));
+        }
+        self.rps = Some(rps);
+        Ok(self)
+    }
+
+    /// Marks the product (first segment of the endpoint path, like `fusion`
+    /// or `nft`) as unavailable in the environment. Client fails requests to
+    /// it without sending them.
+    pub fn without_product(mut self, product: impl Into<String>) -> Environment {
+        self.unavailable.insert(product.into());
+        self
+    }
+
+    /// Applies overrides from the environment variables.
+    pub fn with_env_overrides(self) -> Environment {
+        self.with_overrides(|var| std::env::var(var).ok())
+    }
+
+    fn with_overrides(mut self, lookup: impl Fn(&str) -> Option<String>) -> Environment {
+        if let Some(name) = lookup(ENVIRONMENT_VAR) {
+            self.name = name;
+        }
+        if let Some(base_url) = lookup(BASE_URL_VAR) {
+            self.base_url = base_url.trim_end_matches('/').to_string();
+        }
+        match lookup(RPS_VAR).map(|rps| rps.parse::<f64>()) {
+            Some(Ok(rps)) if is_valid_rps(rps) => self.rps = Some(rps),
+            Some(_) => tracing::warn!("{} should be a positive number, ignoring it", RPS_VAR),
+            None => {}
+        }
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    pub fn rps(&self) -> Option<f64> {
+        self.rps
+    }
+
+    /// Returns true if the product is available in the environment.
+    pub fn is_available(&self, product: &str) -> bool {
+        !self.unavailable.contains(product)
+    }
+}
+
+pub(crate) fn is_valid_rps(rps: f64) -> bool {
+    rps > 0.0 && rps.is_finite()
+}
+
+impl Default for Environment {
+    fn default() -> Self {
+        Environment::production()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::{new_with_environment, SupportedNetworks},
+        error::OneInchError,
+        utils::mock_server::MockServer,
+    };
+
+    #[test]
+    fn test_env_overrides_take_precedence() {
+        let environment = Environment::production().with_overrides(|var| match var {
+            BASE_URL_VAR => Some("https://staging.example.com/".to_string()),
+            RPS_VAR => Some("not a number".to_string()),
+            _ => None,
+        });
+
+        assert_eq!(environment.name(), "production");
+        assert_eq!(environment.base_url(), "https://staging.example.com");
+        assert_eq!(environment.rps(), None);
+        assert!(environment.is_available("swap"));
+
+        for rps in ["0", "-1", "NaN", "inf"] {
+            let environment = Environment::production().with_overrides(|var| (var == RPS_VAR).then(|| rps.to_string()));
+            assert_eq!(environment.rps(), None);
+            assert!(Environment::production().with_rps(rps.parse().unwrap()).is_err());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unavailable_products_are_not_requested() {
+        let server = MockServer::start(r#"{"address": "0x1111111111111111111111111111111111111111"}"#).await;
+        let environment = Environment::custom("staging", &server.base_url).without_product("swap");
+        let client = new_with_environment("token".to_string(), SupportedNetworks::Ethereum, environment);
+
+        assert!(matches!(
+            client.get_router_address().await,
+            Err(OneInchError::ProductUnavailable { product, environment }) if product == "swap" && environment == "staging"
+        ));
+        assert!(server.requests().is_empty());
+    }
+
+    #[test]
+    fn test_base_url_is_normalized() {
+        let environment = Environment::production().with_base_url("http://127.0.0.1:8080/");
+        assert_eq!(environment.name(), "production");
+        assert_eq!(environment.base_url(), "http://127.0.0.1:8080");
+    }
+}
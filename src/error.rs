@@ -0,0 +1,223 @@
+use crate::{auth::ApiKeyError, client::SendError, response::ResponseBodyError, swap::PreflightError, utils::runtime};
+use std::time::Duration;
+use thiserror::Error;
+
+/// Represents errors that can occur while performing requests to the API.
+///
+/// Variants are split by the [phase](ErrorPhase) of the request they happened
+/// in, so callers can decide whether retrying is safe.
+#[derive(Error, Debug)]
+pub enum OneInchError {
+    /// API key couldn't be obtained, so the request hasn't been sent.
+    #[error("API key error: {0}")]
+    ApiKey(ApiKeyError),
+
+    /// Request failed because of a network error.
+    ///
+    /// Connection failures happen before anything is sent, so it's safe to
+    /// retry them, while other network errors may happen after the request
+    /// has been processed.
+    #[error("Network error: {0}")]
+    Network(#[from] reqwest::Error),
+
+    /// Response body couldn't be read, e.g. it exceeded the size limit or
+    /// took too long. Request has been processed, so for POST requests it
+    /// may have had side effects.
+    #[error("Response error: {source}")]
+    Response { source: ResponseBodyError, request_id: Option<String> },
+
+    /// Response couldn't be deserialized from JSON. This could happen if the
+    /// response format is different than expected.
+    #[error("JSON parsing error: {source}")]
+    Json { source: serde_json::Error, request_id: Option<String> },
+
+    /// API responded with error status.
+    ///
+    /// `error` and `description` are filled in if the API described the
+    /// error in the response body, like insufficient funds or invalid request
+    /// parameters. `request_id` is taken from the body, or from the
+    /// `x-request-id` header if the body has none.
+    #[error("API error {status_code}: {}", description.as_deref().unwrap_or("no description"))]
+    Api { status_code: u16, error: Option<String>, description: Option<String>, request_id: Option<String> },
+
+    /// Gateway in front of the API responded with a server error whose body
+    /// isn't JSON, like an HTML error page or an empty body, so the API itself
+    /// is likely unavailable. Contains content type and the truncated body
+    /// sample, for diagnostics.
+    #[error("Upstream unavailable ({status_code}): {body_sample}")]
+    UpstreamUnavailable { status_code: u16, content_type: Option<String>, body_sample: String, request_id: Option<String> },
+
+    /// API rejected the request because of the rate limit, so it hasn't been
+    /// processed. `retry_after` is taken from the `Retry-After` header if the
+    /// API provided it.
+    #[error("Rate limited, retry after {retry_after:?}")]
+    RateLimited { retry_after: Option<Duration>, request_id: Option<String> },
+
+    /// Preflight check found the request would fail, so it hasn't been sent.
+    #[error("Preflight check failed: {0}")]
+    Preflight(#[from] PreflightError),
+
+    /// Swap was refused by the client's
+    /// [price impact policy](crate::swap::PriceImpactPolicy), so its
+    /// transaction hasn't been returned. `impact` and `threshold` are
+    /// fractions, like `0.05` for 5%.
+    #[error("Price impact {:.2}% exceeds the threshold of {:.2}%", impact * 100.0, threshold * 100.0)]
+    ImpactTooHigh { impact: f64, threshold: f64 },
+
+    /// Circuit of the endpoint is open after consecutive failures, so the
+    /// request hasn't been sent. It will be let through after `retry_in`.
+    #[error("Circuit of {endpoint} is open, retry in {retry_in:?}")]
+    CircuitOpen { endpoint: String, retry_in: Duration },
+
+    /// Product of the endpoint is disabled in the client's
+    /// [environment](crate::environment::Environment), so the request hasn't
+    /// been sent.
+    #[error("{product} is unavailable in the {environment} environment")]
+    ProductUnavailable { product: String, environment: String },
+
+    /// Request parameters are invalid, so the request hasn't been sent.
+    #[error("Validation error: {0}")]
+    Validation(String),
+
+    /// Call was abandoned because its
+    /// [cancellation token](crate::cancel::CancellationToken) was cancelled.
+    /// Request may have been processed by then.
+    #[error("Cancelled")]
+    Cancelled,
+
+    /// Call was abandoned because its [deadline](crate::cancel::Deadline)
+    /// passed. Request may have been processed by then.
+    #[error("Deadline exceeded")]
+    DeadlineExceeded,
+}
+
+/// Phase of the request in which [`OneInchError`] happened.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ErrorPhase {
+    /// Request never reached 1inch, it's safe to retry it as is.
+    Request,
+    /// Request was rejected, by 1inch or by the client's own validation, it
+    /// needs parameter changes.
+    Rejected,
+    /// Outcome of the request is unknown, because response couldn't be
+    /// received or parsed.
+    Response,
+}
+
+impl OneInchError {
+    /// Returns the phase of the request the error happened in.
+    pub fn phase(&self) -> ErrorPhase {
+        match self {
+            OneInchError::ApiKey(_)
+            | OneInchError::RateLimited { .. }
+            | OneInchError::CircuitOpen { .. }
+            | OneInchError::ProductUnavailable { .. } => ErrorPhase::Request,
+            OneInchError::Network(e) if runtime::is_connect(e) || e.is_builder() => ErrorPhase::Request,
+            OneInchError::Validation(_) | OneInchError::Preflight(_) | OneInchError::ImpactTooHigh { .. } => ErrorPhase::Rejected,
+            OneInchError::Api { status_code, .. } if *status_code < 500 => ErrorPhase::Rejected,
+            OneInchError::Network(_)
+            | OneInchError::Api { .. }
+            | OneInchError::UpstreamUnavailable { .. }
+            | OneInchError::Response { .. }
+            | OneInchError::Json { .. }
+            | OneInchError::Cancelled
+            | OneInchError::DeadlineExceeded => ErrorPhase::Response,
+        }
+    }
+
+    /// Returns true if the request can be retried as is without risk of
+    /// performing it twice.
+    pub fn is_safe_to_retry(&self) -> bool {
+        self.phase() == ErrorPhase::Request
+    }
+
+    /// Returns id of the request the API responded to with the error, which
+    /// 1inch support asks for when investigating failures.
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            OneInchError::Api { request_id, .. }
+            | OneInchError::UpstreamUnavailable { request_id, .. }
+            | OneInchError::RateLimited { request_id, .. }
+            | OneInchError::Response { request_id, .. }
+            | OneInchError::Json { request_id, .. } => request_id.as_deref(),
+            _ => None,
+        }
+    }
+
+    // Attaches id of the request to errors of reading its response, which
+    // don't know it themselves.
+    pub(crate) fn with_request_id(mut self, id: Option<String>) -> OneInchError {
+        if let OneInchError::Response { request_id, .. } | OneInchError::Json { request_id, .. } = &mut self {
+            *request_id = id;
+        }
+        self
+    }
+
+    /// Returns HTTP status code of the error response, if the API responded.
+    pub fn status_code(&self) -> Option<u16> {
+        match self {
+            OneInchError::Api { status_code, .. } | OneInchError::UpstreamUnavailable { status_code, .. } => Some(*status_code),
+            OneInchError::RateLimited { .. } => Some(429),
+            _ => None,
+        }
+    }
+}
+
+impl From<ResponseBodyError> for OneInchError {
+    fn from(source: ResponseBodyError) -> Self {
+        OneInchError::Response { source, request_id: None }
+    }
+}
+
+impl From<serde_json::Error> for OneInchError {
+    fn from(source: serde_json::Error) -> Self {
+        OneInchError::Json { source, request_id: None }
+    }
+}
+
+impl From<SendError> for OneInchError {
+    fn from(e: SendError) -> Self {
+        match e {
+            SendError::ApiKey(e) => OneInchError::ApiKey(e),
+            SendError::Network(e) => OneInchError::Network(e),
+            SendError::RateLimited { retry_after, request_id } => OneInchError::RateLimited { retry_after, request_id },
+            SendError::CircuitOpen { endpoint, retry_in } => {
+                OneInchError::CircuitOpen { endpoint: endpoint.to_string(), retry_in }
+            }
+            SendError::ProductUnavailable { product, environment } => {
+                OneInchError::ProductUnavailable { product: product.to_string(), environment }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tests classification of errors by request phase.
+    #[test]
+    fn test_error_phases() {
+        let rejected = OneInchError::Api {
+            status_code: 400,
+            error: Some("Bad Request".into()),
+            description: Some("Not enough balance".into()),
+            request_id: Some("id".into()),
+        };
+        assert_eq!(rejected.phase(), ErrorPhase::Rejected);
+        assert_eq!(rejected.to_string(), "API error 400: Not enough balance");
+        assert_eq!(rejected.request_id(), Some("id"));
+
+        let server_error = OneInchError::Api { status_code: 502, error: None, description: None, request_id: None };
+        assert_eq!(server_error.phase(), ErrorPhase::Response);
+        assert_eq!(server_error.status_code(), Some(502));
+
+        assert!(!OneInchError::Validation("invalid amount".into()).is_safe_to_retry());
+        assert!(OneInchError::RateLimited { retry_after: None, request_id: None }.is_safe_to_retry());
+
+        let parse_error = serde_json::from_str::<u8>("{").unwrap_err();
+        let parse_error = OneInchError::from(parse_error).with_request_id(Some("id".into()));
+        assert!(!parse_error.is_safe_to_retry());
+        assert_eq!(parse_error.request_id(), Some("id"));
+    }
+}
@@ -0,0 +1,218 @@
+use crate::client::OneInchClient;
+use futures::future::BoxFuture;
+use std::sync::Arc;
+
+/// Event of the swap lifecycle, emitted by the execution and monitoring
+/// methods of the client to its [`EventSink`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExecutionEvent {
+    /// Quote of the pair was received.
+    QuoteReceived { chain_id: u32, src: String, dst: String, amount: String, dst_amount: String, request_id: Option<String> },
+    /// Approve transaction of the token was signed and broadcast.
+    ApprovalSent { chain_id: u32, token: String, owner: String, amount: String, tx_hash: String },
+    /// Swap transaction was signed and broadcast.
+    SwapBroadcast { chain_id: u32, sender: String, nonce: u64, tx_hash: String },
+    /// Transaction reached the finality configured for the chain.
+    /// `success` is `None` for chains whose receipts don't report it.
+    Confirmed { chain_id: u32, tx_hash: String, block: u64, success: Option<bool> },
+    /// Swap failed to be signed or broadcast, or its transaction reverted.
+    /// `tx_hash` is set if the transaction made it to the chain.
+    Failed { chain_id: u32, sender: Option<String>, tx_hash: Option<String>, error: String },
+}
+
+impl ExecutionEvent {
+    /// Returns chain id of the network the event happened on.
+    pub fn chain_id(&self) -> u32 {
+        match self {
+            ExecutionEvent::QuoteReceived { chain_id, .. }
+            | ExecutionEvent::ApprovalSent { chain_id, .. }
+            | ExecutionEvent::SwapBroadcast { chain_id, .. }
+            | ExecutionEvent::Confirmed { chain_id, .. }
+            | ExecutionEvent::Failed { chain_id, .. } => *chain_id,
+        }
+    }
+}
+
+/// Trait for anything receiving [`ExecutionEvent`]s, like a metrics exporter
+/// or a notification queue. Events are awaited in place of the operation
+/// emitting them, so slow sinks should hand them off to a task or a channel.
+pub trait EventSink: Send + Sync {
+    fn emit<'a>(&'a self, event: &'a ExecutionEvent) -> BoxFuture<'a, ()>;
+}
+
+impl OneInchClient {
+    /// Sets sink the client and its clones emit execution events to.
+    pub fn with_event_sink(mut self, sink: impl EventSink + 'static) -> OneInchClient {
+        self.event_sink = Some(Arc::new(sink));
+        self
+    }
+
+    pub(crate) async fn emit(&self, event: impl FnOnce() -> ExecutionEvent) {
+        if let Some(sink) = &self.event_sink {
+            sink.emit(&event()).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::{new_with_base_url, SupportedNetworks},
+        execution::{PreparedSwap, SignerError, TransactionSigner},
+        swap::{PriceImpactPolicy, QuoteDetailsBuilder, SwapDetailsV6Builder},
+        utils::mock_server::MockServer,
+    };
+    use std::{
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Mutex,
+        },
+        time::{Duration, SystemTime},
+    };
+
+    #[derive(Default)]
+    struct Collecting(Arc<Mutex<Vec<ExecutionEvent>>>);
+
+    impl EventSink for Collecting {
+        fn emit<'a>(&'a self, event: &'a ExecutionEvent) -> BoxFuture<'a, ()> {
+            Box::pin(async move { self.0.lock().unwrap().push(event.clone()) })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_quote_is_emitted() {
+        let server = MockServer::start(r#"{"toAmount": "42"}"#).await;
+        let sink = Collecting::default();
+        let events = sink.0.clone();
+        let client = new_with_base_url("token".into(), SupportedNetworks::Polygon, &server.base_url).with_event_sink(sink);
+
+        let details = QuoteDetailsBuilder::new().src("0xa".into()).dst("0xb".into()).amount("1000".into()).build().unwrap();
+        client.quote(details).await.unwrap();
+
+        let events = events.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].chain_id(), 137);
+        assert!(matches!(&events[0], ExecutionEvent::QuoteReceived { dst_amount, .. } if dst_amount == "42"));
+    }
+
+    #[tokio::test]
+    async fn test_reference_quote_of_impact_check_is_not_emitted() {
+        let server = MockServer::start_routes(&[
+            ("/swap/v5.2/1/quote", r#"{"toAmount": "5"}"#),
+            (
+                "/swap/v6.0/1/swap",
+                r#"{"dstAmount": "5", "tx": {"from": "a", "to": "b", "data": "0x", "value": "0", "gasPrice": "1", "gas": 21000}}"#,
+            ),
+        ])
+        .await;
+        let sink = Collecting::default();
+        let events = sink.0.clone();
+        let client = new_with_base_url("token".into(), SupportedNetworks::Ethereum, &server.base_url)
+            .with_price_impact_policy(PriceImpactPolicy::new(0.05))
+            .with_event_sink(sink);
+
+        assert!(client.swap_v6(swap_details()).await.is_err());
+        assert_eq!(server.requests().len(), 2);
+        assert!(events.lock().unwrap().is_empty());
+    }
+
+    // Signs the first swap and fails the rest.
+    #[derive(Default)]
+    struct SigningOnce(AtomicUsize);
+
+    impl TransactionSigner for SigningOnce {
+        fn sign_swap<'a>(&'a self, _: &'a PreparedSwap, _: u64) -> BoxFuture<'a, Result<String, SignerError>> {
+            let first = self.0.fetch_add(1, Ordering::SeqCst) == 0;
+            Box::pin(async move {
+                if first {
+                    Ok("0x02f8".to_string())
+                } else {
+                    Err("device disconnected".into())
+                }
+            })
+        }
+    }
+
+    fn swap_details() -> crate::swap::SwapDetailsV6 {
+        SwapDetailsV6Builder::new()
+            .src("src".into())
+            .dst("dst".into())
+            .amount("1000".into())
+            .eoa("0x1111111111111111111111111111111111111111".into())
+            .slippage(1)
+            .unwrap()
+            .build()
+            .unwrap()
+    }
+
+    fn prepared_swap() -> PreparedSwap {
+        PreparedSwap {
+            details: swap_details(),
+            response: serde_json::from_str(
+                r#"{"dstAmount": "1", "tx": {"from": "a", "to": "b", "data": "0x", "value": "0", "gasPrice": "1", "gas": 21000}}"#,
+            )
+            .unwrap(),
+            prepared_at: SystemTime::now(),
+            valid_until: SystemTime::now() + Duration::from_secs(60),
+            receiver_kind: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_broadcast_and_signing_failure_are_emitted() {
+        let server =
+            MockServer::start_sequence(&[r#"{"jsonrpc": "2.0", "id": 1, "result": "0x5"}"#, r#"{"transactionHash": "0xabc"}"#])
+                .await;
+        let sink = Collecting::default();
+        let events = sink.0.clone();
+        let client = new_with_base_url("token".into(), SupportedNetworks::Ethereum, &server.base_url).with_event_sink(sink);
+
+        let results = client.execute_many(vec![prepared_swap(), prepared_swap()], &SigningOnce::default(), 1).await;
+        assert_eq!(results[0].as_ref().unwrap(), "0xabc");
+        assert!(results[1].is_err());
+
+        let sender = "0x1111111111111111111111111111111111111111".to_string();
+        let events = events.lock().unwrap();
+        assert_eq!(
+            *events,
+            vec![
+                ExecutionEvent::SwapBroadcast { chain_id: 1, sender: sender.clone(), nonce: 5, tx_hash: "0xabc".into() },
+                ExecutionEvent::Failed { chain_id: 1, sender: Some(sender), tx_hash: None, error: "device disconnected".into() },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_finality_is_emitted_once_per_transaction() {
+        const BLOCK: &str = r#"{"jsonrpc": "2.0", "id": 1, "result": "0x100"}"#;
+        let server = MockServer::start_sequence(&[
+            r#"{"jsonrpc": "2.0", "id": 1, "result": {"transactionHash": "0x01", "blockHash": "0x02", "blockNumber": "0x64",
+                "from": "0x03", "to": "0x04", "gasUsed": "0x5208", "status": "0x1", "logs": []}}"#,
+            BLOCK,
+            r#"{"jsonrpc": "2.0", "id": 1, "result": {"transactionHash": "0x01", "blockHash": "0x02", "blockNumber": "0x64",
+                "from": "0x03", "to": "0x04", "gasUsed": "0x5208", "status": "0x1", "logs": []}}"#,
+            BLOCK,
+            r#"{"jsonrpc": "2.0", "id": 1, "result": {"transactionHash": "0x05", "blockHash": "0x02", "blockNumber": "0x64",
+                "from": "0x03", "to": "0x04", "gasUsed": "0x5208", "status": "0x0", "logs": []}}"#,
+            BLOCK,
+        ])
+        .await;
+        let sink = Collecting::default();
+        let events = sink.0.clone();
+        let client = new_with_base_url("token".into(), SupportedNetworks::Ethereum, &server.base_url).with_event_sink(sink);
+
+        // Repeated checks of the final transaction emit nothing new
+        client.get_transaction_status("0x01").await.unwrap();
+        client.clone().get_transaction_status("0x01").await.unwrap();
+        client.get_transaction_status("0x05").await.unwrap();
+
+        let events = events.lock().unwrap();
+        assert_eq!(events.len(), 2);
+        assert_eq!(
+            events[0],
+            ExecutionEvent::Confirmed { chain_id: 1, tx_hash: "0x01".into(), block: 100, success: Some(true) }
+        );
+        assert!(matches!(&events[1], ExecutionEvent::Failed { tx_hash: Some(hash), sender: None, .. } if hash == "0x05"));
+    }
+}
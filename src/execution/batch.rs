@@ -0,0 +1,193 @@
+use crate::{client::OneInchClient, events::ExecutionEvent, execution::PreparedSwap};
+use futures::{future::BoxFuture, stream, StreamExt};
+use std::{collections::HashMap, error::Error};
+
+/// Error of signing the transaction.
+pub type SignerError = Box<dyn Error + Send + Sync>;
+
+/// Trait for anything able to sign swap transactions. Implement it for your
+/// wallet/signer to execute prepared swaps with
+/// [`execute_many`](OneInchClient::execute_many).
+pub trait TransactionSigner: Send + Sync {
+    /// Signs transaction of the swap with the nonce and returns hex encoded
+    /// raw transaction. Transaction is signed by the swap's
+    /// [signer](PreparedSwap::signer), meta-transactions should be wrapped
+    /// into the wallet's call.
+    fn sign_swap<'a>(&'a self, swap: &'a PreparedSwap, nonce: u64) -> BoxFuture<'a, Result<String, SignerError>>;
+}
+
+impl OneInchClient {
+    /// Executes independent prepared swaps, returning hash of the broadcasted
+    /// transaction or the error for every swap, in order of the input.
+    ///
+    /// Swaps of the same sender are executed one by one with consecutive
+    /// nonces, while swaps of different senders run concurrently, at most
+    /// `max_concurrency` senders at once. Failure of one swap doesn't stop
+    /// the rest: swap failed before broadcasting doesn't take a nonce, and
+    /// after failed broadcasting the nonce is re-read from the chain.
+    pub async fn execute_many(
+        &self,
+        swaps: Vec<PreparedSwap>,
+        signer: &impl TransactionSigner,
+        max_concurrency: usize,
+    ) -> Vec<Result<String, Box<dyn Error + Send + Sync>>> {
+        let total = swaps.len();
+
+        let mut senders: HashMap<String, Vec<(usize, PreparedSwap)>> = HashMap::new();
+        for (index, swap) in swaps.into_iter().enumerate() {
+            senders.entry(swap.signer().to_lowercase()).or_default().push((index, swap));
+        }
+
+        let mut results: Vec<Option<Result<String, Box<dyn Error + Send + Sync>>>> = (0..total).map(|_| None).collect();
+
+        let mut executions = stream::iter(senders)
+            .map(|(sender, swaps)| self.execute_sender_swaps(sender, swaps, signer))
+            .buffer_unordered(max_concurrency.max(1));
+
+        while let Some(sender_results) = executions.next().await {
+            for (index, result) in sender_results {
+                results[index] = Some(result);
+            }
+        }
+
+        results.into_iter().map(|result| result.expect("every swap is executed")).collect()
+    }
+
+    // Executes swaps of one sender sequentially, coordinating their nonces.
+    async fn execute_sender_swaps(
+        &self,
+        sender: String,
+        swaps: Vec<(usize, PreparedSwap)>,
+        signer: &impl TransactionSigner,
+    ) -> Vec<(usize, Result<String, Box<dyn Error + Send + Sync>>)> {
+        let mut nonce = None;
+        let mut results = Vec::with_capacity(swaps.len());
+
+        for (index, swap) in swaps {
+            let result = self.execute_with_nonce(&sender, &swap, signer, &mut nonce).await;
+            if let Err(e) = &result {
+                tracing::warn!(sender = %self.display_address(&sender), index, "swap execution failed: {}", e);
+            }
+            results.push((index, result));
+        }
+
+        results
+    }
+
+    async fn execute_with_nonce(
+        &self,
+        sender: &str,
+        swap: &PreparedSwap,
+        signer: &impl TransactionSigner,
+        nonce: &mut Option<u64>,
+    ) -> Result<String, Box<dyn Error + Send + Sync>> {
+        swap.transaction()?;
+
+        let current = match *nonce {
+            Some(current) => current,
+            None => self.get_nonce(sender).await?,
+        };
+
+        let raw_tx = match signer.sign_swap(swap, current).await {
+            Ok(raw_tx) => raw_tx,
+            Err(e) => {
+                self.emit_failure(sender, &*e).await;
+                return Err(e);
+            }
+        };
+
+        match self.broadcast_public_tx(raw_tx).await {
+            Ok(tx_hash) => {
+                *nonce = Some(current + 1);
+                self.emit(|| ExecutionEvent::SwapBroadcast {
+                    chain_id: self.network_id as u32,
+                    sender: sender.to_string(),
+                    nonce: current,
+                    tx_hash: tx_hash.clone(),
+                })
+                .await;
+                Ok(tx_hash)
+            }
+            Err(e) => {
+                // Transaction may have been accepted anyway, so the next swap
+                // reads the nonce from the chain
+                *nonce = None;
+                self.emit_failure(sender, &e).await;
+                Err(e.into())
+            }
+        }
+    }
+
+    async fn emit_failure(&self, sender: &str, error: &(dyn Error + Sync)) {
+        self.emit(|| ExecutionEvent::Failed {
+            chain_id: self.network_id as u32,
+            sender: Some(sender.to_string()),
+            tx_hash: None,
+            error: error.to_string(),
+        })
+        .await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::{new_with_default_http, SupportedNetworks},
+        execution::ExecutionError,
+        swap::SwapDetailsV6Builder,
+        utils::assert_send,
+    };
+    use std::time::{Duration, SystemTime};
+
+    struct PanickingSigner;
+
+    impl TransactionSigner for PanickingSigner {
+        fn sign_swap<'a>(&'a self, _: &'a PreparedSwap, _: u64) -> BoxFuture<'a, Result<String, SignerError>> {
+            panic!("expired swaps shouldn't be signed")
+        }
+    }
+
+    fn expired_swap(origin: &str) -> PreparedSwap {
+        let details = SwapDetailsV6Builder::new()
+            .src("src".into())
+            .dst("dst".into())
+            .amount("1000".into())
+            .eoa(origin.into())
+            .slippage(1)
+            .unwrap()
+            .build()
+            .unwrap();
+        let response = serde_json::from_str(
+            r#"{"dstAmount": "1", "tx": {"from": "a", "to": "b", "data": "0x", "value": "0", "gasPrice": "1", "gas": 21000}}"#,
+        )
+        .unwrap();
+        let prepared_at = SystemTime::now() - Duration::from_secs(120);
+
+        PreparedSwap { details, response, prepared_at, valid_until: prepared_at + Duration::from_secs(60), receiver_kind: None }
+    }
+
+    #[tokio::test]
+    async fn test_failures_are_isolated_per_swap() {
+        let client = new_with_default_http("token".into(), SupportedNetworks::Ethereum);
+        let swaps = vec![
+            expired_swap("0x1111111111111111111111111111111111111111"),
+            expired_swap("0x2222222222222222222222222222222222222222"),
+            expired_swap("0x1111111111111111111111111111111111111111"),
+        ];
+
+        let results = client.execute_many(swaps, &PanickingSigner, 2).await;
+        assert_eq!(results.len(), 3);
+        for result in results {
+            let error = result.unwrap_err();
+            assert!(matches!(error.downcast_ref::<ExecutionError>(), Some(ExecutionError::Expired { .. })));
+        }
+    }
+
+    #[test]
+    fn test_execution_future_is_send() {
+        let client = new_with_default_http("token".into(), SupportedNetworks::Ethereum);
+        let swaps = vec![expired_swap("0x1111111111111111111111111111111111111111")];
+        assert_send(client.execute_many(swaps, &PanickingSigner, 2));
+    }
+}
@@ -0,0 +1,207 @@
+use crate::{
+    client::OneInchClient,
+    consts::SWAP_V6_API_VERSION,
+    events::ExecutionEvent,
+    execution::{PreparedSwap, SignerError},
+    swap::{
+        approve::{AllowanceDetails, ApproveCallData, ApproveTranactionDetails},
+        parse_amount, QuoteDetails, QuoteResponse, SwapDetailsV6, NATIVE_TOKEN_ADDRESS,
+    },
+};
+use std::{error::Error, future::Future, time::Duration};
+
+/// Swap of the token the wallet may have never approved, prepared with the
+/// approval overlapped with quoting.
+#[derive(Debug)]
+pub struct FirstSwap {
+    /// Hash of the approve transaction, `None` if the router was already
+    /// allowed to spend the amount.
+    pub approve_tx_hash: Option<String>,
+    pub quote: QuoteResponse,
+    pub swap: PreparedSwap,
+}
+
+impl OneInchClient {
+    /// Prepares swap of the token for the first time, shaving the approval
+    /// off its latency: allowance of the v6 router is checked and, if it's
+    /// insufficient, the approve transaction for the amount is built and
+    /// handed to `submit_approve` to be signed and broadcast, while the swap
+    /// is quoted and its receiver verified, if enabled by
+    /// [`with_receiver_verification`](OneInchClient::with_receiver_verification),
+    /// concurrently. Swap is built once all of them complete.
+    ///
+    /// Approve transaction isn't mined by the time the swap is built, so its
+    /// estimation is disabled in that case. Swap transaction should be sent
+    /// with the nonce following the approve's one.
+    pub async fn prepare_first_swap<F>(
+        &self,
+        details: SwapDetailsV6,
+        valid_for: Duration,
+        submit_approve: impl FnOnce(ApproveCallData) -> F,
+    ) -> Result<FirstSwap, Box<dyn Error>>
+    where
+        F: Future<Output = Result<String, SignerError>>,
+    {
+        let (approve_tx_hash, quote, receiver_kind) = futures::join!(
+            self.approve_if_needed(&details, submit_approve),
+            self.quote(quote_details(&details)),
+            self.verify_receiver(&details)
+        );
+        let approve_tx_hash = approve_tx_hash.map_err(|e| e as Box<dyn Error>)?;
+        let quote = quote?;
+
+        let mut details = details;
+        if approve_tx_hash.is_some() {
+            details.disable_estimate = Some(true);
+        }
+        let swap = self.prepare_verified_swap(details, valid_for, receiver_kind).await?;
+
+        Ok(FirstSwap { approve_tx_hash, quote, swap })
+    }
+
+    // Submits approve transaction of the swap amount to the v6 router, unless
+    // the allowance is already sufficient. Returns hash of the transaction.
+    async fn approve_if_needed<F>(
+        &self,
+        details: &SwapDetailsV6,
+        submit_approve: impl FnOnce(ApproveCallData) -> F,
+    ) -> Result<Option<String>, SignerError>
+    where
+        F: Future<Output = Result<String, SignerError>>,
+    {
+        let token = details.src.to_lowercase();
+        if token == NATIVE_TOKEN_ADDRESS {
+            return Ok(None);
+        }
+
+        let needed = parse_amount(&details.amount)?;
+        let allowance = self
+            .get_router_allowance(
+                SWAP_V6_API_VERSION,
+                AllowanceDetails { token_address: token.clone(), wallet_address: details.from.clone() },
+            )
+            .await?;
+        if parse_amount(&allowance.allowance)? >= needed {
+            return Ok(None);
+        }
+
+        let approve = self
+            .get_router_approve(
+                SWAP_V6_API_VERSION,
+                ApproveTranactionDetails { token_address: token, amount: Some(details.amount.clone()) },
+            )
+            .await?;
+        let tx_hash = submit_approve(approve).await?;
+        self.emit(|| ExecutionEvent::ApprovalSent {
+            chain_id: self.network_id as u32,
+            token: details.src.to_lowercase(),
+            owner: details.from.clone(),
+            amount: details.amount.clone(),
+            tx_hash: tx_hash.clone(),
+        })
+        .await;
+
+        Ok(Some(tx_hash))
+    }
+}
+
+// Quote request of the same pair, amount and routing as the swap.
+fn quote_details(details: &SwapDetailsV6) -> QuoteDetails {
+    QuoteDetails {
+        src: details.src.clone(),
+        dst: details.dst.clone(),
+        amount: details.amount.clone(),
+        fee: details.fee,
+        protocols: details.protocols.clone(),
+        gas_price: details.gas_price.clone(),
+        complexity_level: details.complexity_level,
+        parts: details.parts,
+        main_route_parts: details.main_route_parts,
+        gas_limit: details.gas_limit,
+        include_tokens_info: details.include_tokens_info,
+        include_protocols: details.include_protocols,
+        include_gas: details.include_gas,
+        connector_tokens: details.connector_tokens.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::{new_with_base_url, SupportedNetworks},
+        execution::ReceiverKind,
+        swap::SwapDetailsV6Builder,
+        utils::{assert_send, mock_server::MockServer},
+    };
+    use std::sync::Mutex;
+
+    #[test]
+    fn test_first_swap_future_is_send() {
+        let client = new_with_base_url("token".to_string(), SupportedNetworks::Ethereum, "http://localhost");
+        let details = SwapDetailsV6Builder::new()
+            .src("src".into())
+            .dst("dst".into())
+            .amount("1000".into())
+            .eoa("0x1111111111111111111111111111111111111111".into())
+            .slippage(1)
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_send(client.prepare_first_swap(details, Duration::from_secs(60), |_| async { Ok("0xapprove".to_string()) }));
+    }
+
+    #[tokio::test]
+    async fn test_approve_overlaps_quote() {
+        let server = MockServer::start_routes(&[
+            ("/swap/v6.0/1/approve/allowance", r#"{"allowance": "0"}"#),
+            (
+                "/swap/v6.0/1/approve/transaction",
+                r#"{"data": "0x095ea7b3", "gasPrice": "1", "to": "0x6b175474e89094c44da98b954eedeac495271d0f", "value": "0"}"#,
+            ),
+            ("/swap/v5.2/1/quote", r#"{"toAmount": "5"}"#),
+            (
+                "/swap/v6.0/1/swap",
+                r#"{"dstAmount": "5", "tx": {"from": "a", "to": "b", "data": "0x", "value": "0", "gasPrice": "1", "gas": 21000}}"#,
+            ),
+            ("/web3/1", r#"{"jsonrpc": "2.0", "id": 1, "result": "0x6080604052"}"#),
+        ])
+        .await;
+        let client = new_with_base_url("token".to_string(), SupportedNetworks::Ethereum, &server.base_url)
+            .with_receiver_verification(true);
+        let details = SwapDetailsV6Builder::new()
+            .src("0x6B175474E89094C44Da98b954EedeAC495271d0F".into())
+            .dst("0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee".into())
+            .amount("1000".into())
+            // Smart wallet holding the tokens, sent through by the EOA
+            .from("0x1111111111111111111111111111111111111111".into())
+            .origin("0x2222222222222222222222222222222222222222".into())
+            .receiver("0x3333333333333333333333333333333333333333".into())
+            .slippage(1)
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let submitted = Mutex::new(None);
+        let first_swap = client
+            .prepare_first_swap(details, Duration::from_secs(60), |approve| {
+                *submitted.lock().unwrap() = Some(approve);
+                async { Ok("0xapprove".to_string()) }
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(first_swap.approve_tx_hash.as_deref(), Some("0xapprove"));
+        assert_eq!(submitted.lock().unwrap().as_ref().unwrap().data, "0x095ea7b3");
+        assert_eq!(first_swap.quote.to_amount, "5");
+        assert_eq!(first_swap.swap.details.disable_estimate, Some(true));
+        assert_eq!(first_swap.swap.receiver_kind, Some(ReceiverKind::Contract));
+
+        let requests = server.requests();
+        let allowance = requests.iter().find(|r| r.starts_with("/swap/v6.0/1/approve/allowance")).unwrap();
+        assert!(allowance.contains("walletAddress=0x1111111111111111111111111111111111111111"));
+        assert!(requests.iter().any(|r| r.starts_with("/swap/v6.0/1/approve/transaction")));
+        assert!(requests.iter().any(|r| r.starts_with("/web3/1")));
+        assert!(requests.last().unwrap().starts_with("/swap/v6.0/1/swap"));
+    }
+}
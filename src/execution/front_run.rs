@@ -0,0 +1,254 @@
+use crate::{
+    client::OneInchClient,
+    execution::{FrontRunReport, NeighbourTransaction, Sandwich, TokenAmount},
+    web3::{
+        abi::{decode_u64_quantity, decode_uint256},
+        TransactionReceipt, Web3Error,
+    },
+};
+use futures::{stream, StreamExt, TryStreamExt};
+use num_bigint::{BigInt, BigUint, Sign};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    error::Error,
+};
+
+// Topics of the ERC-20 `Transfer` and `Approval` events.
+const TRANSFER_TOPIC: &str = "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef";
+const APPROVAL_TOPIC: &str = "0x8c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925";
+
+// Receipts of the neighbours fetched at once, so large windows don't flood
+// the node.
+const MAX_CONCURRENT_RECEIPTS: usize = 8;
+
+impl OneInchClient {
+    /// Inspects up to `window` transactions right before and after the
+    /// executed swap in its block for ones interacting with the same pools,
+    /// and flags probable sandwiches: a pair of such transactions around the
+    /// swap sent by the same address. See [`FrontRunReport`].
+    pub async fn detect_front_running(&self, tx_hash: &str, window: usize) -> Result<FrontRunReport, Box<dyn Error>> {
+        let receipt = self.get_transaction_receipt(tx_hash).await?.ok_or("Transaction isn't mined yet")?;
+        let block = decode_u64_quantity(&receipt.block_number)?;
+
+        let hashes = self.get_block_transaction_hashes(block).await?;
+        let position =
+            hashes.iter().position(|h| h.eq_ignore_ascii_case(tx_hash)).ok_or("Transaction isn't found in its block")?;
+
+        let before = &hashes[position.saturating_sub(window)..position];
+        let after = &hashes[position + 1..position.saturating_add(1).saturating_add(window).min(hashes.len())];
+
+        let before = self.mined_receipts(before).await?;
+        let after = self.mined_receipts(after).await?;
+
+        Ok(analyze_neighbours(block, &receipt, &before, &after))
+    }
+
+    async fn mined_receipts(&self, hashes: &[String]) -> Result<Vec<TransactionReceipt>, Web3Error> {
+        // Hashes are owned, since the compiler can't prove the future `Send` for
+        // a stream of borrowed ones
+        stream::iter(hashes.to_vec())
+            .map(|hash| async move {
+                let receipt = self.get_transaction_receipt(&hash).await?;
+                receipt.ok_or_else(|| Web3Error::InvalidResponse(format!("Receipt of {} is missing", hash)))
+            })
+            .buffered(MAX_CONCURRENT_RECEIPTS)
+            .try_collect()
+            .await
+    }
+}
+
+// Pools are recognized as contracts emitting events other than plain token
+// transfers and approvals, e.g. `Swap` or `Sync`.
+fn touched_pools(receipt: &TransactionReceipt) -> BTreeSet<String> {
+    receipt
+        .logs
+        .iter()
+        .filter(|log| {
+            log.topics
+                .first()
+                .is_some_and(|topic| !topic.eq_ignore_ascii_case(TRANSFER_TOPIC) && !topic.eq_ignore_ascii_case(APPROVAL_TOPIC))
+        })
+        .map(|log| log.address.to_lowercase())
+        .collect()
+}
+
+fn neighbours<'a>(
+    pools: &BTreeSet<String>,
+    receipts: &'a [TransactionReceipt],
+) -> Vec<(NeighbourTransaction, &'a TransactionReceipt)> {
+    receipts
+        .iter()
+        .filter_map(|receipt| {
+            let shared: Vec<String> = touched_pools(receipt).intersection(pools).cloned().collect();
+            let neighbour = NeighbourTransaction {
+                tx_hash: receipt.transaction_hash.clone(),
+                from: receipt.from.to_lowercase(),
+                shared_pools: shared,
+            };
+            (!neighbour.shared_pools.is_empty()).then_some((neighbour, receipt))
+        })
+        .collect()
+}
+
+// Net token flows of the attacker's addresses (sender and the contract it
+// called) over both transactions. Positive flows are the attacker's profit.
+fn attacker_profit(front: &TransactionReceipt, back: &TransactionReceipt) -> Vec<TokenAmount> {
+    let attacker: BTreeSet<String> =
+        [Some(&front.from), front.to.as_ref()].into_iter().flatten().map(|a| a.to_lowercase()).collect();
+
+    let mut flows: BTreeMap<String, BigInt> = BTreeMap::new();
+    for log in front.logs.iter().chain(back.logs.iter()) {
+        if log.topics.len() != 3 || !log.topics[0].eq_ignore_ascii_case(TRANSFER_TOPIC) {
+            continue;
+        }
+        let Ok(amount) = decode_uint256(&log.data) else { continue };
+        let amount = BigInt::from_biguint(Sign::Plus, amount);
+
+        let address = |topic: &str| format!("0x{}", &topic[topic.len().saturating_sub(40)..]).to_lowercase();
+        let flow = flows.entry(log.address.to_lowercase()).or_default();
+        if attacker.contains(&address(&log.topics[2])) {
+            *flow += &amount;
+        }
+        if attacker.contains(&address(&log.topics[1])) {
+            *flow -= &amount;
+        }
+    }
+
+    flows
+        .into_iter()
+        .filter_map(|(token, flow)| {
+            let amount: BigUint = flow.to_biguint().filter(|a| *a > BigUint::default())?;
+            Some(TokenAmount { token, amount: amount.to_string() })
+        })
+        .collect()
+}
+
+// Builds the report from receipts of the swap and its neighbours, ordered as
+// they are in the block.
+fn analyze_neighbours(
+    block: u64,
+    receipt: &TransactionReceipt,
+    before: &[TransactionReceipt],
+    after: &[TransactionReceipt],
+) -> FrontRunReport {
+    let pools = touched_pools(receipt);
+    let before = neighbours(&pools, before);
+    let after = neighbours(&pools, after);
+
+    let mut sandwiches = vec![];
+    for (front, front_receipt) in &before {
+        for (back, back_receipt) in after.iter().filter(|(back, _)| back.from == front.from) {
+            let pools: Vec<String> = front.shared_pools.iter().filter(|p| back.shared_pools.contains(p)).cloned().collect();
+            if pools.is_empty() {
+                continue;
+            }
+
+            sandwiches.push(Sandwich {
+                attacker: front.from.clone(),
+                front_tx: front.tx_hash.clone(),
+                back_tx: back.tx_hash.clone(),
+                pools,
+                estimated_loss: attacker_profit(front_receipt, back_receipt),
+            });
+        }
+    }
+
+    FrontRunReport {
+        block,
+        pools: pools.into_iter().collect(),
+        before: before.into_iter().map(|(n, _)| n).collect(),
+        after: after.into_iter().map(|(n, _)| n).collect(),
+        sandwiches,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::{new_with_base_url, SupportedNetworks},
+        utils::{assert_send, mock_server::MockServer},
+    };
+
+    const SWAP_TOPIC: &str = "0xc42079f94a6350d7e6235f29174924f928cc2ac818eb64fed8004e115fbcca67";
+
+    fn word(value: u64) -> String {
+        format!("0x{:064x}", value)
+    }
+
+    fn topic(address: &str) -> String {
+        format!("0x{:0>64}", &address[2..])
+    }
+
+    fn receipt(hash: &str, from: &str, to: &str, logs: serde_json::Value) -> TransactionReceipt {
+        serde_json::from_value(serde_json::json!({
+            "transactionHash": hash, "blockHash": "0x01", "blockNumber": "0x10", "from": from, "to": to,
+            "gasUsed": "0x1", "status": "0x1", "logs": logs
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_sandwich_detection() {
+        let (pool, weth, usdc) = (
+            "0x00000000000000000000000000000000000000aa",
+            "0x0000000000000000000000000000000000000001",
+            "0x0000000000000000000000000000000000000002",
+        );
+        let (bot, eoa) = ("0x00000000000000000000000000000000000000b0", "0x00000000000000000000000000000000000000e0");
+        let swap_log = serde_json::json!({"address": pool, "topics": [SWAP_TOPIC], "data": "0x"});
+        let transfer = |token: &str, from: &str, to: &str, amount: u64| serde_json::json!({"address": token, "topics": [TRANSFER_TOPIC, topic(from), topic(to)], "data": word(amount)});
+
+        let ours = receipt(
+            "0x02",
+            "0x00000000000000000000000000000000000000c0",
+            "0x1111",
+            serde_json::json!([swap_log]),
+        );
+        let front = receipt(
+            "0x01",
+            eoa,
+            bot,
+            serde_json::json!([swap_log, transfer(weth, bot, pool, 100), transfer(usdc, pool, bot, 300)]),
+        );
+        let back = receipt(
+            "0x03",
+            eoa,
+            bot,
+            serde_json::json!([swap_log, transfer(usdc, bot, pool, 300), transfer(weth, pool, bot, 104)]),
+        );
+        let unrelated = receipt("0x04", eoa, bot, serde_json::json!([]));
+
+        let report = analyze_neighbours(16, &ours, &[front], &[back, unrelated]);
+
+        assert_eq!(report.pools, vec![pool.to_string()]);
+        assert_eq!(report.after.len(), 1);
+        assert_eq!(report.sandwiches.len(), 1);
+        assert_eq!(report.sandwiches[0].attacker, eoa);
+        assert_eq!(
+            report.sandwiches[0].estimated_loss,
+            vec![TokenAmount { token: weth.to_string(), amount: "4".into() }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unbounded_window_is_clamped_to_the_block() {
+        let server = MockServer::start_sequence(&[
+            r#"{"jsonrpc": "2.0", "id": 1, "result": {"transactionHash": "0x02", "blockHash": "0x01", "blockNumber": "0x10",
+                "from": "0x03", "to": "0x04", "gasUsed": "0x1", "status": "0x1", "logs": []}}"#,
+            r#"{"jsonrpc": "2.0", "id": 1, "result": {"transactions": ["0x02"]}}"#,
+        ])
+        .await;
+        let client = new_with_base_url("token".into(), SupportedNetworks::Ethereum, &server.base_url);
+
+        let report = client.detect_front_running("0x02", usize::MAX).await.unwrap();
+        assert_eq!(report.block, 16);
+        assert!(report.before.is_empty() && report.after.is_empty());
+    }
+
+    #[test]
+    fn test_front_running_future_is_send() {
+        let client = new_with_base_url("token".to_string(), SupportedNetworks::Ethereum, "http://localhost");
+        assert_send(client.detect_front_running("0xhash", 2));
+    }
+}
@@ -0,0 +1,10 @@
+mod batch;
+mod first_swap;
+mod front_run;
+mod prepared;
+mod types;
+
+pub use batch::*;
+pub use first_swap::*;
+pub use prepared::*;
+pub use types::*;
@@ -0,0 +1,238 @@
+use crate::{
+    client::OneInchClient,
+    error::OneInchError,
+    execution::{ExecutionError, ReceiverKind},
+    swap::{SwapDetailsV6, SwapTranactionData, SwapV6Response},
+    utils::runtime::SystemTime,
+    web3::Web3Error,
+};
+use std::time::Duration;
+
+/// Swap transaction received from 1inch together with the details it was
+/// requested with. API itself has no deadlines, so a prepared swap gets a
+/// client-side `valid_until` moment after which execution is refused and the
+/// swap should be re-quoted.
+#[derive(Debug)]
+pub struct PreparedSwap {
+    pub details: SwapDetailsV6,
+    pub response: SwapV6Response,
+    pub prepared_at: SystemTime,
+    pub valid_until: SystemTime,
+    /// Kind of the swap's receiver, if it was verified, see
+    /// [`with_receiver_verification`](OneInchClient::with_receiver_verification).
+    pub receiver_kind: Option<ReceiverKind>,
+}
+
+impl PreparedSwap {
+    /// Returns true if the deadline has passed.
+    pub fn is_expired(&self) -> bool {
+        SystemTime::now() > self.valid_until
+    }
+
+    /// Returns time left before the deadline, `None` if it has passed.
+    pub fn time_left(&self) -> Option<Duration> {
+        self.valid_until.duration_since(SystemTime::now()).ok()
+    }
+
+    /// Returns transaction to be executed, refusing it if the deadline has
+    /// passed.
+    pub fn transaction(&self) -> Result<&SwapTranactionData, ExecutionError> {
+        if self.is_expired() {
+            return Err(ExecutionError::Expired { valid_until: self.valid_until });
+        }
+
+        Ok(&self.response.transaction)
+    }
+
+    /// Returns address which should sign and send the transaction. It's the
+    /// `origin` of the swap, which differs from `from` for meta-transactions.
+    pub fn signer(&self) -> &str {
+        &self.details.origin
+    }
+
+    /// Returns transaction to be signed by the `signer`, refusing it if the
+    /// deadline has passed or the signer isn't the swap's origin. For
+    /// meta-transactions the transaction is executed by the `from` wallet, so
+    /// the relayer should wrap it into the wallet's call rather than send it
+    /// as is.
+    pub fn transaction_for(&self, signer: &str) -> Result<&SwapTranactionData, ExecutionError> {
+        if !signer.eq_ignore_ascii_case(self.signer()) {
+            return Err(ExecutionError::WrongSigner { expected: self.signer().to_string(), actual: signer.to_string() });
+        }
+
+        self.transaction()
+    }
+
+    /// Returns the period the swap was prepared to be valid for.
+    fn validity_period(&self) -> Duration {
+        self.valid_until.duration_since(self.prepared_at).unwrap_or_default()
+    }
+}
+
+impl OneInchClient {
+    /// Enables verification of the receivers of prepared swaps via
+    /// `eth_getCode`, whose result is the swap's
+    /// [`receiver_kind`](PreparedSwap::receiver_kind). Swaps to contracts are
+    /// logged with a warning, since some tokens and contracts reject such
+    /// transfers and the swap reverts.
+    pub fn with_receiver_verification(mut self, enabled: bool) -> OneInchClient {
+        self.verify_receivers = enabled;
+        self
+    }
+
+    /// Performs `swap_v6` request and wraps its result into
+    /// [`PreparedSwap`](crate::execution::PreparedSwap) valid for the
+    /// specified period.
+    pub async fn prepare_swap(&self, details: SwapDetailsV6, valid_for: Duration) -> Result<PreparedSwap, OneInchError> {
+        let receiver_kind = self.verify_receiver(&details).await;
+
+        self.prepare_verified_swap(details, valid_for, receiver_kind).await
+    }
+
+    // Performs `swap_v6` request for the swap whose receiver was already
+    // verified.
+    pub(crate) async fn prepare_verified_swap(
+        &self,
+        details: SwapDetailsV6,
+        valid_for: Duration,
+        receiver_kind: Option<ReceiverKind>,
+    ) -> Result<PreparedSwap, OneInchError> {
+        let prepared_at = SystemTime::now();
+        let response = self.swap_v6(details.clone()).await?;
+
+        Ok(PreparedSwap { details, response, prepared_at, valid_until: prepared_at + valid_for, receiver_kind })
+    }
+
+    /// Re-quotes prepared swap with the same details and validity period.
+    pub async fn requote(&self, prepared: &PreparedSwap) -> Result<PreparedSwap, OneInchError> {
+        self.prepare_swap(prepared.details.clone(), prepared.validity_period()).await
+    }
+
+    // Returns kind of the swap's receiver if verification is enabled and the
+    // swap has a receiver. Failed lookup is only logged, since the swap itself
+    // may still succeed.
+    pub(crate) async fn verify_receiver(&self, details: &SwapDetailsV6) -> Option<ReceiverKind> {
+        let receiver = details.receiver.clone().filter(|_| self.verify_receivers)?;
+
+        let kind = async {
+            let receiver = self.resolve_swap_address(receiver).await?;
+            let code = self.get_code(&receiver).await?;
+            Ok::<_, Web3Error>((receiver, ReceiverKind::from_code(&code)))
+        };
+
+        match kind.await {
+            Ok((receiver, ReceiverKind::Contract)) => {
+                tracing::warn!(
+                    receiver = %self.display_address(&receiver),
+                    "swap receiver is a contract, which may reject the tokens"
+                );
+                Some(ReceiverKind::Contract)
+            }
+            Ok((_, kind)) => Some(kind),
+            Err(e) => {
+                tracing::warn!("swap receiver can't be verified: {}", e);
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::{new_with_base_url, SupportedNetworks},
+        swap::SwapDetailsV6Builder,
+        utils::{assert_send, mock_server::MockServer},
+    };
+
+    fn prepared_swap(prepared_at: SystemTime, valid_for: Duration) -> PreparedSwap {
+        let details = SwapDetailsV6Builder::new()
+            .src("src".into())
+            .dst("dst".into())
+            .amount("1000".into())
+            .meta_transaction(
+                "0x1111111111111111111111111111111111111111".into(),
+                "0x2222222222222222222222222222222222222222".into(),
+            )
+            .slippage(1)
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let response = serde_json::from_str(
+            r#"{"dstAmount": "1", "tx": {"from": "a", "to": "b", "data": "0x", "value": "0", "gasPrice": "1", "gas": 21000}}"#,
+        )
+        .unwrap();
+
+        PreparedSwap { details, response, prepared_at, valid_until: prepared_at + valid_for, receiver_kind: None }
+    }
+
+    #[test]
+    fn test_prepared_swap_refuses_execution_after_deadline() {
+        let fresh = prepared_swap(SystemTime::now(), Duration::from_secs(60));
+        assert!(fresh.transaction().is_ok());
+        assert!(fresh.time_left().is_some());
+
+        let expired = prepared_swap(SystemTime::now() - Duration::from_secs(120), Duration::from_secs(60));
+        assert_eq!(
+            expired.transaction().unwrap_err(),
+            ExecutionError::Expired { valid_until: expired.valid_until }
+        );
+        assert_eq!(expired.validity_period(), Duration::from_secs(60));
+    }
+
+    #[test]
+    fn test_meta_transaction_is_signed_by_origin() {
+        let prepared = prepared_swap(SystemTime::now(), Duration::from_secs(60));
+        assert!(prepared.details.is_meta_transaction());
+        assert_eq!(prepared.details.disable_estimate, Some(true));
+
+        assert!(prepared.transaction_for("0x2222222222222222222222222222222222222222").is_ok());
+        assert!(matches!(
+            prepared.transaction_for(&prepared.details.from),
+            Err(ExecutionError::WrongSigner { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_contract_receiver_is_flagged() {
+        assert_eq!(ReceiverKind::from_code("0x"), ReceiverKind::Eoa);
+        assert_eq!(ReceiverKind::from_code("0x0"), ReceiverKind::Eoa);
+        assert_eq!(ReceiverKind::from_code("0x6080604052"), ReceiverKind::Contract);
+
+        let server = MockServer::start_routes(&[
+            ("/swap/v6.0/1/swap", r#"{"dstAmount": "1", "tx": {"from": "a", "to": "b", "data": "0x", "value": "0", "gasPrice": "1", "gas": 21000}}"#),
+            ("/web3/1", r#"{"jsonrpc": "2.0", "id": 1, "result": "0x6080604052"}"#),
+        ])
+        .await;
+        let client = new_with_base_url("token".to_string(), SupportedNetworks::Ethereum, &server.base_url);
+        let details = SwapDetailsV6Builder::new()
+            .src("src".into())
+            .dst("dst".into())
+            .amount("1000".into())
+            .eoa("0x1111111111111111111111111111111111111111".into())
+            .receiver("0x3333333333333333333333333333333333333333".into())
+            .slippage(1)
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let prepared = client.prepare_swap(details.clone(), Duration::from_secs(60)).await.unwrap();
+        assert_eq!(prepared.receiver_kind, None);
+        assert_eq!(server.requests().len(), 1);
+
+        let client = client.with_receiver_verification(true);
+        let prepared = client.prepare_swap(details, Duration::from_secs(60)).await.unwrap();
+        assert_eq!(prepared.receiver_kind, Some(ReceiverKind::Contract));
+        assert!(server.requests()[1].starts_with("/web3/1"));
+    }
+
+    #[test]
+    fn test_prepare_futures_are_send() {
+        let client = new_with_base_url("token".to_string(), SupportedNetworks::Ethereum, "http://localhost");
+        let prepared = prepared_swap(SystemTime::now(), Duration::from_secs(60));
+        assert_send(client.prepare_swap(prepared.details.clone(), Duration::from_secs(60)));
+        assert_send(client.requote(&prepared));
+    }
+}
@@ -0,0 +1,90 @@
+use crate::utils::runtime::SystemTime;
+use thiserror::Error;
+
+/// Enumerates errors which execution of prepared swaps may end up with.
+#[derive(Error, Debug, Eq, PartialEq)]
+pub enum ExecutionError {
+    /// Indicates the prepared swap is past its deadline and must be re-quoted
+    /// before execution.
+    #[error("Prepared swap expired, it should be re-quoted before execution")]
+    Expired { valid_until: SystemTime },
+
+    /// Indicates the transaction was about to be signed by an address other
+    /// than its origin. For meta-transactions `from` is a smart-contract
+    /// wallet, which can't sign anything.
+    #[error("Swap should be signed by {expected}, not by {actual}")]
+    WrongSigner { expected: String, actual: String },
+}
+
+/// Kind of the account receiving tokens of the swap.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ReceiverKind {
+    /// Externally owned account.
+    Eoa,
+    /// Contract, which may run hooks on receiving the tokens. Some tokens
+    /// reject transfers to contracts, and contracts without a fallback
+    /// reject the native currency, so swaps to them may revert.
+    Contract,
+}
+
+impl ReceiverKind {
+    /// Determines kind of the account from its code, as returned by
+    /// `eth_getCode`. EOAs delegating to a contract (EIP-7702) run its code on
+    /// transfers, so they are contracts too.
+    pub fn from_code(code: &str) -> ReceiverKind {
+        match code.trim_start_matches("0x").trim_start_matches('0') {
+            "" => ReceiverKind::Eoa,
+            _ => ReceiverKind::Contract,
+        }
+    }
+}
+
+/// Amount of the token, in its minimal units.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct TokenAmount {
+    pub token: String,
+    pub amount: String,
+}
+
+/// Transaction next to the swap in its block, interacting with the same
+/// pools.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct NeighbourTransaction {
+    pub tx_hash: String,
+    pub from: String,
+    /// Pools the transaction shares with the swap.
+    pub shared_pools: Vec<String>,
+}
+
+/// Probable sandwich of the swap: transactions of the same sender right
+/// before and after it, interacting with the same pools.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Sandwich {
+    pub attacker: String,
+    pub front_tx: String,
+    pub back_tx: String,
+    pub pools: Vec<String>,
+    /// Loss of the swap estimated as the attacker's gross profit: tokens its
+    /// addresses gained over both transactions, before gas costs.
+    pub estimated_loss: Vec<TokenAmount>,
+}
+
+/// Front-running analysis of the executed swap.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct FrontRunReport {
+    pub block: u64,
+    /// Pools the swap interacted with.
+    pub pools: Vec<String>,
+    /// Transactions before the swap interacting with the same pools.
+    pub before: Vec<NeighbourTransaction>,
+    /// Transactions after the swap interacting with the same pools.
+    pub after: Vec<NeighbourTransaction>,
+    pub sandwiches: Vec<Sandwich>,
+}
+
+impl FrontRunReport {
+    /// Returns true if the swap was probably sandwiched.
+    pub fn is_sandwiched(&self) -> bool {
+        !self.sandwiches.is_empty()
+    }
+}
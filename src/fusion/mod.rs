@@ -0,0 +1,8 @@
+mod order;
+mod orders;
+mod quote;
+mod relayer;
+mod types;
+
+pub use order::*;
+pub use types::*;
@@ -0,0 +1,328 @@
+use crate::{
+    builder_setter,
+    client::SupportedNetworks,
+    common::eip712::{encode_address, Eip712Error, TypedDataSigner},
+    fusion::{FusionPreset, FusionPresetKind, FusionQuoteResponse},
+    orderbook::{LimitOrder, LimitOrderBuilder, LimitOrderBuilderError, LimitOrderData, MakerTraits, OrderExtension},
+    utils::runtime::{SystemTime, UNIX_EPOCH},
+};
+use serde::Serialize;
+use std::error::Error;
+use thiserror::Error;
+
+/// Enumerates potential errors when constructing `FusionOrder`.
+#[derive(Error, Debug, Eq, PartialEq)]
+pub enum FusionOrderBuilderError {
+    /// Indicates a required field is missing its value.
+    #[error("Missing {0}")]
+    MissingField(&'static str),
+
+    /// Indicates the quote doesn't contain requested preset.
+    #[error("Quote has no {0:?} preset")]
+    MissingPreset(FusionPresetKind),
+
+    /// Indicates some of quote values can't be encoded into the order.
+    #[error("Invalid value: {0}")]
+    InvalidValue(#[from] Eip712Error),
+
+    /// Indicates amount of the secrets doesn't match `secretsCount` of the
+    /// cross-chain preset.
+    #[error("Preset requires {expected} secrets, got {actual}")]
+    SecretsCount { expected: u32, actual: usize },
+
+    /// Indicates the quote whitelists more resolvers than the 5 bits of the
+    /// whitelist length can hold.
+    #[error("Whitelist of {0} resolvers exceeds the limit of {MAX_WHITELIST}")]
+    WhitelistTooLong(usize),
+
+    /// Indicates the underlying limit order can't be built.
+    #[error("Invalid order: {0}")]
+    Order(#[from] LimitOrderBuilderError),
+}
+
+/// Fusion order ready to be signed: limit order with the auction encoded into
+/// its extension.
+#[derive(Debug, Clone)]
+pub struct FusionOrder {
+    pub limit_order: LimitOrder,
+    /// Hex encoded order extension.
+    pub extension: String,
+    pub quote_id: serde_json::Value,
+}
+
+impl FusionOrder {
+    /// Signs the order with provided signer, so it can be submitted to relayer.
+    pub fn sign(&self, signer: &impl TypedDataSigner) -> Result<SignedFusionOrder, Box<dyn Error>> {
+        let signed = self.limit_order.sign(signer)?;
+
+        Ok(SignedFusionOrder {
+            order: signed.order,
+            order_hash: signed.order_hash,
+            signature: signed.signature,
+            extension: self.extension.clone(),
+            quote_id: self.quote_id.clone(),
+        })
+    }
+}
+
+/// Fusion order with the maker's signature in the form relayer accepts it.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignedFusionOrder {
+    pub order: LimitOrderData,
+    #[serde(skip)]
+    pub order_hash: String,
+    pub signature: String,
+    pub extension: String,
+    pub quote_id: serde_json::Value,
+}
+
+/// Builder struct to create instance of
+/// [`FusionOrder`](crate::fusion::FusionOrder) from the Fusion quote.
+#[derive(Default)]
+pub struct FusionOrderBuilder {
+    network: Option<SupportedNetworks>,
+    quote: Option<FusionQuoteResponse>,
+    maker: Option<String>,
+    maker_asset: Option<String>,
+    taker_asset: Option<String>,
+
+    // Optional fields
+    preset: Option<FusionPresetKind>,
+    receiver: Option<String>,
+    nonce: Option<u64>,
+    auction_start_time: Option<u64>,
+}
+
+impl FusionOrderBuilder {
+    pub fn new() -> FusionOrderBuilder {
+        FusionOrderBuilder::default()
+    }
+
+    builder_setter!(network, SupportedNetworks);
+    builder_setter!(quote, FusionQuoteResponse);
+    builder_setter!(maker, String);
+    builder_setter!(maker_asset, String);
+    builder_setter!(taker_asset, String);
+
+    builder_setter!(preset, FusionPresetKind);
+    builder_setter!(receiver, String);
+    builder_setter!(nonce, u64);
+    builder_setter!(auction_start_time, u64);
+
+    /// Attempts to construct a [`FusionOrder`](crate::fusion::FusionOrder)
+    /// from the builder. If not set, preset is the one recommended by the
+    /// quote, auction starts after the delay of the preset and order expires
+    /// when the auction ends.
+    pub fn build(self) -> Result<FusionOrder, FusionOrderBuilderError> {
+        let network = self.network.ok_or(FusionOrderBuilderError::MissingField("network"))?;
+        let quote = self.quote.ok_or(FusionOrderBuilderError::MissingField("quote"))?;
+        let preset_kind = self.preset.unwrap_or(quote.recommended_preset);
+        let preset = quote.preset(preset_kind).ok_or(FusionOrderBuilderError::MissingPreset(preset_kind))?;
+
+        let auction_start_time = self.auction_start_time.unwrap_or_else(|| {
+            let now = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or_default();
+            now + preset.start_auction_in as u64
+        });
+
+        let settlement = decode_address(&quote.settlement_address)?;
+        let auction_details = encode_auction_details(preset, auction_start_time)?;
+
+        let mut post_interaction = settlement.to_vec();
+        post_interaction.extend_from_slice(&encode_whitelist(&quote.whitelist, auction_start_time)?);
+
+        let extension = OrderExtension {
+            making_amount_data: [settlement.as_slice(), &auction_details].concat(),
+            taking_amount_data: [settlement.as_slice(), &auction_details].concat(),
+            post_interaction,
+            ..OrderExtension::default()
+        };
+
+        let mut maker_traits = MakerTraits::new()
+            .has_extension()
+            .post_interaction()
+            .expiration(auction_start_time + preset.auction_duration as u64)
+            .nonce_or_epoch(self.nonce.unwrap_or_default());
+        if !preset.allow_partial_fills {
+            maker_traits = maker_traits.no_partial_fills();
+        }
+        if preset.allow_multiple_fills {
+            maker_traits = maker_traits.allow_multiple_fills();
+        }
+
+        let mut limit_order = LimitOrderBuilder::new()
+            .network(network)
+            .salt(extension.salt(rand::random()).to_string())
+            .maker(self.maker.ok_or(FusionOrderBuilderError::MissingField("maker"))?)
+            .maker_asset(self.maker_asset.ok_or(FusionOrderBuilderError::MissingField("maker_asset"))?)
+            .taker_asset(self.taker_asset.ok_or(FusionOrderBuilderError::MissingField("taker_asset"))?)
+            .making_amount(quote.from_token_amount.clone())
+            .taking_amount(preset.auction_end_amount.clone())
+            .maker_traits(maker_traits.to_string());
+        if let Some(receiver) = self.receiver {
+            limit_order = limit_order.receiver(receiver);
+        }
+
+        Ok(FusionOrder { limit_order: limit_order.build()?, extension: extension.encode_hex(), quote_id: quote.quote_id })
+    }
+}
+
+pub(crate) fn decode_address(address: &str) -> Result<[u8; 20], Eip712Error> {
+    let mut bytes = [0u8; 20];
+    bytes.copy_from_slice(&encode_address(address)?[12..]);
+    Ok(bytes)
+}
+
+// Auction details layout: gasBumpEstimate (uint24), gasPriceEstimate (uint32),
+// startTime (uint32), duration (uint24), initialRateBump (uint24) followed by
+// the points as coefficient (uint24) and delay (uint16) pairs.
+pub(crate) fn encode_auction_details(preset: &FusionPreset, start_time: u64) -> Result<Vec<u8>, Eip712Error> {
+    let (gas_bump_estimate, gas_price_estimate) = match &preset.gas_cost {
+        Some(cost) => (
+            cost.gas_bump_estimate,
+            cost.gas_price_estimate.parse::<u64>().map_err(|_| Eip712Error::InvalidUint(cost.gas_price_estimate.clone()))?,
+        ),
+        None => (0, 0),
+    };
+
+    let mut encoded = Vec::with_capacity(17 + 5 * preset.points.len());
+    encoded.extend_from_slice(&uint_bytes(gas_bump_estimate, 3)?);
+    encoded.extend_from_slice(&uint_bytes(gas_price_estimate, 4)?);
+    encoded.extend_from_slice(&uint_bytes(start_time, 4)?);
+    encoded.extend_from_slice(&uint_bytes(preset.auction_duration as u64, 3)?);
+    encoded.extend_from_slice(&uint_bytes(preset.initial_rate_bump as u64, 3)?);
+    for point in &preset.points {
+        encoded.extend_from_slice(&uint_bytes(point.coefficient as u64, 3)?);
+        encoded.extend_from_slice(&uint_bytes(point.delay as u64, 2)?);
+    }
+
+    Ok(encoded)
+}
+
+// Max amount of the whitelisted resolvers, by the 5 bits encoding it.
+const MAX_WHITELIST: usize = 31;
+
+// Whitelist layout: resolvingStartTime (uint32) followed by the resolvers as
+// last 10 bytes of address and delay (uint16) pairs, and the flags byte with
+// amount of the resolvers in the upper 5 bits.
+pub(crate) fn encode_whitelist(whitelist: &[String], start_time: u64) -> Result<Vec<u8>, FusionOrderBuilderError> {
+    if whitelist.len() > MAX_WHITELIST {
+        return Err(FusionOrderBuilderError::WhitelistTooLong(whitelist.len()));
+    }
+
+    let mut encoded = uint_bytes(start_time, 4)?;
+    for resolver in whitelist {
+        encoded.extend_from_slice(&decode_address(resolver)?[10..]);
+        encoded.extend_from_slice(&[0, 0]);
+    }
+    encoded.push((whitelist.len() as u8) << 3);
+
+    Ok(encoded)
+}
+
+pub(crate) fn uint_bytes(value: u64, size: usize) -> Result<Vec<u8>, Eip712Error> {
+    let bytes = value.to_be_bytes();
+    if bytes[..8 - size].iter().any(|b| *b != 0) {
+        return Err(Eip712Error::InvalidUint(value.to_string()));
+    }
+
+    Ok(bytes[8 - size..].to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        common::eip712::keccak256,
+        fusion::{AuctionGasCost, AuctionPoint},
+    };
+    use num_bigint::BigUint;
+    use std::collections::HashMap;
+
+    fn quote() -> FusionQuoteResponse {
+        let preset = FusionPreset {
+            auction_duration: 180,
+            start_auction_in: 24,
+            initial_rate_bump: 84909,
+            auction_start_amount: "383000000000000".into(),
+            start_amount: "380000000000000".into(),
+            auction_end_amount: "378000000000000".into(),
+            exclusive_resolver: None,
+            cost_in_dst_token: "3000000000000".into(),
+            points: vec![AuctionPoint { delay: 12, coefficient: 50000 }],
+            allow_partial_fills: false,
+            allow_multiple_fills: false,
+            gas_cost: Some(AuctionGasCost { gas_bump_estimate: 10, gas_price_estimate: "1000".into() }),
+        };
+
+        FusionQuoteResponse {
+            quote_id: "quote".into(),
+            from_token_amount: "1000000".into(),
+            to_token_amount: "380000000000000".into(),
+            fee_token: "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48".into(),
+            presets: HashMap::from([(FusionPresetKind::Fast, preset)]),
+            settlement_address: "0xfb2809a5314473e1165f6b58018e20ed8f07b840".into(),
+            whitelist: vec!["0x30A557351eab496FD69F537BE1F8c744A18F94Fd".into()],
+            recommended_preset: FusionPresetKind::Fast,
+            suggested: None,
+            prices: None,
+            volume: None,
+        }
+    }
+
+    fn builder() -> FusionOrderBuilder {
+        FusionOrderBuilder::new()
+            .network(SupportedNetworks::Ethereum)
+            .quote(quote())
+            .maker("0x30A557351eab496FD69F537BE1F8c744A18F94Fd".into())
+            .maker_asset("0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48".into())
+            .taker_asset("0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2".into())
+            .auction_start_time(1_700_000_000)
+    }
+
+    #[test]
+    fn test_fusion_order_commits_to_auction() {
+        let order = builder().nonce(7).build().unwrap();
+        let data = &order.limit_order.order;
+
+        assert_eq!(data.making_amount, "1000000");
+        assert_eq!(data.taking_amount, "378000000000000");
+
+        let extension = hex::decode(order.extension.trim_start_matches("0x")).unwrap();
+        let salt: BigUint = data.salt.parse().unwrap();
+        let mask = (BigUint::from(1u8) << 160u32) - 1u8;
+        assert_eq!(salt & &mask, BigUint::from_bytes_be(&keccak256(&extension)) & mask);
+
+        let traits =
+            MakerTraits::new().has_extension().post_interaction().expiration(1_700_000_180).nonce_or_epoch(7).no_partial_fills();
+        assert_eq!(data.maker_traits, traits.to_string());
+    }
+
+    #[test]
+    fn test_auction_details_encoding() {
+        let encoded = encode_auction_details(quote().recommended().unwrap(), 1_700_000_000).unwrap();
+
+        assert_eq!(hex::encode(encoded), "00000a000003e86553f1000000b4014bad00c350000c");
+    }
+
+    #[test]
+    fn test_fusion_order_builder_requires_preset() {
+        let result = builder().preset(FusionPresetKind::Slow).build();
+        assert_eq!(result.unwrap_err(), FusionOrderBuilderError::MissingPreset(FusionPresetKind::Slow));
+
+        let result = FusionOrderBuilder::new().network(SupportedNetworks::Ethereum).build();
+        assert_eq!(result.unwrap_err(), FusionOrderBuilderError::MissingField("quote"));
+    }
+
+    #[test]
+    fn test_whitelist_length_is_bounded() {
+        let whitelist: Vec<String> = (0..32).map(|i| format!("0x{:040x}", i)).collect();
+        assert_eq!(
+            encode_whitelist(&whitelist, 0).unwrap_err(),
+            FusionOrderBuilderError::WhitelistTooLong(32)
+        );
+
+        let encoded = encode_whitelist(&whitelist[..31], 0).unwrap();
+        assert_eq!(*encoded.last().unwrap(), 31 << 3);
+    }
+}
@@ -0,0 +1,112 @@
+use crate::{
+    client::OneInchClient,
+    common::page::{paginate, Cursor},
+    consts::FUSION_API_VERSION,
+    fusion::{FusionActiveOrder, FusionActiveOrdersPage, FusionOrderStatusResponse},
+    jitter::BackgroundTask,
+    polling::{time_until, PollingPolicy},
+    utils::params::insert_optional_param,
+};
+use futures::Stream;
+use reqwest::Url;
+use std::{error::Error, time::Duration};
+
+impl OneInchClient {
+    /// Get status of the Fusion order, including its fills.
+    pub async fn get_fusion_order_status(&self, order_hash: String) -> Result<FusionOrderStatusResponse, Box<dyn Error>> {
+        let url = format!(
+            "{}/fusion/orders/{}/{}/order/status/{}",
+            self.base_url(),
+            FUSION_API_VERSION,
+            self.network_id,
+            order_hash
+        );
+
+        let request_result = self.send("fusion/orders/status", self.http_client.get(url)).await;
+
+        let response = request_result
+            .map_err(|e| Box::new(e) as Box<dyn Error>)?
+            .error_for_status()
+            .map_err(|e| Box::new(e) as Box<dyn Error>)?;
+
+        let status: FusionOrderStatusResponse = self.read_json("fusion/orders/status", response).await?;
+
+        Ok(status)
+    }
+
+    /// Get a page of the Fusion orders which are currently open for filling.
+    pub async fn get_fusion_active_orders(
+        &self,
+        page: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<FusionActiveOrdersPage, Box<dyn Error>> {
+        let url = format!(
+            "{}/fusion/orders/{}/{}/order/active",
+            self.base_url(),
+            FUSION_API_VERSION,
+            self.network_id
+        );
+
+        let mut params: Vec<(&str, String)> = vec![];
+
+        insert_optional_param(&mut params, "page", page.map(|a| a.to_string()));
+        insert_optional_param(&mut params, "limit", limit.map(|a| a.to_string()));
+
+        let url_with_params = Url::parse_with_params(&url, params).map_err(|e| Box::new(e) as Box<dyn Error>)?;
+
+        let request_result = self.send("fusion/orders/active", self.http_client.get(url_with_params)).await;
+
+        let response = request_result
+            .map_err(|e| Box::new(e) as Box<dyn Error>)?
+            .error_for_status()
+            .map_err(|e| Box::new(e) as Box<dyn Error>)?;
+
+        let orders_page: FusionActiveOrdersPage = self.read_json("fusion/orders/active", response).await?;
+
+        Ok(orders_page)
+    }
+
+    /// Returns a stream of the Fusion orders currently open for filling,
+    /// fetching pages of `limit` orders as the stream is consumed.
+    pub fn stream_fusion_active_orders(&self, limit: u32) -> impl Stream<Item = Result<FusionActiveOrder, Box<dyn Error>>> {
+        let client = self.clone();
+        paginate(None, move |cursor| {
+            let client = client.clone();
+            async move { client.get_fusion_active_orders(cursor.and_then(Cursor::page), Some(limit)).await }
+        })
+    }
+
+    /// Polls status of the Fusion order with the given interval until it
+    /// reaches a final one (filled, cancelled, expired, etc.) and returns it.
+    /// Wrap it into `tokio::time::timeout` to limit the waiting time.
+    pub async fn wait_for_fusion_order(
+        &self,
+        order_hash: String,
+        poll_interval: Duration,
+    ) -> Result<FusionOrderStatusResponse, Box<dyn Error>> {
+        self.wait_for_fusion_order_with_policy(order_hash, &PollingPolicy::fixed(poll_interval)).await
+    }
+
+    /// Polls status of the Fusion order until it reaches a final one, with
+    /// intervals adapted by the policy: frequent right after submission and
+    /// near the end of the auction, backing off in between.
+    pub async fn wait_for_fusion_order_with_policy(
+        &self,
+        order_hash: String,
+        policy: &PollingPolicy,
+    ) -> Result<FusionOrderStatusResponse, Box<dyn Error>> {
+        self.jitter.sleep(BackgroundTask::OrderMonitor, Duration::ZERO).await;
+
+        let mut poll = 0;
+        loop {
+            let status = self.get_fusion_order_status(order_hash.clone()).await?;
+            if status.status.is_final() {
+                return Ok(status);
+            }
+
+            let interval = policy.interval(poll, status.auction_end().map(time_until));
+            self.jitter.sleep(BackgroundTask::OrderMonitor, interval).await;
+            poll = poll.saturating_add(1);
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use crate::{
+    client::OneInchClient,
+    consts::FUSION_API_VERSION,
+    fusion::{FusionQuoteDetails, FusionQuoteResponse},
+    utils::params::insert_optional_param,
+};
+use reqwest::Url;
+use std::error::Error;
+
+impl OneInchClient {
+    /// Get Fusion quote with auction presets for the swap.
+    pub async fn get_fusion_quote(&self, details: FusionQuoteDetails) -> Result<FusionQuoteResponse, Box<dyn Error>> {
+        let url = format!(
+            "{}/fusion/quoter/{}/{}/quote/receive",
+            self.base_url(),
+            FUSION_API_VERSION,
+            self.network_id
+        );
+
+        // Adding required parameters
+        let mut params: Vec<(&str, String)> = vec![
+            ("fromTokenAddress", details.src),
+            ("toTokenAddress", details.dst),
+            ("amount", details.amount),
+            ("walletAddress", details.wallet_address),
+        ];
+
+        // Adding optional parameters
+        insert_optional_param(&mut params, "enableEstimate", details.enable_estimate.map(|a| a.to_string()));
+        insert_optional_param(&mut params, "fee", details.fee.map(|a| a.to_string()));
+        insert_optional_param(&mut params, "isPermit2", details.is_permit2.map(|a| a.to_string()));
+        insert_optional_param(&mut params, "permit", details.permit);
+        insert_optional_param(&mut params, "source", details.source);
+
+        let url_with_params = Url::parse_with_params(&url, params).map_err(|e| Box::new(e) as Box<dyn Error>)?;
+
+        let request_result = self.send("fusion/quoter", self.http_client.get(url_with_params)).await;
+
+        let response = request_result
+            .map_err(|e| Box::new(e) as Box<dyn Error>)?
+            .error_for_status()
+            .map_err(|e| Box::new(e) as Box<dyn Error>)?;
+
+        let quote: FusionQuoteResponse = self.read_json("fusion/quoter", response).await?;
+
+        Ok(quote)
+    }
+}
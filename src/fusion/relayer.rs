@@ -0,0 +1,26 @@
+use crate::{client::OneInchClient, consts::FUSION_API_VERSION, fusion::SignedFusionOrder};
+use std::error::Error;
+
+impl OneInchClient {
+    /// Submits signed Fusion order to the relayer, so resolvers can start the
+    /// auction. Returns hash of the submitted order.
+    pub async fn submit_fusion_order(&self, order: SignedFusionOrder) -> Result<String, Box<dyn Error>> {
+        let url = format!(
+            "{}/fusion/relayer/{}/{}/order/submit",
+            self.base_url(),
+            FUSION_API_VERSION,
+            self.network_id
+        );
+
+        let request_result = self.send("fusion/relayer", self.http_client.post(url).json(&order)).await;
+
+        // Relayer replies with an empty body, so the hash is the one computed
+        // while signing.
+        request_result
+            .map_err(|e| Box::new(e) as Box<dyn Error>)?
+            .error_for_status()
+            .map_err(|e| Box::new(e) as Box<dyn Error>)?;
+
+        Ok(order.order_hash)
+    }
+}
@@ -0,0 +1,409 @@
+use crate::{
+    builder_setter,
+    common::page::Page,
+    orderbook::LimitOrderData,
+    utils::{
+        builder::BasicBuilderError,
+        iso8601::parse_timestamp,
+        runtime::{SystemTime, UNIX_EPOCH},
+    },
+};
+use serde::Deserialize;
+use std::{collections::HashMap, time::Duration};
+
+/// Builder struct to create instance of
+/// [`FusionQuoteDetails`](crate::fusion::FusionQuoteDetails).
+#[derive(Default)]
+pub struct FusionQuoteDetailsBuilder {
+    src: Option<String>,
+    dst: Option<String>,
+    amount: Option<String>,
+    wallet_address: Option<String>,
+
+    // Optional fields
+    enable_estimate: Option<bool>,
+    fee: Option<u32>,
+    is_permit2: Option<bool>,
+    permit: Option<String>,
+    source: Option<String>,
+}
+
+impl FusionQuoteDetailsBuilder {
+    pub fn new() -> FusionQuoteDetailsBuilder {
+        FusionQuoteDetailsBuilder::default()
+    }
+
+    builder_setter!(src, String);
+    builder_setter!(dst, String);
+    builder_setter!(amount, String);
+    builder_setter!(wallet_address, String);
+
+    builder_setter!(enable_estimate, bool);
+    builder_setter!(fee, u32);
+    builder_setter!(is_permit2, bool);
+    builder_setter!(permit, String);
+    builder_setter!(source, String);
+
+    /// Attempts to construct a
+    /// [`FusionQuoteDetails`](crate::fusion::FusionQuoteDetails) from the
+    /// builder, returning errors if required fields are missing.
+    pub fn build(self) -> Result<FusionQuoteDetails, BasicBuilderError> {
+        Ok(FusionQuoteDetails {
+            src: self.src.ok_or(BasicBuilderError::MissingField("src"))?,
+            dst: self.dst.ok_or(BasicBuilderError::MissingField("dst"))?,
+            amount: self.amount.ok_or(BasicBuilderError::MissingField("amount"))?,
+            wallet_address: self.wallet_address.ok_or(BasicBuilderError::MissingField("wallet_address"))?,
+            enable_estimate: self.enable_estimate,
+            fee: self.fee,
+            is_permit2: self.is_permit2,
+            permit: self.permit,
+            source: self.source,
+        })
+    }
+}
+
+/// FusionQuoteDetails is struct that contains data we need to get a Fusion
+/// quote.
+#[derive(Debug, Clone)]
+pub struct FusionQuoteDetails {
+    pub src: String,            // Source token address.
+    pub dst: String,            // Destination token address.
+    pub amount: String,         // Amount of source token to be swapped.
+    pub wallet_address: String, // Address of the maker.
+
+    // Optional fields
+    pub enable_estimate: Option<bool>, // Should be true to get quote which can be used to create an order.
+    pub fee: Option<u32>,              // Integrator fee in bps.
+    pub is_permit2: Option<bool>,
+    pub permit: Option<String>,
+    pub source: Option<String>,
+}
+
+/// Kind of the auction preset.
+#[derive(Deserialize, Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum FusionPresetKind {
+    Fast,
+    Medium,
+    Slow,
+    Custom,
+}
+
+/// Point of the auction price curve: after `delay` seconds since the previous
+/// point rate bump becomes equal to `coefficient`.
+#[derive(Deserialize, Debug, Clone, Copy)]
+pub struct AuctionPoint {
+    pub delay: u32,
+    pub coefficient: u32,
+}
+
+/// Estimated gas cost the auction takes into account.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AuctionGasCost {
+    pub gas_bump_estimate: u64,
+    pub gas_price_estimate: String,
+}
+
+/// Auction parameters of the preset.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FusionPreset {
+    /// Duration of the auction in seconds.
+    pub auction_duration: u32,
+    /// Delay in seconds before the auction starts.
+    pub start_auction_in: u32,
+    pub initial_rate_bump: u32,
+    pub auction_start_amount: String,
+    pub start_amount: String,
+    pub auction_end_amount: String,
+    pub exclusive_resolver: Option<serde_json::Value>,
+    pub cost_in_dst_token: String,
+    pub points: Vec<AuctionPoint>,
+    pub allow_partial_fills: bool,
+    pub allow_multiple_fills: bool,
+    pub gas_cost: Option<AuctionGasCost>,
+}
+
+/// Values related to the source and destination tokens.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FusionTokenPair {
+    pub from_token: String,
+    pub to_token: String,
+}
+
+/// Values expressed in USD.
+#[derive(Deserialize, Debug, Clone)]
+pub struct FusionUsdValues {
+    pub usd: FusionTokenPair,
+}
+
+/// FusionQuoteResponse is a struct to deserialize data we can get on Fusion
+/// quote request.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FusionQuoteResponse {
+    /// Identifier of the quote, should be passed back when submitting order.
+    pub quote_id: serde_json::Value,
+    pub from_token_amount: String,
+    pub to_token_amount: String,
+    pub fee_token: String,
+    pub presets: HashMap<FusionPresetKind, FusionPreset>,
+    pub settlement_address: String,
+    pub whitelist: Vec<String>,
+    #[serde(rename = "recommended_preset")]
+    pub recommended_preset: FusionPresetKind,
+    pub suggested: Option<bool>,
+    pub prices: Option<FusionUsdValues>,
+    pub volume: Option<FusionUsdValues>,
+}
+
+impl FusionQuoteResponse {
+    /// Returns the preset of the specified kind, if server provided it.
+    pub fn preset(&self, kind: FusionPresetKind) -> Option<&FusionPreset> {
+        self.presets.get(&kind)
+    }
+
+    /// Returns the preset server recommends.
+    pub fn recommended(&self) -> Option<&FusionPreset> {
+        self.preset(self.recommended_preset)
+    }
+}
+
+/// Status of the Fusion order.
+#[derive(Deserialize, Debug, Copy, Clone, Eq, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum FusionOrderStatus {
+    Pending,
+    PartiallyFilled,
+    Filled,
+    Cancelled,
+    Expired,
+    FalsePredicate,
+    NotEnoughBalanceOrAllowance,
+    WrongPermit,
+    InvalidSignature,
+    /// Status this version of the crate doesn't know about.
+    #[serde(other)]
+    Unknown,
+}
+
+impl FusionOrderStatus {
+    /// Returns true if the order can't change its status anymore.
+    pub fn is_final(&self) -> bool {
+        !matches!(
+            self,
+            FusionOrderStatus::Pending | FusionOrderStatus::PartiallyFilled | FusionOrderStatus::Unknown
+        )
+    }
+}
+
+/// Fill of the Fusion order by resolver.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FusionOrderFill {
+    pub tx_hash: String,
+    pub filled_maker_amount: String,
+    pub filled_auction_taker_amount: String,
+}
+
+/// FusionOrderStatusResponse is a struct to deserialize data we can get on
+/// Fusion order status request.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FusionOrderStatusResponse {
+    pub order_hash: String,
+    pub status: FusionOrderStatus,
+    #[serde(default)]
+    pub fills: Vec<FusionOrderFill>,
+    pub auction_start_date: Option<u64>,
+    pub auction_duration: Option<u64>,
+    pub initial_rate_bump: Option<u64>,
+    pub created_at: Option<String>,
+    pub cancel_tx: Option<String>,
+}
+
+impl FusionOrderStatusResponse {
+    /// Returns hashes of the transactions which filled the order.
+    pub fn fill_tx_hashes(&self) -> Vec<&str> {
+        self.fills.iter().map(|f| f.tx_hash.as_str()).collect()
+    }
+
+    /// Returns unix timestamp the auction of the order ends at, if known and
+    /// representable.
+    pub fn auction_end(&self) -> Option<u64> {
+        self.auction_start_date?.checked_add(self.auction_duration?)
+    }
+}
+
+/// Fusion order open for filling, as the active orders feed returns it.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FusionActiveOrder {
+    pub order_hash: String,
+    pub signature: String,
+    pub order: LimitOrderData,
+    pub extension: String,
+    pub quote_id: serde_json::Value,
+    /// Time after which the order can't be filled, in ISO 8601.
+    pub deadline: String,
+    /// Time the auction starts at, in ISO 8601.
+    pub auction_start_date: String,
+    /// Time the auction ends at, in ISO 8601.
+    pub auction_end_date: String,
+    pub remaining_maker_amount: String,
+    pub maker_balance: Option<String>,
+    pub maker_allowance: Option<String>,
+    #[serde(default)]
+    pub is_maker_contract: bool,
+}
+
+impl FusionActiveOrder {
+    /// Returns unix timestamp after which the order can't be filled, `None`
+    /// if the deadline is malformed.
+    pub fn deadline_timestamp(&self) -> Option<u64> {
+        parse_timestamp(&self.deadline)
+    }
+
+    /// Returns unix timestamp the auction starts at, `None` if the date is
+    /// malformed.
+    pub fn auction_start_timestamp(&self) -> Option<u64> {
+        parse_timestamp(&self.auction_start_date)
+    }
+
+    /// Returns unix timestamp the auction ends at, `None` if the date is
+    /// malformed.
+    pub fn auction_end_timestamp(&self) -> Option<u64> {
+        parse_timestamp(&self.auction_end_date)
+    }
+
+    /// Returns time left until the end of the auction.
+    pub fn auction_time_left(&self) -> Duration {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or_default();
+        self.auction_time_left_at(now)
+    }
+
+    /// Returns time left until the end of the auction at the given unix
+    /// timestamp, zero if the end date is malformed.
+    pub fn auction_time_left_at(&self, now: u64) -> Duration {
+        Duration::from_secs(self.auction_end_timestamp().unwrap_or_default().saturating_sub(now))
+    }
+
+    /// Returns true if the auction has started at the given unix timestamp,
+    /// false if the start date is malformed.
+    pub fn is_auction_started_at(&self, now: u64) -> bool {
+        self.auction_start_timestamp().is_some_and(|start| now >= start)
+    }
+}
+
+/// Page of the Fusion active orders.
+pub type FusionActiveOrdersPage = Page<FusionActiveOrder>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fusion_quote_response_deserialization() {
+        let body = r#"{
+            "quoteId": "c5b4e6e4-bd5b-4e4a-8b51-7b6c9e1d5d2f",
+            "fromTokenAmount": "1000000",
+            "toTokenAmount": "380000000000000",
+            "feeToken": "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+            "presets": {
+                "fast": {
+                    "auctionDuration": 180,
+                    "startAuctionIn": 24,
+                    "initialRateBump": 84909,
+                    "auctionStartAmount": "383000000000000",
+                    "startAmount": "380000000000000",
+                    "auctionEndAmount": "378000000000000",
+                    "exclusiveResolver": null,
+                    "costInDstToken": "3000000000000",
+                    "points": [{"delay": 12, "coefficient": 50000}],
+                    "allowPartialFills": false,
+                    "allowMultipleFills": false,
+                    "gasCost": {"gasBumpEstimate": 10, "gasPriceEstimate": "1000"}
+                }
+            },
+            "settlementAddress": "0xfb2809a5314473e1165f6b58018e20ed8f07b840",
+            "whitelist": ["0x01"],
+            "recommended_preset": "fast",
+            "suggested": true,
+            "prices": {"usd": {"fromToken": "1", "toToken": "2600"}},
+            "volume": {"usd": {"fromToken": "1", "toToken": "1"}}
+        }"#;
+
+        let quote: FusionQuoteResponse = serde_json::from_str(body).unwrap();
+
+        assert_eq!(quote.recommended().unwrap().auction_duration, 180);
+        assert!(quote.preset(FusionPresetKind::Slow).is_none());
+    }
+
+    #[test]
+    fn test_fusion_order_status_deserialization() {
+        let body = r#"{
+            "orderHash": "0x01",
+            "status": "filled",
+            "fills": [{"txHash": "0x02", "filledMakerAmount": "1000000", "filledAuctionTakerAmount": "380000000000000"}],
+            "auctionStartDate": 1700000000,
+            "auctionDuration": 180,
+            "initialRateBump": 84909,
+            "createdAt": "2023-11-14T22:13:20.000Z",
+            "cancelTx": null
+        }"#;
+
+        let status: FusionOrderStatusResponse = serde_json::from_str(body).unwrap();
+        assert_eq!(status.status, FusionOrderStatus::Filled);
+        assert!(status.status.is_final());
+        assert_eq!(status.fill_tx_hashes(), vec!["0x02"]);
+        assert_eq!(status.auction_end(), Some(1_700_000_180));
+
+        let hostile = FusionOrderStatusResponse { auction_start_date: Some(u64::MAX), ..status };
+        assert_eq!(hostile.auction_end(), None);
+
+        let status: FusionOrderStatus = serde_json::from_str(r#""some-new-status""#).unwrap();
+        assert_eq!(status, FusionOrderStatus::Unknown);
+        assert!(!status.is_final());
+    }
+
+    #[test]
+    fn test_fusion_active_orders_page_deserialization() {
+        let body = r#"{
+            "meta": {"totalItems": 1, "itemsPerPage": 100, "totalPages": 1, "currentPage": 1},
+            "items": [{
+                "orderHash": "0x806039f5149065924ad52de616b50abff488c986716d052e9c160887bc09e559",
+                "signature": "0x38de7c8c406c8668204717b9e8e2bd6d1d3a2dc4e2c6a7e1a8f0e3d2c1b0a9f85e7d6c5b4a3928170f6e5d4c3b2a19081c2d3e4f5a6b7c8d9e0f1a2b3c4d5e6f1b",
+                "deadline": "2023-11-14T22:16:40.000Z",
+                "auctionStartDate": "2023-11-14T22:13:20.000Z",
+                "auctionEndDate": "2023-11-14T22:16:20.000Z",
+                "quoteId": "c5b4e6e4-bd5b-4e4a-8b51-7b6c9e1d5d2f",
+                "remainingMakerAmount": "1000000",
+                "makerBalance": "1000000",
+                "makerAllowance": "115792089237316195423570985008687907853269984665640564039457584007913129639935",
+                "isMakerContract": false,
+                "extension": "0x",
+                "order": {
+                    "salt": "1",
+                    "maker": "0x30a557351eab496fd69f537be1f8c744a18f94fd",
+                    "receiver": "0x0000000000000000000000000000000000000000",
+                    "makerAsset": "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+                    "takerAsset": "0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2",
+                    "makingAmount": "1000000",
+                    "takingAmount": "378000000000000",
+                    "makerTraits": "0"
+                }
+            }]
+        }"#;
+
+        let page: FusionActiveOrdersPage = serde_json::from_str(body).unwrap();
+        let order = &page.items[0];
+
+        assert_eq!(order.auction_time_left_at(1_700_000_100), Duration::from_secs(80));
+        assert_eq!(order.auction_time_left_at(1_700_000_500), Duration::ZERO);
+        assert_eq!(order.deadline_timestamp(), Some(1_700_000_200));
+        assert!(!order.is_auction_started_at(1_699_999_999));
+        assert!(order.is_auction_started_at(1_700_000_000));
+    }
+}
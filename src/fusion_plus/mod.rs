@@ -0,0 +1,11 @@
+mod order;
+mod orders;
+mod quote;
+mod relayer;
+mod secrets;
+mod settlement;
+mod types;
+
+pub use order::*;
+pub use secrets::*;
+pub use types::*;
@@ -0,0 +1,313 @@
+use crate::{
+    builder_setter,
+    client::SupportedNetworks,
+    common::eip712::{encode_address, encode_uint, Eip712Error, TypedDataSigner},
+    fusion::{decode_address, encode_auction_details, encode_whitelist, FusionOrderBuilderError, FusionPresetKind},
+    fusion_plus::{CrossChainQuoteResponse, OrderSecrets, TimeLocks},
+    orderbook::{LimitOrder, LimitOrderBuilder, LimitOrderData, MakerTraits, OrderExtension},
+    utils::runtime::{SystemTime, UNIX_EPOCH},
+};
+use num_bigint::BigUint;
+use serde::Serialize;
+use std::error::Error;
+
+/// Cross-chain order ready to be signed: limit order on the source chain with
+/// the auction and escrow parameters encoded into its extension.
+#[derive(Debug, Clone)]
+pub struct CrossChainOrder {
+    pub limit_order: LimitOrder,
+    /// Hex encoded order extension.
+    pub extension: String,
+    pub quote_id: serde_json::Value,
+    pub src_chain_id: u32,
+    /// Hashes of the secrets, required by relayer for orders allowing
+    /// multiple fills.
+    pub secret_hashes: Option<Vec<String>>,
+}
+
+impl CrossChainOrder {
+    /// Signs the order with provided signer, so it can be submitted to relayer.
+    pub fn sign(&self, signer: &impl TypedDataSigner) -> Result<SignedCrossChainOrder, Box<dyn Error>> {
+        let signed = self.limit_order.sign(signer)?;
+
+        Ok(SignedCrossChainOrder {
+            order: signed.order,
+            order_hash: signed.order_hash,
+            src_chain_id: self.src_chain_id,
+            signature: signed.signature,
+            extension: self.extension.clone(),
+            quote_id: self.quote_id.clone(),
+            secret_hashes: self.secret_hashes.clone(),
+        })
+    }
+}
+
+/// Cross-chain order with the maker's signature in the form relayer accepts
+/// it.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignedCrossChainOrder {
+    pub order: LimitOrderData,
+    #[serde(skip)]
+    pub order_hash: String,
+    pub src_chain_id: u32,
+    pub signature: String,
+    pub extension: String,
+    pub quote_id: serde_json::Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret_hashes: Option<Vec<String>>,
+}
+
+/// Builder struct to create instance of
+/// [`CrossChainOrder`](crate::fusion_plus::CrossChainOrder) from the Fusion+
+/// quote.
+#[derive(Default)]
+pub struct CrossChainOrderBuilder {
+    src_chain: Option<SupportedNetworks>,
+    dst_chain: Option<SupportedNetworks>,
+    quote: Option<CrossChainQuoteResponse>,
+    secrets: Option<OrderSecrets>,
+    maker: Option<String>,
+    maker_asset: Option<String>,
+    /// Placeholder ERC-20 token on the source chain, since the real token
+    /// maker receives lives on the destination chain.
+    taker_asset: Option<String>,
+    dst_token: Option<String>,
+
+    // Optional fields
+    preset: Option<FusionPresetKind>,
+    receiver: Option<String>,
+    nonce: Option<u64>,
+    auction_start_time: Option<u64>,
+}
+
+impl CrossChainOrderBuilder {
+    pub fn new() -> CrossChainOrderBuilder {
+        CrossChainOrderBuilder::default()
+    }
+
+    builder_setter!(src_chain, SupportedNetworks);
+    builder_setter!(dst_chain, SupportedNetworks);
+    builder_setter!(quote, CrossChainQuoteResponse);
+    builder_setter!(secrets, OrderSecrets);
+    builder_setter!(maker, String);
+    builder_setter!(maker_asset, String);
+    builder_setter!(taker_asset, String);
+    builder_setter!(dst_token, String);
+
+    builder_setter!(preset, FusionPresetKind);
+    builder_setter!(receiver, String);
+    builder_setter!(nonce, u64);
+    builder_setter!(auction_start_time, u64);
+
+    /// Attempts to construct a
+    /// [`CrossChainOrder`](crate::fusion_plus::CrossChainOrder) from the
+    /// builder. If not set, preset is the one recommended by the quote,
+    /// auction starts after the delay of the preset and order expires when
+    /// the auction ends. Amount of secrets must match `secretsCount` of the
+    /// preset.
+    pub fn build(self) -> Result<CrossChainOrder, FusionOrderBuilderError> {
+        let src_chain = self.src_chain.ok_or(FusionOrderBuilderError::MissingField("src_chain"))?;
+        let dst_chain = self.dst_chain.ok_or(FusionOrderBuilderError::MissingField("dst_chain"))?;
+        let quote = self.quote.ok_or(FusionOrderBuilderError::MissingField("quote"))?;
+        let secrets = self.secrets.ok_or(FusionOrderBuilderError::MissingField("secrets"))?;
+        let dst_token = self.dst_token.ok_or(FusionOrderBuilderError::MissingField("dst_token"))?;
+        let preset_kind = self.preset.unwrap_or(quote.recommended_preset);
+        let preset = quote.preset(preset_kind).ok_or(FusionOrderBuilderError::MissingPreset(preset_kind))?;
+        if secrets.len() != preset.secrets_count as usize {
+            return Err(FusionOrderBuilderError::SecretsCount { expected: preset.secrets_count, actual: secrets.len() });
+        }
+
+        let auction_start_time = self.auction_start_time.unwrap_or_else(|| {
+            let now = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or_default();
+            now + preset.auction.start_auction_in as u64
+        });
+
+        let factory = decode_address(&quote.src_escrow_factory)?;
+        let auction_details = encode_auction_details(&preset.auction, auction_start_time)?;
+
+        let mut post_interaction = factory.to_vec();
+        post_interaction.extend_from_slice(&encode_whitelist(&quote.whitelist, auction_start_time)?);
+        post_interaction.extend_from_slice(&encode_escrow_data(
+            &secrets,
+            dst_chain,
+            &dst_token,
+            &quote.src_safety_deposit,
+            &quote.dst_safety_deposit,
+            &quote.time_locks,
+        )?);
+
+        let extension = OrderExtension {
+            making_amount_data: [factory.as_slice(), &auction_details].concat(),
+            taking_amount_data: [factory.as_slice(), &auction_details].concat(),
+            post_interaction,
+            ..OrderExtension::default()
+        };
+
+        let mut maker_traits = MakerTraits::new()
+            .has_extension()
+            .post_interaction()
+            .expiration(auction_start_time + preset.auction.auction_duration as u64)
+            .nonce_or_epoch(self.nonce.unwrap_or_default());
+        if secrets.allows_multiple_fills() {
+            maker_traits = maker_traits.allow_multiple_fills();
+        } else {
+            maker_traits = maker_traits.no_partial_fills();
+        }
+
+        let mut limit_order = LimitOrderBuilder::new()
+            .network(src_chain)
+            .salt(extension.salt(rand::random()).to_string())
+            .maker(self.maker.ok_or(FusionOrderBuilderError::MissingField("maker"))?)
+            .maker_asset(self.maker_asset.ok_or(FusionOrderBuilderError::MissingField("maker_asset"))?)
+            .taker_asset(self.taker_asset.ok_or(FusionOrderBuilderError::MissingField("taker_asset"))?)
+            .making_amount(quote.src_token_amount.clone())
+            .taking_amount(preset.auction.auction_end_amount.clone())
+            .maker_traits(maker_traits.to_string());
+        if let Some(receiver) = self.receiver {
+            limit_order = limit_order.receiver(receiver);
+        }
+
+        let secret_hashes = secrets
+            .allows_multiple_fills()
+            .then(|| secrets.secret_hashes().iter().map(|h| format!("0x{}", hex::encode(h))).collect());
+
+        Ok(CrossChainOrder {
+            limit_order: limit_order.build()?,
+            extension: extension.encode_hex(),
+            quote_id: quote.quote_id,
+            src_chain_id: src_chain as u32,
+            secret_hashes,
+        })
+    }
+}
+
+// Escrow data layout: hashlock, destination chain id, destination token,
+// safety deposits (source one in the upper 128 bits) and time locks
+// (one uint32 per stage, source withdrawal in the lowest bits), each as 32
+// bytes word.
+fn encode_escrow_data(
+    secrets: &OrderSecrets,
+    dst_chain: SupportedNetworks,
+    dst_token: &str,
+    src_safety_deposit: &str,
+    dst_safety_deposit: &str,
+    time_locks: &TimeLocks,
+) -> Result<Vec<u8>, Eip712Error> {
+    let parse = |value: &str| value.parse::<BigUint>().map_err(|_| Eip712Error::InvalidUint(value.to_string()));
+    let safety_deposit = (parse(src_safety_deposit)? << 128u32) | parse(dst_safety_deposit)?;
+
+    let stages = [
+        time_locks.src_withdrawal,
+        time_locks.src_public_withdrawal,
+        time_locks.src_cancellation,
+        time_locks.src_public_cancellation,
+        time_locks.dst_withdrawal,
+        time_locks.dst_public_withdrawal,
+        time_locks.dst_cancellation,
+    ];
+    let packed_time_locks =
+        stages.iter().enumerate().fold(BigUint::default(), |acc, (i, stage)| acc | (BigUint::from(*stage) << (32 * i)));
+
+    let mut encoded = secrets.hashlock().to_vec();
+    encoded.extend_from_slice(&encode_uint(&(dst_chain as u32).to_string())?);
+    encoded.extend_from_slice(&encode_address(dst_token)?);
+    encoded.extend_from_slice(&encode_uint(&safety_deposit.to_string())?);
+    encoded.extend_from_slice(&encode_uint(&packed_time_locks.to_string())?);
+
+    Ok(encoded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quote() -> CrossChainQuoteResponse {
+        let body = r#"{
+            "quoteId": "quote",
+            "srcTokenAmount": "1000000",
+            "dstTokenAmount": "998000",
+            "presets": {
+                "fast": {
+                    "auctionDuration": 180,
+                    "startAuctionIn": 24,
+                    "initialRateBump": 84909,
+                    "auctionStartAmount": "999000",
+                    "startAmount": "998000",
+                    "auctionEndAmount": "995000",
+                    "exclusiveResolver": null,
+                    "costInDstToken": "3000",
+                    "points": [],
+                    "allowPartialFills": true,
+                    "allowMultipleFills": true,
+                    "gasCost": null,
+                    "secretsCount": 3
+                }
+            },
+            "srcEscrowFactory": "0xa7bcb4eac8964306f9e3764f67db6a7af6ddf99a",
+            "dstEscrowFactory": "0xa7bcb4eac8964306f9e3764f67db6a7af6ddf99a",
+            "whitelist": [],
+            "timeLocks": {
+                "srcWithdrawal": 36,
+                "srcPublicWithdrawal": 372,
+                "srcCancellation": 528,
+                "srcPublicCancellation": 648,
+                "dstWithdrawal": 36,
+                "dstPublicWithdrawal": 336,
+                "dstCancellation": 456
+            },
+            "srcSafetyDeposit": "1",
+            "dstSafetyDeposit": "2",
+            "recommendedPreset": "fast"
+        }"#;
+
+        serde_json::from_str(body).unwrap()
+    }
+
+    #[test]
+    fn test_cross_chain_order_with_multiple_fills() {
+        let secrets = OrderSecrets::generate(3).unwrap();
+        let order = CrossChainOrderBuilder::new()
+            .src_chain(SupportedNetworks::Ethereum)
+            .dst_chain(SupportedNetworks::Arbitrum)
+            .quote(quote())
+            .secrets(secrets.clone())
+            .maker("0x30A557351eab496FD69F537BE1F8c744A18F94Fd".into())
+            .maker_asset("0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48".into())
+            .taker_asset("0xda0000d4000015a526378bb6fafc650cea5966f8".into())
+            .dst_token("0xaf88d065e77c8cc2239327c5edb3a432268e5831".into())
+            .auction_start_time(1_700_000_000)
+            .build()
+            .unwrap();
+
+        assert_eq!(order.src_chain_id, 1);
+        assert_eq!(order.secret_hashes.as_ref().unwrap().len(), 3);
+        assert_eq!(order.limit_order.order.taking_amount, "995000");
+
+        // Escrow data is the tail of the extension
+        let extension = hex::decode(order.extension.trim_start_matches("0x")).unwrap();
+        let escrow_data = &extension[extension.len() - 5 * 32..];
+        assert_eq!(&escrow_data[..32], &secrets.hashlock());
+        assert_eq!(escrow_data[63], 42161u32.to_be_bytes()[3]);
+        // Source deposit in the upper 128 bits
+        assert_eq!(&escrow_data[96 + 15..96 + 17], &[1, 0]);
+        assert_eq!(escrow_data[127], 2);
+        assert_eq!(&escrow_data[156..160], &36u32.to_be_bytes());
+    }
+
+    #[test]
+    fn test_secrets_count_must_match_preset() {
+        let result = CrossChainOrderBuilder::new()
+            .src_chain(SupportedNetworks::Ethereum)
+            .dst_chain(SupportedNetworks::Arbitrum)
+            .quote(quote())
+            .secrets(OrderSecrets::generate(2).unwrap())
+            .maker("0x30A557351eab496FD69F537BE1F8c744A18F94Fd".into())
+            .maker_asset("0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48".into())
+            .taker_asset("0xda0000d4000015a526378bb6fafc650cea5966f8".into())
+            .dst_token("0xaf88d065e77c8cc2239327c5edb3a432268e5831".into())
+            .build();
+
+        assert_eq!(result.unwrap_err(), FusionOrderBuilderError::SecretsCount { expected: 3, actual: 2 });
+    }
+}
@@ -0,0 +1,52 @@
+use crate::{
+    client::OneInchClient,
+    consts::FUSION_PLUS_API_VERSION,
+    fusion_plus::{CrossChainOrderStatusResponse, ReadyToAcceptSecretFills},
+};
+use std::error::Error;
+
+impl OneInchClient {
+    /// Get status of the cross-chain order, including its fills and their
+    /// escrow events.
+    pub async fn get_fusion_plus_status(&self, order_hash: String) -> Result<CrossChainOrderStatusResponse, Box<dyn Error>> {
+        let url = format!(
+            "{}/fusion-plus/orders/{}/order/status/{}",
+            self.base_url(),
+            FUSION_PLUS_API_VERSION,
+            order_hash
+        );
+
+        let request_result = self.send("fusion-plus/orders/status", self.http_client.get(url)).await;
+
+        let response = request_result
+            .map_err(|e| Box::new(e) as Box<dyn Error>)?
+            .error_for_status()
+            .map_err(|e| Box::new(e) as Box<dyn Error>)?;
+
+        let status: CrossChainOrderStatusResponse = self.read_json("fusion-plus/orders/status", response).await?;
+
+        Ok(status)
+    }
+
+    /// Get fills of the cross-chain order whose escrows are deployed and
+    /// verified, so their secrets can be submitted.
+    pub async fn get_ready_to_accept_secret_fills(&self, order_hash: String) -> Result<ReadyToAcceptSecretFills, Box<dyn Error>> {
+        let url = format!(
+            "{}/fusion-plus/orders/{}/order/ready-to-accept-secret-fills/{}",
+            self.base_url(),
+            FUSION_PLUS_API_VERSION,
+            order_hash
+        );
+
+        let request_result = self.send("fusion-plus/orders/ready-to-accept-secret-fills", self.http_client.get(url)).await;
+
+        let response = request_result
+            .map_err(|e| Box::new(e) as Box<dyn Error>)?
+            .error_for_status()
+            .map_err(|e| Box::new(e) as Box<dyn Error>)?;
+
+        let fills: ReadyToAcceptSecretFills = self.read_json("fusion-plus/orders/ready-to-accept-secret-fills", response).await?;
+
+        Ok(fills)
+    }
+}
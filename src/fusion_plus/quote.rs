@@ -0,0 +1,48 @@
+use crate::{
+    client::OneInchClient,
+    consts::FUSION_PLUS_API_VERSION,
+    fusion_plus::{CrossChainQuoteDetails, CrossChainQuoteResponse},
+    utils::params::insert_optional_param,
+};
+use reqwest::Url;
+use std::error::Error;
+
+impl OneInchClient {
+    /// Get Fusion+ quote for the cross-chain swap with auction presets, time
+    /// locks and safety deposits of the escrows.
+    pub async fn get_cross_chain_quote(
+        &self,
+        details: CrossChainQuoteDetails,
+    ) -> Result<CrossChainQuoteResponse, Box<dyn Error>> {
+        let url = format!("{}/fusion-plus/quoter/{}/quote/receive", self.base_url(), FUSION_PLUS_API_VERSION);
+
+        // Adding required parameters
+        let mut params: Vec<(&str, String)> = vec![
+            ("srcChain", details.src_chain.to_string()),
+            ("dstChain", details.dst_chain.to_string()),
+            ("srcTokenAddress", details.src_token),
+            ("dstTokenAddress", details.dst_token),
+            ("amount", details.amount),
+            ("walletAddress", details.wallet_address),
+        ];
+
+        // Adding optional parameters
+        insert_optional_param(&mut params, "enableEstimate", details.enable_estimate.map(|a| a.to_string()));
+        insert_optional_param(&mut params, "fee", details.fee.map(|a| a.to_string()));
+        insert_optional_param(&mut params, "isPermit2", details.is_permit2.map(|a| a.to_string()));
+        insert_optional_param(&mut params, "permit", details.permit);
+
+        let url_with_params = Url::parse_with_params(&url, params).map_err(|e| Box::new(e) as Box<dyn Error>)?;
+
+        let request_result = self.send("fusion-plus/quoter", self.http_client.get(url_with_params)).await;
+
+        let response = request_result
+            .map_err(|e| Box::new(e) as Box<dyn Error>)?
+            .error_for_status()
+            .map_err(|e| Box::new(e) as Box<dyn Error>)?;
+
+        let quote: CrossChainQuoteResponse = self.read_json("fusion-plus/quoter", response).await?;
+
+        Ok(quote)
+    }
+}
@@ -0,0 +1,41 @@
+use crate::{client::OneInchClient, consts::FUSION_PLUS_API_VERSION, fusion_plus::SignedCrossChainOrder};
+use std::error::Error;
+
+impl OneInchClient {
+    /// Submits signed cross-chain order to the Fusion+ relayer. Returns hash
+    /// of the submitted order. Secrets should be kept, since they have to be
+    /// revealed once resolver deploys escrows on both chains.
+    pub async fn submit_cross_chain_order(&self, order: SignedCrossChainOrder) -> Result<String, Box<dyn Error>> {
+        let url = format!("{}/fusion-plus/relayer/{}/submit", self.base_url(), FUSION_PLUS_API_VERSION);
+
+        let request_result = self.send("fusion-plus/relayer", self.http_client.post(url).json(&order)).await;
+
+        // Relayer replies with an empty body, so the hash is the one computed
+        // while signing.
+        request_result
+            .map_err(|e| Box::new(e) as Box<dyn Error>)?
+            .error_for_status()
+            .map_err(|e| Box::new(e) as Box<dyn Error>)?;
+
+        Ok(order.order_hash)
+    }
+
+    /// Reveals the secret of the cross-chain order fill to the relayer, which
+    /// shares it with the resolver to unlock escrows. Submit it only for fills
+    /// returned by
+    /// [`get_ready_to_accept_secret_fills`](OneInchClient::get_ready_to_accept_secret_fills).
+    pub async fn submit_secret(&self, order_hash: String, secret: String) -> Result<(), Box<dyn Error>> {
+        let url = format!("{}/fusion-plus/relayer/{}/submit/secret", self.base_url(), FUSION_PLUS_API_VERSION);
+
+        let body = serde_json::json!({ "orderHash": order_hash, "secret": secret });
+
+        let request_result = self.send("fusion-plus/relayer", self.http_client.post(url).json(&body)).await;
+
+        request_result
+            .map_err(|e| Box::new(e) as Box<dyn Error>)?
+            .error_for_status()
+            .map_err(|e| Box::new(e) as Box<dyn Error>)?;
+
+        Ok(())
+    }
+}
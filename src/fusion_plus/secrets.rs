@@ -0,0 +1,176 @@
+use crate::common::eip712::keccak256;
+use std::fmt;
+use thiserror::Error;
+
+/// Enumerates errors of the order secrets.
+#[derive(Error, Debug, Eq, PartialEq)]
+pub enum OrderSecretsError {
+    /// Indicates no secrets were provided.
+    #[error("At least one secret is required")]
+    NoSecrets,
+
+    /// Indicates amount of the fill parts doesn't fit into the hashlock.
+    #[error("Too many secrets: {0}")]
+    TooManySecrets(usize),
+}
+
+/// Secrets of the cross-chain order and the hashlock committing to them.
+///
+/// Order which can be filled only once uses a single secret and its hash as
+/// the hashlock. Order allowing multiple fills uses one secret per fill part
+/// (plus one for the last fill), and the hashlock is the root of the Merkle
+/// tree of the secrets with the amount of parts in the upper 16 bits.
+///
+/// Secrets are redacted from the debug output, which shows only their amount
+/// and the hashlock.
+#[derive(Clone)]
+pub struct OrderSecrets {
+    secrets: Vec<[u8; 32]>,
+}
+
+impl OrderSecrets {
+    /// Generates `count` random secrets.
+    pub fn generate(count: u32) -> Result<OrderSecrets, OrderSecretsError> {
+        OrderSecrets::from_secrets((0..count).map(|_| rand::random()).collect())
+    }
+
+    /// Wraps already existing secrets, e.g. restored from storage.
+    pub fn from_secrets(secrets: Vec<[u8; 32]>) -> Result<OrderSecrets, OrderSecretsError> {
+        match secrets.len() {
+            0 => Err(OrderSecretsError::NoSecrets),
+            // Amount of the parts is stored in 16 bits of the hashlock
+            len if len - 1 > u16::MAX as usize => Err(OrderSecretsError::TooManySecrets(len)),
+            _ => Ok(OrderSecrets { secrets }),
+        }
+    }
+
+    /// Returns amount of the secrets.
+    pub fn len(&self) -> usize {
+        self.secrets.len()
+    }
+
+    /// Always false, since there is at least one secret.
+    pub fn is_empty(&self) -> bool {
+        self.secrets.is_empty()
+    }
+
+    /// Returns the secret with the given index as `0x` prefixed hex string.
+    pub fn secret_hex(&self, index: usize) -> Option<String> {
+        self.secrets.get(index).map(|s| format!("0x{}", hex::encode(s)))
+    }
+
+    /// Returns keccak256 hashes of the secrets.
+    pub fn secret_hashes(&self) -> Vec<[u8; 32]> {
+        self.secrets.iter().map(|s| keccak256(s)).collect()
+    }
+
+    /// Returns leaves of the Merkle tree: hashes of the secret index (uint64)
+    /// packed with the secret hash.
+    pub fn merkle_leaves(&self) -> Vec<[u8; 32]> {
+        self.secret_hashes()
+            .iter()
+            .enumerate()
+            .map(|(i, hash)| {
+                let mut packed = (i as u64).to_be_bytes().to_vec();
+                packed.extend_from_slice(hash);
+                keccak256(&packed)
+            })
+            .collect()
+    }
+
+    /// Returns true if the secrets allow filling the order by parts.
+    pub fn allows_multiple_fills(&self) -> bool {
+        self.secrets.len() > 1
+    }
+
+    /// Computes the hashlock of the order.
+    pub fn hashlock(&self) -> [u8; 32] {
+        if !self.allows_multiple_fills() {
+            return keccak256(&self.secrets[0]);
+        }
+
+        let mut hashlock = merkle_root(self.merkle_leaves());
+        let parts = (self.secrets.len() - 1) as u16;
+        hashlock[..2].copy_from_slice(&parts.to_be_bytes());
+        hashlock
+    }
+
+    /// Returns the hashlock as `0x` prefixed hex string.
+    pub fn hashlock_hex(&self) -> String {
+        format!("0x{}", hex::encode(self.hashlock()))
+    }
+}
+
+impl fmt::Debug for OrderSecrets {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OrderSecrets").field("count", &self.len()).field("hashlock", &self.hashlock_hex()).finish()
+    }
+}
+
+// Computes root of the Merkle tree with sorted leaves and commutative pair
+// hashing, laid out the same way as OpenZeppelin `SimpleMerkleTree` does, so
+// proofs generated by resolvers match.
+fn merkle_root(mut leaves: Vec<[u8; 32]>) -> [u8; 32] {
+    leaves.sort();
+
+    let len = 2 * leaves.len() - 1;
+    let mut tree = vec![[0u8; 32]; len];
+    for (i, leaf) in leaves.iter().enumerate() {
+        tree[len - 1 - i] = *leaf;
+    }
+    for i in (0..len - leaves.len()).rev() {
+        tree[i] = hash_pair(&tree[2 * i + 1], &tree[2 * i + 2]);
+    }
+
+    tree[0]
+}
+
+fn hash_pair(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+    keccak256(&[first.as_slice(), second.as_slice()].concat())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_single_fill_hashlock_is_secret_hash() {
+        let secrets = OrderSecrets::from_secrets(vec![[1u8; 32]]).unwrap();
+
+        assert!(!secrets.allows_multiple_fills());
+        assert_eq!(secrets.hashlock(), keccak256(&[1u8; 32]));
+    }
+
+    #[test]
+    fn test_multiple_fills_hashlock_is_merkle_root_with_parts() {
+        let secrets = OrderSecrets::from_secrets(vec![[1u8; 32], [2u8; 32], [3u8; 32]]).unwrap();
+        let leaves = secrets.merkle_leaves();
+
+        let mut sorted = leaves.clone();
+        sorted.sort();
+        // Layout: root <- (node <- (sorted[0], sorted[1])), sorted[2]
+        let node = hash_pair(&sorted[1], &sorted[0]);
+        let root = hash_pair(&node, &sorted[2]);
+
+        let hashlock = secrets.hashlock();
+        assert_eq!(&hashlock[..2], &[0, 2]);
+        assert_eq!(&hashlock[2..], &root[2..]);
+    }
+
+    #[test]
+    fn test_debug_redacts_secrets() {
+        let secrets = OrderSecrets::from_secrets(vec![[0xab; 32], [0xcd; 32]]).unwrap();
+        let debug = format!("{:?}", secrets);
+
+        assert!(debug.contains("count: 2"));
+        assert!(debug.contains(&secrets.hashlock_hex()));
+        assert!(!debug.contains("abab") && !debug.contains("171"));
+    }
+
+    #[test]
+    fn test_no_secrets_is_error() {
+        assert_eq!(OrderSecrets::generate(0).unwrap_err(), OrderSecretsError::NoSecrets);
+        assert_eq!(OrderSecrets::from_secrets(vec![]).unwrap_err(), OrderSecretsError::NoSecrets);
+    }
+}
@@ -0,0 +1,44 @@
+use crate::{
+    client::OneInchClient,
+    fusion_plus::{CrossChainOrderStatusResponse, OrderSecrets},
+    jitter::BackgroundTask,
+};
+use std::{collections::HashSet, error::Error, time::Duration};
+
+impl OneInchClient {
+    /// Drives settlement of the submitted cross-chain order: polls its status
+    /// with the given interval and reveals secrets of the fills whose escrows
+    /// are deployed, until the order reaches a final status, which is
+    /// returned. Wrap it into `tokio::time::timeout` to limit the waiting
+    /// time.
+    pub async fn settle_cross_chain_order(
+        &self,
+        order_hash: String,
+        secrets: &OrderSecrets,
+        poll_interval: Duration,
+    ) -> Result<CrossChainOrderStatusResponse, Box<dyn Error>> {
+        let mut revealed = HashSet::new();
+
+        self.jitter.sleep(BackgroundTask::OrderMonitor, Duration::ZERO).await;
+
+        loop {
+            let status = self.get_fusion_plus_status(order_hash.clone()).await?;
+            if status.status.is_final() {
+                return Ok(status);
+            }
+
+            let ready = self.get_ready_to_accept_secret_fills(order_hash.clone()).await?;
+            for fill in ready.fills {
+                if revealed.contains(&fill.idx) {
+                    continue;
+                }
+
+                let secret = secrets.secret_hex(fill.idx).ok_or(format!("Order has no secret with index {}", fill.idx))?;
+                self.submit_secret(order_hash.clone(), secret).await?;
+                revealed.insert(fill.idx);
+            }
+
+            self.jitter.sleep(BackgroundTask::OrderMonitor, poll_interval).await;
+        }
+    }
+}
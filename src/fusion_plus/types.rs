@@ -0,0 +1,395 @@
+use crate::{
+    builder_setter,
+    client::SupportedNetworks,
+    fusion::{FusionPreset, FusionPresetKind},
+    utils::builder::BasicBuilderError,
+};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// Builder struct to create instance of
+/// [`CrossChainQuoteDetails`](crate::fusion_plus::CrossChainQuoteDetails).
+#[derive(Default)]
+pub struct CrossChainQuoteDetailsBuilder {
+    src_chain: Option<SupportedNetworks>,
+    dst_chain: Option<SupportedNetworks>,
+    src_token: Option<String>,
+    dst_token: Option<String>,
+    amount: Option<String>,
+    wallet_address: Option<String>,
+
+    // Optional fields
+    enable_estimate: Option<bool>,
+    fee: Option<u32>,
+    is_permit2: Option<bool>,
+    permit: Option<String>,
+}
+
+impl CrossChainQuoteDetailsBuilder {
+    pub fn new() -> CrossChainQuoteDetailsBuilder {
+        CrossChainQuoteDetailsBuilder::default()
+    }
+
+    builder_setter!(src_chain, SupportedNetworks);
+    builder_setter!(dst_chain, SupportedNetworks);
+    builder_setter!(src_token, String);
+    builder_setter!(dst_token, String);
+    builder_setter!(amount, String);
+    builder_setter!(wallet_address, String);
+
+    builder_setter!(enable_estimate, bool);
+    builder_setter!(fee, u32);
+    builder_setter!(is_permit2, bool);
+    builder_setter!(permit, String);
+
+    /// Attempts to construct a
+    /// [`CrossChainQuoteDetails`](crate::fusion_plus::CrossChainQuoteDetails)
+    /// from the builder, returning errors if required fields are missing.
+    pub fn build(self) -> Result<CrossChainQuoteDetails, BasicBuilderError> {
+        Ok(CrossChainQuoteDetails {
+            src_chain: self.src_chain.ok_or(BasicBuilderError::MissingField("src_chain"))?,
+            dst_chain: self.dst_chain.ok_or(BasicBuilderError::MissingField("dst_chain"))?,
+            src_token: self.src_token.ok_or(BasicBuilderError::MissingField("src_token"))?,
+            dst_token: self.dst_token.ok_or(BasicBuilderError::MissingField("dst_token"))?,
+            amount: self.amount.ok_or(BasicBuilderError::MissingField("amount"))?,
+            wallet_address: self.wallet_address.ok_or(BasicBuilderError::MissingField("wallet_address"))?,
+            enable_estimate: self.enable_estimate,
+            fee: self.fee,
+            is_permit2: self.is_permit2,
+            permit: self.permit,
+        })
+    }
+}
+
+/// CrossChainQuoteDetails is struct that contains data we need to get a
+/// Fusion+ quote. Chains are set explicitly, so network of the client doesn't
+/// matter.
+#[derive(Debug, Clone)]
+pub struct CrossChainQuoteDetails {
+    pub src_chain: SupportedNetworks,
+    pub dst_chain: SupportedNetworks,
+    pub src_token: String,      // Token address on the source chain.
+    pub dst_token: String,      // Token address on the destination chain.
+    pub amount: String,         // Amount of source token to be swapped.
+    pub wallet_address: String, // Address of the maker.
+
+    // Optional fields
+    pub enable_estimate: Option<bool>, // Should be true to get quote which can be used to create an order.
+    pub fee: Option<u32>,              // Integrator fee in bps.
+    pub is_permit2: Option<bool>,
+    pub permit: Option<String>,
+}
+
+/// Auction preset of the cross-chain swap.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CrossChainPreset {
+    #[serde(flatten)]
+    pub auction: FusionPreset,
+    /// Amount of secrets maker has to generate, one per possible partial fill.
+    pub secrets_count: u32,
+}
+
+/// Time locks of the escrows, in seconds since the escrow deployment. Each
+/// stage begins at its time lock. Escrows keep them as uint32, so larger
+/// values are rejected on deserialization.
+#[derive(Deserialize, Debug, Clone, Copy, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeLocks {
+    pub src_withdrawal: u32,
+    pub src_public_withdrawal: u32,
+    pub src_cancellation: u32,
+    pub src_public_cancellation: u32,
+    pub dst_withdrawal: u32,
+    pub dst_public_withdrawal: u32,
+    pub dst_cancellation: u32,
+}
+
+/// Values related to the source and destination tokens.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CrossChainTokenPair {
+    pub src_token: String,
+    pub dst_token: String,
+}
+
+/// Values expressed in USD.
+#[derive(Deserialize, Debug, Clone)]
+pub struct CrossChainUsdValues {
+    pub usd: CrossChainTokenPair,
+}
+
+/// CrossChainQuoteResponse is a struct to deserialize data we can get on
+/// Fusion+ quote request.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CrossChainQuoteResponse {
+    /// Identifier of the quote, should be passed back when submitting order.
+    pub quote_id: serde_json::Value,
+    pub src_token_amount: String,
+    pub dst_token_amount: String,
+    pub presets: HashMap<FusionPresetKind, CrossChainPreset>,
+    pub src_escrow_factory: String,
+    pub dst_escrow_factory: String,
+    pub whitelist: Vec<String>,
+    pub time_locks: TimeLocks,
+    /// Safety deposit in native token of the source chain resolver puts into
+    /// the escrow.
+    pub src_safety_deposit: String,
+    /// Safety deposit in native token of the destination chain resolver puts
+    /// into the escrow.
+    pub dst_safety_deposit: String,
+    pub recommended_preset: FusionPresetKind,
+    pub prices: Option<CrossChainUsdValues>,
+    pub volume: Option<CrossChainUsdValues>,
+}
+
+impl CrossChainQuoteResponse {
+    /// Returns the preset of the specified kind, if server provided it.
+    pub fn preset(&self, kind: FusionPresetKind) -> Option<&CrossChainPreset> {
+        self.presets.get(&kind)
+    }
+
+    /// Returns the preset server recommends.
+    pub fn recommended(&self) -> Option<&CrossChainPreset> {
+        self.preset(self.recommended_preset)
+    }
+}
+
+/// Status of the cross-chain order.
+#[derive(Deserialize, Debug, Copy, Clone, Eq, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum CrossChainOrderStatus {
+    Pending,
+    Executed,
+    Expired,
+    Cancelled,
+    Refunding,
+    Refunded,
+    /// Status this version of the crate doesn't know about.
+    #[serde(other)]
+    Unknown,
+}
+
+impl CrossChainOrderStatus {
+    /// Returns true if the order can't change its status anymore.
+    pub fn is_final(&self) -> bool {
+        matches!(
+            self,
+            CrossChainOrderStatus::Executed
+                | CrossChainOrderStatus::Expired
+                | CrossChainOrderStatus::Cancelled
+                | CrossChainOrderStatus::Refunded
+        )
+    }
+}
+
+/// Chain side of the escrow.
+#[derive(Deserialize, Debug, Copy, Clone, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum EscrowSide {
+    Src,
+    Dst,
+}
+
+/// Action performed with the escrow.
+#[derive(Deserialize, Debug, Copy, Clone, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum EscrowAction {
+    SrcEscrowCreated,
+    DstEscrowCreated,
+    Withdrawn,
+    FundsRescued,
+    EscrowCancelled,
+    /// Action this version of the crate doesn't know about.
+    #[serde(other)]
+    Unknown,
+}
+
+/// On-chain event of the escrow.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EscrowEvent {
+    pub transaction_hash: String,
+    pub escrow: String,
+    pub side: EscrowSide,
+    pub action: EscrowAction,
+    pub block_timestamp: u64,
+}
+
+/// Stage of the cross-chain fill settlement, in the order fill goes through
+/// them.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord)]
+pub enum SettlementStage {
+    /// Resolver hasn't deployed any escrow yet.
+    AwaitingEscrows,
+    /// Escrow is deployed on the source chain only.
+    SrcEscrowDeployed,
+    /// Escrows are deployed on both chains, the secret can be revealed.
+    EscrowsDeployed,
+    /// Funds are withdrawn to the maker on the destination chain, so the
+    /// secret is public.
+    DstWithdrawn,
+    /// Funds are withdrawn on both chains.
+    Completed,
+    /// Escrow has been cancelled or its funds rescued.
+    Cancelled,
+}
+
+/// Fill of the cross-chain order by resolver.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CrossChainFill {
+    pub status: String,
+    pub tx_hash: String,
+    pub filled_maker_amount: String,
+    pub filled_auction_taker_amount: String,
+    #[serde(default)]
+    pub escrow_events: Vec<EscrowEvent>,
+}
+
+impl CrossChainFill {
+    /// Returns settlement stage of the fill, derived from its escrow events.
+    pub fn stage(&self) -> SettlementStage {
+        let happened =
+            |side: EscrowSide, action: EscrowAction| self.escrow_events.iter().any(|e| e.side == side && e.action == action);
+
+        if self.escrow_events.iter().any(|e| matches!(e.action, EscrowAction::EscrowCancelled | EscrowAction::FundsRescued)) {
+            return SettlementStage::Cancelled;
+        }
+
+        match (
+            happened(EscrowSide::Src, EscrowAction::SrcEscrowCreated),
+            happened(EscrowSide::Dst, EscrowAction::DstEscrowCreated),
+            happened(EscrowSide::Dst, EscrowAction::Withdrawn),
+            happened(EscrowSide::Src, EscrowAction::Withdrawn),
+        ) {
+            (_, _, true, true) => SettlementStage::Completed,
+            (_, _, true, false) => SettlementStage::DstWithdrawn,
+            (true, true, _, _) => SettlementStage::EscrowsDeployed,
+            (true, false, _, _) => SettlementStage::SrcEscrowDeployed,
+            _ => SettlementStage::AwaitingEscrows,
+        }
+    }
+}
+
+/// CrossChainOrderStatusResponse is a struct to deserialize data we can get on
+/// Fusion+ order status request.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CrossChainOrderStatusResponse {
+    pub order_hash: String,
+    pub status: CrossChainOrderStatus,
+    #[serde(default)]
+    pub fills: Vec<CrossChainFill>,
+    pub src_chain_id: Option<u32>,
+    pub dst_chain_id: Option<u32>,
+    pub auction_start_date: Option<u64>,
+    pub auction_duration: Option<u64>,
+    pub created_at: Option<u64>,
+    pub cancel_tx: Option<String>,
+}
+
+/// Fill whose escrows are deployed and verified by relayer, so the maker can
+/// reveal its secret.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ReadyToAcceptSecretFill {
+    /// Index of the secret to reveal.
+    pub idx: usize,
+    pub src_escrow_deploy_tx_hash: String,
+    pub dst_escrow_deploy_tx_hash: String,
+}
+
+/// Represents the struct we receive after requesting fills ready to accept
+/// secrets.
+#[derive(Deserialize, Debug, Clone)]
+pub struct ReadyToAcceptSecretFills {
+    pub fills: Vec<ReadyToAcceptSecretFill>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cross_chain_quote_response_deserialization() {
+        let body = r#"{
+            "quoteId": "c5b4e6e4-bd5b-4e4a-8b51-7b6c9e1d5d2f",
+            "srcTokenAmount": "1000000",
+            "dstTokenAmount": "998000",
+            "presets": {
+                "fast": {
+                    "auctionDuration": 180,
+                    "startAuctionIn": 24,
+                    "initialRateBump": 84909,
+                    "auctionStartAmount": "999000",
+                    "startAmount": "998000",
+                    "auctionEndAmount": "995000",
+                    "exclusiveResolver": null,
+                    "costInDstToken": "3000",
+                    "points": [{"delay": 12, "coefficient": 50000}],
+                    "allowPartialFills": true,
+                    "allowMultipleFills": true,
+                    "gasCost": {"gasBumpEstimate": 10, "gasPriceEstimate": "1000"},
+                    "secretsCount": 4
+                }
+            },
+            "srcEscrowFactory": "0xa7bcb4eac8964306f9e3764f67db6a7af6ddf99a",
+            "dstEscrowFactory": "0xa7bcb4eac8964306f9e3764f67db6a7af6ddf99a",
+            "whitelist": ["0x01"],
+            "timeLocks": {
+                "srcWithdrawal": 36,
+                "srcPublicWithdrawal": 372,
+                "srcCancellation": 528,
+                "srcPublicCancellation": 648,
+                "dstWithdrawal": 36,
+                "dstPublicWithdrawal": 336,
+                "dstCancellation": 456
+            },
+            "srcSafetyDeposit": "81000000000000",
+            "dstSafetyDeposit": "2000000000000",
+            "recommendedPreset": "fast",
+            "prices": {"usd": {"srcToken": "1", "dstToken": "1"}},
+            "volume": null
+        }"#;
+
+        let quote: CrossChainQuoteResponse = serde_json::from_str(body).unwrap();
+        let preset = quote.recommended().unwrap();
+
+        assert_eq!(preset.secrets_count, 4);
+        assert_eq!(preset.auction.auction_duration, 180);
+        assert_eq!(quote.time_locks.dst_cancellation, 456);
+    }
+
+    #[test]
+    fn test_cross_chain_fill_stages() {
+        let body = r#"{
+            "orderHash": "0x01",
+            "status": "pending",
+            "fills": [{
+                "status": "pending",
+                "txHash": "0x02",
+                "filledMakerAmount": "1000000",
+                "filledAuctionTakerAmount": "998000",
+                "escrowEvents": [
+                    {"transactionHash": "0x03", "escrow": "0x04", "side": "src", "action": "src_escrow_created", "blockTimestamp": 1},
+                    {"transactionHash": "0x05", "escrow": "0x06", "side": "dst", "action": "dst_escrow_created", "blockTimestamp": 2}
+                ]
+            }],
+            "srcChainId": 1,
+            "dstChainId": 42161
+        }"#;
+
+        let mut status: CrossChainOrderStatusResponse = serde_json::from_str(body).unwrap();
+        assert!(!status.status.is_final());
+        assert_eq!(status.fills[0].stage(), SettlementStage::EscrowsDeployed);
+
+        let withdrawn: EscrowEvent = serde_json::from_str(
+            r#"{"transactionHash": "0x07", "escrow": "0x06", "side": "dst", "action": "withdrawn", "blockTimestamp": 3}"#,
+        )
+        .unwrap();
+        status.fills[0].escrow_events.push(withdrawn);
+        assert_eq!(status.fills[0].stage(), SettlementStage::DstWithdrawn);
+    }
+}
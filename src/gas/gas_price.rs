@@ -0,0 +1,37 @@
+use crate::{
+    client::OneInchClient,
+    consts::GAS_PRICE_API_VERSION,
+    error::OneInchError,
+    gas::{GasPriceResponse, GasTier},
+};
+
+impl OneInchClient {
+    /// Get current gas prices for the network of the client.
+    pub async fn get_gas_price(&self) -> Result<GasPriceResponse, OneInchError> {
+        let url = format!("{}/gas-price/{}/{}", self.base_url(), GAS_PRICE_API_VERSION, self.network_id);
+
+        let gas_price_response: GasPriceResponse = self.request_json("gas-price", self.http_client.get(url)).await?;
+
+        Ok(gas_price_response)
+    }
+
+    /// Resolves gas price which should be sent with request. Concrete
+    /// `gas_price` always takes precedence, tier is resolved via Gas Price API
+    /// only if there is no concrete value.
+    pub(crate) async fn resolve_gas_price(
+        &self,
+        gas_price: Option<String>,
+        gas_tier: Option<GasTier>,
+    ) -> Result<Option<String>, OneInchError> {
+        match (gas_price, gas_tier) {
+            (Some(price), _) => Ok(Some(price)),
+            (None, Some(tier)) => {
+                let price = self.get_gas_price().await?.price_for(tier);
+                Ok(Some(price.ok_or_else(|| {
+                    OneInchError::Validation("gas price API returned malformed fees".into())
+                })?))
+            }
+            (None, None) => Ok(None),
+        }
+    }
+}
@@ -0,0 +1,4 @@
+mod gas_price;
+mod types;
+
+pub use types::*;
@@ -0,0 +1,127 @@
+use num_bigint::BigUint;
+use serde::Deserialize;
+
+/// Gas price tier which can be used instead of a concrete gas price value.
+/// The client resolves it to actual price via Gas Price API at call time.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub enum GasTier {
+    Slow,
+    #[default]
+    Standard,
+    Fast,
+    Instant,
+}
+
+/// Fee values of a single tier on networks supporting EIP-1559.
+#[derive(Deserialize, Debug, Clone)]
+pub struct Eip1559GasFee {
+    #[serde(rename = "maxPriorityFeePerGas")]
+    pub max_priority_fee_per_gas: String,
+
+    #[serde(rename = "maxFeePerGas")]
+    pub max_fee_per_gas: String,
+}
+
+/// Gas prices as server returns them for networks supporting EIP-1559.
+#[derive(Deserialize, Debug, Clone)]
+pub struct Eip1559GasPrice {
+    #[serde(rename = "baseFee")]
+    pub base_fee: String,
+
+    pub low: Eip1559GasFee,
+    pub medium: Eip1559GasFee,
+    pub high: Eip1559GasFee,
+    pub instant: Eip1559GasFee,
+}
+
+/// Gas prices as server returns them for networks without EIP-1559 support.
+#[derive(Deserialize, Debug, Clone)]
+pub struct LegacyGasPrice {
+    pub standard: String,
+    pub fast: String,
+    pub instant: String,
+}
+
+/// GasPriceResponse is struct that defines object that server returns on
+/// /gas-price request. Its shape depends on whether network supports EIP-1559.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum GasPriceResponse {
+    Eip1559(Eip1559GasPrice),
+    Legacy(LegacyGasPrice),
+}
+
+impl GasPriceResponse {
+    /// Returns gas price in wei for the specified tier. For EIP-1559 networks
+    /// it's the price the transaction is expected to pay, the base fee plus
+    /// `maxPriorityFeePerGas` of the tier, rather than the `maxFeePerGas` cap.
+    /// Legacy networks have no tier below `standard`, so `Slow` resolves to
+    /// it as well. Returns `None` if the fees aren't decimal numbers.
+    pub fn price_for(&self, tier: GasTier) -> Option<String> {
+        match self {
+            GasPriceResponse::Eip1559(prices) => {
+                let fee = match tier {
+                    GasTier::Slow => &prices.low,
+                    GasTier::Standard => &prices.medium,
+                    GasTier::Fast => &prices.high,
+                    GasTier::Instant => &prices.instant,
+                };
+                let base_fee: BigUint = prices.base_fee.parse().ok()?;
+                let priority_fee: BigUint = fee.max_priority_fee_per_gas.parse().ok()?;
+                Some((base_fee + priority_fee).to_string())
+            }
+            GasPriceResponse::Legacy(prices) => match tier {
+                GasTier::Slow | GasTier::Standard => Some(prices.standard.clone()),
+                GasTier::Fast => Some(prices.fast.clone()),
+                GasTier::Instant => Some(prices.instant.clone()),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_price_for_eip1559_response() {
+        let body = r#"{
+            "baseFee": "100",
+            "low": {"maxPriorityFeePerGas": "1", "maxFeePerGas": "101"},
+            "medium": {"maxPriorityFeePerGas": "2", "maxFeePerGas": "102"},
+            "high": {"maxPriorityFeePerGas": "3", "maxFeePerGas": "103"},
+            "instant": {"maxPriorityFeePerGas": "4", "maxFeePerGas": "104"}
+        }"#;
+
+        let response: GasPriceResponse = serde_json::from_str(body).unwrap();
+
+        // Base fee plus the tip, not the fee cap
+        assert_eq!(response.price_for(GasTier::Slow).unwrap(), "101");
+        assert_eq!(response.price_for(GasTier::Standard).unwrap(), "102");
+        assert_eq!(response.price_for(GasTier::Fast).unwrap(), "103");
+        assert_eq!(response.price_for(GasTier::Instant).unwrap(), "104");
+
+        let body = r#"{
+            "baseFee": "30000000000",
+            "low": {"maxPriorityFeePerGas": "1000000", "maxFeePerGas": "45001000000"},
+            "medium": {"maxPriorityFeePerGas": "50000000", "maxFeePerGas": "45050000000"},
+            "high": {"maxPriorityFeePerGas": "100000000", "maxFeePerGas": "45100000000"},
+            "instant": {"maxPriorityFeePerGas": "nan", "maxFeePerGas": "60200000000"}
+        }"#;
+        let response: GasPriceResponse = serde_json::from_str(body).unwrap();
+
+        assert_eq!(response.price_for(GasTier::Standard).unwrap(), "30050000000");
+        assert_eq!(response.price_for(GasTier::Instant), None);
+    }
+
+    #[test]
+    fn test_price_for_legacy_response() {
+        let body = r#"{"standard": "5", "fast": "6", "instant": "7"}"#;
+
+        let response: GasPriceResponse = serde_json::from_str(body).unwrap();
+
+        assert_eq!(response.price_for(GasTier::Slow).unwrap(), "5");
+        assert_eq!(response.price_for(GasTier::Standard).unwrap(), "5");
+        assert_eq!(response.price_for(GasTier::Instant).unwrap(), "7");
+    }
+}
@@ -0,0 +1,97 @@
+use crate::{client::OneInchClient, consts::SWAP_API_VERSION, error::OneInchError};
+use serde::Deserialize;
+
+/// Body of the healthcheck response.
+#[derive(Deserialize, Debug, Clone)]
+pub struct HealthcheckResponse {
+    pub status: String,
+}
+
+/// Status of the API for the client's network.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum HealthStatus {
+    /// API is available.
+    Healthy,
+    /// API responded, but reported itself unavailable or failed the check.
+    Unhealthy { reason: String },
+}
+
+impl HealthStatus {
+    /// Returns true if the API is available.
+    pub fn is_healthy(&self) -> bool {
+        *self == HealthStatus::Healthy
+    }
+
+    fn from_response(status_code: u16, body: Option<HealthcheckResponse>) -> HealthStatus {
+        match body {
+            Some(body) if (200..300).contains(&status_code) && body.status.eq_ignore_ascii_case("ok") => HealthStatus::Healthy,
+            Some(body) => HealthStatus::Unhealthy { reason: format!("status {}: {}", status_code, body.status) },
+            None => HealthStatus::Unhealthy { reason: format!("status {}", status_code) },
+        }
+    }
+}
+
+impl OneInchClient {
+    /// Performs healthcheck request for the client's network. Errors are
+    /// returned only if the API couldn't be reached at all.
+    pub async fn healthcheck(&self) -> Result<HealthStatus, OneInchError> {
+        let url = format!("{}/swap/{}/{}/healthcheck", self.base_url(), SWAP_API_VERSION, self.network_id);
+
+        match self.request_json::<HealthcheckResponse>("swap/healthcheck", self.http_client.get(url)).await {
+            Ok(body) => Ok(HealthStatus::from_response(200, Some(body))),
+            // API responded, but failed the check
+            Err(e @ (OneInchError::Api { .. } | OneInchError::UpstreamUnavailable { .. } | OneInchError::Json { .. })) => {
+                Ok(HealthStatus::Unhealthy { reason: e.to_string() })
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Readiness probe: returns true if the API for the client's network is
+    /// reachable and healthy.
+    pub async fn is_ready(&self) -> bool {
+        matches!(self.healthcheck().await, Ok(HealthStatus::Healthy))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::{new_with_base_url, SupportedNetworks},
+        utils::mock_server::MockServer,
+    };
+
+    #[test]
+    fn test_health_status_from_response() {
+        let ok = || Some(HealthcheckResponse { status: "OK".into() });
+
+        assert!(HealthStatus::from_response(200, ok()).is_healthy());
+        assert_eq!(
+            HealthStatus::from_response(503, ok()),
+            HealthStatus::Unhealthy { reason: "status 503: OK".into() }
+        );
+        assert_eq!(
+            HealthStatus::from_response(502, None),
+            HealthStatus::Unhealthy { reason: "status 502".into() }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_failed_check_is_unhealthy() {
+        let server = MockServer::start_with_status(
+            400,
+            r#"{"error": "Bad Request", "description": "chain is paused", "statusCode": 400, "requestId": "id"}"#,
+        )
+        .await;
+        let client = new_with_base_url("token".to_string(), SupportedNetworks::Ethereum, &server.base_url);
+
+        let status = client.healthcheck().await.unwrap();
+        assert!(matches!(status, HealthStatus::Unhealthy { reason } if reason.contains("chain is paused")));
+        assert!(!client.is_ready().await);
+
+        let server = MockServer::start(r#"{"status": "OK"}"#).await;
+        let client = new_with_base_url("token".to_string(), SupportedNetworks::Ethereum, &server.base_url);
+        assert!(client.is_ready().await);
+    }
+}
@@ -0,0 +1,44 @@
+use crate::{
+    client::OneInchClient,
+    common::page::{paginate, Page},
+    consts::HISTORY_API_VERSION,
+    error::OneInchError,
+    history::{HistoryEvent, HistoryEventsResponse},
+    utils::params::insert_optional_param,
+};
+use futures::Stream;
+use reqwest::Url;
+use std::error::Error;
+
+impl OneInchClient {
+    /// Performs request to get the latest history events of the wallet on the
+    /// client's network.
+    pub async fn get_history_events(&self, address: String, limit: Option<u32>) -> Result<Page<HistoryEvent>, OneInchError> {
+        let url = format!("{}/history/{}/history/{}/events", self.base_url(), HISTORY_API_VERSION, address);
+
+        let mut params: Vec<(&str, String)> = vec![("chainId", self.network_id.to_string())];
+        insert_optional_param(&mut params, "limit", limit.map(|l| l.to_string()));
+
+        let url_with_params = Url::parse_with_params(&url, params).map_err(|e| OneInchError::Validation(e.to_string()))?;
+
+        let events: HistoryEventsResponse = self.request_json("history/events", self.http_client.get(url_with_params)).await?;
+
+        // API returns only the latest events, without pagination
+        Ok(Page::last(events.items))
+    }
+
+    /// Returns a stream of the latest history events of the wallet, for
+    /// uniformity with other paginated endpoints. API doesn't paginate
+    /// history, so the stream ends after the single page.
+    pub fn stream_history_events(
+        &self,
+        address: String,
+        limit: Option<u32>,
+    ) -> impl Stream<Item = Result<HistoryEvent, Box<dyn Error>>> {
+        let client = self.clone();
+        paginate(None, move |_| {
+            let (client, address) = (client.clone(), address.clone());
+            async move { Ok(client.get_history_events(address, limit).await?) }
+        })
+    }
+}
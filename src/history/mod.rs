@@ -0,0 +1,4 @@
+mod events;
+mod types;
+
+pub use types::*;
@@ -0,0 +1,88 @@
+use serde::Deserialize;
+
+/// Movement of the token within the transaction.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenAction {
+    pub address: String,
+    pub standard: String,
+    pub from_address: String,
+    pub to_address: String,
+    pub amount: String,
+    /// `In`, `Out`, `Self` or `On`.
+    pub direction: String,
+}
+
+/// Details of the transaction behind the history event.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryEventDetails {
+    pub tx_hash: String,
+    pub chain_id: u64,
+    pub block_number: u64,
+    pub block_time_sec: u64,
+    pub status: String,
+    /// Type of the transaction like `Transfer`, `Approve` or `SwapExactInput`.
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub from_address: String,
+    pub to_address: String,
+    #[serde(default)]
+    pub token_actions: Vec<TokenAction>,
+}
+
+/// Event of the wallet history.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryEvent {
+    pub id: String,
+    pub address: String,
+    pub time_ms: u64,
+    pub details: HistoryEventDetails,
+}
+
+/// Represents the struct we receive after requesting history events of the
+/// wallet. Events are ordered from the newest to the oldest.
+#[derive(Deserialize, Debug, Clone)]
+pub struct HistoryEventsResponse {
+    pub items: Vec<HistoryEvent>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_history_events_deserialization() {
+        let body = r#"{"items": [{
+            "id": "2593390706880512",
+            "address": "0x30a557351eab496fd69f537be1f8c744a18f94fd",
+            "type": 0,
+            "rating": "Reliable",
+            "timeMs": 1700000000000,
+            "details": {
+                "txHash": "0x01",
+                "chainId": 1,
+                "blockNumber": 18500000,
+                "blockTimeSec": 1700000000,
+                "status": "completed",
+                "type": "Transfer",
+                "fromAddress": "0x30a557351eab496fd69f537be1f8c744a18f94fd",
+                "toAddress": "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+                "tokenActions": [{
+                    "address": "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+                    "standard": "ERC20",
+                    "fromAddress": "0x30a557351eab496fd69f537be1f8c744a18f94fd",
+                    "toAddress": "0x0000000000000000000000000000000000000001",
+                    "amount": "1000000",
+                    "direction": "Out"
+                }]
+            }
+        }]}"#;
+
+        let response: HistoryEventsResponse = serde_json::from_str(body).unwrap();
+
+        assert_eq!(response.items[0].details.kind, "Transfer");
+        assert_eq!(response.items[0].details.token_actions[0].amount, "1000000");
+    }
+}
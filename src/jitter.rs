@@ -0,0 +1,93 @@
+use crate::utils::runtime;
+use rand::Rng;
+use std::{collections::HashMap, time::Duration};
+
+/// Kind of the background task which periodically polls the API.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum BackgroundTask {
+    /// Polling of token prices.
+    PriceFeed,
+    /// Polling of order statuses until they are final.
+    OrderMonitor,
+    /// Polling of wallet balances and history.
+    HistorySync,
+}
+
+/// Jitter windows of the background tasks, per task kind.
+///
+/// Every delay between polls of a task is extended by a random duration within
+/// its window, and the first poll is postponed the same way. This way many
+/// instances started simultaneously don't synchronize their request bursts and
+/// don't trip rate limits shared between them. No jitter is applied by
+/// default.
+///
+/// ```
+/// use one_inch::jitter::{BackgroundTask, JitterConfig};
+/// use std::time::Duration;
+///
+/// let jitter = JitterConfig::uniform(Duration::from_secs(1))
+///     .with_window(BackgroundTask::OrderMonitor, Duration::from_millis(200));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct JitterConfig {
+    windows: HashMap<BackgroundTask, Duration>,
+}
+
+impl JitterConfig {
+    pub fn new() -> JitterConfig {
+        JitterConfig::default()
+    }
+
+    /// Creates configuration with the same window for every task kind.
+    pub fn uniform(window: Duration) -> JitterConfig {
+        [BackgroundTask::PriceFeed, BackgroundTask::OrderMonitor, BackgroundTask::HistorySync]
+            .into_iter()
+            .fold(JitterConfig::new(), |config, task| config.with_window(task, window))
+    }
+
+    /// Sets jitter window of the task kind.
+    pub fn with_window(mut self, task: BackgroundTask, window: Duration) -> JitterConfig {
+        self.windows.insert(task, window);
+        self
+    }
+
+    /// Returns jitter window of the task kind.
+    pub fn window(&self, task: BackgroundTask) -> Duration {
+        self.windows.get(&task).copied().unwrap_or_default()
+    }
+
+    /// Returns random delay within the window of the task kind.
+    pub fn delay(&self, task: BackgroundTask) -> Duration {
+        let window = self.window(task);
+        if window.is_zero() {
+            return Duration::ZERO;
+        }
+
+        window.mul_f64(rand::thread_rng().gen::<f64>())
+    }
+
+    /// Sleeps for the interval extended by random delay of the task kind.
+    pub(crate) async fn sleep(&self, task: BackgroundTask, interval: Duration) {
+        let delay = interval + self.delay(task);
+        if !delay.is_zero() {
+            runtime::sleep(delay).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jitter_delay_within_window() {
+        let window = Duration::from_millis(100);
+        let jitter = JitterConfig::new().with_window(BackgroundTask::PriceFeed, window);
+
+        for _ in 0..100 {
+            assert!(jitter.delay(BackgroundTask::PriceFeed) < window);
+        }
+        assert_eq!(jitter.delay(BackgroundTask::OrderMonitor), Duration::ZERO);
+        assert_eq!(JitterConfig::uniform(window).window(BackgroundTask::HistorySync), window);
+    }
+}
@@ -0,0 +1,136 @@
+use reqwest::Method;
+use serde::{Serialize, Serializer};
+use std::sync::Mutex;
+
+/// Journal of the requests sent by the client and its clones, in a
+/// deterministic order, so audits and replayed test runs can reconstruct how
+/// quotes, approvals and executions of concurrent tasks interleaved.
+///
+/// Every request gets a sequence number when it's sent. Start and completion
+/// of requests tick one logical clock, so request `a` completed before `b`
+/// started if `a.completed_at < b.started_at`, regardless of wall clock
+/// precision. Retries are separate requests.
+///
+/// Entries serialize to JSON, so the journal of a recorded run can be stored
+/// along with its fixtures, see `Fixtures::save_journal` of the `test-util`
+/// feature.
+#[derive(Debug, Default)]
+pub struct RequestJournal {
+    state: Mutex<JournalState>,
+}
+
+// Sequence and clock are ticked under the same lock, so sequence numbers
+// follow the logical start times.
+#[derive(Debug, Default)]
+struct JournalState {
+    clock: u64,
+    sequence: u64,
+    entries: Vec<JournalEntry>,
+}
+
+impl JournalState {
+    fn tick(&mut self) -> u64 {
+        self.clock += 1;
+        self.clock - 1
+    }
+}
+
+/// Request of the journal.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JournalEntry {
+    /// Number of the request in order of sending, starting from 0.
+    pub sequence: u64,
+    /// Logical time the request was sent at.
+    pub started_at: u64,
+    /// Logical time the response (or the error) was received at.
+    pub completed_at: u64,
+    pub endpoint: &'static str,
+    #[serde(serialize_with = "serialize_method")]
+    pub method: Method,
+    pub url: String,
+    /// Status of the response, `None` if the request failed without one.
+    pub status: Option<u16>,
+}
+
+fn serialize_method<S: Serializer>(method: &Method, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(method.as_str())
+}
+
+// Request which has been sent but hasn't completed yet.
+pub(crate) struct PendingEntry {
+    sequence: u64,
+    started_at: u64,
+    endpoint: &'static str,
+    method: Method,
+    url: String,
+}
+
+impl RequestJournal {
+    pub fn new() -> RequestJournal {
+        RequestJournal::default()
+    }
+
+    /// Returns completed requests ordered by sequence number.
+    pub fn entries(&self) -> Vec<JournalEntry> {
+        let mut entries = self.state.lock().unwrap().entries.clone();
+        entries.sort_by_key(|entry| entry.sequence);
+        entries
+    }
+
+    /// Removes the recorded requests. Sequence and clock keep counting, so
+    /// entries recorded later don't collide with the removed ones.
+    pub fn clear(&self) {
+        self.state.lock().unwrap().entries.clear();
+    }
+
+    pub(crate) fn start(&self, endpoint: &'static str, method: Method, url: String) -> PendingEntry {
+        let mut state = self.state.lock().unwrap();
+        state.sequence += 1;
+        PendingEntry { sequence: state.sequence - 1, started_at: state.tick(), endpoint, method, url }
+    }
+
+    pub(crate) fn complete(&self, pending: PendingEntry, status: Option<u16>) {
+        let mut state = self.state.lock().unwrap();
+        let entry = JournalEntry {
+            sequence: pending.sequence,
+            started_at: pending.started_at,
+            completed_at: state.tick(),
+            endpoint: pending.endpoint,
+            method: pending.method,
+            url: pending.url,
+            status,
+        };
+        state.entries.push(entry);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::{new_with_base_url, SupportedNetworks},
+        utils::mock_server::MockServer,
+    };
+
+    #[tokio::test]
+    async fn test_concurrent_requests_are_ordered() {
+        let server = MockServer::start(r#"{"address": "0x1111111111111111111111111111111111111111"}"#).await;
+        let client = new_with_base_url("token".to_string(), SupportedNetworks::Ethereum, &server.base_url)
+            .with_journal(RequestJournal::new());
+
+        client.get_router_address().await.unwrap();
+        let polygon = client.with_chain(SupportedNetworks::Polygon);
+        let (a, b) = futures::join!(client.get_router_address(), polygon.get_router_address());
+        a.unwrap();
+        b.unwrap();
+
+        let entries = client.journal().unwrap().entries();
+        assert_eq!(entries.iter().map(|entry| entry.sequence).collect::<Vec<_>>(), vec![0, 1, 2]);
+        assert_eq!((entries[0].started_at, entries[0].completed_at), (0, 1));
+        // Concurrent requests overlap
+        assert!(entries[1].started_at < entries[2].completed_at && entries[2].started_at < entries[1].completed_at);
+        assert!(entries.iter().all(|entry| entry.status == Some(200) && entry.endpoint == "swap/approve/spender"));
+        assert!(entries[2].url.contains("/137/"));
+    }
+}
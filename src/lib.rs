@@ -9,10 +9,95 @@
 //! various operations. Check out the examples here: [1inch-rs examples](https://github.com/rosenthall/1inch-rs/tree/master/examples).
 extern crate core;
 
+/// Stable single import of the commonly used types.
+pub mod prelude;
+
 /// The client module for interacting with the 1inch API.
 /// Offers methods to make requests to the API and handle responses.
 pub mod client;
 
+/// Configuration of the clients loaded from a TOML file.
+#[cfg(feature = "config")]
+pub mod config;
+
+/// Human labels of addresses, like routers and own wallets, for logs.
+pub mod address_book;
+
+/// Events of the swap lifecycle, emitted to a sink registered on the client.
+pub mod events;
+
+/// Circuit breaker failing requests fast during API outages.
+pub mod circuit_breaker;
+
+/// Deadlines and cancellation of the calls which are no longer relevant.
+pub mod cancel;
+
+/// Deterministically ordered journal of the requests, for audits and
+/// replays.
+pub mod journal;
+
+/// Mock transport answering requests with canned responses, and recorded
+/// fixtures of the real ones, for offline tests.
+#[cfg(all(feature = "test-util", not(target_arch = "wasm32")))]
+pub mod test_util;
+
+/// In-memory cache of the responses of slow-changing endpoints.
+pub mod cache;
+
+/// Client of many networks at once, sharing the API key and connection
+/// pool.
+pub mod multichain;
+
+/// Synchronous client wrapping the async one, for scripts and CLI tools.
+#[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+pub mod blocking;
+
+/// Discovery of the chains supported by the API.
+pub mod chains;
+
+/// API key handling, including providers fetching the key lazily instead of
+/// holding it in memory.
+pub mod auth;
+
+/// Environment profiles (production, staging, etc.) bundling base URL, rate
+/// limits and products availability.
+pub mod environment;
+
+/// Introspection of the client: compiled products, configured chains and
+/// products the API key has access to.
+pub mod capabilities;
+
+/// Client-side rate limiting, including partitioning of the budget between
+/// tenants sharing one client.
+pub mod rate_limit;
+
+/// Retrying of idempotent requests on transient failures with jittered
+/// exponential backoff.
+pub mod retry;
+
+/// Timeouts of the requests, per endpoint.
+pub mod timeout;
+
+/// Jitter of the background tasks polling the API, so many instances don't
+/// synchronize their request bursts.
+pub mod jitter;
+
+/// Adaptive intervals of order status polling.
+pub mod polling;
+
+/// Healthcheck of the API, usable as a readiness probe.
+pub mod health;
+
+/// Splitting of the requests for long address lists into chunks within the
+/// API limits.
+pub mod chunked;
+
+/// Reading of response bodies, including size limits configuration.
+pub mod response;
+
+/// Unified error of the requests to the API.
+pub mod error;
+
 // Constants used across the crate, including API namespace versions, and a
 // basic url.
 mod consts;
@@ -27,6 +112,64 @@ pub mod swap;
 /// Common structures definitions shared by other modules.
 pub mod common;
 
+/// Formatting of token amounts for display, with consistent rounding
+/// policies.
+pub mod amount;
+
 /// Modules related to tokens, including retrieving supported currencies, token
 /// metadata, getting its price.
 pub mod tokens;
+
+/// Gas Price API and gas tiers which can be used instead of a concrete gas
+/// price in swap requests.
+pub mod gas;
+
+/// Limit Order Protocol support: building, hashing and signing orders and
+/// working with the Orderbook API.
+pub mod orderbook;
+
+/// Execution of prepared swaps, including client-side deadlines enforcement.
+pub mod execution;
+
+/// JSON-RPC calls through 1inch Web3 RPC endpoint, sharing API key with the
+/// rest of the client.
+pub mod web3;
+
+/// Fusion API: intent-based swaps settled by resolvers via Dutch auctions.
+pub mod fusion;
+
+/// Fusion+ API: cross-chain intent-based swaps settled through escrows on both
+/// chains.
+pub mod fusion_plus;
+
+/// Balance API: token balances of wallets.
+pub mod balance;
+
+/// History API: transaction history of wallets.
+pub mod history;
+
+/// Traces API: call trace trees of the transactions, handy for debugging
+/// failed swaps.
+pub mod traces;
+
+/// NFT API: NFTs held by the address across chains.
+pub mod nft;
+
+/// Domains API: resolution of ENS and other names to addresses and back.
+pub mod domains;
+
+/// Token Details API: description and market data of the tokens.
+pub mod token_details;
+
+/// Transaction Gateway API: broadcasting of signed transactions.
+pub mod tx_gateway;
+
+/// Charts API: candles of the token pair prices.
+pub mod charts;
+
+/// Polling price feed with optional smoothing of the spot prices.
+pub mod price_feed;
+
+/// Wallet-level tools, like watching the wallet for balance, history and price
+/// changes.
+pub mod wallet;
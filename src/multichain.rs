@@ -0,0 +1,83 @@
+use crate::client::{OneInchClient, SupportedNetworks};
+use std::{borrow::Cow, collections::HashMap};
+
+/// Client of many networks at once, holding a client per network. Clients
+/// are clones of one base client, so they share the API key, connection pool,
+/// rate limiter and quota tracking.
+///
+/// ```
+/// use one_inch::{
+///     client::{new_with_default_http, SupportedNetworks},
+///     multichain::MultiChainClient,
+/// };
+///
+/// let client = new_with_default_http("token".to_string(), SupportedNetworks::Ethereum);
+/// let clients = MultiChainClient::new(client, [SupportedNetworks::Base, SupportedNetworks::Arbitrum]);
+/// assert_eq!(clients.on(SupportedNetworks::Base).network() as u32, 8453);
+/// ```
+#[derive(Clone)]
+pub struct MultiChainClient {
+    base: OneInchClient,
+    clients: HashMap<u32, OneInchClient>,
+}
+
+impl MultiChainClient {
+    /// Creates clients of the networks from the base client.
+    pub fn new(client: OneInchClient, networks: impl IntoIterator<Item = SupportedNetworks>) -> MultiChainClient {
+        let clients = networks.into_iter().map(|network| (network as u32, client.with_chain(network))).collect();
+        MultiChainClient { base: client, clients }
+    }
+
+    /// Sets the client of its network, replacing the one derived from the
+    /// base client. Handy for network-specific settings, like timeouts of a
+    /// slow chain.
+    pub fn with_network_client(mut self, client: OneInchClient) -> MultiChainClient {
+        self.clients.insert(client.network() as u32, client);
+        self
+    }
+
+    /// Returns the client of the network. Networks the client wasn't created
+    /// for are served by the base client switched to them.
+    pub fn on(&self, network: SupportedNetworks) -> Cow<'_, OneInchClient> {
+        match self.clients.get(&(network as u32)) {
+            Some(client) => Cow::Borrowed(client),
+            None => Cow::Owned(self.base.with_chain(network)),
+        }
+    }
+
+    /// Returns the client of the network, if the client was created for it.
+    pub fn get(&self, network: SupportedNetworks) -> Option<&OneInchClient> {
+        self.clients.get(&(network as u32))
+    }
+
+    /// Returns networks the client was created for, in no particular order.
+    pub fn networks(&self) -> impl Iterator<Item = SupportedNetworks> + '_ {
+        self.clients.values().map(|client| client.network())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{client::new_with_base_url, utils::mock_server::MockServer};
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_requests_are_routed_by_network() {
+        let server = MockServer::start(r#"{"address": "0x1111111111111111111111111111111111111111"}"#).await;
+        let client = new_with_base_url("token".to_string(), SupportedNetworks::Ethereum, &server.base_url);
+        let clients = MultiChainClient::new(client.clone(), [SupportedNetworks::Base])
+            .with_network_client(client.with_chain(SupportedNetworks::Polygon).with_call_timeout(Duration::from_secs(5)));
+
+        clients.on(SupportedNetworks::Base).get_router_address().await.unwrap();
+        clients.on(SupportedNetworks::Polygon).get_router_address().await.unwrap();
+        clients.on(SupportedNetworks::Arbitrum).get_router_address().await.unwrap();
+
+        let requests = server.requests();
+        assert!(requests[0].starts_with("/swap/v5.2/8453/"));
+        assert!(requests[1].starts_with("/swap/v5.2/137/"));
+        assert!(requests[2].starts_with("/swap/v5.2/42161/"));
+        assert_eq!(clients.networks().count(), 2);
+        assert!(clients.get(SupportedNetworks::Arbitrum).is_none());
+    }
+}
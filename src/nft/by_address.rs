@@ -0,0 +1,50 @@
+use crate::{
+    client::OneInchClient,
+    common::page::{paginate, Cursor, Page},
+    consts::NFT_API_VERSION,
+    nft::{Nft, NftsByAddressDetails, NftsByAddressResponse},
+    utils::params::insert_optional_param,
+};
+use futures::Stream;
+use reqwest::Url;
+use std::error::Error;
+
+impl OneInchClient {
+    /// Performs request to get NFTs held by the address on the requested
+    /// chains.
+    pub async fn get_nfts_by_address(&self, details: NftsByAddressDetails) -> Result<Page<Nft>, Box<dyn Error>> {
+        let url = format!("{}/nft/{}/byaddress", self.base_url(), NFT_API_VERSION);
+
+        let chains = details.chains.unwrap_or_else(|| vec![self.network_id]);
+
+        let mut params: Vec<(&str, String)> = chains.iter().map(|chain| ("chainIds", chain.to_string())).collect();
+        params.push(("address", details.address));
+        insert_optional_param(&mut params, "limit", details.limit.map(|l| l.to_string()));
+        insert_optional_param(&mut params, "offset", details.offset.map(|o| o.to_string()));
+
+        let url_with_params = Url::parse_with_params(&url, params).map_err(|e| Box::new(e) as Box<dyn Error>)?;
+
+        let request_result = self.send("nft/byaddress", self.http_client.get(url_with_params)).await;
+
+        let response = request_result
+            .map_err(|e| Box::new(e) as Box<dyn Error>)?
+            .error_for_status()
+            .map_err(|e| Box::new(e) as Box<dyn Error>)?;
+
+        let nfts: NftsByAddressResponse = self.read_json("nft/byaddress", response).await?;
+
+        Ok(Page::offset(nfts.assets, details.offset, details.limit))
+    }
+
+    /// Returns a stream of NFTs held by the address, starting from the offset
+    /// of the details and fetching the following pages of `limit` NFTs as the
+    /// stream is consumed. Without `limit` all the NFTs come in one page.
+    pub fn stream_nfts_by_address(&self, details: NftsByAddressDetails) -> impl Stream<Item = Result<Nft, Box<dyn Error>>> {
+        let client = self.clone();
+        paginate(details.offset.map(Cursor::Offset), move |cursor| {
+            let (client, mut details) = (client.clone(), details.clone());
+            details.offset = cursor.and_then(Cursor::offset);
+            async move { client.get_nfts_by_address(details).await }
+        })
+    }
+}
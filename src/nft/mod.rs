@@ -0,0 +1,4 @@
+mod by_address;
+mod types;
+
+pub use types::*;
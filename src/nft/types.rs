@@ -0,0 +1,120 @@
+use crate::{builder_setter, client::SupportedNetworks, utils::builder::BasicBuilderError};
+use serde::Deserialize;
+
+/// Builder struct to create instance of
+/// [`NftsByAddressDetails`](crate::nft::NftsByAddressDetails).
+#[derive(Default)]
+pub struct NftsByAddressDetailsBuilder {
+    address: Option<String>,
+
+    // Optional fields
+    chains: Option<Vec<SupportedNetworks>>,
+    limit: Option<u32>,
+    offset: Option<u32>,
+}
+
+impl NftsByAddressDetailsBuilder {
+    pub fn new() -> NftsByAddressDetailsBuilder {
+        NftsByAddressDetailsBuilder::default()
+    }
+
+    builder_setter!(address, String);
+    builder_setter!(chains, Vec<SupportedNetworks>);
+    builder_setter!(limit, u32);
+    builder_setter!(offset, u32);
+
+    /// Attempts to construct a
+    /// [`NftsByAddressDetails`](crate::nft::NftsByAddressDetails) from the
+    /// builder, returning errors if required fields are missing.
+    pub fn build(self) -> Result<NftsByAddressDetails, BasicBuilderError> {
+        Ok(NftsByAddressDetails {
+            address: self.address.ok_or(BasicBuilderError::MissingField("address"))?,
+            chains: self.chains,
+            limit: self.limit,
+            offset: self.offset,
+        })
+    }
+}
+
+/// NftsByAddressDetails is struct that contains data we need to get NFTs held
+/// by the address.
+#[derive(Debug, Clone)]
+pub struct NftsByAddressDetails {
+    pub address: String, // Address of the holder.
+
+    // Optional fields
+    pub chains: Option<Vec<SupportedNetworks>>, // Chains to get NFTs on, the client's network by default.
+    pub limit: Option<u32>,                     // Page size.
+    pub offset: Option<u32>,                    // Number of NFTs to skip.
+}
+
+/// Contract of the NFT collection.
+#[derive(Deserialize, Debug, Clone)]
+pub struct NftAssetContract {
+    pub address: Option<String>,
+    /// Token standard, like `ERC721` or `ERC1155`.
+    pub schema_name: Option<String>,
+    pub image_url: Option<String>,
+}
+
+/// NFT held by the address.
+#[derive(Deserialize, Debug, Clone)]
+pub struct Nft {
+    pub id: Option<String>,
+    pub token_id: String,
+    pub name: Option<String>,
+    /// Source of the NFT data.
+    pub provider: Option<String>,
+    #[serde(rename = "chainId")]
+    pub chain_id: u32,
+    pub asset_contract: Option<NftAssetContract>,
+    pub image_url: Option<String>,
+    pub image_preview_url: Option<String>,
+    pub animation_url: Option<String>,
+    pub external_link: Option<String>,
+    pub permalink: Option<String>,
+}
+
+impl Nft {
+    /// Returns address of the NFT contract, if known.
+    pub fn contract_address(&self) -> Option<&str> {
+        self.asset_contract.as_ref()?.address.as_deref()
+    }
+}
+
+/// Represents the struct we receive after requesting NFTs by address.
+#[derive(Deserialize, Debug, Clone)]
+pub struct NftsByAddressResponse {
+    #[serde(default)]
+    pub assets: Vec<Nft>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nfts_response_deserialization() {
+        let body = r#"{
+            "assets": [{
+                "id": "1",
+                "token_id": "7804",
+                "provider": "POAP",
+                "name": "ETHGlobal",
+                "chainId": 100,
+                "asset_contract": {"address": "0x22c1f6050e56d2876009903609a2cc3fef83b415", "schema_name": "ERC721", "image_url": null},
+                "image_url": "https://assets.poap.xyz/1.png",
+                "image_preview_url": null,
+                "animation_url": null,
+                "external_link": null,
+                "permalink": "https://poap.gallery/event/1"
+            }]
+        }"#;
+
+        let response: NftsByAddressResponse = serde_json::from_str(body).unwrap();
+
+        assert_eq!(response.assets[0].chain_id, 100);
+        assert_eq!(response.assets[0].contract_address(), Some("0x22c1f6050e56d2876009903609a2cc3fef83b415"));
+        assert!(NftsByAddressDetailsBuilder::new().limit(10).build().is_err());
+    }
+}
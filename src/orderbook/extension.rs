@@ -0,0 +1,93 @@
+use crate::common::eip712::keccak256;
+use num_bigint::BigUint;
+
+/// Extension of the Limit Order Protocol v4 order. Order commits to its
+/// extension via the lower 160 bits of the salt.
+#[derive(Debug, Clone, Default)]
+pub struct OrderExtension {
+    pub maker_asset_suffix: Vec<u8>,
+    pub taker_asset_suffix: Vec<u8>,
+    pub making_amount_data: Vec<u8>,
+    pub taking_amount_data: Vec<u8>,
+    pub predicate: Vec<u8>,
+    pub maker_permit: Vec<u8>,
+    pub pre_interaction: Vec<u8>,
+    pub post_interaction: Vec<u8>,
+    pub custom_data: Vec<u8>,
+}
+
+impl OrderExtension {
+    /// Returns true if there is no data in the extension.
+    pub fn is_empty(&self) -> bool {
+        self.encode().is_empty()
+    }
+
+    /// Encodes the extension: 32 bytes of packed end offsets of the fields
+    /// (field `i` takes bits `32 * i` to `32 * (i + 1)`) followed by the fields
+    /// themselves and custom data.
+    pub fn encode(&self) -> Vec<u8> {
+        let fields = [
+            &self.maker_asset_suffix,
+            &self.taker_asset_suffix,
+            &self.making_amount_data,
+            &self.taking_amount_data,
+            &self.predicate,
+            &self.maker_permit,
+            &self.pre_interaction,
+            &self.post_interaction,
+        ];
+
+        let data_len: usize = fields.iter().map(|f| f.len()).sum();
+        if data_len == 0 && self.custom_data.is_empty() {
+            return vec![];
+        }
+
+        let mut offsets = [0u8; 32];
+        let mut end = 0u32;
+        for (i, field) in fields.iter().enumerate() {
+            end += field.len() as u32;
+            // Field 0 occupies the lowest 4 bytes of the big-endian word
+            offsets[28 - 4 * i..32 - 4 * i].copy_from_slice(&end.to_be_bytes());
+        }
+
+        let mut encoded = offsets.to_vec();
+        fields.iter().for_each(|f| encoded.extend_from_slice(f));
+        encoded.extend_from_slice(&self.custom_data);
+        encoded
+    }
+
+    /// Returns `0x` prefixed hex encoded extension, as the API accepts it.
+    pub fn encode_hex(&self) -> String {
+        format!("0x{}", hex::encode(self.encode()))
+    }
+
+    /// Builds salt committing to the extension: lower 160 bits are taken from
+    /// the extension hash, upper 96 bits from `random`.
+    pub fn salt(&self, random: u128) -> BigUint {
+        let hash = BigUint::from_bytes_be(&keccak256(&self.encode()));
+        let mask = (BigUint::from(1u8) << 160u32) - 1u8;
+        let high = BigUint::from(random & ((1u128 << 96) - 1)) << 160u32;
+
+        high | (hash & mask)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extension_encoding_offsets() {
+        let extension = OrderExtension { making_amount_data: vec![1, 2], post_interaction: vec![3], ..OrderExtension::default() };
+
+        let encoded = extension.encode();
+
+        // End offsets from the highest field to the lowest one
+        let offsets: Vec<u32> = encoded[..32].chunks(4).map(|c| u32::from_be_bytes(c.try_into().unwrap())).collect();
+        assert_eq!(offsets, vec![3, 2, 2, 2, 2, 2, 0, 0]);
+        assert_eq!(&encoded[32..], &[1, 2, 3]);
+
+        assert!(OrderExtension::default().is_empty());
+        assert_eq!(OrderExtension::default().salt(0), BigUint::from_bytes_be(&keccak256(&[])[12..]));
+    }
+}
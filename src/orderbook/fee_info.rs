@@ -0,0 +1,35 @@
+use crate::{
+    client::OneInchClient,
+    consts::ORDERBOOK_API_VERSION,
+    orderbook::{FeeInfoRequestDetails, FeeInfoResponse},
+};
+use reqwest::Url;
+use std::error::Error;
+
+impl OneInchClient {
+    /// Get fee parameters required for limit orders of the specified pair and
+    /// amounts.
+    pub async fn get_orderbook_fee_info(&self, details: FeeInfoRequestDetails) -> Result<FeeInfoResponse, Box<dyn Error>> {
+        let url = format!("{}/orderbook/{}/{}/fee-info", self.base_url(), ORDERBOOK_API_VERSION, self.network_id);
+
+        let params: Vec<(&str, String)> = vec![
+            ("makerAsset", details.maker_asset),
+            ("takerAsset", details.taker_asset),
+            ("makerAmount", details.maker_amount),
+            ("takerAmount", details.taker_amount),
+        ];
+
+        let url_with_params = Url::parse_with_params(&url, params).map_err(|e| Box::new(e) as Box<dyn Error>)?;
+
+        let request_result = self.send("orderbook/fee-info", self.http_client.get(url_with_params)).await;
+
+        let response = request_result
+            .map_err(|e| Box::new(e) as Box<dyn Error>)?
+            .error_for_status()
+            .map_err(|e| Box::new(e) as Box<dyn Error>)?;
+
+        let fee_info: FeeInfoResponse = self.read_json("orderbook/fee-info", response).await?;
+
+        Ok(fee_info)
+    }
+}
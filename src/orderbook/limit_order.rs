@@ -0,0 +1,260 @@
+use crate::{
+    builder_setter,
+    client::SupportedNetworks,
+    common::eip712::{encode_address, encode_uint, keccak256, typed_data_hash, Eip712Domain, Eip712Error, TypedDataSigner},
+    consts::{AGGREGATION_ROUTER_V6_ADDRESS, AGGREGATION_ROUTER_V6_ZKSYNC_ADDRESS},
+    utils::runtime::{SystemTime, UNIX_EPOCH},
+};
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use thiserror::Error;
+
+const ZERO_ADDRESS: &str = "0x0000000000000000000000000000000000000000";
+
+/// Enumerates potential errors when constructing `LimitOrder`.
+#[derive(Error, Debug, Eq, PartialEq)]
+pub enum LimitOrderBuilderError {
+    /// Indicates a required field is missing its value.
+    #[error("Missing {0}")]
+    MissingField(&'static str),
+
+    /// Indicates some of values can't be encoded into the order.
+    #[error("Invalid value: {0}")]
+    InvalidValue(#[from] Eip712Error),
+}
+
+/// Order structure of the Limit Order Protocol v4 in the same form as the
+/// Orderbook API accepts and returns it. All uint256 values are decimal
+/// strings.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct LimitOrderData {
+    pub salt: String,
+    pub maker: String,
+    pub receiver: String,
+    pub maker_asset: String,
+    pub taker_asset: String,
+    pub making_amount: String,
+    pub taking_amount: String,
+    pub maker_traits: String,
+}
+
+impl LimitOrderData {
+    const TYPE: &'static str = "Order(uint256 salt,address maker,address receiver,address makerAsset,address takerAsset,uint256 \
+                                makingAmount,uint256 takingAmount,uint256 makerTraits)";
+
+    /// Computes EIP-712 hash of the order struct (without domain).
+    pub fn struct_hash(&self) -> Result<[u8; 32], Eip712Error> {
+        let mut encoded = Vec::with_capacity(9 * 32);
+        encoded.extend_from_slice(&keccak256(Self::TYPE.as_bytes()));
+        encoded.extend_from_slice(&encode_uint(&self.salt)?);
+        encoded.extend_from_slice(&encode_address(&self.maker)?);
+        encoded.extend_from_slice(&encode_address(&self.receiver)?);
+        encoded.extend_from_slice(&encode_address(&self.maker_asset)?);
+        encoded.extend_from_slice(&encode_address(&self.taker_asset)?);
+        encoded.extend_from_slice(&encode_uint(&self.making_amount)?);
+        encoded.extend_from_slice(&encode_uint(&self.taking_amount)?);
+        encoded.extend_from_slice(&encode_uint(&self.maker_traits)?);
+
+        Ok(keccak256(&encoded))
+    }
+
+    /// Returns the `Order` type definition in the typed data format.
+    pub fn type_definition() -> serde_json::Value {
+        serde_json::json!([
+            { "name": "salt", "type": "uint256" },
+            { "name": "maker", "type": "address" },
+            { "name": "receiver", "type": "address" },
+            { "name": "makerAsset", "type": "address" },
+            { "name": "takerAsset", "type": "address" },
+            { "name": "makingAmount", "type": "uint256" },
+            { "name": "takingAmount", "type": "uint256" },
+            { "name": "makerTraits", "type": "uint256" },
+        ])
+    }
+}
+
+/// Limit order bound to the EIP-712 domain of the contract it's going to be
+/// filled on. Use [`LimitOrderBuilder`](crate::orderbook::LimitOrderBuilder)
+/// to create it.
+#[derive(Debug, Clone)]
+pub struct LimitOrder {
+    pub order: LimitOrderData,
+    pub domain: Eip712Domain,
+}
+
+impl LimitOrder {
+    /// Returns canonical EIP-712 typed data (domain, types, message) of the
+    /// order, ready to be passed to `eth_signTypedData_v4`.
+    pub fn typed_data(&self) -> serde_json::Value {
+        serde_json::json!({
+            "types": {
+                "EIP712Domain": Eip712Domain::type_definition(),
+                "Order": LimitOrderData::type_definition(),
+            },
+            "primaryType": "Order",
+            "domain": self.domain,
+            "message": self.order,
+        })
+    }
+
+    /// Computes the order hash, which is also the value being signed.
+    pub fn hash(&self) -> Result<[u8; 32], Eip712Error> {
+        Ok(typed_data_hash(&self.domain.separator()?, &self.order.struct_hash()?))
+    }
+
+    /// Returns the order hash as `0x` prefixed hex string, the same way
+    /// Orderbook API identifies orders.
+    pub fn hash_hex(&self) -> Result<String, Eip712Error> {
+        Ok(format!("0x{}", hex::encode(self.hash()?)))
+    }
+
+    /// Signs the order with provided signer.
+    pub fn sign(&self, signer: &impl TypedDataSigner) -> Result<SignedLimitOrder, Box<dyn Error>> {
+        let hash = self.hash()?;
+        let signature = signer.sign_typed_data(&self.typed_data(), hash)?;
+
+        Ok(SignedLimitOrder { order: self.order.clone(), order_hash: format!("0x{}", hex::encode(hash)), signature })
+    }
+}
+
+/// Limit order with the maker's signature.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignedLimitOrder {
+    #[serde(rename = "data")]
+    pub order: LimitOrderData,
+    pub order_hash: String,
+    pub signature: String,
+}
+
+/// Builder struct to create instance of
+/// [`LimitOrder`](crate::orderbook::LimitOrder).
+#[derive(Default)]
+pub struct LimitOrderBuilder {
+    network: Option<SupportedNetworks>,
+    maker: Option<String>,
+    maker_asset: Option<String>,
+    taker_asset: Option<String>,
+    making_amount: Option<String>,
+    taking_amount: Option<String>,
+
+    // Optional fields
+    salt: Option<String>,
+    receiver: Option<String>,
+    maker_traits: Option<String>,
+    verifying_contract: Option<String>,
+}
+
+impl LimitOrderBuilder {
+    pub fn new() -> LimitOrderBuilder {
+        LimitOrderBuilder::default()
+    }
+
+    builder_setter!(network, SupportedNetworks);
+    builder_setter!(maker, String);
+    builder_setter!(maker_asset, String);
+    builder_setter!(taker_asset, String);
+    builder_setter!(making_amount, String);
+    builder_setter!(taking_amount, String);
+
+    builder_setter!(salt, String);
+    builder_setter!(receiver, String);
+    builder_setter!(maker_traits, String);
+    builder_setter!(verifying_contract, String);
+
+    /// Attempts to construct a [`LimitOrder`](crate::orderbook::LimitOrder)
+    /// from the builder, returning errors if required fields are missing or if
+    /// some of values can't be encoded. If not set, salt is derived from
+    /// current time, receiver is zero address (which means maker), maker
+    /// traits are zero and verifying contract is 1inch Aggregation Router v6.
+    pub fn build(self) -> Result<LimitOrder, LimitOrderBuilderError> {
+        let network = self.network.ok_or(LimitOrderBuilderError::MissingField("network"))?;
+
+        let salt = self.salt.unwrap_or_else(|| {
+            SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_nanos()).unwrap_or_default().to_string()
+        });
+
+        let verifying_contract = self.verifying_contract.unwrap_or_else(|| match network {
+            SupportedNetworks::ZkSync => AGGREGATION_ROUTER_V6_ZKSYNC_ADDRESS.to_string(),
+            _ => AGGREGATION_ROUTER_V6_ADDRESS.to_string(),
+        });
+
+        let order = LimitOrderData {
+            salt,
+            maker: self.maker.ok_or(LimitOrderBuilderError::MissingField("maker"))?,
+            receiver: self.receiver.unwrap_or_else(|| ZERO_ADDRESS.to_string()),
+            maker_asset: self.maker_asset.ok_or(LimitOrderBuilderError::MissingField("maker_asset"))?,
+            taker_asset: self.taker_asset.ok_or(LimitOrderBuilderError::MissingField("taker_asset"))?,
+            making_amount: self.making_amount.ok_or(LimitOrderBuilderError::MissingField("making_amount"))?,
+            taking_amount: self.taking_amount.ok_or(LimitOrderBuilderError::MissingField("taking_amount"))?,
+            maker_traits: self.maker_traits.unwrap_or_else(|| "0".to_string()),
+        };
+
+        let domain = Eip712Domain {
+            name: "1inch Aggregation Router".to_string(),
+            version: "6".to_string(),
+            chain_id: network as u64,
+            verifying_contract,
+        };
+
+        // Making sure all the values are encodable, so hashing won't fail later
+        order.struct_hash()?;
+        domain.separator()?;
+
+        Ok(LimitOrder { order, domain })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn builder() -> LimitOrderBuilder {
+        LimitOrderBuilder::new()
+            .network(SupportedNetworks::Ethereum)
+            .salt("1".into())
+            .maker("0x30A557351eab496FD69F537BE1F8c744A18F94Fd".into())
+            .maker_asset("0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48".into())
+            .taker_asset("0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2".into())
+            .making_amount("1000000".into())
+            .taking_amount("1000000000000000".into())
+    }
+
+    #[test]
+    fn test_limit_order_builder_defaults() {
+        let order = builder().build().unwrap();
+
+        assert_eq!(order.order.receiver, ZERO_ADDRESS);
+        assert_eq!(order.order.maker_traits, "0");
+        assert_eq!(order.domain.chain_id, 1);
+        assert_eq!(order.domain.verifying_contract, AGGREGATION_ROUTER_V6_ADDRESS);
+
+        let typed_data = order.typed_data();
+        assert_eq!(typed_data["primaryType"], "Order");
+        assert_eq!(typed_data["message"]["makingAmount"], "1000000");
+        assert_eq!(typed_data["domain"]["verifyingContract"], AGGREGATION_ROUTER_V6_ADDRESS);
+    }
+
+    #[test]
+    fn test_limit_order_hash_depends_on_domain() {
+        let ethereum_order = builder().build().unwrap();
+        let bsc_order = builder().network(SupportedNetworks::BSC).build().unwrap();
+
+        assert_eq!(ethereum_order.hash_hex().unwrap().len(), 66);
+        assert_eq!(ethereum_order.hash().unwrap(), builder().build().unwrap().hash().unwrap());
+        assert_ne!(ethereum_order.hash().unwrap(), bsc_order.hash().unwrap());
+    }
+
+    #[test]
+    fn test_limit_order_builder_validates_values() {
+        let result = builder().maker("not an address".into()).build();
+        assert_eq!(
+            result.unwrap_err(),
+            LimitOrderBuilderError::InvalidValue(Eip712Error::InvalidAddress("not an address".into()))
+        );
+
+        let result = LimitOrderBuilder::new().network(SupportedNetworks::BSC).build();
+        assert_eq!(result.unwrap_err(), LimitOrderBuilderError::MissingField("maker"));
+    }
+}
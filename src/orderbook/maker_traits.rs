@@ -0,0 +1,109 @@
+use core::fmt;
+use num_bigint::BigUint;
+
+const NO_PARTIAL_FILLS_FLAG: u32 = 255;
+const ALLOW_MULTIPLE_FILLS_FLAG: u32 = 254;
+const PRE_INTERACTION_CALL_FLAG: u32 = 252;
+const POST_INTERACTION_CALL_FLAG: u32 = 251;
+const HAS_EXTENSION_FLAG: u32 = 249;
+const USE_PERMIT2_FLAG: u32 = 248;
+
+const EXPIRATION_OFFSET: u32 = 80;
+const NONCE_OR_EPOCH_OFFSET: u32 = 120;
+const SERIES_OFFSET: u32 = 160;
+const UINT40_MASK: u64 = (1 << 40) - 1;
+
+/// Builder of the `makerTraits` value of the Limit Order Protocol v4 order,
+/// which packs order flags, expiration, nonce and series into one uint256.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct MakerTraits {
+    value: BigUint,
+}
+
+impl MakerTraits {
+    pub fn new() -> MakerTraits {
+        MakerTraits::default()
+    }
+
+    /// Forbids partial fills of the order.
+    pub fn no_partial_fills(self) -> MakerTraits {
+        self.with_flag(NO_PARTIAL_FILLS_FLAG)
+    }
+
+    /// Allows the order to be filled multiple times.
+    pub fn allow_multiple_fills(self) -> MakerTraits {
+        self.with_flag(ALLOW_MULTIPLE_FILLS_FLAG)
+    }
+
+    /// Marks that order has pre-interaction call in its extension.
+    pub fn pre_interaction(self) -> MakerTraits {
+        self.with_flag(PRE_INTERACTION_CALL_FLAG)
+    }
+
+    /// Marks that order has post-interaction call in its extension.
+    pub fn post_interaction(self) -> MakerTraits {
+        self.with_flag(POST_INTERACTION_CALL_FLAG)
+    }
+
+    /// Marks that order has an extension.
+    pub fn has_extension(self) -> MakerTraits {
+        self.with_flag(HAS_EXTENSION_FLAG)
+    }
+
+    /// Marks that maker's permit is a Permit2 one.
+    pub fn use_permit2(self) -> MakerTraits {
+        self.with_flag(USE_PERMIT2_FLAG)
+    }
+
+    /// Sets unix timestamp the order expires at.
+    pub fn expiration(self, expiration: u64) -> MakerTraits {
+        self.with_field(EXPIRATION_OFFSET, expiration)
+    }
+
+    /// Sets nonce or epoch of the order.
+    pub fn nonce_or_epoch(self, nonce: u64) -> MakerTraits {
+        self.with_field(NONCE_OR_EPOCH_OFFSET, nonce)
+    }
+
+    /// Sets series of the order.
+    pub fn series(self, series: u64) -> MakerTraits {
+        self.with_field(SERIES_OFFSET, series)
+    }
+
+    /// Returns the packed value.
+    pub fn value(&self) -> &BigUint {
+        &self.value
+    }
+
+    fn with_flag(mut self, bit: u32) -> MakerTraits {
+        self.value |= BigUint::from(1u8) << bit;
+        self
+    }
+
+    // Fields are 40 bits wide, so the value is truncated to fit.
+    fn with_field(mut self, offset: u32, value: u64) -> MakerTraits {
+        let mask = BigUint::from(UINT40_MASK) << offset;
+        self.value = (&self.value ^ (&self.value & &mask)) | (BigUint::from(value & UINT40_MASK) << offset);
+        self
+    }
+}
+
+impl fmt::Display for MakerTraits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_maker_traits_packing() {
+        let traits = MakerTraits::new().has_extension().expiration(1).nonce_or_epoch(2).expiration(3);
+
+        let expected = (BigUint::from(1u8) << 249u32) | (BigUint::from(3u8) << 80u32) | (BigUint::from(2u8) << 120u32);
+        assert_eq!(traits.value(), &expected);
+        assert_eq!(MakerTraits::new().to_string(), "0");
+    }
+}
@@ -0,0 +1,11 @@
+mod extension;
+mod fee_info;
+mod limit_order;
+mod maker_traits;
+mod orders;
+mod types;
+
+pub use extension::*;
+pub use limit_order::*;
+pub use maker_traits::*;
+pub use types::*;
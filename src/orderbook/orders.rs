@@ -0,0 +1,164 @@
+use crate::{
+    client::OneInchClient,
+    common::page::{paginate, Cursor, Page},
+    consts::ORDERBOOK_API_VERSION,
+    error::OneInchError,
+    jitter::BackgroundTask,
+    orderbook::{AllOrdersRequestDetails, OrderEvent, OrderRecord, OrderStatus, OrderValidity, OrdersPage},
+    polling::{time_until, PollingPolicy},
+    utils::params::insert_optional_param,
+};
+use futures::Stream;
+use reqwest::Url;
+use std::{error::Error, time::Duration};
+
+impl OneInchClient {
+    /// Get orders created by the specified maker. Empty `statuses` means
+    /// orders are not filtered by validity.
+    pub async fn get_orders_by_maker(
+        &self,
+        address: String,
+        page: Option<u32>,
+        limit: Option<u32>,
+        statuses: Vec<OrderValidity>,
+    ) -> Result<Page<OrderRecord>, OneInchError> {
+        let url = format!(
+            "{}/orderbook/{}/{}/address/{}",
+            self.base_url(),
+            ORDERBOOK_API_VERSION,
+            self.network_id,
+            address
+        );
+
+        let mut params: Vec<(&str, String)> = vec![];
+
+        insert_optional_param(&mut params, "page", page.map(|a| a.to_string()));
+        insert_optional_param(&mut params, "limit", limit.map(|a| a.to_string()));
+
+        insert_optional_param(&mut params, "statuses", join_statuses(&statuses));
+
+        let url_with_params = Url::parse_with_params(&url, params).map_err(|e| OneInchError::Validation(e.to_string()))?;
+
+        let orders: Vec<OrderRecord> = self.request_json("orderbook/address", self.http_client.get(url_with_params)).await?;
+
+        Ok(Page::numbered(orders, page, limit))
+    }
+
+    /// Get a page of all the orders in the public orderbook of the network.
+    pub async fn get_all_orders(&self, details: AllOrdersRequestDetails) -> Result<OrdersPage, OneInchError> {
+        let url = format!("{}/orderbook/{}/{}/all", self.base_url(), ORDERBOOK_API_VERSION, self.network_id);
+
+        let mut params: Vec<(&str, String)> = vec![];
+
+        insert_optional_param(&mut params, "page", details.page.map(|a| a.to_string()));
+        insert_optional_param(&mut params, "limit", details.limit.map(|a| a.to_string()));
+        insert_optional_param(&mut params, "statuses", join_statuses(&details.statuses));
+        insert_optional_param(&mut params, "sortBy", details.sort_by.map(|a| a.to_string()));
+        insert_optional_param(&mut params, "makerAsset", details.maker_asset);
+        insert_optional_param(&mut params, "takerAsset", details.taker_asset);
+
+        let url_with_params = Url::parse_with_params(&url, params).map_err(|e| OneInchError::Validation(e.to_string()))?;
+
+        let orders_page: OrdersPage = self.request_json("orderbook/all", self.http_client.get(url_with_params)).await?;
+
+        Ok(orders_page)
+    }
+
+    /// Returns a stream of the orders created by the maker, fetching pages of
+    /// `limit` orders as the stream is consumed.
+    pub fn stream_orders_by_maker(
+        &self,
+        address: String,
+        limit: u32,
+        statuses: Vec<OrderValidity>,
+    ) -> impl Stream<Item = Result<OrderRecord, Box<dyn Error>>> {
+        let client = self.clone();
+        paginate(None, move |cursor| {
+            let (client, address, statuses) = (client.clone(), address.clone(), statuses.clone());
+            async move { Ok(client.get_orders_by_maker(address, cursor.and_then(Cursor::page), Some(limit), statuses).await?) }
+        })
+    }
+
+    /// Returns a stream of all the orders in the public orderbook, starting
+    /// from the page of the details and fetching the following ones as the
+    /// stream is consumed.
+    pub fn stream_all_orders(&self, details: AllOrdersRequestDetails) -> impl Stream<Item = Result<OrderRecord, Box<dyn Error>>> {
+        let client = self.clone();
+        paginate(details.page.map(Cursor::Page), move |cursor| {
+            let (client, mut details) = (client.clone(), details.clone());
+            details.page = cursor.and_then(Cursor::page);
+            async move { Ok(client.get_all_orders(details).await?) }
+        })
+    }
+
+    /// Get order by its hash.
+    pub async fn get_order_by_hash(&self, hash: String) -> Result<OrderRecord, OneInchError> {
+        let url = format!(
+            "{}/orderbook/{}/{}/order/{}",
+            self.base_url(),
+            ORDERBOOK_API_VERSION,
+            self.network_id,
+            hash
+        );
+
+        let order: OrderRecord = self.request_json("orderbook/order", self.http_client.get(url)).await?;
+
+        Ok(order)
+    }
+
+    /// Get fill and cancel events of the order.
+    pub async fn get_order_events(&self, hash: String) -> Result<Vec<OrderEvent>, OneInchError> {
+        let url = format!(
+            "{}/orderbook/{}/{}/events/{}",
+            self.base_url(),
+            ORDERBOOK_API_VERSION,
+            self.network_id,
+            hash
+        );
+
+        let events: Vec<OrderEvent> = self.request_json("orderbook/events", self.http_client.get(url)).await?;
+
+        Ok(events)
+    }
+
+    /// Get typed status of the order, performing requests for both the order
+    /// and its events.
+    pub async fn get_order_status(&self, hash: String) -> Result<OrderStatus, OneInchError> {
+        let order = self.get_order_by_hash(hash.clone()).await?;
+        let events = self.get_order_events(hash).await?;
+
+        Ok(order.status(&events))
+    }
+
+    /// Polls status of the order until it's filled, cancelled or expired and
+    /// returns it, with intervals adapted by the policy to the order's
+    /// expiration. Wrap it into `tokio::time::timeout` to limit the waiting
+    /// time.
+    pub async fn wait_for_order(&self, hash: String, policy: &PollingPolicy) -> Result<OrderStatus, OneInchError> {
+        self.jitter.sleep(BackgroundTask::OrderMonitor, Duration::ZERO).await;
+
+        let mut poll = 0;
+        loop {
+            let order = self.get_order_by_hash(hash.clone()).await?;
+            let events = self.get_order_events(hash.clone()).await?;
+            let status = order.status(&events);
+            if status.is_final() {
+                return Ok(status);
+            }
+
+            let interval = policy.interval(poll, order.expiration().map(time_until));
+            self.jitter.sleep(BackgroundTask::OrderMonitor, interval).await;
+            poll = poll.saturating_add(1);
+        }
+    }
+}
+
+// Joins statuses into the comma separated list, `None` if there are no
+// statuses to filter by.
+fn join_statuses(statuses: &[OrderValidity]) -> Option<String> {
+    if statuses.is_empty() {
+        return None;
+    }
+
+    Some(statuses.iter().map(|s| (*s as u8).to_string()).collect::<Vec<String>>().join(","))
+}
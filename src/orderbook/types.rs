@@ -0,0 +1,371 @@
+use crate::{
+    builder_setter,
+    common::page::Page,
+    orderbook::LimitOrderData,
+    utils::{
+        builder::BasicBuilderError,
+        runtime::{SystemTime, UNIX_EPOCH},
+    },
+};
+use num_bigint::BigUint;
+use serde::Deserialize;
+use std::collections::HashMap;
+use strum_macros::Display;
+
+/// Validity status of the order as Orderbook API filters orders by it.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[repr(u8)]
+pub enum OrderValidity {
+    Valid = 1,
+    TemporarilyInvalid = 2,
+    Invalid = 3,
+}
+
+/// Field the orders of the public book can be sorted by.
+#[derive(Debug, Display, Copy, Clone, Eq, PartialEq)]
+#[strum(serialize_all = "camelCase")]
+pub enum OrdersSortBy {
+    CreateDateTime,
+    TakerRate,
+    MakerRate,
+    MakerAmount,
+    TakerAmount,
+}
+
+/// How much of the order has already been filled.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum FillState {
+    Unfilled,
+    PartiallyFilled,
+    Filled,
+}
+
+/// Typed status of the order derived from its record and events.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum OrderStatus {
+    Valid,
+    PartiallyFilled,
+    Filled,
+    Cancelled,
+    Expired,
+    /// Order can't be filled for other reasons, like insufficient maker
+    /// balance or allowance.
+    Invalid,
+}
+
+impl OrderStatus {
+    /// Returns true if the status won't change anymore. Invalid orders may
+    /// become valid again, e.g. after the maker approves the token.
+    pub fn is_final(&self) -> bool {
+        matches!(self, OrderStatus::Filled | OrderStatus::Cancelled | OrderStatus::Expired)
+    }
+}
+
+/// Action which order event reports.
+#[derive(Deserialize, Debug, Copy, Clone, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum OrderEventAction {
+    Fill,
+    Cancel,
+    #[serde(other)]
+    Other,
+}
+
+/// OrderEvent is struct that defines on-chain event (fill or cancel) of the
+/// order as Orderbook API returns it.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderEvent {
+    pub id: u64,
+    pub network: u32,
+    pub log_id: String,
+    pub version: u32,
+    pub action: OrderEventAction,
+    pub order_hash: String,
+    pub taker: String,
+    pub remaining_maker_amount: String,
+    pub transaction_hash: String,
+    pub block_number: u64,
+    pub create_date_time: String,
+}
+
+/// Order data as Orderbook API returns it: the order itself plus its
+/// extension.
+#[derive(Deserialize, Debug, Clone)]
+pub struct OrderRecordData {
+    #[serde(flatten)]
+    pub order: LimitOrderData,
+
+    #[serde(default)]
+    pub extension: String,
+}
+
+/// OrderRecord is struct that defines an order stored in the orderbook
+/// together with its current fill and funding state.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderRecord {
+    pub order_hash: String,
+    pub signature: String,
+    pub create_date_time: String,
+    pub data: OrderRecordData,
+
+    /// Amount of maker asset which is still available for filling.
+    pub remaining_maker_amount: String,
+    pub maker_balance: String,
+    pub maker_allowance: String,
+
+    pub maker_rate: Option<String>,
+    pub taker_rate: Option<String>,
+
+    #[serde(default)]
+    pub is_maker_contract: bool,
+
+    pub order_invalid_reason: Option<String>,
+}
+
+impl OrderRecord {
+    /// Returns amount of maker asset which has already been filled.
+    pub fn filled_maker_amount(&self) -> BigUint {
+        let making = self.data.order.making_amount.parse::<BigUint>().unwrap_or_default();
+        let remaining = self.remaining_maker_amount.parse::<BigUint>().unwrap_or_default();
+
+        if remaining > making {
+            return BigUint::default();
+        }
+        making - remaining
+    }
+
+    /// Returns fill state of the order.
+    pub fn fill_state(&self) -> FillState {
+        let filled = self.filled_maker_amount();
+
+        if filled == BigUint::default() {
+            FillState::Unfilled
+        } else if self.remaining_maker_amount.parse::<BigUint>().unwrap_or_default() == BigUint::default() {
+            FillState::Filled
+        } else {
+            FillState::PartiallyFilled
+        }
+    }
+
+    /// Returns unix timestamp the order expires at, `None` if it never
+    /// expires. Expiration is encoded into bits 80-119 of `makerTraits`.
+    pub fn expiration(&self) -> Option<u64> {
+        let traits = self.data.order.maker_traits.parse::<BigUint>().unwrap_or_default();
+        let expiration = (traits >> 80u32) & BigUint::from(u64::MAX >> 24);
+
+        expiration.to_u64_digits().first().copied().filter(|e| *e != 0)
+    }
+
+    /// Derives typed status of the order from the record and its events.
+    pub fn status(&self, events: &[OrderEvent]) -> OrderStatus {
+        if events.iter().any(|e| e.action == OrderEventAction::Cancel) {
+            return OrderStatus::Cancelled;
+        }
+
+        match self.fill_state() {
+            FillState::Filled => return OrderStatus::Filled,
+            FillState::PartiallyFilled if self.order_invalid_reason.is_none() => return OrderStatus::PartiallyFilled,
+            _ => {}
+        }
+
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or_default();
+        if self.expiration().is_some_and(|expiration| expiration <= now) {
+            return OrderStatus::Expired;
+        }
+
+        if self.order_invalid_reason.is_some() {
+            return OrderStatus::Invalid;
+        }
+
+        OrderStatus::Valid
+    }
+}
+
+/// Builder struct to create instance of
+/// [`AllOrdersRequestDetails`](crate::orderbook::AllOrdersRequestDetails).
+#[derive(Default)]
+pub struct AllOrdersRequestBuilder {
+    page: Option<u32>,
+    limit: Option<u32>,
+    statuses: Option<Vec<OrderValidity>>,
+    sort_by: Option<OrdersSortBy>,
+    maker_asset: Option<String>,
+    taker_asset: Option<String>,
+}
+
+impl AllOrdersRequestBuilder {
+    pub fn new() -> AllOrdersRequestBuilder {
+        AllOrdersRequestBuilder::default()
+    }
+
+    builder_setter!(page, u32);
+    builder_setter!(limit, u32);
+    builder_setter!(statuses, Vec<OrderValidity>);
+    builder_setter!(sort_by, OrdersSortBy);
+    builder_setter!(maker_asset, String);
+    builder_setter!(taker_asset, String);
+
+    /// Constructs
+    /// [`AllOrdersRequestDetails`](crate::orderbook::AllOrdersRequestDetails)
+    /// from the builder. All the fields are optional.
+    pub fn build(self) -> AllOrdersRequestDetails {
+        AllOrdersRequestDetails {
+            page: self.page,
+            limit: self.limit,
+            statuses: self.statuses.unwrap_or_default(),
+            sort_by: self.sort_by,
+            maker_asset: self.maker_asset,
+            taker_asset: self.taker_asset,
+        }
+    }
+}
+
+/// Struct contains the values we need to perform request for orders of the
+/// public book. Empty `statuses` means orders are not filtered by validity.
+#[derive(Debug, Clone)]
+pub struct AllOrdersRequestDetails {
+    pub page: Option<u32>,
+    pub limit: Option<u32>,
+    pub statuses: Vec<OrderValidity>,
+    pub sort_by: Option<OrdersSortBy>,
+    pub maker_asset: Option<String>,
+    pub taker_asset: Option<String>,
+}
+
+/// Page of the public orderbook.
+pub type OrdersPage = Page<OrderRecord>;
+
+/// Builder struct to create instance of
+/// [`FeeInfoRequestDetails`](crate::orderbook::FeeInfoRequestDetails).
+#[derive(Default)]
+pub struct FeeInfoRequestBuilder {
+    maker_asset: Option<String>,
+    taker_asset: Option<String>,
+    maker_amount: Option<String>,
+    taker_amount: Option<String>,
+}
+
+impl FeeInfoRequestBuilder {
+    pub fn new() -> FeeInfoRequestBuilder {
+        FeeInfoRequestBuilder::default()
+    }
+
+    builder_setter!(maker_asset, String);
+    builder_setter!(taker_asset, String);
+    builder_setter!(maker_amount, String);
+    builder_setter!(taker_amount, String);
+
+    /// Attempts to construct a
+    /// [`FeeInfoRequestDetails`](crate::orderbook::FeeInfoRequestDetails) from
+    /// the builder, returning errors if required fields are missing.
+    pub fn build(self) -> Result<FeeInfoRequestDetails, BasicBuilderError> {
+        Ok(FeeInfoRequestDetails {
+            maker_asset: self.maker_asset.ok_or(BasicBuilderError::MissingField("maker_asset"))?,
+            taker_asset: self.taker_asset.ok_or(BasicBuilderError::MissingField("taker_asset"))?,
+            maker_amount: self.maker_amount.ok_or(BasicBuilderError::MissingField("maker_amount"))?,
+            taker_amount: self.taker_amount.ok_or(BasicBuilderError::MissingField("taker_amount"))?,
+        })
+    }
+}
+
+/// Struct contains the values we need to perform orderbook fee-info request.
+#[derive(Debug, Clone)]
+pub struct FeeInfoRequestDetails {
+    pub maker_asset: String,
+    pub taker_asset: String,
+    pub maker_amount: String,
+    pub taker_amount: String,
+}
+
+/// Represents fee parameters the orderbook requires from limit orders of the
+/// network. They should be taken into account when constructing
+/// `makerTraits` and the order extension.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FeeInfoResponse {
+    /// Resolvers whitelisted for the fee discount, keyed by their address.
+    pub whitelist: HashMap<String, String>,
+    pub fee_bps: u32,
+    pub whitelist_discount_percent: u32,
+    pub protocol_fee_receiver: String,
+    pub extension_address: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(making_amount: &str, remaining_maker_amount: &str) -> OrderRecord {
+        record_with_traits(making_amount, remaining_maker_amount, "0")
+    }
+
+    fn record_with_traits(making_amount: &str, remaining_maker_amount: &str, maker_traits: &str) -> OrderRecord {
+        let body = format!(
+            r#"{{
+                "orderHash": "0x01",
+                "signature": "0x02",
+                "createDateTime": "2024-01-01T00:00:00.000Z",
+                "remainingMakerAmount": "{remaining_maker_amount}",
+                "makerBalance": "1000",
+                "makerAllowance": "1000",
+                "data": {{
+                    "salt": "1",
+                    "maker": "0x30a557351eab496fd69f537be1f8c744a18f94fd",
+                    "receiver": "0x0000000000000000000000000000000000000000",
+                    "makerAsset": "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+                    "takerAsset": "0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2",
+                    "makingAmount": "{making_amount}",
+                    "takingAmount": "1",
+                    "makerTraits": "{maker_traits}",
+                    "extension": "0x"
+                }},
+                "makerRate": "1",
+                "takerRate": "1",
+                "isMakerContract": false,
+                "orderInvalidReason": null
+            }}"#
+        );
+        serde_json::from_str(&body).unwrap()
+    }
+
+    #[test]
+    fn test_order_record_fill_state() {
+        assert_eq!(record("100", "100").fill_state(), FillState::Unfilled);
+        assert_eq!(record("100", "40").fill_state(), FillState::PartiallyFilled);
+        assert_eq!(record("100", "40").filled_maker_amount(), BigUint::from(60u8));
+        assert_eq!(record("100", "0").fill_state(), FillState::Filled);
+    }
+
+    #[test]
+    fn test_order_record_status() {
+        let cancel_event: OrderEvent = serde_json::from_str(
+            r#"{
+                "id": 1,
+                "network": 1,
+                "logId": "1",
+                "version": 4,
+                "action": "cancel",
+                "orderHash": "0x01",
+                "taker": "0x0000000000000000000000000000000000000000",
+                "remainingMakerAmount": "100",
+                "transactionHash": "0x03",
+                "blockNumber": 1,
+                "createDateTime": "2024-01-01T00:00:00.000Z"
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(record("100", "100").status(&[]), OrderStatus::Valid);
+        assert_eq!(record("100", "40").status(&[]), OrderStatus::PartiallyFilled);
+        assert_eq!(record("100", "0").status(&[]), OrderStatus::Filled);
+        assert_eq!(record("100", "100").status(&[cancel_event]), OrderStatus::Cancelled);
+
+        // Expiration at unix timestamp 1 shifted into its position in maker traits
+        let expired_traits = (BigUint::from(1u8) << 80u32).to_string();
+        let expired = record_with_traits("100", "100", &expired_traits);
+        assert_eq!(expired.expiration(), Some(1));
+        assert_eq!(expired.status(&[]), OrderStatus::Expired);
+    }
+}
@@ -0,0 +1,136 @@
+use crate::utils::runtime::{SystemTime, UNIX_EPOCH};
+use std::time::Duration;
+
+/// Policy of polling order statuses with adaptive intervals.
+///
+/// Status is polled often right after the order is submitted, when it's most
+/// likely to change, then the interval grows by `backoff_factor` after every
+/// poll up to `max_interval`. Within `near_expiry_window` before the order
+/// expires polling speeds up to `near_expiry_interval` again, so the final
+/// status is noticed soon. Interval never reaches past the expiry.
+///
+/// ```
+/// use one_inch::polling::PollingPolicy;
+/// use std::time::Duration;
+///
+/// let policy = PollingPolicy::new()
+///     .with_initial_interval(Duration::from_millis(500))
+///     .with_max_interval(Duration::from_secs(10));
+/// assert_eq!(policy.interval(0, None), Duration::from_millis(500));
+/// assert_eq!(policy.interval(100, None), Duration::from_secs(10));
+/// ```
+#[derive(Debug, Clone)]
+pub struct PollingPolicy {
+    initial_interval: Duration,
+    backoff_factor: f64,
+    max_interval: Duration,
+    near_expiry_window: Duration,
+    near_expiry_interval: Duration,
+}
+
+impl Default for PollingPolicy {
+    fn default() -> Self {
+        PollingPolicy {
+            initial_interval: Duration::from_secs(1),
+            backoff_factor: 1.5,
+            max_interval: Duration::from_secs(30),
+            near_expiry_window: Duration::from_secs(30),
+            near_expiry_interval: Duration::from_secs(2),
+        }
+    }
+}
+
+impl PollingPolicy {
+    /// Creates policy starting with 1s interval, growing 1.5 times per poll up
+    /// to 30s, and polling every 2s within 30s before the expiry.
+    pub fn new() -> PollingPolicy {
+        PollingPolicy::default()
+    }
+
+    /// Creates policy polling with the same interval regardless of the
+    /// expiry.
+    pub fn fixed(interval: Duration) -> PollingPolicy {
+        PollingPolicy {
+            initial_interval: interval,
+            backoff_factor: 1.0,
+            max_interval: interval,
+            near_expiry_window: Duration::ZERO,
+            near_expiry_interval: interval,
+        }
+    }
+
+    pub fn with_initial_interval(mut self, interval: Duration) -> PollingPolicy {
+        self.initial_interval = interval;
+        self
+    }
+
+    /// Sets factor the interval is multiplied by after every poll. Factors
+    /// below 1 are treated as 1.
+    pub fn with_backoff_factor(mut self, factor: f64) -> PollingPolicy {
+        self.backoff_factor = factor.max(1.0);
+        self
+    }
+
+    pub fn with_max_interval(mut self, interval: Duration) -> PollingPolicy {
+        self.max_interval = interval;
+        self
+    }
+
+    /// Sets how long before the expiry polling speeds up, and the interval
+    /// used then.
+    pub fn with_near_expiry(mut self, window: Duration, interval: Duration) -> PollingPolicy {
+        self.near_expiry_window = window;
+        self.near_expiry_interval = interval;
+        self
+    }
+
+    /// Returns interval before the next poll, following the poll with the
+    /// given index (starting from zero). `time_to_expiry` is the time left
+    /// until the order expires, if known.
+    pub fn interval(&self, poll: u32, time_to_expiry: Option<Duration>) -> Duration {
+        let exponent = i32::try_from(poll).unwrap_or(i32::MAX);
+        let backoff = self.initial_interval.as_secs_f64() * self.backoff_factor.powi(exponent);
+        let mut interval = Duration::try_from_secs_f64(backoff).unwrap_or(self.max_interval).min(self.max_interval);
+
+        if let Some(left) = time_to_expiry {
+            if left <= self.near_expiry_window {
+                interval = interval.min(self.near_expiry_interval);
+            }
+            // Poll right at the expiry rather than long after it
+            if !left.is_zero() {
+                interval = interval.min(left);
+            }
+        }
+
+        interval
+    }
+}
+
+// Returns time left until the unix timestamp, zero if it's passed.
+pub(crate) fn time_until(timestamp: u64) -> Duration {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+    Duration::from_secs(timestamp).saturating_sub(now)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interval_adapts_to_expiry() {
+        let policy = PollingPolicy::new();
+        assert_eq!(policy.interval(0, None), Duration::from_secs(1));
+        assert_eq!(policy.interval(2, None), Duration::from_millis(2250));
+        assert_eq!(policy.interval(50, None), Duration::from_secs(30));
+
+        // Far from the expiry the backoff applies, near it polling speeds up
+        assert_eq!(policy.interval(50, Some(Duration::from_secs(600))), Duration::from_secs(30));
+        assert_eq!(policy.interval(50, Some(Duration::from_secs(20))), Duration::from_secs(2));
+        assert_eq!(policy.interval(50, Some(Duration::from_secs(45))), Duration::from_secs(30));
+        assert_eq!(policy.interval(50, Some(Duration::from_millis(500))), Duration::from_millis(500));
+
+        let fixed = PollingPolicy::fixed(Duration::from_secs(5));
+        assert_eq!(fixed.interval(10, None), Duration::from_secs(5));
+        assert_eq!(fixed.interval(10, Some(Duration::from_secs(1))), Duration::from_secs(1));
+    }
+}
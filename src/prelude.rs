@@ -0,0 +1,54 @@
+//! Single import of the types most applications need, kept stable while
+//! internal module paths are reorganized.
+//!
+//! The prelude is versioned: `prelude::v1` only ever gains items, while
+//! breaking changes of its contents go into a new version. `prelude::*`
+//! re-exports the latest version.
+//!
+//! ```
+//! use one_inch::prelude::*;
+//!
+//! let client = new_with_default_http("token".to_string(), SupportedNetworks::Ethereum);
+//! let details = QuoteDetailsBuilder::new()
+//!     .src("0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee".to_string())
+//!     .dst("0x6b175474e89094c44da98b954eedeac495271d0f".to_string())
+//!     .amount("1000000000000000000".to_string())
+//!     .build();
+//! assert!(details.is_ok());
+//! # drop(client);
+//! ```
+
+/// First version of the prelude.
+pub mod v1 {
+    pub use crate::{
+        amount::{AmountError, AmountRole, RoundingPolicy},
+        auth::{ApiKeyError, ApiKeyPool, ApiKeyProvider},
+        cache::ResponseCache,
+        client::{
+            new_with_base_url, new_with_default_http, new_with_environment, new_with_http_client, new_with_key_pool,
+            new_with_key_provider, ClientBuilderError, OneInchClient, OneInchClientBuilder, SendError, SupportedCurrencies,
+            SupportedNetworks,
+        },
+        common::{
+            page::{paginate, Cursor, Page},
+            token::TokenInfo,
+        },
+        environment::Environment,
+        error::OneInchError,
+        fusion::{FusionOrderBuilder, FusionQuoteDetailsBuilder},
+        fusion_plus::{CrossChainOrderBuilder, CrossChainQuoteDetailsBuilder},
+        multichain::MultiChainClient,
+        orderbook::{AllOrdersRequestBuilder, LimitOrderBuilder},
+        polling::PollingPolicy,
+        rate_limit::RateLimiter,
+        retry::RetryPolicy,
+        swap::{
+            approve::{AllowanceDetailsBuilder, ApproveTranactionDetailsBuilder},
+            PreflightError, QuoteDetailsBuilder, SwapDetailsBuilder, SwapDetailsV6Builder,
+        },
+        timeout::Timeouts,
+        wallet::Wallet,
+    };
+}
+
+pub use v1::*;
@@ -0,0 +1,50 @@
+use crate::{
+    client::OneInchClient,
+    jitter::BackgroundTask,
+    price_feed::{PriceFeedConfig, PriceSeries},
+    tokens::tokens_price::TokensPricesRequestDetails,
+};
+use futures::{stream, Stream};
+use std::{collections::HashMap, error::Error, time::Duration};
+
+struct Feed {
+    client: OneInchClient,
+    addresses: Vec<String>,
+    config: PriceFeedConfig,
+    series: HashMap<String, PriceSeries>,
+    polled: bool,
+}
+
+impl OneInchClient {
+    /// Returns a stream which periodically polls spot prices of the tokens and
+    /// yields their series after every poll, keyed by lowercase token address.
+    /// Failed polls are yielded as errors and polling goes on.
+    pub fn price_feed(
+        &self,
+        addresses: Vec<String>,
+        config: PriceFeedConfig,
+    ) -> impl Stream<Item = Result<HashMap<String, PriceSeries>, Box<dyn Error>>> {
+        let feed = Feed { client: self.clone(), addresses, config, series: HashMap::new(), polled: false };
+
+        stream::unfold(feed, |mut feed| async move {
+            let interval = if feed.polled { feed.config.interval } else { Duration::ZERO };
+            feed.client.jitter.sleep(BackgroundTask::PriceFeed, interval).await;
+            feed.polled = true;
+
+            let details =
+                TokensPricesRequestDetails { addresses: feed.addresses.clone(), currency: Some(feed.config.currency.clone()) };
+            let response = match feed.client.get_tokens_price(details).await {
+                Ok(response) => response,
+                Err(e) => return Some((Err(e.into()), feed)),
+            };
+
+            for (token, price) in response.prices {
+                let Ok(price) = price.parse() else { continue };
+                let (smoothing, history) = (feed.config.smoothing, feed.config.history);
+                feed.series.entry(token).or_insert_with(|| PriceSeries::new(smoothing, history)).push(price);
+            }
+
+            Some((Ok(feed.series.clone()), feed))
+        })
+    }
+}
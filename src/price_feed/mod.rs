@@ -0,0 +1,6 @@
+mod feed;
+mod native;
+mod types;
+
+pub use native::*;
+pub use types::*;
@@ -0,0 +1,209 @@
+use crate::{
+    amount::{format_amount, RoundingPolicy},
+    client::{OneInchClient, SupportedCurrencies, SupportedNetworks},
+    error::OneInchError,
+    execution::{ExecutionError, PreparedSwap},
+    swap::{parse_amount, NATIVE_TOKEN_ADDRESS},
+    utils::runtime::Instant,
+};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use thiserror::Error;
+
+/// Decimals of the native tokens of all the supported networks.
+const NATIVE_DECIMALS: u8 = 18;
+
+/// USD prices of the native tokens per chain, shared by all clones of the
+/// client (including the ones of other chains), and used by every gas-in-USD
+/// computation of the crate, so they agree on the price and don't fetch it
+/// each on their own.
+///
+/// Prices are fetched from the spot price API on demand and reused until
+/// they are older than the max staleness. Manual overrides, like a price of
+/// a chain the spot price API lags on, take precedence and never go stale.
+///
+/// ```
+/// use one_inch::{client::SupportedNetworks, price_feed::NativePriceFeed};
+/// use std::time::Duration;
+///
+/// let feed = NativePriceFeed::new(Duration::from_secs(30));
+/// feed.set_override(SupportedNetworks::Gnosis, 1.0);
+/// assert_eq!(feed.cached(SupportedNetworks::Gnosis), Some(1.0));
+/// assert_eq!(feed.cached(SupportedNetworks::Ethereum), None);
+/// ```
+#[derive(Debug)]
+pub struct NativePriceFeed {
+    max_staleness: Duration,
+    // By chain id
+    prices: Mutex<HashMap<u32, (Instant, f64)>>,
+    overrides: Mutex<HashMap<u32, f64>>,
+}
+
+/// Enumerates errors of the native token prices.
+#[derive(Error, Debug)]
+pub enum NativePriceError {
+    /// Spot price API didn't return price of the chain's native token.
+    #[error("Price of the native token on {0} is unavailable")]
+    Unavailable(SupportedNetworks),
+
+    /// Request to the spot price API failed, or the amounts to price are
+    /// invalid.
+    #[error(transparent)]
+    Request(#[from] OneInchError),
+
+    /// Prepared swap can't be priced, e.g. because it has expired.
+    #[error(transparent)]
+    Swap(#[from] ExecutionError),
+}
+
+impl Default for NativePriceFeed {
+    fn default() -> Self {
+        NativePriceFeed::new(Duration::from_secs(60))
+    }
+}
+
+impl NativePriceFeed {
+    /// Creates feed reusing fetched prices for `max_staleness`.
+    pub fn new(max_staleness: Duration) -> NativePriceFeed {
+        NativePriceFeed { max_staleness, prices: Mutex::new(HashMap::new()), overrides: Mutex::new(HashMap::new()) }
+    }
+
+    pub fn max_staleness(&self) -> Duration {
+        self.max_staleness
+    }
+
+    /// Sets USD price of the chain's native token, used instead of the
+    /// fetched one until it's cleared.
+    pub fn set_override(&self, network: SupportedNetworks, usd: f64) {
+        self.overrides.lock().unwrap().insert(network as u32, usd);
+    }
+
+    pub fn clear_override(&self, network: SupportedNetworks) {
+        self.overrides.lock().unwrap().remove(&(network as u32));
+    }
+
+    /// Returns USD price of the chain's native token if it's overridden or
+    /// fetched no longer than the max staleness ago.
+    pub fn cached(&self, network: SupportedNetworks) -> Option<f64> {
+        if let Some(usd) = self.overrides.lock().unwrap().get(&(network as u32)) {
+            return Some(*usd);
+        }
+
+        let prices = self.prices.lock().unwrap();
+        prices.get(&(network as u32)).filter(|(fetched_at, _)| fetched_at.elapsed() < self.max_staleness).map(|(_, usd)| *usd)
+    }
+
+    fn update(&self, network: SupportedNetworks, usd: f64) {
+        self.prices.lock().unwrap().insert(network as u32, (Instant::now(), usd));
+    }
+}
+
+impl OneInchClient {
+    /// Replaces the built-in feed of the native token prices, e.g. to change
+    /// its staleness bound or share one feed between separately created
+    /// clients.
+    pub fn with_native_price_feed(mut self, feed: Arc<NativePriceFeed>) -> OneInchClient {
+        self.native_prices = feed;
+        self
+    }
+
+    pub fn native_price_feed(&self) -> &Arc<NativePriceFeed> {
+        &self.native_prices
+    }
+
+    /// Returns USD price of the native token of the client's network, from
+    /// the feed or fetched from the spot price API if the feed's one is stale.
+    pub async fn native_price_usd(&self) -> Result<f64, NativePriceError> {
+        if let Some(usd) = self.native_prices.cached(self.network_id) {
+            return Ok(usd);
+        }
+
+        // Single address never needs chunking
+        let response =
+            self.get_tokens_price_chunk(vec![NATIVE_TOKEN_ADDRESS.to_string()], Some(SupportedCurrencies::USD)).await?;
+        let usd = response
+            .prices
+            .get(NATIVE_TOKEN_ADDRESS)
+            .and_then(|price| price.parse::<f64>().ok())
+            .ok_or(NativePriceError::Unavailable(self.network_id))?;
+
+        self.native_prices.update(self.network_id, usd);
+        Ok(usd)
+    }
+
+    /// Returns USD cost of `gas` units at the gas price, in wei.
+    pub async fn gas_cost_usd(&self, gas: u128, gas_price: &str) -> Result<f64, NativePriceError> {
+        let cost = parse_amount(gas_price)? * gas;
+        let cost: f64 = format_amount(&cost.to_string(), NATIVE_DECIMALS, NATIVE_DECIMALS, RoundingPolicy::Floor)
+            .ok()
+            .and_then(|cost| cost.parse().ok())
+            .ok_or_else(|| OneInchError::Validation(format!("invalid gas cost: {}", cost)))?;
+
+        Ok(cost * self.native_price_usd().await?)
+    }
+
+    /// Returns USD cost of the gas of the prepared swap's transaction, by its
+    /// gas limit and price.
+    pub async fn swap_gas_cost_usd(&self, swap: &PreparedSwap) -> Result<f64, NativePriceError> {
+        let tx = swap.transaction()?;
+        self.gas_cost_usd(tx.gas, &tx.gas_price).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::new_with_base_url,
+        utils::{assert_send, mock_server::MockServer},
+    };
+
+    #[tokio::test]
+    async fn test_gas_cost_uses_shared_native_price() {
+        let server = MockServer::start(r#"{"0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee": "2000.5"}"#).await;
+        let client = new_with_base_url("token".to_string(), SupportedNetworks::Ethereum, &server.base_url);
+
+        // 21000 gas at 10 gwei is 0.00021 ETH
+        let cost = client.gas_cost_usd(21_000, "10000000000").await.unwrap();
+        assert!((cost - 0.00021 * 2000.5).abs() < 1e-9);
+        client.native_price_usd().await.unwrap();
+        assert_eq!(server.requests().len(), 1);
+
+        // Clones of other chains share the feed, but not the price
+        let polygon = client.with_chain(SupportedNetworks::Polygon);
+        polygon.native_price_feed().set_override(SupportedNetworks::Polygon, 0.5);
+        assert_eq!(polygon.native_price_usd().await.unwrap(), 0.5);
+        assert_eq!(client.native_price_feed().cached(SupportedNetworks::Polygon), Some(0.5));
+        assert_eq!(server.requests().len(), 1);
+
+        assert!(client.gas_cost_usd(1, "0x1").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_native_price_keeps_api_errors() {
+        let server = MockServer::start_with_status(
+            400,
+            r#"{"error": "Bad Request", "description": "unsupported chain", "statusCode": 400, "requestId": "id"}"#,
+        )
+        .await;
+        let client = new_with_base_url("token".to_string(), SupportedNetworks::Ethereum, &server.base_url);
+
+        let error = client.native_price_usd().await.unwrap_err();
+        assert!(
+            matches!(error, NativePriceError::Request(OneInchError::Api { status_code: 400, .. })),
+            "{:?}",
+            error
+        );
+        assert!(error.to_string().contains("unsupported chain"));
+    }
+
+    #[test]
+    fn test_native_price_futures_are_send() {
+        let client = new_with_base_url("token".to_string(), SupportedNetworks::Ethereum, "http://localhost");
+        assert_send(client.native_price_usd());
+        assert_send(client.gas_cost_usd(21_000, "1"));
+    }
+}
@@ -0,0 +1,128 @@
+use crate::client::SupportedCurrencies;
+use std::{collections::VecDeque, time::Duration};
+
+/// Smoothing applied to the price series, so single-sample spikes of the spot
+/// price don't trip trigger conditions.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Smoothing {
+    /// Exponential moving average over roughly `samples` samples, i.e. with
+    /// `2 / (samples + 1)` smoothing factor.
+    Ema { samples: usize },
+    /// Median of the last `samples` samples.
+    Median { samples: usize },
+}
+
+/// Configuration of the price feed.
+#[derive(Debug, Clone)]
+pub struct PriceFeedConfig {
+    /// Interval between polls of the prices.
+    pub interval: Duration,
+    /// Currency prices are requested in.
+    pub currency: SupportedCurrencies,
+    /// Smoothing of the prices, none by default.
+    pub smoothing: Option<Smoothing>,
+    /// Amount of the latest samples kept in the series.
+    pub history: usize,
+}
+
+impl Default for PriceFeedConfig {
+    fn default() -> Self {
+        PriceFeedConfig { interval: Duration::from_secs(10), currency: SupportedCurrencies::USD, smoothing: None, history: 100 }
+    }
+}
+
+/// Latest samples of the token price, both raw and smoothed. Without
+/// smoothing both series are the same.
+#[derive(Debug, Clone)]
+pub struct PriceSeries {
+    smoothing: Option<Smoothing>,
+    history: usize,
+    raw: VecDeque<f64>,
+    smoothed: VecDeque<f64>,
+}
+
+impl PriceSeries {
+    /// Creates empty series keeping `history` latest samples.
+    pub fn new(smoothing: Option<Smoothing>, history: usize) -> PriceSeries {
+        PriceSeries { smoothing, history: history.max(1), raw: VecDeque::new(), smoothed: VecDeque::new() }
+    }
+
+    /// Adds raw sample to the series and returns its smoothed value.
+    pub fn push(&mut self, price: f64) -> f64 {
+        self.raw.push_back(price);
+
+        let smoothed = match self.smoothing {
+            None => price,
+            Some(Smoothing::Ema { samples }) => {
+                let alpha = 2.0 / (samples.max(1) as f64 + 1.0);
+                match self.smoothed.back() {
+                    Some(previous) => alpha * price + (1.0 - alpha) * previous,
+                    None => price,
+                }
+            }
+            Some(Smoothing::Median { samples }) => {
+                let mut window: Vec<f64> = self.raw.iter().rev().take(samples.max(1)).copied().collect();
+                window.sort_by(f64::total_cmp);
+                let middle = window.len() / 2;
+                match window.len() % 2 {
+                    0 => (window[middle - 1] + window[middle]) / 2.0,
+                    _ => window[middle],
+                }
+            }
+        };
+        self.smoothed.push_back(smoothed);
+
+        // Median window is limited by the kept history as well
+        if self.raw.len() > self.history {
+            self.raw.pop_front();
+            self.smoothed.pop_front();
+        }
+
+        smoothed
+    }
+
+    /// Returns raw samples, from the oldest to the latest.
+    pub fn raw(&self) -> &VecDeque<f64> {
+        &self.raw
+    }
+
+    /// Returns smoothed samples, from the oldest to the latest.
+    pub fn smoothed(&self) -> &VecDeque<f64> {
+        &self.smoothed
+    }
+
+    /// Returns the latest raw sample.
+    pub fn latest_raw(&self) -> Option<f64> {
+        self.raw.back().copied()
+    }
+
+    /// Returns the latest smoothed sample.
+    pub fn latest_smoothed(&self) -> Option<f64> {
+        self.smoothed.back().copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_smoothing_suppresses_spikes() {
+        let samples = [100.0, 100.0, 150.0, 100.0];
+
+        let mut median = PriceSeries::new(Some(Smoothing::Median { samples: 3 }), 10);
+        let smoothed: Vec<f64> = samples.iter().map(|p| median.push(*p)).collect();
+        assert_eq!(smoothed, vec![100.0, 100.0, 100.0, 100.0]);
+        assert_eq!(median.latest_raw(), Some(100.0));
+        assert_eq!(median.raw().iter().copied().max_by(f64::total_cmp), Some(150.0));
+
+        let mut ema = PriceSeries::new(Some(Smoothing::Ema { samples: 3 }), 2);
+        samples.iter().for_each(|p| {
+            ema.push(*p);
+        });
+        assert_eq!(ema.smoothed(), &VecDeque::from(vec![125.0, 112.5]));
+
+        let mut raw = PriceSeries::new(None, 10);
+        assert_eq!(raw.push(150.0), 150.0);
+    }
+}
@@ -0,0 +1,331 @@
+use crate::{
+    environment::is_valid_rps,
+    utils::runtime::{self, Instant},
+};
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use std::{collections::HashMap, sync::Mutex, time::Duration};
+use thiserror::Error;
+
+/// Client-side requests-per-second limiter based on token buckets.
+///
+/// The RPS budget can be partitioned between named tenants (strategies,
+/// modules, etc) sharing one client. Each tenant gets a guaranteed share of the
+/// budget proportional to its weight, so a busy tenant can't starve others.
+/// Capacity left unused by idle tenants goes to a spare pool which any tenant
+/// may borrow from, so the budget isn't wasted either. Tokens of the partitions
+/// and the spare pool never add up to more than the burst. Requests without a
+/// tenant (or of an unknown one) are served from the spare pool only.
+///
+/// ```
+/// use one_inch::rate_limit::RateLimiter;
+///
+/// // 70% of 10 RPS are reserved for execution and 30% for analytics.
+/// let limiter = RateLimiter::new(10.0).unwrap().partition("execution", 7).partition("analytics", 3);
+/// assert!(RateLimiter::new(0.0).is_err());
+/// ```
+#[derive(Debug)]
+pub struct RateLimiter {
+    rps: f64,
+    burst: f64,
+    state: Mutex<LimiterState>,
+}
+
+/// Enumerates errors of the rate limiter configuration.
+#[derive(Error, Debug, PartialEq)]
+pub enum RateLimitError {
+    /// Indicates the RPS budget isn't a positive finite number.
+    #[error("RPS budget should be a positive number, got {0}")]
+    InvalidRps(f64),
+
+    /// Indicates the burst is zero.
+    #[error("Burst should be positive")]
+    InvalidBurst,
+}
+
+#[derive(Debug)]
+struct LimiterState {
+    partitions: HashMap<String, Bucket>,
+    spare: Bucket,
+    total_weight: u32,
+    last_refill: Instant,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    weight: u32,
+    rate: f64,
+    capacity: f64,
+    tokens: f64,
+}
+
+impl Bucket {
+    fn new(weight: u32, rate: f64, capacity: f64) -> Bucket {
+        let capacity = capacity.max(1.0);
+        Bucket { weight, rate, capacity, tokens: capacity }
+    }
+
+    // Adds tokens to the bucket and returns amount which doesn't fit into it.
+    fn refill(&mut self, tokens: f64) -> f64 {
+        self.tokens += tokens;
+        let overflow = (self.tokens - self.capacity).max(0.0);
+        self.tokens -= overflow;
+        overflow
+    }
+
+    fn try_take(&mut self) -> bool {
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            return true;
+        }
+        false
+    }
+}
+
+impl RateLimiter {
+    /// Creates a limiter allowing `rps` requests per second in total, with
+    /// bursts of up to one second worth of requests.
+    pub fn new(rps: f64) -> Result<RateLimiter, RateLimitError> {
+        if !is_valid_rps(rps) {
+            return Err(RateLimitError::InvalidRps(rps));
+        }
+
+        Ok(RateLimiter {
+            rps,
+            burst: rps,
+            state: Mutex::new(LimiterState {
+                partitions: HashMap::new(),
+                spare: Bucket::new(0, rps, rps),
+                total_weight: 0,
+                last_refill: Instant::now(),
+            }),
+        })
+    }
+
+    /// Reserves a share of the budget proportional to `weight` for the tenant.
+    pub fn partition(self, tenant: impl Into<String>, weight: u32) -> RateLimiter {
+        {
+            let mut state = self.state.lock().unwrap();
+            state.partitions.insert(tenant.into(), Bucket::new(weight, 0.0, 0.0));
+            state.total_weight = state.partitions.values().map(|b| b.weight).sum();
+        }
+        self.rebuild_buckets();
+        self
+    }
+
+    /// Sets amount of requests which can be performed at once after the
+    /// limiter has been idle. Partitions get shares of the burst proportional
+    /// to their weights. Lower burst smooths the requests out, which suits
+    /// plans counting requests over short windows.
+    pub fn with_burst(mut self, burst: u32) -> Result<RateLimiter, RateLimitError> {
+        if burst == 0 {
+            return Err(RateLimitError::InvalidBurst);
+        }
+
+        self.burst = burst as f64;
+        self.rebuild_buckets();
+        Ok(self)
+    }
+
+    // Resets buckets to the current budget, burst and partition weights.
+    fn rebuild_buckets(&self) {
+        let mut state = self.state.lock().unwrap();
+        let total_weight = state.total_weight.max(1) as f64;
+        for bucket in state.partitions.values_mut() {
+            let share = bucket.weight as f64 / total_weight;
+            *bucket = Bucket::new(bucket.weight, self.rps * share, self.burst * share);
+        }
+
+        // Spare pool starts with the share of the burst partitions don't
+        // reserve
+        let reserved: f64 = state.partitions.values().map(|b| b.tokens).sum();
+        state.spare = Bucket::new(0, 0.0, self.burst);
+        state.spare.tokens = (self.burst - reserved).max(0.0);
+    }
+
+    /// Returns the total RPS budget.
+    pub fn rps(&self) -> f64 {
+        self.rps
+    }
+
+    /// Returns amount of requests which can be performed at once.
+    pub fn burst(&self) -> f64 {
+        self.burst
+    }
+
+    /// Waits until the tenant is allowed to perform a request.
+    pub async fn acquire(&self, tenant: Option<&str>) {
+        while let Err(wait) = self.try_acquire(tenant) {
+            runtime::sleep(wait).await;
+        }
+    }
+
+    /// Takes a permit for the request if there is one, otherwise returns time
+    /// to wait before trying again.
+    pub fn try_acquire(&self, tenant: Option<&str>) -> Result<(), Duration> {
+        let mut state = self.state.lock().unwrap();
+        state.refill(self.rps, self.burst);
+
+        if let Some(bucket) = tenant.and_then(|t| state.partitions.get_mut(t)) {
+            if bucket.try_take() {
+                return Ok(());
+            }
+        }
+
+        if state.spare.try_take() {
+            return Ok(());
+        }
+
+        Err(Duration::from_secs_f64(1.0 / self.rps))
+    }
+}
+
+impl LimiterState {
+    fn refill(&mut self, rps: f64, burst: f64) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        self.last_refill = now;
+
+        // Without partitions whole budget goes to the spare pool, otherwise it
+        // gets only what partitions can't hold.
+        let mut spare_tokens = if self.total_weight == 0 { rps * elapsed } else { 0.0 };
+        for bucket in self.partitions.values_mut() {
+            spare_tokens += bucket.refill(bucket.rate * elapsed);
+        }
+        self.spare.refill(spare_tokens);
+
+        // Spare pool holds only what partitions leave of the burst
+        let reserved: f64 = self.partitions.values().map(|b| b.tokens).sum();
+        self.spare.tokens = self.spare.tokens.min((burst - reserved).max(0.0));
+    }
+}
+
+/// Quota of the API key as reported by the rate limit headers of the API
+/// response.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Quota {
+    /// Amount of requests allowed in the current window.
+    pub limit: Option<u64>,
+    /// Amount of requests left in the current window.
+    pub remaining: Option<u64>,
+    /// Time left before the window resets.
+    pub reset: Option<Duration>,
+}
+
+impl Quota {
+    /// Reads quota from `X-RateLimit-*` headers, `None` if the response has
+    /// none of them.
+    pub fn from_headers(headers: &HeaderMap) -> Option<Quota> {
+        let header = |name: &str| headers.get(name).and_then(|value| value.to_str().ok()?.trim().parse::<u64>().ok());
+
+        let quota = Quota {
+            limit: header("x-ratelimit-limit"),
+            remaining: header("x-ratelimit-remaining"),
+            reset: header("x-ratelimit-reset").map(Duration::from_secs),
+        };
+        (quota != Quota::default()).then_some(quota)
+    }
+}
+
+/// Tracker of the quota reported by the latest response, shared by all clones
+/// of the client.
+#[derive(Debug, Default)]
+pub(crate) struct QuotaTracker {
+    last: Mutex<Option<Quota>>,
+}
+
+impl QuotaTracker {
+    pub(crate) fn update(&self, headers: &HeaderMap) {
+        if let Some(quota) = Quota::from_headers(headers) {
+            *self.last.lock().unwrap() = Some(quota);
+        }
+    }
+
+    pub(crate) fn last(&self) -> Option<Quota> {
+        *self.last.lock().unwrap()
+    }
+}
+
+/// Reads `Retry-After` header given in seconds. HTTP date form isn't
+/// supported and is ignored.
+pub(crate) fn parse_retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?;
+    value.trim().parse::<u64>().ok().map(Duration::from_secs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invalid_budget_is_rejected() {
+        for rps in [0.0, -1.0, f64::INFINITY] {
+            assert_eq!(RateLimiter::new(rps).unwrap_err(), RateLimitError::InvalidRps(rps));
+        }
+        assert!(RateLimiter::new(f64::NAN).is_err());
+        assert_eq!(RateLimiter::new(1.0).unwrap().with_burst(0).unwrap_err(), RateLimitError::InvalidBurst);
+    }
+
+    #[test]
+    fn test_partitions_split_budget_by_weight() {
+        let limiter = RateLimiter::new(10.0).unwrap().partition("execution", 7).partition("analytics", 3);
+
+        let state = limiter.state.lock().unwrap();
+        assert_eq!(state.partitions["execution"].rate, 7.0);
+        assert_eq!(state.partitions["analytics"].rate, 3.0);
+    }
+
+    #[test]
+    fn test_busy_tenant_does_not_starve_others() {
+        let limiter = RateLimiter::new(10.0).unwrap().partition("execution", 7).partition("analytics", 3);
+
+        // Analytics exhausts its own share, the rest of the burst is reserved.
+        let mut analytics_permits = 0;
+        while limiter.try_acquire(Some("analytics")).is_ok() {
+            analytics_permits += 1;
+        }
+        assert_eq!(analytics_permits, 3);
+
+        // Execution still has its guaranteed share.
+        let mut execution_permits = 0;
+        while limiter.try_acquire(Some("execution")).is_ok() {
+            execution_permits += 1;
+        }
+        assert_eq!(execution_permits, 7);
+        assert_eq!(analytics_permits + execution_permits, 10);
+    }
+
+    #[test]
+    fn test_unknown_tenant_is_served_from_spare_pool() {
+        let limiter = RateLimiter::new(2.0).unwrap();
+
+        assert!(limiter.try_acquire(None).is_ok());
+        assert!(limiter.try_acquire(Some("unknown")).is_ok());
+        assert!(limiter.try_acquire(None).is_err());
+    }
+
+    #[test]
+    fn test_rate_limit_headers() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(Quota::from_headers(&headers), None);
+
+        headers.insert(RETRY_AFTER, "3".parse().unwrap());
+        headers.insert("x-ratelimit-remaining", "0".parse().unwrap());
+        headers.insert("x-ratelimit-limit", "100".parse().unwrap());
+        assert_eq!(parse_retry_after(&headers), Some(Duration::from_secs(3)));
+        assert_eq!(
+            Quota::from_headers(&headers),
+            Some(Quota { limit: Some(100), remaining: Some(0), reset: None })
+        );
+
+        headers.insert(RETRY_AFTER, "Wed, 21 Oct 2015 07:28:00 GMT".parse().unwrap());
+        assert_eq!(parse_retry_after(&headers), None);
+    }
+
+    #[test]
+    fn test_burst_limits_requests_at_once() {
+        let limiter = RateLimiter::new(10.0).unwrap().with_burst(2).unwrap();
+        assert!(limiter.try_acquire(None).is_ok());
+        assert!(limiter.try_acquire(None).is_ok());
+        assert!(limiter.try_acquire(None).is_err());
+    }
+}
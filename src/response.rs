@@ -0,0 +1,422 @@
+use crate::{
+    cache::{CacheLookup, Revalidation},
+    client::OneInchClient,
+    error::OneInchError,
+    swap::SwapRequestError,
+    utils::runtime::{self, Instant},
+};
+use futures::StreamExt;
+use reqwest::{
+    header::{HeaderMap, CONTENT_TYPE},
+    RequestBuilder, Response,
+};
+use serde::de::{DeserializeOwned, IgnoredAny};
+use std::{collections::HashMap, ops::Deref, sync::Arc, time::Duration};
+use thiserror::Error;
+
+// Max amount of bytes of the partially received body attached to the error.
+const PREVIEW_LEN: usize = 512;
+
+/// Response header with id of the request, which 1inch support asks for when
+/// investigating failures.
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Response of the API along with id of its request from the
+/// [`REQUEST_ID_HEADER`]. Dereferences to the response itself.
+#[derive(Debug, Clone)]
+pub struct WithRequestId<T> {
+    pub value: T,
+    /// Id of the request, `None` if the API didn't provide one or the
+    /// response was served from the cache.
+    pub request_id: Option<String>,
+}
+
+impl<T> WithRequestId<T> {
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> Deref for WithRequestId<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+pub(crate) fn request_id(headers: &HeaderMap) -> Option<String> {
+    headers.get(REQUEST_ID_HEADER).and_then(|id| id.to_str().ok()).map(str::to_string)
+}
+
+/// Limits of the response body size and read time. Body which exceeds the
+/// limit is not read any further, so an unexpectedly huge payload can't exhaust
+/// memory, and slow upstream can't hang the request.
+///
+/// Endpoints are identified by names like `swap/tokens` or `orderbook/all`,
+/// where the first segment is the product and the rest is the endpoint path
+/// without network and parameters.
+#[derive(Debug, Clone, Default)]
+pub struct ResponseLimits {
+    default: Option<usize>,
+    per_endpoint: HashMap<String, usize>,
+    body_timeout: Option<Duration>,
+}
+
+impl ResponseLimits {
+    /// Creates limits with no restrictions.
+    pub fn new() -> ResponseLimits {
+        ResponseLimits::default()
+    }
+
+    /// Sets max body size in bytes for all the endpoints without their own
+    /// limit.
+    pub fn default_limit(mut self, max_bytes: usize) -> ResponseLimits {
+        self.default = Some(max_bytes);
+        self
+    }
+
+    /// Sets max body size in bytes for the specific endpoint.
+    pub fn endpoint_limit(mut self, endpoint: impl Into<String>, max_bytes: usize) -> ResponseLimits {
+        self.per_endpoint.insert(endpoint.into(), max_bytes);
+        self
+    }
+
+    /// Sets max time of reading the body, counted from receiving response
+    /// headers.
+    pub fn body_timeout(mut self, timeout: Duration) -> ResponseLimits {
+        self.body_timeout = Some(timeout);
+        self
+    }
+
+    /// Returns the limit applied to the endpoint, if any.
+    pub fn limit_for(&self, endpoint: &str) -> Option<usize> {
+        self.per_endpoint.get(endpoint).copied().or(self.default)
+    }
+}
+
+/// Represents errors that can occur while reading response body.
+#[derive(Error, Debug)]
+pub enum ResponseBodyError {
+    /// Body is larger than the limit configured for the endpoint.
+    #[error("Response of {endpoint} exceeds the limit of {limit} bytes")]
+    TooLarge { endpoint: &'static str, limit: usize },
+
+    /// Body wasn't received completely in time. Contains preview of the bytes
+    /// received so far, for diagnostics.
+    #[error("Response of {endpoint} timed out after {received} bytes received: {preview}")]
+    Timeout { endpoint: &'static str, received: usize, preview: String },
+
+    /// Body couldn't be read because of a network error.
+    #[error("Network error: {0}")]
+    Network(#[from] reqwest::Error),
+
+    /// Body couldn't be written to the file.
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+impl OneInchClient {
+    /// Reads response body, respecting the size limit of the endpoint.
+    pub(crate) async fn read_body(&self, endpoint: &'static str, response: Response) -> Result<Vec<u8>, ResponseBodyError> {
+        let limit = self.response_limits.limit_for(endpoint);
+        let too_large = |limit| ResponseBodyError::TooLarge { endpoint, limit };
+
+        // Refusing early if server declares the body is too large
+        if let (Some(limit), Some(length)) = (limit, response.content_length()) {
+            if length as usize > limit {
+                return Err(too_large(limit));
+            }
+        }
+
+        let deadline = self.response_limits.body_timeout.map(|timeout| Instant::now() + timeout);
+        let timed_out = |body: &[u8]| ResponseBodyError::Timeout { endpoint, received: body.len(), preview: preview(body) };
+
+        let mut chunks = Box::pin(runtime::body_chunks(response));
+        let mut body = Vec::new();
+        loop {
+            let chunk = match deadline {
+                Some(deadline) => runtime::timeout(deadline.saturating_duration_since(Instant::now()), chunks.next())
+                    .await
+                    .map_err(|_| timed_out(&body))?,
+                None => chunks.next().await,
+            };
+
+            // Timeouts of the http client are salvaged the same way
+            let chunk = match chunk {
+                Some(Ok(chunk)) => chunk,
+                None => break,
+                Some(Err(e)) if e.is_timeout() => return Err(timed_out(&body)),
+                Some(Err(e)) => return Err(e.into()),
+            };
+            body.extend_from_slice(chunk.as_ref());
+
+            if let Some(limit) = limit.filter(|limit| body.len() > *limit) {
+                return Err(too_large(limit));
+            }
+        }
+
+        Ok(body)
+    }
+
+    /// Reads response body respecting the size limit of the endpoint and
+    /// deserializes it from JSON.
+    pub(crate) async fn read_json<T: DeserializeOwned>(
+        &self,
+        endpoint: &'static str,
+        response: Response,
+    ) -> Result<T, OneInchError> {
+        let body = self.read_body(endpoint, response).await?;
+        Ok(serde_json::from_slice(&body)?)
+    }
+
+    /// Sends request to the endpoint and deserializes its response. Error
+    /// statuses are turned into [`OneInchError::Api`], with the description
+    /// of the error if the API provided one, or into
+    /// [`OneInchError::UpstreamUnavailable`] if a server error has no JSON
+    /// body.
+    pub(crate) async fn request_json<T: DeserializeOwned>(
+        &self,
+        endpoint: &'static str,
+        request: RequestBuilder,
+    ) -> Result<T, OneInchError> {
+        Ok(self.request_json_with_id::<T>(endpoint, request).await?.into_inner())
+    }
+
+    /// Sends request like [`request_json`](OneInchClient::request_json),
+    /// returning the response along with id of the request.
+    pub(crate) async fn request_json_with_id<T: DeserializeOwned>(
+        &self,
+        endpoint: &'static str,
+        request: RequestBuilder,
+    ) -> Result<WithRequestId<T>, OneInchError> {
+        let cached_url = match &self.response_cache {
+            Some(cache) if cache.ttl_for(endpoint).is_some() => {
+                request.try_clone().and_then(|request| request.build().ok()).map(|request| request.url().to_string())
+            }
+            _ => None,
+        };
+        if let (Some(cache), Some(url)) = (&self.response_cache, &cached_url) {
+            match cache.get(endpoint, url) {
+                CacheLookup::Fresh(body) | CacheLookup::Stale(body, false) => {
+                    return Ok(WithRequestId { value: serde_json::from_slice(&body)?, request_id: None })
+                }
+                CacheLookup::Stale(body, true) => {
+                    let client = self.clone();
+                    let revalidation = cache.revalidation(endpoint, url);
+                    let url = url.clone();
+                    runtime::spawn(async move { client.revalidate(endpoint, request, url, revalidation).await });
+                    return Ok(WithRequestId { value: serde_json::from_slice(&body)?, request_id: None });
+                }
+                CacheLookup::Miss => {}
+            }
+        }
+
+        let response = self.send(endpoint, request).await?;
+        let request_id = request_id(response.headers());
+
+        let status_code = response.status().as_u16();
+        if response.status().is_client_error() || response.status().is_server_error() {
+            let content_type =
+                response.headers().get(CONTENT_TYPE).and_then(|content_type| content_type.to_str().ok()).map(str::to_string);
+            let error_body = self.read_body(endpoint, response).await.unwrap_or_default();
+            #[cfg(feature = "trace-bodies")]
+            tracing::debug!(endpoint, body = %String::from_utf8_lossy(&error_body), "API rejected the request");
+            #[cfg(all(feature = "tracing", not(feature = "trace-bodies")))]
+            tracing::debug!(endpoint, status_code, "API rejected the request");
+
+            return Err(error_response(status_code, content_type, request_id, &error_body));
+        }
+
+        let body = match self.read_body(endpoint, response).await {
+            Ok(body) => body,
+            Err(e) => return Err(OneInchError::from(e).with_request_id(request_id)),
+        };
+        #[cfg(feature = "trace-bodies")]
+        tracing::trace!(endpoint, body = %String::from_utf8_lossy(&body), "API responded");
+        let value = match serde_json::from_slice(&body) {
+            Ok(value) => value,
+            Err(e) => return Err(OneInchError::from(e).with_request_id(request_id)),
+        };
+        if let (Some(cache), Some(url)) = (&self.response_cache, &cached_url) {
+            cache.insert(endpoint, url, Arc::new(body));
+        }
+
+        Ok(WithRequestId { value, request_id })
+    }
+
+    // Refreshes the stale cached response of the request. Failures are only
+    // logged, the stale response is served until it expires.
+    async fn revalidate(&self, endpoint: &'static str, request: RequestBuilder, url: String, _revalidation: Revalidation) {
+        let Some(cache) = &self.response_cache else {
+            return;
+        };
+
+        let refreshed = async {
+            let response = self.send(endpoint, request).await?.error_for_status()?;
+            let body = self.read_body(endpoint, response).await?;
+            serde_json::from_slice::<IgnoredAny>(&body)?;
+            Ok::<_, Box<dyn std::error::Error>>(body)
+        };
+        match refreshed.await {
+            Ok(body) => cache.insert(endpoint, &url, Arc::new(body)),
+            Err(e) => tracing::debug!(endpoint, "stale response can't be refreshed: {}", e),
+        }
+    }
+
+    /// Streams response body to the file without holding it in memory and
+    /// without applying size limits. Returns amount of bytes written.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) async fn stream_body_to_file(
+        &self,
+        mut response: Response,
+        path: &std::path::Path,
+    ) -> Result<u64, ResponseBodyError> {
+        use tokio::{fs::File, io::AsyncWriteExt};
+
+        let mut file = File::create(path).await?;
+        let mut written = 0;
+
+        while let Some(chunk) = response.chunk().await? {
+            file.write_all(&chunk).await?;
+            written += chunk.len() as u64;
+        }
+        file.flush().await?;
+
+        Ok(written)
+    }
+}
+
+// Converts error response into the error. Server errors whose body isn't
+// JSON come from the gateway rather than the API, so they are reported with
+// the body sample instead of as a parse error.
+fn error_response(status_code: u16, content_type: Option<String>, request_id: Option<String>, body: &[u8]) -> OneInchError {
+    if let Ok(err) = serde_json::from_slice::<SwapRequestError>(body) {
+        return OneInchError::Api {
+            status_code,
+            error: Some(err.error),
+            description: Some(err.description),
+            request_id: Some(err.request_id),
+        };
+    }
+
+    let is_json = content_type.as_deref().is_some_and(|content_type| content_type.contains("json"))
+        && serde_json::from_slice::<serde::de::IgnoredAny>(body).is_ok();
+    if status_code >= 500 && !is_json {
+        return OneInchError::UpstreamUnavailable {
+            status_code,
+            content_type,
+            body_sample: preview(body.trim_ascii()),
+            request_id,
+        };
+    }
+
+    OneInchError::Api { status_code, error: None, description: None, request_id }
+}
+
+// Returns lossy UTF-8 preview of the partially received body, truncated to
+// `PREVIEW_LEN` bytes.
+fn preview(body: &[u8]) -> String {
+    let mut preview = String::from_utf8_lossy(&body[..body.len().min(PREVIEW_LEN)]).into_owned();
+    if body.len() > PREVIEW_LEN {
+        preview.push_str("...");
+    }
+    preview
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::{new_with_base_url, SupportedNetworks},
+        swap::{QuoteDetailsBuilder, SwapDetailsBuilder},
+        utils::mock_server::MockServer,
+    };
+
+    #[test]
+    fn test_endpoint_limit_takes_precedence_over_default() {
+        let limits = ResponseLimits::new().default_limit(1024).endpoint_limit("swap/tokens", 4096);
+
+        assert_eq!(limits.limit_for("swap/tokens"), Some(4096));
+        assert_eq!(limits.limit_for("swap/quote"), Some(1024));
+        assert_eq!(ResponseLimits::new().limit_for("swap/quote"), None);
+    }
+
+    #[test]
+    fn test_partial_body_preview_is_truncated() {
+        assert_eq!(preview(b"{\"tokens\": {"), "{\"tokens\": {");
+
+        let long = vec![b'a'; PREVIEW_LEN + 1];
+        assert_eq!(preview(&long), format!("{}...", "a".repeat(PREVIEW_LEN)));
+    }
+
+    #[test]
+    fn test_request_id_is_read_from_header() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(request_id(&headers), None);
+        headers.insert(REQUEST_ID_HEADER, "9f1c".parse().unwrap());
+        assert_eq!(request_id(&headers).as_deref(), Some("9f1c"));
+
+        let bare = error_response(404, Some("text/plain".into()), Some("9f1c".into()), b"not found");
+        assert_eq!(bare.request_id(), Some("9f1c"));
+
+        // Id from the body takes precedence
+        let described = error_response(
+            400,
+            Some("application/json".into()),
+            Some("9f1c".into()),
+            br#"{"statusCode": 400, "error": "Bad Request", "description": "invalid", "requestId": "body-id"}"#,
+        );
+        assert_eq!(described.request_id(), Some("body-id"));
+    }
+
+    #[test]
+    fn test_non_json_server_errors_are_upstream_unavailable() {
+        let html = format!("<html><body>{}</body></html>", "502 Bad Gateway ".repeat(100));
+        match error_response(502, Some("text/html".into()), Some("req-1".into()), html.as_bytes()) {
+            OneInchError::UpstreamUnavailable { status_code, content_type, body_sample, request_id } => {
+                assert_eq!(status_code, 502);
+                assert_eq!(request_id.as_deref(), Some("req-1"));
+                assert_eq!(content_type.as_deref(), Some("text/html"));
+                assert!(body_sample.starts_with("<html>") && body_sample.ends_with("..."));
+            }
+            e => panic!("unexpected error: {e:?}"),
+        }
+
+        let empty = error_response(503, None, None, b"");
+        assert!(matches!(empty, OneInchError::UpstreamUnavailable { status_code: 503, .. }));
+        assert_eq!(empty.status_code(), Some(503));
+
+        let json = error_response(500, Some("application/json".into()), None, br#"{"message": "internal"}"#);
+        assert!(matches!(json, OneInchError::Api { status_code: 500, description: None, .. }));
+        assert!(matches!(
+            error_response(404, Some("text/html".into()), None, b"<html>"),
+            OneInchError::Api { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_request_id_is_attached_to_response_errors() {
+        let server = MockServer::start_with_headers(200, &[("x-request-id", "req-2")], r#"{"toAmount": 1"#).await;
+        let client = new_with_base_url("token".into(), SupportedNetworks::Ethereum, &server.base_url);
+        let details = QuoteDetailsBuilder::new().src("0xa".into()).dst("0xb".into()).amount("1000".into()).build().unwrap();
+
+        let error = client.quote(details).await.unwrap_err();
+        assert!(matches!(error, OneInchError::Json { .. }));
+        assert_eq!(error.request_id(), Some("req-2"));
+
+        let client = client.with_response_limits(ResponseLimits::new().endpoint_limit("swap/swap", 4));
+        let details = SwapDetailsBuilder::new()
+            .src("0xa".into())
+            .dst("0xb".into())
+            .amount("1000".into())
+            .from_addr("0x1111111111111111111111111111111111111111".into())
+            .slippage(1)
+            .unwrap()
+            .build()
+            .unwrap();
+        let error = client.swap_with_request_id(details).await.unwrap_err();
+        assert!(matches!(error, OneInchError::Response { .. }));
+        assert_eq!(error.request_id(), Some("req-2"));
+    }
+}
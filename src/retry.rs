@@ -0,0 +1,99 @@
+use crate::{client::SendError, utils::runtime};
+use rand::Rng;
+use reqwest::{Method, RequestBuilder, Response};
+use std::time::Duration;
+
+/// Policy of retrying idempotent (GET) requests which failed because of a
+/// transient failure: timeout, connection error, rate limit or 5xx response.
+/// Rate limited requests are retried no sooner than `Retry-After` says, and
+/// aren't retried at all if it's longer than `max_delay`.
+///
+/// Delay before the retry grows exponentially from `base_delay` up to
+/// `max_delay`, and the actual delay is picked uniformly from zero to it (full
+/// jitter), so clients failing at once don't retry in sync.
+///
+/// ```
+/// use one_inch::retry::RetryPolicy;
+/// use std::time::Duration;
+///
+/// let policy = RetryPolicy::new(3).with_base_delay(Duration::from_millis(100));
+/// assert!(policy.backoff(2) <= Duration::from_millis(400));
+/// ```
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+}
+
+impl RetryPolicy {
+    /// Creates policy retrying request up to `max_retries` times, with 200ms
+    /// base delay capped by 10s.
+    pub fn new(max_retries: u32) -> RetryPolicy {
+        RetryPolicy { max_retries, base_delay: Duration::from_millis(200), max_delay: Duration::from_secs(10) }
+    }
+
+    pub fn with_base_delay(mut self, base_delay: Duration) -> RetryPolicy {
+        self.base_delay = base_delay;
+        self
+    }
+
+    pub fn with_max_delay(mut self, max_delay: Duration) -> RetryPolicy {
+        self.max_delay = max_delay;
+        self
+    }
+
+    pub fn max_retries(&self) -> u32 {
+        self.max_retries
+    }
+
+    pub fn max_delay(&self) -> Duration {
+        self.max_delay
+    }
+
+    /// Returns upper bound of the delay before the retry following the
+    /// failed attempt (starting from zero).
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        self.base_delay.saturating_mul(2u32.saturating_pow(attempt)).min(self.max_delay)
+    }
+
+    /// Returns jittered delay before the retry following the failed attempt.
+    pub fn delay(&self, attempt: u32) -> Duration {
+        self.backoff(attempt).mul_f64(rand::thread_rng().gen_range(0.0..=1.0))
+    }
+
+    /// Returns true if the outcome of the request is a transient failure
+    /// worth retrying.
+    pub fn is_transient(&self, result: &Result<Response, SendError>) -> bool {
+        match result {
+            Ok(response) => response.status().is_server_error(),
+            Err(SendError::Network(e)) => e.is_timeout() || runtime::is_connect(e),
+            Err(SendError::RateLimited { .. }) => true,
+            Err(SendError::ApiKey(_) | SendError::CircuitOpen { .. } | SendError::ProductUnavailable { .. }) => false,
+        }
+    }
+}
+
+// Returns true if the request can be retried without side effects. Requests
+// whose body can't be cloned can't be retried at all.
+pub(crate) fn is_retryable_request(request: &RequestBuilder) -> bool {
+    request.try_clone().and_then(|request| request.build().ok()).is_some_and(|request| request.method() == Method::GET)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_is_capped() {
+        let policy = RetryPolicy::new(5).with_base_delay(Duration::from_millis(100)).with_max_delay(Duration::from_secs(1));
+        assert_eq!(policy.backoff(0), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(400));
+        assert_eq!(policy.backoff(10), Duration::from_secs(1));
+        assert!(policy.delay(3) <= Duration::from_millis(800));
+
+        let client = reqwest::Client::new();
+        assert!(is_retryable_request(&client.get("https://api.1inch.dev/swap")));
+        assert!(!is_retryable_request(&client.post("https://api.1inch.dev/swap")));
+    }
+}
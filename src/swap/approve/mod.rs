@@ -2,5 +2,6 @@ mod types;
 
 pub use types::*;
 mod allowance;
+#[allow(clippy::module_inception)]
 mod approve;
 mod spender;
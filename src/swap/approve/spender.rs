@@ -1,27 +1,14 @@
-use crate::{
-    client::OneInchClient,
-    consts::{BASIC_URL, SWAP_API_VERSION},
-    swap::approve::RouterAddress,
-};
-use std::error::Error;
+use crate::{client::OneInchClient, consts::SWAP_API_VERSION, error::OneInchError, swap::approve::RouterAddress};
 
 impl OneInchClient {
     /// Retrieves the router address for the specified network.
-    pub async fn get_router_address(&self) -> Result<RouterAddress, Box<dyn Error>> {
+    pub async fn get_router_address(&self) -> Result<RouterAddress, OneInchError> {
         // Construct the URL for fetching router address.
-        let url = format!("{}/swap/{}/{}/approve/spender", BASIC_URL, SWAP_API_VERSION, self.network_id);
+        let url = format!("{}/swap/{}/{}/approve/spender", self.base_url(), SWAP_API_VERSION, self.network_id);
 
-        // Send HTTP GET request with authorization header.
-        let request_result = self.http_client.get(url).header("Authorization", &self.token).send().await;
-
-        // Handle request errors and check for successful response.
-        let response = request_result
-            .map_err(|e| Box::new(e) as Box<dyn Error>)?
-            .error_for_status()
-            .map_err(|e| Box::new(e) as Box<dyn Error>)?;
-
-        // Parse JSON response into RouterAddress type.
-        let address: RouterAddress = response.json().await.map_err(|e| Box::new(e) as Box<dyn Error>)?;
+        // Send HTTP GET request with authorization header and parse JSON
+        // response into RouterAddress type.
+        let address: RouterAddress = self.request_json("swap/approve/spender", self.http_client.get(url)).await?;
 
         // Return the obtained router address.
         Ok(address)
@@ -1,4 +1,7 @@
-use crate::{builder_setter, utils::builder::BasicBuilderError};
+use crate::{
+    builder_setter,
+    utils::{builder::BasicBuilderError, integrity},
+};
 use serde::Deserialize;
 
 /// Builder struct to create instance of `AllowanceDetails`
@@ -89,12 +92,14 @@ pub struct ApproveTranactionDetails {
 /// Includes raw transaction and other data to perform tx.
 #[derive(Debug, Clone, Deserialize)]
 pub struct ApproveCallData {
+    #[serde(deserialize_with = "integrity::calldata")]
     pub data: String,
 
-    #[serde(rename = "gasPrice")]
+    #[serde(rename = "gasPrice", deserialize_with = "integrity::gas_price")]
     pub gas_price: String,
 
     pub to: String,
+    #[serde(deserialize_with = "integrity::value")]
     pub value: String,
 }
 
@@ -0,0 +1,139 @@
+use crate::{
+    common::token::TokenInfo,
+    swap::{QuoteResponse, SelectedProtocol, SwapResponse, SwapTranactionData, SwapV6Response},
+};
+use serde::Serialize;
+
+/// Flat representation of the swap response, containing only scalar columns,
+/// so it can be inserted into analytics stores as is. Token columns are empty
+/// unless the response includes tokens info, and the route is empty unless it
+/// includes protocols.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct FlatSwap {
+    pub src: Option<String>,
+    pub src_symbol: Option<String>,
+    pub dst: Option<String>,
+    pub dst_symbol: Option<String>,
+    pub dst_amount: String,
+    pub from: String,
+    pub router: String,
+    pub value: String,
+    pub gas: u64,
+    pub gas_price: String,
+    pub route: String,
+}
+
+/// Flat representation of the quote response. See [`FlatSwap`].
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct FlatQuote {
+    pub src: Option<String>,
+    pub src_symbol: Option<String>,
+    pub dst: Option<String>,
+    pub dst_symbol: Option<String>,
+    pub dst_amount: String,
+    pub route: String,
+}
+
+impl SwapResponse {
+    /// Converts the response into a flat DTO.
+    pub fn to_flat(&self) -> FlatSwap {
+        flat_swap(&self.from_token, &self.to_token, &self.to_amount, &self.protocols, &self.transaction)
+    }
+}
+
+impl SwapV6Response {
+    /// Converts the response into a flat DTO.
+    pub fn to_flat(&self) -> FlatSwap {
+        flat_swap(&self.from_token, &self.to_token, &self.dst_amount, &self.protocols, &self.transaction)
+    }
+}
+
+impl QuoteResponse {
+    /// Converts the response into a flat DTO.
+    pub fn to_flat(&self) -> FlatQuote {
+        FlatQuote {
+            src: self.from_token.as_ref().map(|t| t.address.clone()),
+            src_symbol: self.from_token.as_ref().map(|t| t.symbol.clone()),
+            dst: self.to_token.as_ref().map(|t| t.address.clone()),
+            dst_symbol: self.to_token.as_ref().map(|t| t.symbol.clone()),
+            dst_amount: self.to_amount.clone(),
+            route: self.protocols.as_deref().map(route_summary).unwrap_or_default(),
+        }
+    }
+}
+
+fn flat_swap(
+    from_token: &Option<TokenInfo>,
+    to_token: &Option<TokenInfo>,
+    dst_amount: &str,
+    protocols: &Option<Vec<Vec<Vec<SelectedProtocol>>>>,
+    tx: &SwapTranactionData,
+) -> FlatSwap {
+    FlatSwap {
+        src: from_token.as_ref().map(|t| t.address.clone()),
+        src_symbol: from_token.as_ref().map(|t| t.symbol.clone()),
+        dst: to_token.as_ref().map(|t| t.address.clone()),
+        dst_symbol: to_token.as_ref().map(|t| t.symbol.clone()),
+        dst_amount: dst_amount.to_string(),
+        from: tx.from.clone(),
+        router: tx.to.clone(),
+        value: tx.value.clone(),
+        gas: u64::try_from(tx.gas).unwrap_or(u64::MAX),
+        gas_price: tx.gas_price.clone(),
+        route: protocols.as_deref().map(route_summary).unwrap_or_default(),
+    }
+}
+
+/// Summarizes the route into a single string: parallel routes are separated
+/// by ` | `, hops of the route by ` > ` and protocols splitting the hop by `+`,
+/// e.g. `UNISWAP_V3(100) > CURVE(60)+BALANCER(40)`.
+pub fn route_summary(protocols: &[Vec<Vec<SelectedProtocol>>]) -> String {
+    protocols
+        .iter()
+        .map(|route| {
+            route
+                .iter()
+                .map(|hop| hop.iter().map(|p| format!("{}({})", p.name, p.part)).collect::<Vec<_>>().join("+"))
+                .collect::<Vec<_>>()
+                .join(" > ")
+        })
+        .collect::<Vec<_>>()
+        .join(" | ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn protocol(name: &str, part: f64) -> SelectedProtocol {
+        SelectedProtocol { name: name.into(), part, from_token_address: "0x01".into(), to_token_address: "0x02".into() }
+    }
+
+    #[test]
+    fn test_swap_to_flat() {
+        let response = SwapV6Response {
+            from_token: None,
+            to_token: None,
+            dst_amount: "1000".into(),
+            protocols: Some(vec![vec![
+                vec![protocol("UNISWAP_V3", 100.0)],
+                vec![protocol("CURVE", 60.0), protocol("BALANCER", 40.0)],
+            ]]),
+            transaction: SwapTranactionData {
+                from: "0x03".into(),
+                to: "0x111111125421ca6dc452d289314280a0f8842a65".into(),
+                data: "0x".into(),
+                value: "0".into(),
+                gas_price: "1000000000".into(),
+                gas: 150000,
+            },
+        };
+
+        let flat = response.to_flat();
+
+        assert_eq!(flat.route, "UNISWAP_V3(100) > CURVE(60)+BALANCER(40)");
+        assert_eq!(flat.router, "0x111111125421ca6dc452d289314280a0f8842a65");
+        assert_eq!(flat.gas, 150000);
+        assert_eq!(flat.src, None);
+    }
+}
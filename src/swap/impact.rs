@@ -0,0 +1,154 @@
+use crate::{
+    client::OneInchClient,
+    error::OneInchError,
+    swap::{parse_amount, QuoteDetailsBuilder},
+};
+use num_bigint::BigUint;
+
+/// Client-level protection from fat-fingered swaps: swap whose price impact
+/// exceeds the threshold is refused with [`OneInchError::ImpactTooHigh`]
+/// before its transaction is returned.
+///
+/// Price impact is the shortfall of the swap's rate against the rate of a
+/// reference quote of a small fraction of the amount (1/1000 by default),
+/// which barely moves the pools.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PriceImpactPolicy {
+    max_impact: f64,
+    reference_fraction: u32,
+}
+
+impl PriceImpactPolicy {
+    /// Creates policy refusing swaps with price impact above `max_impact`,
+    /// a fraction like `0.05` for 5%.
+    pub fn new(max_impact: f64) -> PriceImpactPolicy {
+        PriceImpactPolicy { max_impact, reference_fraction: 1000 }
+    }
+
+    /// Sets which fraction of the amount is quoted for the reference rate,
+    /// e.g. `1000` for 1/1000 of it.
+    pub fn with_reference_fraction(mut self, reference_fraction: u32) -> PriceImpactPolicy {
+        self.reference_fraction = reference_fraction.max(1);
+        self
+    }
+
+    pub fn max_impact(&self) -> f64 {
+        self.max_impact
+    }
+}
+
+/// Returns price impact of receiving `dst_amount` for `amount`, against the
+/// reference quote of receiving `reference_dst_amount` for `reference_amount`.
+/// It's `0.0` if the rate is the same, and negative if the swap is better than
+/// the reference. `None` if any amount is invalid or the reference is empty.
+pub fn price_impact(amount: &str, dst_amount: &str, reference_amount: &str, reference_dst_amount: &str) -> Option<f64> {
+    let [amount, dst_amount, reference_amount, reference_dst_amount] =
+        [amount, dst_amount, reference_amount, reference_dst_amount].map(|amount| amount.parse::<f64>().ok());
+
+    let rate = dst_amount? / amount?;
+    let reference_rate = reference_dst_amount? / reference_amount?;
+    (reference_rate > 0.0 && rate.is_finite()).then(|| 1.0 - rate / reference_rate)
+}
+
+impl OneInchClient {
+    /// Sets price impact policy all swaps of the client are checked against.
+    pub fn with_price_impact_policy(mut self, policy: PriceImpactPolicy) -> OneInchClient {
+        self.price_impact_policy = Some(policy);
+        self
+    }
+
+    /// Returns a client sharing everything with this one, whose swaps aren't
+    /// checked for price impact. Handy to override the policy for a single
+    /// call which is meant to move the price:
+    /// `client.allowing_high_impact().swap_v6(details)`.
+    pub fn allowing_high_impact(&self) -> OneInchClient {
+        OneInchClient { price_impact_policy: None, ..self.clone() }
+    }
+
+    // Checks swap of `amount` of `src` receiving `dst_amount` of `dst` against
+    // the price impact policy, if the client has one.
+    pub(crate) async fn check_price_impact(
+        &self,
+        src: &str,
+        dst: &str,
+        amount: &str,
+        dst_amount: &str,
+    ) -> Result<(), OneInchError> {
+        let Some(policy) = &self.price_impact_policy else {
+            return Ok(());
+        };
+
+        let reference_amount = parse_amount(amount)? / BigUint::from(policy.reference_fraction);
+        if reference_amount == BigUint::default() {
+            // Amount is too small to move the price
+            return Ok(());
+        }
+        let reference_amount = reference_amount.to_string();
+
+        let details = QuoteDetailsBuilder::new()
+            .src(src.to_string())
+            .dst(dst.to_string())
+            .amount(reference_amount.clone())
+            .build()
+            .map_err(|e| OneInchError::Validation(e.to_string()))?;
+        let reference = self.request_quote(details).await?;
+        let impact = price_impact(amount, dst_amount, &reference_amount, &reference.to_amount)
+            .ok_or_else(|| OneInchError::Validation("price impact can't be computed from the quote".into()))?;
+
+        if impact > policy.max_impact {
+            return Err(OneInchError::ImpactTooHigh { impact, threshold: policy.max_impact });
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::{new_with_base_url, SupportedNetworks},
+        swap::SwapDetailsV6Builder,
+        utils::mock_server::MockServer,
+    };
+
+    #[tokio::test]
+    async fn test_swap_with_high_impact_is_refused() {
+        assert!((price_impact("1000", "900", "10", "10").unwrap() - 0.1).abs() < 1e-9);
+        assert_eq!(price_impact("1000", "900", "10", "0"), None);
+
+        // Swap of 1000 returns 5, as does the reference quote of 1
+        let server = MockServer::start_routes(&[
+            ("/swap/v5.2/1/quote", r#"{"toAmount": "5"}"#),
+            (
+                "/swap/v6.0/1/swap",
+                r#"{"dstAmount": "5", "tx": {"from": "a", "to": "b", "data": "0x", "value": "0", "gasPrice": "1", "gas": 21000}}"#,
+            ),
+        ])
+        .await;
+        let client = new_with_base_url("token".to_string(), SupportedNetworks::Ethereum, &server.base_url)
+            .with_price_impact_policy(PriceImpactPolicy::new(0.05));
+        let details = SwapDetailsV6Builder::new()
+            .src("src".into())
+            .dst("dst".into())
+            .amount("1000".into())
+            .eoa("0x1111111111111111111111111111111111111111".into())
+            .slippage(1)
+            .unwrap()
+            .build()
+            .unwrap();
+
+        match client.swap_v6(details.clone()).await {
+            Err(error @ OneInchError::ImpactTooHigh { .. }) => {
+                assert!(!error.is_safe_to_retry());
+                let OneInchError::ImpactTooHigh { impact, threshold } = error else { unreachable!() };
+                assert!(impact > 0.99);
+                assert_eq!(threshold, 0.05);
+            }
+            other => panic!("unexpected result: {:?}", other.map(|swap| swap.dst_amount)),
+        }
+        assert!(server.requests()[1].ends_with("amount=1"));
+
+        assert!(client.allowing_high_impact().swap_v6(details).await.is_ok());
+    }
+}
@@ -1,7 +1,4 @@
-use crate::{
-    client::OneInchClient,
-    consts::{BASIC_URL, SWAP_API_VERSION},
-};
+use crate::{client::OneInchClient, consts::SWAP_API_VERSION};
 use serde::Deserialize;
 use std::error::Error;
 
@@ -26,18 +23,8 @@ impl OneInchClient {
     /// Get current list of liquidity sources that are available for routing in
     /// 1inch.
     pub async fn get_liquidity_sources(&self) -> Result<LiquidityProtocolsResponse, Box<dyn Error>> {
-        let url = format!("{}/swap/{}/{}/liquidity-sources", BASIC_URL, SWAP_API_VERSION, self.network_id);
+        let url = format!("{}/swap/{}/{}/liquidity-sources", self.base_url(), SWAP_API_VERSION, self.network_id);
 
-        let request_result = self.http_client.get(url).header("Authorization", &self.token).send().await;
-
-        let response = request_result
-            .map_err(|e| Box::new(e) as Box<dyn Error>)?
-            .error_for_status()
-            .map_err(|e| Box::new(e) as Box<dyn Error>)?;
-
-        let liquidity_sources_response: LiquidityProtocolsResponse =
-            response.json().await.map_err(|e| Box::new(e) as Box<dyn Error>)?;
-
-        Ok(liquidity_sources_response)
+        Ok(self.request_json("swap/liquidity-sources", self.http_client.get(url)).await?)
     }
 }
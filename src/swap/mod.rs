@@ -1,12 +1,26 @@
+mod flat;
 mod liquidity_pools;
+#[allow(clippy::module_inception)]
 mod swap;
 mod tokens_list;
 
 /// Everything you need for performing requests on the swap/approve/* endpoints
 pub mod approve;
+mod impact;
+mod preflight;
 mod quote;
+mod quote_cache;
+mod slippage;
 mod types;
+mod venues;
 
+pub use flat::*;
+pub use impact::*;
 pub use liquidity_pools::*;
+pub use preflight::*;
+pub use quote::*;
+pub use quote_cache::*;
+pub use slippage::*;
 pub use tokens_list::*;
 pub use types::*;
+pub use venues::*;
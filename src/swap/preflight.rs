@@ -0,0 +1,112 @@
+use crate::{client::OneInchClient, consts::SWAP_V6_API_VERSION, error::OneInchError, swap::approve::AllowanceDetails};
+use num_bigint::BigUint;
+use thiserror::Error;
+
+/// Address the API uses for the native currency of the network.
+pub const NATIVE_TOKEN_ADDRESS: &str = "0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee";
+
+/// Enumerates reasons the swap would fail, found before sending it.
+#[derive(Error, Debug, Clone, Eq, PartialEq)]
+pub enum PreflightError {
+    /// Wallet doesn't hold enough of the source token.
+    #[error("Insufficient balance of {token}: {needed} needed, {available} available")]
+    InsufficientBalance { token: String, needed: BigUint, available: BigUint },
+
+    /// Router isn't allowed to spend enough of the source token, it should be
+    /// approved first.
+    #[error("Insufficient allowance of {token}: {needed} needed, {available} approved")]
+    InsufficientAllowance { token: String, needed: BigUint, available: BigUint },
+}
+
+impl OneInchClient {
+    /// Checks that the wallet holds enough of the source token and the v6
+    /// router is allowed to spend it, via Balance API and allowance endpoint.
+    pub async fn preflight_swap(&self, from: &str, src: &str, amount: &str) -> Result<(), OneInchError> {
+        self.preflight_swap_with_router(SWAP_V6_API_VERSION, from, src, amount, false).await
+    }
+
+    // Preflight against the router of the swap API version, since every
+    // version has its own router and therefore its own allowance. Swaps
+    // authorized by a permit or through Permit2 don't need the router's
+    // allowance, so only the balance is checked for them.
+    pub(crate) async fn preflight_swap_with_router(
+        &self,
+        version: &str,
+        from: &str,
+        src: &str,
+        amount: &str,
+        permitted: bool,
+    ) -> Result<(), OneInchError> {
+        let token = src.to_lowercase();
+        let needed = parse_amount(amount)?;
+
+        let balances = self.get_wallet_balances(from.to_string()).await?;
+        let available = match balances.balances.get(&token) {
+            Some(balance) => parse_amount(balance)?,
+            None => BigUint::default(),
+        };
+        if available < needed {
+            return Err(PreflightError::InsufficientBalance { token, needed, available }.into());
+        }
+
+        if token == NATIVE_TOKEN_ADDRESS || permitted {
+            return Ok(());
+        }
+
+        let allowance = self
+            .get_router_allowance(
+                version,
+                AllowanceDetails { token_address: token.clone(), wallet_address: from.to_string() },
+            )
+            .await?;
+        let available = parse_amount(&allowance.allowance)?;
+        if available < needed {
+            return Err(PreflightError::InsufficientAllowance { token, needed, available }.into());
+        }
+
+        Ok(())
+    }
+}
+
+pub(crate) fn parse_amount(amount: &str) -> Result<BigUint, OneInchError> {
+    amount.parse().map_err(|_| OneInchError::Validation(format!("invalid amount: {}", amount)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::{new_with_base_url, SupportedNetworks},
+        utils::mock_server::MockServer,
+    };
+
+    #[test]
+    fn test_preflight_error_messages() {
+        let error: OneInchError = PreflightError::InsufficientAllowance {
+            token: "0xa0b8".into(),
+            needed: BigUint::from(1000u32),
+            available: BigUint::from(10u32),
+        }
+        .into();
+
+        assert!(!error.is_safe_to_retry());
+        assert_eq!(
+            error.to_string(),
+            "Preflight check failed: Insufficient allowance of 0xa0b8: 1000 needed, 10 approved"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_permitted_swaps_skip_allowance() {
+        let server = MockServer::start(r#"{"0xa0b8": "1000", "allowance": "0"}"#).await;
+        let client = new_with_base_url("token".into(), SupportedNetworks::Ethereum, &server.base_url);
+
+        let result = client.preflight_swap_with_router(SWAP_V6_API_VERSION, "0x01", "0xa0b8", "1000", false).await;
+        assert!(matches!(
+            result,
+            Err(OneInchError::Preflight(PreflightError::InsufficientAllowance { .. }))
+        ));
+        assert!(client.preflight_swap_with_router(SWAP_V6_API_VERSION, "0x01", "0xa0b8", "1000", true).await.is_ok());
+        assert!(server.requests().last().unwrap().contains("/balance/"));
+    }
+}
@@ -1,25 +1,122 @@
-use std::error::Error;
-
 use crate::{
     client::OneInchClient,
-    consts::{BASIC_URL, SWAP_API_VERSION},
-    swap::{QuoteDetails, QuoteResponse, SwapError, SwapRequestError},
-    utils::params::insert_optional_param,
+    consts::SWAP_API_VERSION,
+    error::OneInchError,
+    events::ExecutionEvent,
+    response::WithRequestId,
+    swap::{QuoteDetails, QuoteResponse},
+    utils::{integrity::check_integer, params::insert_optional_param},
 };
+use futures::{stream, StreamExt};
 use reqwest::Url;
 
+/// Quote request prepared once for the pair and parameters, for loops quoting
+/// the same pair many times. URL with all the parameters is built and
+/// validated up front, and only the amount is appended per call.
+///
+/// Requests still go through the client, so rate limiting, retries and key
+/// rotation apply to them as usual.
+#[derive(Clone)]
+pub struct PreparedQuote {
+    client: OneInchClient,
+    // URL ending with `amount=`
+    url_prefix: String,
+}
+
+impl PreparedQuote {
+    /// Returns URL of the quote of the amount.
+    pub fn url_for(&self, amount: &str) -> String {
+        let mut url = String::with_capacity(self.url_prefix.len() + amount.len());
+        url.push_str(&self.url_prefix);
+        url.push_str(amount);
+        url
+    }
+
+    /// Performs `quote` request of the amount, in minimal units of `src`.
+    pub async fn quote(&self, amount: &str) -> Result<QuoteResponse, OneInchError> {
+        check_integer(amount).map_err(OneInchError::Validation)?;
+
+        self.client.request_json("swap/quote", self.client.http_client.get(self.url_for(amount))).await
+    }
+}
+
 impl OneInchClient {
     /// Performs `quote` request with predefined parameters.
-    pub async fn quote(&self, details: QuoteDetails) -> Result<QuoteResponse, Box<dyn Error>> {
-        let url = format!("{}/swap/{}/{}/quote/", BASIC_URL, SWAP_API_VERSION, self.network_id);
+    pub async fn quote(&self, details: QuoteDetails) -> Result<QuoteResponse, OneInchError> {
+        Ok(self.quote_with_request_id(details).await?.into_inner())
+    }
+
+    /// Performs `quote` request like [`quote`](OneInchClient::quote),
+    /// returning the response along with id of the request, for reports to
+    /// 1inch support.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "quote",
+            skip_all,
+            fields(chain = %self.network_id, src = %details.src, dst = %details.dst, amount = %details.amount)
+        )
+    )]
+    pub async fn quote_with_request_id(&self, details: QuoteDetails) -> Result<WithRequestId<QuoteResponse>, OneInchError> {
+        let (src, dst, amount) = (details.src.clone(), details.dst.clone(), details.amount.clone());
+        let quote = self.request_quote(details).await?;
+        self.emit(|| ExecutionEvent::QuoteReceived {
+            chain_id: self.network_id as u32,
+            src,
+            dst,
+            amount,
+            dst_amount: quote.to_amount.clone(),
+            request_id: quote.request_id.clone(),
+        })
+        .await;
+
+        Ok(quote)
+    }
+
+    // Requests the quote without emitting it, for quotes the client takes
+    // internally, like the reference of the price impact check.
+    pub(crate) async fn request_quote(&self, details: QuoteDetails) -> Result<WithRequestId<QuoteResponse>, OneInchError> {
+        let url_with_params = self.quote_url(details)?;
+        self.request_json_with_id("swap/quote", self.http_client.get(url_with_params)).await
+    }
+
+    /// Performs `quote` requests of all the details concurrently, at most
+    /// `max_concurrency` at once, returning the quote or the error of every
+    /// request in order of the input. Requests go through the client's rate
+    /// limiter and retries like any other, so failure of one doesn't affect
+    /// the rest.
+    pub async fn quote_many(
+        &self,
+        details: Vec<QuoteDetails>,
+        max_concurrency: usize,
+    ) -> Vec<Result<QuoteResponse, OneInchError>> {
+        stream::iter(details).map(|details| self.quote(details)).buffered(max_concurrency.max(1)).collect().await
+    }
+
+    /// Prepares quote request with the parameters of the details for repeated
+    /// quoting of different amounts. Amount of the details is ignored.
+    pub fn prepare_quote(&self, mut details: QuoteDetails) -> Result<PreparedQuote, OneInchError> {
+        details.amount = String::new();
+        let url_prefix = self.quote_url(details)?.to_string();
+
+        Ok(PreparedQuote { client: self.clone(), url_prefix })
+    }
+
+    // Builds URL of the quote request. Amount is the last parameter, so the
+    // prepared quote can append it.
+    fn quote_url(&self, details: QuoteDetails) -> Result<Url, OneInchError> {
+        let url = format!("{}/swap/{}/{}/quote/", self.base_url(), SWAP_API_VERSION, self.network_id);
 
         // Adding required parameters
-        let mut params: Vec<(&str, String)> = vec![("src", details.src), ("dst", details.dst), ("amount", details.amount)];
+        let mut params: Vec<(&str, String)> = vec![("src", details.src), ("dst", details.dst)];
 
         // Adding optional bool parameters
-        insert_optional_param(&mut params, "includeGas", details.include_gas.map(|a| a.to_string()));
-        insert_optional_param(&mut params, "includeProtocols", details.include_protocols.map(|a| a.to_string()));
-        insert_optional_param(&mut params, "includeTokensInfo", details.include_tokens_info.map(|a| a.to_string()));
+        self.include_defaults.insert_params(
+            &mut params,
+            details.include_tokens_info,
+            details.include_protocols,
+            details.include_gas,
+        );
 
         // Adding optional num parameters
         insert_optional_param(&mut params, "fee", details.fee.map(|a| a.to_string()));
@@ -33,36 +130,78 @@ impl OneInchClient {
         insert_optional_param(&mut params, "gasPrice", details.gas_price);
         insert_optional_param(&mut params, "connectorTokens", details.connector_tokens);
 
-        let url_with_params = Url::parse_with_params(&url, params).map_err(|e| Box::new(e) as Box<dyn Error>)?;
-
-        let response = match self.http_client.get(url_with_params).header("Authorization", &self.token).send().await {
-            Ok(response) => response,
-            Err(e) => return Err(SwapError::Network(e).into()),
-        };
-
-        if response.status().as_u16() == 400 {
-            let error_body = response.text().await.unwrap_or_default();
-            return match serde_json::from_str::<SwapRequestError>(&error_body) {
-                Ok(err) => Err(SwapError::SwapRequest {
-                    description: err.description,
-                    error: err.error,
-                    status_code: err.status_code,
-                    request_id: err.request_id,
-                }
-                .into()),
-                Err(e) => Err(SwapError::Other(format!("Error parsing error response: {}", e)).into()),
-            };
-        }
-
-        if response.status().is_client_error() || response.status().is_server_error() {
-            return Err(SwapError::Other(format!("Server responded with error: {}", response.status())).into());
-        }
-
-        let quote_data: QuoteResponse = match response.json().await {
-            Ok(data) => data,
-            Err(e) => return Err(SwapError::Network(e).into()),
-        };
-
-        Ok(quote_data)
+        params.push(("amount", details.amount));
+
+        Url::parse_with_params(&url, params).map_err(|e| OneInchError::Validation(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::{new_with_base_url, SupportedNetworks},
+        swap::QuoteDetailsBuilder,
+        utils::mock_server::MockServer,
+    };
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_prepared_quote_substitutes_amount() {
+        let server = MockServer::start(r#"{"toAmount": "1"}"#).await;
+        let client = new_with_base_url("token".to_string(), SupportedNetworks::Ethereum, &server.base_url);
+        let details = QuoteDetailsBuilder::new()
+            .src("0xsrc".into())
+            .dst("0xdst".into())
+            .amount("1".into())
+            .fee(1)
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let prepared = client.prepare_quote(details.clone()).unwrap();
+        prepared.quote("2500").await.unwrap();
+        let prepared_query = server.last_query();
+
+        client.quote(QuoteDetails { amount: "2500".into(), ..details }).await.unwrap();
+        assert_eq!(server.last_query(), prepared_query);
+
+        assert!(prepared.url_for("42").ends_with("&amount=42"));
+        assert!(matches!(prepared.quote("1&fee=3").await, Err(OneInchError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_quote_many_answers_every_request() {
+        // Earlier requests are answered later, so responses arrive out of order
+        const RESPONSES: [&str; 5] = [
+            r#"{"toAmount": "10"}"#,
+            r#"{"toAmount": "20"}"#,
+            r#"{"toAmount": "30"}"#,
+            r#"{"toAmount": "40"}"#,
+            r#"{"toAmount": "50"}"#,
+        ];
+        let server = MockServer::start_delayed(|target| {
+            let amount: usize = target.rsplit("amount=").next().unwrap().parse().unwrap();
+            (RESPONSES[amount - 1], Duration::from_millis(20 * (6 - amount as u64)))
+        })
+        .await;
+        let client = new_with_base_url("token".to_string(), SupportedNetworks::Ethereum, &server.base_url);
+        let details: Vec<QuoteDetails> = (1..=5)
+            .map(|amount| {
+                QuoteDetailsBuilder::new()
+                    .src("0xsrc".into())
+                    .dst(format!("0xdst{}", amount))
+                    .amount(amount.to_string())
+                    .build()
+                    .unwrap()
+            })
+            .collect();
+
+        let quotes = client.quote_many(details, 2).await;
+        let amounts: Vec<&str> = quotes.iter().map(|quote| quote.as_ref().unwrap().to_amount.as_str()).collect();
+        assert_eq!(amounts, vec!["10", "20", "30", "40", "50"]);
+        assert_eq!(server.requests().len(), 5);
+        assert_eq!(server.max_in_flight(), 2);
+        assert!(client.quote_many(vec![], 0).await.is_empty());
     }
 }
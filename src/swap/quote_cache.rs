@@ -0,0 +1,201 @@
+use crate::{
+    client::{OneInchClient, SupportedNetworks},
+    error::OneInchError,
+    swap::{QuoteDetails, QuoteResponse},
+    utils::runtime::Instant,
+};
+use num_bigint::BigUint;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+/// Bucket of the amounts sharing one cached quote.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AmountBucket {
+    /// Index of the bucket, amounts of the bucket `k` are in range
+    /// `[(1 + g)^k, (1 + g)^(k + 1))` where `g` is the granularity.
+    pub index: u32,
+    /// Lower bound of the bucket, the amount which is actually quoted.
+    pub representative: BigUint,
+}
+
+/// Normalizes the amount to the bucket of the specified granularity (in basis
+/// points, e.g. `100` for 1%). Buckets are geometric, so every amount of the
+/// bucket is within `granularity` from its representative.
+///
+/// Returns `None` for zero amount or zero granularity.
+pub fn normalize_amount(amount: &BigUint, granularity_bps: u32) -> Option<AmountBucket> {
+    if granularity_bps == 0 || *amount == BigUint::from(0u8) {
+        return None;
+    }
+
+    let growth = 1.0 + granularity_bps as f64 / 10_000.0;
+    let amount_f64: f64 = amount.to_string().parse().ok()?;
+    let mut index = (amount_f64.ln() / growth.ln()).floor().max(0.0) as u32;
+
+    // Float estimate may be off by one near the bucket boundaries
+    while index > 0 && bucket_bound(growth, index) > *amount {
+        index -= 1;
+    }
+    while bucket_bound(growth, index + 1) <= *amount {
+        index += 1;
+    }
+
+    Some(AmountBucket { index, representative: bucket_bound(growth, index) })
+}
+
+// Lower bound of the bucket, rounded up so it's never below the previous one.
+fn bucket_bound(growth: f64, index: u32) -> BigUint {
+    let bound = growth.powi(index as i32).ceil();
+    format!("{:.0}", bound).parse().unwrap_or_default()
+}
+
+/// Configuration of the [`QuoteCache`].
+#[derive(Debug, Clone)]
+pub struct QuoteCacheConfig {
+    /// Width of the amount buckets in basis points. Output of the cached
+    /// quote is re-scaled linearly within the bucket, so it ignores change of
+    /// the price impact between the quoted amount and the requested one,
+    /// which is at most this wide.
+    pub granularity_bps: u32,
+    /// How long the quote is reused.
+    pub ttl: Duration,
+}
+
+impl Default for QuoteCacheConfig {
+    fn default() -> Self {
+        QuoteCacheConfig { granularity_bps: 100, ttl: Duration::from_secs(5) }
+    }
+}
+
+/// Quote re-scaled from the cached quote of the amount bucket.
+#[derive(Debug, Clone)]
+pub struct ScaledQuote {
+    /// Output amount re-scaled to the requested amount.
+    pub to_amount: BigUint,
+    /// Amount which was actually quoted.
+    pub quoted_amount: BigUint,
+    /// Relative distance between the requested and the quoted amounts. It's
+    /// the accuracy bound of the re-scaled output: the actual output may
+    /// differ from `to_amount` by the change of the price impact over this
+    /// distance. It doesn't exceed the granularity of the cache, except for
+    /// tiny amounts whose bucket bounds are rounded to integers.
+    pub deviation: f64,
+    /// True if the quote was taken from the cache without a request.
+    pub cached: bool,
+    /// Response of the quoted amount.
+    pub response: Arc<QuoteResponse>,
+}
+
+/// Cache of quotes keyed by normalized amount buckets, for high-frequency
+/// UIs which accept small inaccuracy in exchange for far fewer requests.
+/// Every parameter except the amount has to match for the quote to be
+/// reused.
+#[derive(Debug, Default)]
+pub struct QuoteCache {
+    config: QuoteCacheConfig,
+    entries: Mutex<HashMap<String, (Instant, Arc<QuoteResponse>)>>,
+}
+
+impl QuoteCache {
+    pub fn new(config: QuoteCacheConfig) -> QuoteCache {
+        QuoteCache { config, entries: Mutex::new(HashMap::new()) }
+    }
+
+    pub fn config(&self) -> &QuoteCacheConfig {
+        &self.config
+    }
+
+    /// Removes expired entries from the cache.
+    pub fn purge_expired(&self) {
+        let ttl = self.config.ttl;
+        self.entries.lock().unwrap().retain(|_, (cached_at, _)| cached_at.elapsed() < ttl);
+    }
+
+    fn get(&self, key: &str) -> Option<Arc<QuoteResponse>> {
+        let entries = self.entries.lock().unwrap();
+        entries.get(key).filter(|(cached_at, _)| cached_at.elapsed() < self.config.ttl).map(|(_, response)| response.clone())
+    }
+
+    fn insert(&self, key: String, response: Arc<QuoteResponse>) {
+        self.entries.lock().unwrap().insert(key, (Instant::now(), response));
+    }
+}
+
+// Cache key of the network and details: everything except the amount, which
+// is replaced by the bucket.
+fn cache_key(network: SupportedNetworks, details: &QuoteDetails, bucket: &AmountBucket) -> String {
+    let mut normalized = details.clone();
+    normalized.amount = bucket.representative.to_string();
+    format!("{}:{:?}", network, normalized)
+}
+
+/// Re-scales output of the quoted amount linearly to the requested amount.
+pub fn rescale_output(to_amount: &BigUint, quoted_amount: &BigUint, amount: &BigUint) -> BigUint {
+    if *quoted_amount == BigUint::from(0u8) {
+        return BigUint::from(0u8);
+    }
+    to_amount * amount / quoted_amount
+}
+
+impl OneInchClient {
+    /// Performs `quote` request for the bucket of the amount, reusing the
+    /// cached quote of the bucket if it's not expired, and re-scales its
+    /// output to the requested amount.
+    pub async fn quote_bucketed(&self, cache: &QuoteCache, details: QuoteDetails) -> Result<ScaledQuote, OneInchError> {
+        let amount: BigUint =
+            details.amount.parse().map_err(|_| OneInchError::Validation(format!("invalid amount: {}", details.amount)))?;
+        let bucket = normalize_amount(&amount, cache.config.granularity_bps)
+            .ok_or_else(|| OneInchError::Validation("amount and cache granularity should be greater than zero".to_string()))?;
+
+        let key = cache_key(self.network_id, &details, &bucket);
+        let (response, cached) = match cache.get(&key) {
+            Some(response) => (response, true),
+            None => {
+                let normalized = QuoteDetails { amount: bucket.representative.to_string(), ..details };
+                let response = Arc::new(self.quote(normalized).await?);
+                cache.insert(key, response.clone());
+                (response, false)
+            }
+        };
+
+        let quoted_to_amount: BigUint = response
+            .to_amount
+            .parse()
+            .map_err(|_| OneInchError::Validation(format!("invalid quoted amount: {}", response.to_amount)))?;
+
+        let deviation = (&amount - &bucket.representative).to_string().parse::<f64>().unwrap_or(0.0)
+            / bucket.representative.to_string().parse::<f64>().unwrap_or(1.0);
+
+        Ok(ScaledQuote {
+            to_amount: rescale_output(&quoted_to_amount, &bucket.representative, &amount),
+            quoted_amount: bucket.representative,
+            deviation,
+            cached,
+            response,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_amount_buckets() {
+        let amount: BigUint = "1000000000000000000".parse().unwrap();
+        let bucket = normalize_amount(&amount, 100).unwrap();
+        assert!(bucket.representative <= amount);
+        assert!(&bucket.representative * 101u32 > &amount * 100u32);
+
+        // Amounts within the same 1% bucket share the quote
+        let nearby = &bucket.representative + &bucket.representative / 1000u32;
+        assert_eq!(normalize_amount(&nearby, 100).unwrap(), bucket);
+        assert_eq!(normalize_amount(&BigUint::from(0u8), 100), None);
+
+        let rescaled = rescale_output(&BigUint::from(2000u32), &BigUint::from(1000u32), &BigUint::from(1005u32));
+        assert_eq!(rescaled, BigUint::from(2010u32));
+    }
+}
@@ -0,0 +1,146 @@
+use crate::{
+    client::OneInchClient,
+    error::OneInchError,
+    swap::{parse_amount, QuoteDetails, SlippageRecommendation, SlippageSimulation, SlippageSimulationConfig},
+};
+use num_bigint::BigUint;
+
+/// Price of the token pair at the moment of time, like charts data provides
+/// it.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PricePoint {
+    /// Unix timestamp in seconds.
+    pub time: u64,
+    pub price: f64,
+}
+
+impl OneInchClient {
+    /// Quotes the swap and simulates it at every candidate slippage of the
+    /// config, using volatility of the recent `prices` of the pair. See
+    /// [`simulate_slippage`].
+    pub async fn recommend_slippage(
+        &self,
+        details: QuoteDetails,
+        prices: &[PricePoint],
+        config: &SlippageSimulationConfig,
+    ) -> Result<SlippageRecommendation, OneInchError> {
+        let quote = self.quote(details).await?;
+        simulate_slippage(&quote.to_amount, prices, config)
+    }
+}
+
+/// Simulates the swap quoted to receive `quoted_amount` at every candidate
+/// slippage of the config and recommends the tightest one whose predicted
+/// failure probability is acceptable.
+///
+/// Price is modelled as a driftless random walk whose volatility is estimated
+/// from log returns of `prices`, so the swap fails if the price moves against
+/// it beyond the slippage within the config's execution horizon.
+///
+/// Fails with [`OneInchError::Validation`] if the amount is invalid or there
+/// are less than two prices at distinct times.
+pub fn simulate_slippage(
+    quoted_amount: &str,
+    prices: &[PricePoint],
+    config: &SlippageSimulationConfig,
+) -> Result<SlippageRecommendation, OneInchError> {
+    let quoted = parse_amount(quoted_amount)?;
+    let volatility = volatility_per_second(prices)
+        .ok_or_else(|| OneInchError::Validation("At least two prices at distinct times are needed".to_string()))?;
+
+    let horizon_volatility = volatility * config.horizon.as_secs_f64().sqrt();
+
+    let mut candidates = config.candidates.clone();
+    candidates.sort_unstable();
+    candidates.dedup();
+
+    let simulations: Vec<SlippageSimulation> = candidates
+        .into_iter()
+        .map(|slippage| {
+            let min_received = &quoted * BigUint::from(100 - slippage.min(100)) / 100u8;
+            let failure_probability = match horizon_volatility > 0.0 {
+                // Price has to drop by more than `slippage` percent
+                true if slippage < 100 => normal_cdf((1.0 - slippage as f64 / 100.0).ln() / horizon_volatility),
+                _ => 0.0,
+            };
+
+            SlippageSimulation { slippage, min_received: min_received.to_string(), failure_probability }
+        })
+        .collect();
+
+    let recommended = simulations.iter().find(|s| s.failure_probability <= config.max_failure_probability).map(|s| s.slippage);
+
+    Ok(SlippageRecommendation { recommended, volatility, simulations })
+}
+
+// Standard deviation of log returns normalized to one second. Returns `None`
+// if there is not enough data to estimate it.
+fn volatility_per_second(prices: &[PricePoint]) -> Option<f64> {
+    let mut sorted: Vec<&PricePoint> = prices.iter().filter(|p| p.price > 0.0).collect();
+    sorted.sort_by_key(|p| p.time);
+
+    // Returns are normalized by square root of their interval, so unevenly
+    // sampled series are supported.
+    let returns: Vec<f64> = sorted
+        .windows(2)
+        .filter(|w| w[1].time > w[0].time)
+        .map(|w| (w[1].price / w[0].price).ln() / ((w[1].time - w[0].time) as f64).sqrt())
+        .collect();
+
+    if returns.is_empty() {
+        return None;
+    }
+
+    let variance = returns.iter().map(|r| r * r).sum::<f64>() / returns.len() as f64;
+    Some(variance.sqrt())
+}
+
+// Standard normal cumulative distribution function, via Abramowitz and Stegun
+// 7.1.26 approximation of erf (absolute error below 1.5e-7).
+fn normal_cdf(x: f64) -> f64 {
+    let z = x.abs() / std::f64::consts::SQRT_2;
+    let t = 1.0 / (1.0 + 0.3275911 * z);
+    let poly = t * (0.254829592 + t * (-0.284496736 + t * (1.421413741 + t * (-1.453152027 + t * 1.061405429))));
+    let erf = 1.0 - poly * (-z * z).exp();
+
+    if x >= 0.0 {
+        0.5 * (1.0 + erf)
+    } else {
+        0.5 * (1.0 - erf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_simulate_slippage_recommends_tightest_acceptable() {
+        // Price alternates by ~0.1% every minute
+        let prices: Vec<PricePoint> =
+            (0..60).map(|i| PricePoint { time: i * 60, price: if i % 2 == 0 { 100.0 } else { 100.1 } }).collect();
+
+        let config = SlippageSimulationConfig {
+            candidates: vec![5, 1, 2],
+            horizon: Duration::from_secs(600),
+            max_failure_probability: 0.05,
+        };
+
+        let recommendation = simulate_slippage("1000", &prices, &config).unwrap();
+
+        let slippages: Vec<usize> = recommendation.simulations.iter().map(|s| s.slippage).collect();
+        assert_eq!(slippages, vec![1, 2, 5]);
+        assert_eq!(recommendation.simulations[0].min_received, "990");
+        assert!(recommendation.simulations[0].failure_probability > recommendation.simulations[1].failure_probability);
+        assert_eq!(recommendation.recommended, Some(1));
+
+        assert!((normal_cdf(0.0) - 0.5).abs() < 1e-7);
+        assert!((normal_cdf(-1.6448536) - 0.05).abs() < 1e-6);
+        assert!(matches!(
+            simulate_slippage("1000", &prices[..1], &config),
+            Err(OneInchError::Validation(_))
+        ));
+        assert!(matches!(simulate_slippage("1e3", &prices, &config), Err(OneInchError::Validation(_))));
+    }
+}
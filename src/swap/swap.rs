@@ -1,23 +1,58 @@
-use std::error::Error;
-
 use crate::{
     client::OneInchClient,
-    consts::{BASIC_URL, SWAP_API_VERSION, SWAP_V6_API_VERSION},
-    swap::{SwapDetails, SwapError, SwapRequestError, SwapResponse},
-    utils::params::insert_optional_param,
+    consts::{SWAP_API_VERSION, SWAP_V6_API_VERSION},
+    error::OneInchError,
+    response::WithRequestId,
+    swap::{SwapDetails, SwapResponse},
+    utils::{deprecation::warn_if_deprecated_method, params::insert_optional_param},
 };
 use reqwest::Url;
 
 use super::{SwapDetailsV6, SwapV6Response};
 
 impl OneInchClient {
-    /// Performs swap request with predefined parameters.
-    pub async fn swap(&self, details: SwapDetails) -> Result<SwapResponse, Box<dyn Error>> {
-        let url = format!("{}/swap/{}/{}/swap/", BASIC_URL, SWAP_API_VERSION, self.network_id);
+    /// Performs swap request with predefined parameters. It uses deprecated
+    /// v5.2 API, consider using [`swap_v6`](OneInchClient::swap_v6) instead.
+    pub async fn swap(&self, details: SwapDetails) -> Result<SwapResponse, OneInchError> {
+        Ok(self.swap_with_request_id(details).await?.into_inner())
+    }
+
+    /// Performs swap request like [`swap`](OneInchClient::swap), returning
+    /// the response along with id of the request, for reports to 1inch
+    /// support.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "swap",
+            skip_all,
+            fields(chain = %self.network_id, src = %details.src, dst = %details.dst, amount = %details.amount)
+        )
+    )]
+    pub async fn swap_with_request_id(&self, details: SwapDetails) -> Result<WithRequestId<SwapResponse>, OneInchError> {
+        warn_if_deprecated_method("swap");
+
+        let url = format!("{}/swap/{}/{}/swap/", self.base_url(), SWAP_API_VERSION, self.network_id);
+
+        // Resolving addresses, since they can be specified as domains if
+        // resolution is enabled
+        let from = self.resolve_swap_address(details.from).await?;
+        let receiver = match details.receiver {
+            Some(receiver) => Some(self.resolve_swap_address(receiver).await?),
+            None => None,
+        };
+
+        // Checking balance and allowance, so shortfalls are reported in
+        // details instead of the estimation error
+        if self.swap_preflight && details.disable_estimate != Some(true) {
+            let permitted = details.permit.is_some();
+            self.preflight_swap_with_router(SWAP_API_VERSION, &from, &details.src, &details.amount, permitted).await?;
+        }
+
+        let (src, dst, amount) = (details.src.clone(), details.dst.clone(), details.amount.clone());
 
         // Adding required parameters
         let mut params: Vec<(&str, String)> = vec![
-            ("from", details.from),
+            ("from", from),
             ("slippage", details.slippage.to_string()),
             ("src", details.src),
             ("dst", details.dst),
@@ -27,9 +62,12 @@ impl OneInchClient {
         // Adding optional bool parameters
         insert_optional_param(&mut params, "disableEstimate", details.disable_estimate.map(|a| a.to_string()));
         insert_optional_param(&mut params, "allowPartialFill", details.allow_partial_fill.map(|a| a.to_string()));
-        insert_optional_param(&mut params, "includeGas", details.include_gas.map(|a| a.to_string()));
-        insert_optional_param(&mut params, "includeProtocols", details.include_protocols.map(|a| a.to_string()));
-        insert_optional_param(&mut params, "includeTokensInfo", details.include_tokens_info.map(|a| a.to_string()));
+        self.include_defaults.insert_params(
+            &mut params,
+            details.include_tokens_info,
+            details.include_protocols,
+            details.include_gas,
+        );
 
         // Adding optional num parameters
         insert_optional_param(&mut params, "fee", details.fee.map(|a| a.to_string()));
@@ -38,55 +76,68 @@ impl OneInchClient {
         insert_optional_param(&mut params, "mainRouteParts", details.main_route_parts.map(|a| a.to_string()));
         insert_optional_param(&mut params, "gasLimit", details.gas_limit.map(|a| a.to_string()));
 
+        // Resolving gas price, since it can be specified as a tier
+        let gas_price = self.resolve_gas_price(details.gas_price, details.gas_tier).await?;
+
         // Adding optional string parameters
         insert_optional_param(&mut params, "protocols", details.protocols);
-        insert_optional_param(&mut params, "gasPrice", details.gas_price);
+        insert_optional_param(&mut params, "gasPrice", gas_price);
         insert_optional_param(&mut params, "connectorTokens", details.connector_tokens);
         insert_optional_param(&mut params, "permit", details.permit);
-        insert_optional_param(&mut params, "receiver", details.receiver);
+        insert_optional_param(&mut params, "receiver", receiver);
         insert_optional_param(&mut params, "referrer", details.referrer);
 
-        let url_with_params = Url::parse_with_params(&url, params).map_err(|e| Box::new(e) as Box<dyn Error>)?;
+        let url_with_params = Url::parse_with_params(&url, params).map_err(|e| OneInchError::Validation(e.to_string()))?;
 
-        let response = match self.http_client.get(url_with_params).header("Authorization", &self.token).send().await {
-            Ok(response) => response,
-            Err(e) => return Err(SwapError::Network(e).into()),
-        };
+        let swap_data: WithRequestId<SwapResponse> =
+            self.request_json_with_id("swap/swap", self.http_client.get(url_with_params)).await?;
+        self.check_price_impact(&src, &dst, &amount, &swap_data.to_amount).await?;
 
-        if response.status().as_u16() == 400 {
-            let error_body = response.text().await.unwrap_or_default();
-            return match serde_json::from_str::<SwapRequestError>(&error_body) {
-                Ok(err) => Err(SwapError::SwapRequest {
-                    description: err.description,
-                    error: err.error,
-                    status_code: err.status_code,
-                    request_id: err.request_id,
-                }
-                .into()),
-                Err(e) => Err(SwapError::Other(format!("Error parsing error response: {}", e)).into()),
-            };
-        }
+        Ok(swap_data)
+    }
 
-        if response.status().is_client_error() || response.status().is_server_error() {
-            return Err(SwapError::Other(format!("Server responded with error: {}", response.status())).into());
-        }
+    /// Performs swap request with predefined parameters.
+    pub async fn swap_v6(&self, details: SwapDetailsV6) -> Result<SwapV6Response, OneInchError> {
+        Ok(self.swap_v6_with_request_id(details).await?.into_inner())
+    }
 
-        let swap_data: SwapResponse = match response.json().await {
-            Ok(data) => data,
-            Err(e) => return Err(SwapError::Network(e).into()),
+    /// Performs swap request like [`swap_v6`](OneInchClient::swap_v6),
+    /// returning the response along with id of the request, for reports to
+    /// 1inch support.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "swap_v6",
+            skip_all,
+            fields(chain = %self.network_id, src = %details.src, dst = %details.dst, amount = %details.amount)
+        )
+    )]
+    pub async fn swap_v6_with_request_id(&self, details: SwapDetailsV6) -> Result<WithRequestId<SwapV6Response>, OneInchError> {
+        #[cfg(feature = "tracing")]
+        tracing::debug!(slippage = details.slippage, disable_estimate = ?details.disable_estimate, "requesting swap");
+
+        let url = format!("{}/swap/{}/{}/swap/", self.base_url(), SWAP_V6_API_VERSION, self.network_id);
+
+        // Resolving addresses, since they can be specified as domains if
+        // resolution is enabled
+        let from = self.resolve_swap_address(details.from).await?;
+        let receiver = match details.receiver {
+            Some(receiver) => Some(self.resolve_swap_address(receiver).await?),
+            None => None,
         };
 
-        Ok(swap_data)
-    }
+        // Checking balance and allowance, so shortfalls are reported in
+        // details instead of the estimation error
+        if self.swap_preflight && details.disable_estimate != Some(true) {
+            let permitted = details.permit.is_some() || details.use_permit2 == Some(true);
+            self.preflight_swap_with_router(SWAP_V6_API_VERSION, &from, &details.src, &details.amount, permitted).await?;
+        }
 
-    /// Performs swap request with predefined parameters.
-    pub async fn swap_v6(&self, details: SwapDetailsV6) -> Result<SwapV6Response, Box<dyn Error>> {
-        tracing::info!("start oninch swap v6 with tails: {:?}", details);
-        let url = format!("{}/swap/{}/{}/swap/", BASIC_URL, SWAP_V6_API_VERSION, self.network_id);
+        let (src, dst, amount) = (details.src.clone(), details.dst.clone(), details.amount.clone());
 
         // Adding required parameters
         let mut params: Vec<(&str, String)> = vec![
-            ("from", details.from),
+            ("from", from),
             ("slippage", details.slippage.to_string()),
             ("src", details.src),
             ("dst", details.dst),
@@ -97,9 +148,12 @@ impl OneInchClient {
         // Adding optional bool parameters
         insert_optional_param(&mut params, "disableEstimate", details.disable_estimate.map(|a| a.to_string()));
         insert_optional_param(&mut params, "allowPartialFill", details.allow_partial_fill.map(|a| a.to_string()));
-        insert_optional_param(&mut params, "includeGas", details.include_gas.map(|a| a.to_string()));
-        insert_optional_param(&mut params, "includeProtocols", details.include_protocols.map(|a| a.to_string()));
-        insert_optional_param(&mut params, "includeTokensInfo", details.include_tokens_info.map(|a| a.to_string()));
+        self.include_defaults.insert_params(
+            &mut params,
+            details.include_tokens_info,
+            details.include_protocols,
+            details.include_gas,
+        );
 
         // Adding optional num parameters
         insert_optional_param(&mut params, "fee", details.fee.map(|a| a.to_string()));
@@ -108,52 +162,24 @@ impl OneInchClient {
         insert_optional_param(&mut params, "mainRouteParts", details.main_route_parts.map(|a| a.to_string()));
         insert_optional_param(&mut params, "gasLimit", details.gas_limit.map(|a| a.to_string()));
 
+        // Resolving gas price, since it can be specified as a tier
+        let gas_price = self.resolve_gas_price(details.gas_price, details.gas_tier).await?;
+
         // Adding optional string parameters
         insert_optional_param(&mut params, "protocols", details.protocols);
-        insert_optional_param(&mut params, "gasPrice", details.gas_price);
+        insert_optional_param(&mut params, "gasPrice", gas_price);
         insert_optional_param(&mut params, "connectorTokens", details.connector_tokens);
         insert_optional_param(&mut params, "permit", details.permit);
-        insert_optional_param(&mut params, "receiver", details.receiver);
+        insert_optional_param(&mut params, "receiver", receiver);
         insert_optional_param(&mut params, "referrer", details.referrer);
 
         insert_optional_param(&mut params, "usePermit2", details.use_permit2.map(|a| a.to_string()));
 
-        let url_with_params = Url::parse_with_params(&url, params).map_err(|e| Box::new(e) as Box<dyn Error>)?;
+        let url_with_params = Url::parse_with_params(&url, params).map_err(|e| OneInchError::Validation(e.to_string()))?;
 
-        let response = match self.http_client.get(url_with_params).header("Authorization", &self.token).send().await {
-            Ok(response) => response,
-            Err(e) => return Err(SwapError::Network(e).into()),
-        };
-
-        if response.status().as_u16() == 400 {
-            let error_body = response.text().await.unwrap_or_default();
-            tracing::info!("oneinch swap v6 response error_body: {:?}", error_body);
-            return match serde_json::from_str::<SwapRequestError>(&error_body) {
-                Ok(err) => Err(SwapError::SwapRequest {
-                    description: err.description,
-                    error: err.error,
-                    status_code: err.status_code,
-                    request_id: err.request_id,
-                }
-                .into()),
-                Err(e) => Err(SwapError::Other(format!("Error parsing error response: {}", e)).into()),
-            };
-        }
-
-        if response.status().is_client_error() || response.status().is_server_error() {
-            return Err(SwapError::Other(format!("Server responded with error: {}", response.status())).into());
-        }
-
-        let text = response.text().await;
-        tracing::info!("oneinche swap v6 response info: {:?}", text);
-        let swap_data: SwapV6Response = match serde_json::from_str(&text?) {
-            Ok(data) => data,
-            Err(e) => return Err(SwapError::JsonParse(e).into()),
-        };
-        // let swap_data: SwapV6Response = match response.json().await {
-        //     Ok(data) => data,
-        //     Err(e) => return Err(SwapError::Network(e).into()),
-        // };
+        let swap_data: WithRequestId<SwapV6Response> =
+            self.request_json_with_id("swap/swap", self.http_client.get(url_with_params)).await?;
+        self.check_price_impact(&src, &dst, &amount, &swap_data.dst_amount).await?;
 
         Ok(swap_data)
     }
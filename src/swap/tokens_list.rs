@@ -1,8 +1,4 @@
-use crate::{
-    client::OneInchClient,
-    common::token::TokenInfo,
-    consts::{BASIC_URL, SWAP_API_VERSION},
-};
+use crate::{client::OneInchClient, common::token::TokenInfo, consts::SWAP_API_VERSION};
 use serde::Deserialize;
 use std::{collections::HashMap, error::Error};
 
@@ -17,17 +13,26 @@ pub struct TokensListResponse {
 impl OneInchClient {
     /// Get current list of tokens that are available for swaping in 1inch.
     pub async fn get_tokens_list(&self) -> Result<TokensListResponse, Box<dyn Error>> {
-        let url = format!("{}/swap/{}/{}/tokens", BASIC_URL, SWAP_API_VERSION, self.network_id);
+        let url = format!("{}/swap/{}/{}/tokens", self.base_url(), SWAP_API_VERSION, self.network_id);
 
-        let request_result = self.http_client.get(url).header("Authorization", &self.token).send().await;
+        Ok(self.request_json("swap/tokens", self.http_client.get(url)).await?)
+    }
+
+    /// Downloads current list of tokens that are available for swaping in
+    /// 1inch straight to the file, without holding it in memory and without
+    /// applying response size limits. Returns amount of bytes written. Not
+    /// available in the browser.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn download_tokens_list(&self, path: impl AsRef<std::path::Path>) -> Result<u64, Box<dyn Error>> {
+        let url = format!("{}/swap/{}/{}/tokens", self.base_url(), SWAP_API_VERSION, self.network_id);
+
+        let request_result = self.send("swap/tokens", self.http_client.get(url)).await;
 
         let response = request_result
             .map_err(|e| Box::new(e) as Box<dyn Error>)?
             .error_for_status()
             .map_err(|e| Box::new(e) as Box<dyn Error>)?;
 
-        let token_list_response: TokensListResponse = response.json().await.map_err(|e| Box::new(e) as Box<dyn Error>)?;
-
-        Ok(token_list_response)
+        Ok(self.stream_body_to_file(response, path.as_ref()).await?)
     }
 }
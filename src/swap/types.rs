@@ -1,7 +1,13 @@
 use crate::builder_setter;
 
-use crate::common::token::TokenInfo;
+use crate::{
+    common::token::TokenInfo,
+    domains::is_address,
+    gas::GasTier,
+    utils::{integrity, params::insert_optional_param},
+};
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 use thiserror::Error;
 
 /// Enumerates potential errors when constructing `SwapDetails`.
@@ -17,6 +23,11 @@ pub enum SwapDetailsBuilderError {
 
     #[error("Invalid fee value. It should be between 0 and 3.")]
     InvalidFee,
+
+    /// Indicates the origin isn't an address. It's an EOA sending the
+    /// transaction, so it can't be a domain or be omitted.
+    #[error("Invalid origin value. It should be an EOA address.")]
+    InvalidOrigin,
 }
 
 /// Enumerates potential errors when constructing `QuoteDetails`.
@@ -36,13 +47,14 @@ pub struct SwapDetails {
     pub src: String,     // Source token address.
     pub dst: String,     // Destination token address.
     pub amount: String,  // Amount to be swapped.
-    pub from: String,    // Address of the user initiating the swap.
+    pub from: String,    // Address of the user initiating the swap, or domain if resolution is enabled.
     pub slippage: usize, // Permitted slippage percentage.
 
     // Optional fields
     pub fee: Option<u8>,
     pub protocols: Option<String>,
     pub gas_price: Option<String>,
+    pub gas_tier: Option<GasTier>, // Used to resolve gas price if `gas_price` isn't set.
     pub complexity_level: Option<u128>,
     pub parts: Option<u128>,
     pub main_route_parts: Option<u128>,
@@ -53,7 +65,7 @@ pub struct SwapDetails {
     pub include_gas: Option<bool>,
     pub connector_tokens: Option<String>,
     pub permit: Option<String>,
-    pub receiver: Option<String>,
+    pub receiver: Option<String>, // Address or domain, like `from`.
     pub referrer: Option<String>,
 
     pub disable_estimate: Option<bool>,   // If true, disables estimation.
@@ -73,6 +85,7 @@ pub struct SwapDetailsBuilder {
     fee: Option<u8>,
     protocols: Option<String>,
     gas_price: Option<String>,
+    gas_tier: Option<GasTier>,
     complexity_level: Option<u128>,
     parts: Option<u128>,
     main_route_parts: Option<u128>,
@@ -114,50 +127,45 @@ pub struct SwapResponse {
 pub struct SwapTranactionData {
     pub from: String,
     pub to: String,
+    #[serde(deserialize_with = "integrity::calldata")]
     pub data: String,
+    #[serde(deserialize_with = "integrity::value")]
     pub value: String,
 
-    #[serde(rename = "gasPrice")]
+    #[serde(rename = "gasPrice", deserialize_with = "integrity::gas_price")]
     pub gas_price: String,
 
     pub gas: u128,
 }
 
-/// Represents errors that can occur during both swap or quote request.
-/// We use the same struct to handle errors that may occur with `swap` and
-/// `quote` requests because the possible errors are almost identical. This enum
-/// aggregates various types of errors related to these operations,
-/// including HTTP requests, JSON parsing, and swap API specific errors.
-#[derive(Error, Debug)]
-pub enum SwapError {
-    /// Error related to network requests.
-    ///
-    /// Used for handling issues with network requests, such as server
-    /// unavailability, network connectivity problems, etc.
-    #[error("Network error: {0}")]
-    Network(reqwest::Error),
-
-    /// Error while parsing JSON.
-    ///
-    /// Occurs when the server's response cannot be correctly deserialized from
-    /// JSON. This could happen if the response format is different than
-    /// expected.
-    #[error("JSON parsing error: {0}")]
-    JsonParse(serde_json::Error),
-
-    /// Specific error related to swap/quote API.
-    ///
-    /// Represents errors specific to the swap API, like insufficient funds or
-    /// invalid request parameters.
-    #[error("Swap request error: {description}")]
-    SwapRequest { description: String, error: String, status_code: u16, request_id: String },
-
-    /// A general error.
-    ///
-    /// Used for other types of errors that do not fit into the above
-    /// categories.
-    #[error("Other error: {0}")]
-    Other(String),
+/// Client-level defaults of the `include*` flags, applied to every quote and
+/// swap request unless the flag is set in the request itself.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct IncludeDefaults {
+    pub tokens_info: Option<bool>,
+    pub protocols: Option<bool>,
+    pub gas: Option<bool>,
+}
+
+impl IncludeDefaults {
+    /// Defaults which include tokens info, protocols and gas in every response.
+    pub fn all() -> IncludeDefaults {
+        IncludeDefaults { tokens_info: Some(true), protocols: Some(true), gas: Some(true) }
+    }
+
+    // Inserts `include*` params, falling back to the defaults for the flags
+    // which aren't set in the request.
+    pub(crate) fn insert_params(
+        &self,
+        params: &mut Vec<(&str, String)>,
+        tokens_info: Option<bool>,
+        protocols: Option<bool>,
+        gas: Option<bool>,
+    ) {
+        insert_optional_param(params, "includeGas", gas.or(self.gas).map(|a| a.to_string()));
+        insert_optional_param(params, "includeProtocols", protocols.or(self.protocols).map(|a| a.to_string()));
+        insert_optional_param(params, "includeTokensInfo", tokens_info.or(self.tokens_info).map(|a| a.to_string()));
+    }
 }
 
 /// Represents an error response from the swap/quote API.
@@ -225,6 +233,7 @@ impl SwapDetailsBuilder {
 
     builder_setter!(protocols, String);
     builder_setter!(gas_price, String);
+    builder_setter!(gas_tier, GasTier);
     builder_setter!(complexity_level, u128);
     builder_setter!(parts, u128);
     builder_setter!(main_route_parts, u128);
@@ -275,6 +284,7 @@ impl SwapDetailsBuilder {
             fee: self.fee,
             protocols: self.protocols,
             gas_price: self.gas_price,
+            gas_tier: self.gas_tier,
             complexity_level: self.complexity_level,
             parts: self.parts,
             main_route_parts: self.main_route_parts,
@@ -410,7 +420,7 @@ pub struct SwapDetailsV6 {
     pub src: String,     // Source token address.
     pub dst: String,     // Destination token address.
     pub amount: String,  // Amount to be swapped.
-    pub from: String,    // Address of the user initiating the swap.
+    pub from: String,    // Address of the user initiating the swap, or domain if resolution is enabled.
     pub origin: String,  // An EOA address that initiate the transaction
     pub slippage: usize, // Permitted slippage percentage.
 
@@ -418,6 +428,7 @@ pub struct SwapDetailsV6 {
     pub fee: Option<u8>,
     pub protocols: Option<String>,
     pub gas_price: Option<String>,
+    pub gas_tier: Option<GasTier>, // Used to resolve gas price if `gas_price` isn't set.
     pub complexity_level: Option<u128>,
     pub parts: Option<u128>,
     pub main_route_parts: Option<u128>,
@@ -428,7 +439,7 @@ pub struct SwapDetailsV6 {
     pub include_gas: Option<bool>,
     pub connector_tokens: Option<String>,
     pub permit: Option<String>,
-    pub receiver: Option<String>,
+    pub receiver: Option<String>, // Address or domain, like `from`.
     pub referrer: Option<String>,
 
     pub disable_estimate: Option<bool>,   // If true, disables estimation.
@@ -437,6 +448,14 @@ pub struct SwapDetailsV6 {
     pub use_permit2: Option<bool>,
 }
 
+impl SwapDetailsV6 {
+    /// Returns true if the swap is a meta-transaction, which is sent by
+    /// `origin` on behalf of another `from` address.
+    pub fn is_meta_transaction(&self) -> bool {
+        !self.from.eq_ignore_ascii_case(&self.origin)
+    }
+}
+
 /// Represents the details required for performing a token swap.
 #[derive(Default)]
 pub struct SwapDetailsV6Builder {
@@ -451,6 +470,7 @@ pub struct SwapDetailsV6Builder {
     pub fee: Option<u8>,
     pub protocols: Option<String>,
     pub gas_price: Option<String>,
+    pub gas_tier: Option<GasTier>, // Used to resolve gas price if `gas_price` isn't set.
     pub complexity_level: Option<u128>,
     pub parts: Option<u128>,
     pub main_route_parts: Option<u128>,
@@ -481,8 +501,24 @@ impl SwapDetailsV6Builder {
     builder_setter!(origin, String);
     builder_setter!(from, String);
 
+    /// Sets both `from` and `origin` to the EOA which both holds the tokens
+    /// and sends the transaction, as in a regular swap.
+    pub fn eoa(self, address: String) -> Self {
+        self.from(address.clone()).origin(address)
+    }
+
+    /// Sets up a meta-transaction: tokens are held by the `smart_wallet`
+    /// contract, while transaction is sent by the `relayer` EOA. Estimation is
+    /// disabled unless it's set explicitly, since API can't estimate a call
+    /// the relayer is going to wrap.
+    pub fn meta_transaction(mut self, smart_wallet: String, relayer: String) -> Self {
+        self.disable_estimate.get_or_insert(true);
+        self.from(smart_wallet).origin(relayer)
+    }
+
     builder_setter!(protocols, String);
     builder_setter!(gas_price, String);
+    builder_setter!(gas_tier, GasTier);
     builder_setter!(complexity_level, u128);
     builder_setter!(parts, u128);
     builder_setter!(main_route_parts, u128);
@@ -529,12 +565,15 @@ impl SwapDetailsV6Builder {
             dst: self.dst.ok_or(SwapDetailsBuilderError::MissingField("dst"))?,
             amount: self.amount.ok_or(SwapDetailsBuilderError::MissingField("amount"))?.to_string(),
             from: self.from.ok_or(SwapDetailsBuilderError::MissingField("from"))?,
-            origin: self.origin.ok_or(SwapDetailsBuilderError::MissingField("origin"))?,
+            origin: Some(self.origin.ok_or(SwapDetailsBuilderError::MissingField("origin"))?)
+                .filter(|origin| is_address(origin))
+                .ok_or(SwapDetailsBuilderError::InvalidOrigin)?,
             slippage: self.slippage.ok_or(SwapDetailsBuilderError::MissingField("slippage"))?,
 
             fee: self.fee,
             protocols: self.protocols,
             gas_price: self.gas_price,
+            gas_tier: self.gas_tier,
             complexity_level: self.complexity_level,
             parts: self.parts,
             main_route_parts: self.main_route_parts,
@@ -571,6 +610,53 @@ pub struct SwapV6Response {
     pub transaction: SwapTranactionData,
 }
 
+/// Configuration of the slippage simulation.
+#[derive(Debug, Clone)]
+pub struct SlippageSimulationConfig {
+    /// Slippage percentages to simulate the swap at.
+    pub candidates: Vec<usize>,
+    /// Time between quoting and execution of the swap, during which the price
+    /// may move.
+    pub horizon: Duration,
+    /// Highest predicted failure probability which is acceptable.
+    pub max_failure_probability: f64,
+}
+
+impl Default for SlippageSimulationConfig {
+    fn default() -> Self {
+        SlippageSimulationConfig {
+            candidates: vec![1, 2, 3, 5, 10],
+            horizon: Duration::from_secs(60),
+            max_failure_probability: 0.05,
+        }
+    }
+}
+
+/// Outcome of the swap simulated at the slippage.
+#[derive(Debug, Clone)]
+pub struct SlippageSimulation {
+    /// Slippage percentage.
+    pub slippage: usize,
+    /// Minimal amount the swap returns at the slippage.
+    pub min_received: String,
+    /// Predicted probability of the swap to revert because of the price
+    /// movement.
+    pub failure_probability: f64,
+}
+
+/// Slippage recommendation along with simulations it's based on.
+#[derive(Debug, Clone)]
+pub struct SlippageRecommendation {
+    /// Tightest simulated slippage with acceptable failure probability, if
+    /// any.
+    pub recommended: Option<usize>,
+    /// Estimated volatility of the pair's price, as standard deviation of log
+    /// returns per second.
+    pub volatility: f64,
+    /// Simulations in ascending order of slippage.
+    pub simulations: Vec<SlippageSimulation>,
+}
+
 /// Tests for the `SwapDetailsBuilder` and related components.
 #[cfg(test)]
 mod tests {
@@ -615,4 +701,59 @@ mod tests {
             assert_eq!(err, SwapDetailsBuilderError::InvalidSlippage);
         }
     }
+
+    /// Tests the builder's response to an origin which isn't an address.
+    #[test]
+    fn test_invalid_origin_in_builder() {
+        let builder = || {
+            SwapDetailsV6Builder::new()
+                .src("from_token".to_string())
+                .dst("to_token".to_string())
+                .amount("1000".to_string())
+                .slippage(1)
+                .unwrap()
+        };
+
+        let result = builder().meta_transaction("wallet.eth".to_string(), "relayer.eth".to_string()).build();
+        assert_eq!(result.unwrap_err(), SwapDetailsBuilderError::InvalidOrigin);
+
+        let details = builder().eoa("0x1111111111111111111111111111111111111111".to_string()).build().unwrap();
+        assert!(!details.is_meta_transaction());
+        assert_eq!(details.disable_estimate, None);
+    }
+
+    /// Tests that per-request `include*` flags take precedence over defaults.
+    #[test]
+    fn test_include_defaults_fallback() {
+        let mut params = vec![];
+        IncludeDefaults::all().insert_params(&mut params, Some(false), None, None);
+
+        assert_eq!(
+            params,
+            vec![
+                ("includeGas", "true".to_string()),
+                ("includeProtocols", "true".to_string()),
+                ("includeTokensInfo", "false".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_malformed_transaction_fields_are_rejected() {
+        let tx = |data: &str, value: &str| {
+            format!(
+                r#"{{"from": "a", "to": "b", "data": "{}", "value": "{}", "gasPrice": "1", "gas": 21000}}"#,
+                data, value
+            )
+        };
+
+        assert!(serde_json::from_str::<SwapTranactionData>(&tx("0x12ab", "0")).is_ok());
+
+        let error = serde_json::from_str::<SwapTranactionData>(&tx("0x12a", "0")).unwrap_err();
+        assert!(error.is_data());
+        assert!(error.to_string().starts_with("invalid `data`: odd number of hex digits"));
+
+        let error = serde_json::from_str::<SwapTranactionData>(&tx("0x", "1e18")).unwrap_err();
+        assert!(error.to_string().starts_with("invalid `value`"));
+    }
 }
@@ -0,0 +1,182 @@
+use crate::{
+    swap::{QuoteResponse, SelectedProtocol, SwapResponse, SwapV6Response},
+    utils::runtime::{SystemTime, UNIX_EPOCH},
+};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    time::Duration,
+};
+
+/// Usage statistics of the venue (protocol) in the recorded routes.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct VenueStats {
+    pub name: String,
+    /// Amount of the recorded quotes and swaps routed through the venue.
+    pub routed: u64,
+    /// Amount of the route hops the venue took part in.
+    pub hops: u64,
+    /// Sum of the venue's parts of the hops, in percents.
+    pub total_share: f64,
+    /// Token pairs (source and destination address) the venue swapped.
+    pub pairs: BTreeSet<(String, String)>,
+}
+
+impl VenueStats {
+    /// Returns average part of the hop the venue took, in percents.
+    pub fn average_share(&self) -> f64 {
+        match self.hops {
+            0 => 0.0,
+            hops => self.total_share / hops as f64,
+        }
+    }
+
+    fn merge(&mut self, other: &VenueStats) {
+        self.routed += other.routed;
+        self.hops += other.hops;
+        self.total_share += other.total_share;
+        self.pairs.extend(other.pairs.iter().cloned());
+    }
+}
+
+/// Venue statistics over the period.
+#[derive(Debug, Clone)]
+pub struct VenuePeriodStats {
+    pub start: SystemTime,
+    /// Amount of the recorded quotes and swaps with routes.
+    pub routes: u64,
+    /// Venues ordered by `routed`, the most used first.
+    pub venues: Vec<VenueStats>,
+}
+
+#[derive(Debug, Default)]
+struct Period {
+    routes: u64,
+    venues: HashMap<String, VenueStats>,
+}
+
+/// Aggregator of venue usage statistics over the recorded quotes and swaps,
+/// bucketed by periods of time. Only responses requested with protocols
+/// included can be recorded.
+#[derive(Debug)]
+pub struct VenueStatsAggregator {
+    period: Duration,
+    periods: BTreeMap<u64, Period>,
+}
+
+impl VenueStatsAggregator {
+    /// Creates aggregator bucketing statistics by `period`.
+    pub fn new(period: Duration) -> VenueStatsAggregator {
+        VenueStatsAggregator { period: period.max(Duration::from_secs(1)), periods: BTreeMap::new() }
+    }
+
+    /// Records route of the quote received at the moment. Returns false if
+    /// the quote has no route.
+    pub fn record_quote(&mut self, at: SystemTime, quote: &QuoteResponse) -> bool {
+        self.record_route(at, quote.protocols.as_deref())
+    }
+
+    /// Records route of the swap received at the moment. Returns false if the
+    /// swap has no route.
+    pub fn record_swap(&mut self, at: SystemTime, swap: &SwapResponse) -> bool {
+        self.record_route(at, swap.protocols.as_deref())
+    }
+
+    /// Records route of the v6 swap received at the moment. Returns false if
+    /// the swap has no route.
+    pub fn record_swap_v6(&mut self, at: SystemTime, swap: &SwapV6Response) -> bool {
+        self.record_route(at, swap.protocols.as_deref())
+    }
+
+    /// Records the route, as quote and swap responses contain it.
+    pub fn record_route(&mut self, at: SystemTime, protocols: Option<&[Vec<Vec<SelectedProtocol>>]>) -> bool {
+        let Some(protocols) = protocols.filter(|p| !p.is_empty()) else {
+            return false;
+        };
+
+        let secs = at.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+        let period = self.periods.entry(secs - secs % self.period.as_secs()).or_default();
+        period.routes += 1;
+
+        let mut routed = HashSet::new();
+        for protocol in protocols.iter().flatten().flatten() {
+            let stats = period
+                .venues
+                .entry(protocol.name.clone())
+                .or_insert_with(|| VenueStats { name: protocol.name.clone(), ..VenueStats::default() });
+
+            if routed.insert(&protocol.name) {
+                stats.routed += 1;
+            }
+            stats.hops += 1;
+            stats.total_share += protocol.part;
+            stats.pairs.insert((protocol.from_token_address.to_lowercase(), protocol.to_token_address.to_lowercase()));
+        }
+
+        true
+    }
+
+    /// Returns statistics per period, from the oldest to the latest.
+    pub fn over_time(&self) -> Vec<VenuePeriodStats> {
+        self.periods
+            .iter()
+            .map(|(start, period)| VenuePeriodStats {
+                start: UNIX_EPOCH + Duration::from_secs(*start),
+                routes: period.routes,
+                venues: sorted(period.venues.values().cloned()),
+            })
+            .collect()
+    }
+
+    /// Returns statistics over all the recorded periods, the most used venues
+    /// first.
+    pub fn totals(&self) -> Vec<VenueStats> {
+        let mut totals: HashMap<&str, VenueStats> = HashMap::new();
+        for stats in self.periods.values().flat_map(|p| p.venues.values()) {
+            totals
+                .entry(&stats.name)
+                .or_insert_with(|| VenueStats { name: stats.name.clone(), ..VenueStats::default() })
+                .merge(stats);
+        }
+
+        sorted(totals.into_values())
+    }
+}
+
+fn sorted(venues: impl Iterator<Item = VenueStats>) -> Vec<VenueStats> {
+    let mut venues: Vec<VenueStats> = venues.collect();
+    venues.sort_by(|a, b| b.routed.cmp(&a.routed).then_with(|| a.name.cmp(&b.name)));
+    venues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn protocol(name: &str, part: f64) -> SelectedProtocol {
+        SelectedProtocol { name: name.into(), part, from_token_address: "0xA".into(), to_token_address: "0xB".into() }
+    }
+
+    #[test]
+    fn test_venue_stats_aggregation() {
+        let mut aggregator = VenueStatsAggregator::new(Duration::from_secs(3600));
+        let start = UNIX_EPOCH + Duration::from_secs(7200);
+
+        let split = vec![vec![vec![protocol("CURVE", 60.0), protocol("UNISWAP_V3", 40.0)], vec![protocol("CURVE", 100.0)]]];
+        assert!(aggregator.record_route(start, Some(&split)));
+        assert!(aggregator.record_route(start + Duration::from_secs(3600), Some(&[vec![vec![protocol("UNISWAP_V3", 100.0)]]])));
+        assert!(!aggregator.record_route(start, None));
+
+        let totals = aggregator.totals();
+        assert_eq!(
+            totals.iter().map(|v| (v.name.as_str(), v.routed)).collect::<Vec<_>>(),
+            vec![("UNISWAP_V3", 2), ("CURVE", 1)]
+        );
+        assert_eq!(totals[1].average_share(), 80.0);
+        assert_eq!(totals[1].pairs.len(), 1);
+
+        let periods = aggregator.over_time();
+        assert_eq!(periods.len(), 2);
+        assert_eq!(periods[0].start, start);
+        assert_eq!(periods[0].venues[0].name, "CURVE");
+    }
+}
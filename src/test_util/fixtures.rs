@@ -0,0 +1,241 @@
+use crate::{client::OneInchClient, common::eip712::keccak256, journal::RequestJournal};
+use reqwest::{RequestBuilder, Response};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+/// Fixtures of the requests stored on disk, a JSON file per request/response
+/// pair, for deterministic integration tests against real API responses.
+///
+/// In recording mode requests are sent as usual and every response is written
+/// to the directory. In replay mode nothing is sent: requests are answered
+/// with the recorded responses, and requests which weren't recorded get 404 in
+/// the format of the API errors.
+///
+/// Requests are matched by endpoint, method, path with query and body, so
+/// fixtures recorded against one base URL replay against any. Identical
+/// requests are numbered in order, so a polled endpoint replays its responses
+/// in the recorded order, the last one repeating. API key isn't recorded.
+///
+/// ```no_run
+/// use one_inch::{client::{new_with_default_http, SupportedNetworks}, test_util::Fixtures};
+///
+/// // Record once against the real API...
+/// let client = new_with_default_http("token".to_string(), SupportedNetworks::Ethereum)
+///     .with_fixtures(Fixtures::record("tests/fixtures/quote"));
+/// // ...and replay in tests, without network or API key
+/// let client = new_with_default_http("mock".to_string(), SupportedNetworks::Ethereum)
+///     .with_fixtures(Fixtures::replay("tests/fixtures/quote"));
+/// ```
+#[derive(Debug)]
+pub struct Fixtures {
+    dir: PathBuf,
+    mode: FixtureMode,
+    occurrences: Mutex<HashMap<String, usize>>,
+}
+
+/// Mode of the [`Fixtures`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum FixtureMode {
+    /// Requests are sent and their responses written to disk.
+    Record,
+    /// Requests are answered with the responses from disk.
+    Replay,
+}
+
+// Request/response pair, as stored on disk.
+#[derive(Debug, Serialize, Deserialize)]
+struct Fixture {
+    endpoint: String,
+    method: String,
+    url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    request_body: Option<String>,
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: String,
+}
+
+impl Fixtures {
+    /// Records responses into the directory, creating it if needed.
+    pub fn record(dir: impl Into<PathBuf>) -> Fixtures {
+        Fixtures::new(dir.into(), FixtureMode::Record)
+    }
+
+    /// Replays responses recorded in the directory.
+    pub fn replay(dir: impl Into<PathBuf>) -> Fixtures {
+        Fixtures::new(dir.into(), FixtureMode::Replay)
+    }
+
+    fn new(dir: PathBuf, mode: FixtureMode) -> Fixtures {
+        Fixtures { dir, mode, occurrences: Mutex::new(HashMap::new()) }
+    }
+
+    pub fn mode(&self) -> FixtureMode {
+        self.mode
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Writes entries of the journal to `journal.json` in the directory, so
+    /// the order of the recorded requests is kept along with them.
+    pub fn save_journal(&self, journal: &RequestJournal) -> Result<(), Box<dyn std::error::Error>> {
+        std::fs::create_dir_all(&self.dir)?;
+        std::fs::write(self.dir.join("journal.json"), serde_json::to_vec_pretty(&journal.entries())?)?;
+        Ok(())
+    }
+
+    pub(crate) async fn dispatch(&self, endpoint: &'static str, request: RequestBuilder) -> Result<Response, reqwest::Error> {
+        let (client, request) = request.build_split();
+        let request = request?;
+
+        let url = match request.url().query() {
+            Some(query) => format!("{}?{}", request.url().path(), query),
+            None => request.url().path().to_string(),
+        };
+        let request_body = request.body().and_then(|body| body.as_bytes()).map(|body| String::from_utf8_lossy(body).into_owned());
+        let key = format!("{} {} {}", request.method(), url, request_body.as_deref().unwrap_or_default());
+        let name = format!("{}-{}", endpoint.replace('/', "_"), &hex::encode(keccak256(key.as_bytes()))[..16]);
+
+        let occurrence = {
+            let mut occurrences = self.occurrences.lock().unwrap();
+            let occurrence = occurrences.entry(name.clone()).or_default();
+            *occurrence += 1;
+            *occurrence - 1
+        };
+
+        match self.mode {
+            FixtureMode::Replay => Ok(self.replayed(&name, occurrence).unwrap_or_else(|| {
+                let description = format!("no fixture recorded for {} {}", request.method(), url);
+                let body = serde_json::json!({
+                    "statusCode": 404,
+                    "error": "Not Found",
+                    "description": description,
+                    "requestId": "fixture",
+                });
+                to_response(404, &[("content-type".into(), "application/json".into())], body.to_string())
+            })),
+            FixtureMode::Record => {
+                let method = request.method().to_string();
+                let response = client.execute(request).await?;
+
+                let status = response.status().as_u16();
+                let headers: Vec<(String, String)> = response
+                    .headers()
+                    .iter()
+                    .filter(|(name, _)| *name != reqwest::header::SET_COOKIE)
+                    .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+                    .collect();
+                let body = String::from_utf8_lossy(&response.bytes().await?).into_owned();
+
+                let fixture = Fixture { endpoint: endpoint.to_string(), method, url, request_body, status, headers, body };
+                if let Err(e) = self.write(&name, occurrence, &fixture) {
+                    tracing::warn!(endpoint, "fixture can't be written: {}", e);
+                }
+
+                Ok(to_response(fixture.status, &fixture.headers, fixture.body))
+            }
+        }
+    }
+
+    fn path(&self, name: &str, occurrence: usize) -> PathBuf {
+        self.dir.join(format!("{}-{}.json", name, occurrence))
+    }
+
+    fn write(&self, name: &str, occurrence: usize, fixture: &Fixture) -> Result<(), Box<dyn std::error::Error>> {
+        std::fs::create_dir_all(&self.dir)?;
+        std::fs::write(self.path(name, occurrence), serde_json::to_vec_pretty(fixture)?)?;
+        Ok(())
+    }
+
+    // Returns the recorded response of the request's occurrence, or of the
+    // last recorded one if the request was repeated more times on replay.
+    fn replayed(&self, name: &str, occurrence: usize) -> Option<Response> {
+        (0..=occurrence).rev().find_map(|occurrence| {
+            let content = std::fs::read(self.path(name, occurrence)).ok()?;
+            match serde_json::from_slice::<Fixture>(&content) {
+                Ok(fixture) => Some(to_response(fixture.status, &fixture.headers, fixture.body)),
+                Err(e) => {
+                    tracing::warn!(name, occurrence, "fixture can't be parsed: {}", e);
+                    None
+                }
+            }
+        })
+    }
+}
+
+fn to_response(status: u16, headers: &[(String, String)], body: String) -> Response {
+    let mut response = http::Response::builder().status(status);
+    for (name, value) in headers {
+        // Body is stored decompressed
+        if name != "content-encoding" && name != "content-length" {
+            response = response.header(name, value);
+        }
+    }
+    response.body(body).expect("fixture response is valid").into()
+}
+
+impl OneInchClient {
+    /// Records responses of the client and its clones to the fixtures, or
+    /// answers requests with them instead of sending, depending on the mode.
+    pub fn with_fixtures(mut self, fixtures: Fixtures) -> OneInchClient {
+        self.fixtures = Some(Arc::new(fixtures));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::{new_with_base_url, new_with_default_http, SupportedNetworks},
+        error::OneInchError,
+        utils::mock_server::MockServer,
+    };
+
+    #[tokio::test]
+    async fn test_recorded_responses_are_replayed() {
+        let dir = std::env::temp_dir().join(format!("one-inch-fixtures-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let server = MockServer::start(r#"{"address": "0x1111111111111111111111111111111111111111"}"#).await;
+        let recording = new_with_base_url("token".to_string(), SupportedNetworks::Ethereum, &server.base_url)
+            .with_fixtures(Fixtures::record(&dir))
+            .with_journal(RequestJournal::new());
+        recording.get_router_address().await.unwrap();
+        recording.get_router_address().await.unwrap();
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 2);
+        assert!(!std::fs::read_to_string(std::fs::read_dir(&dir).unwrap().next().unwrap().unwrap().path())
+            .unwrap()
+            .contains("token"));
+
+        recording.fixtures.as_ref().unwrap().save_journal(recording.journal().unwrap()).unwrap();
+        let journal: serde_json::Value = serde_json::from_slice(&std::fs::read(dir.join("journal.json")).unwrap()).unwrap();
+        assert_eq!(journal[1]["sequence"], 1);
+        assert_eq!(journal[1]["method"], "GET");
+        assert_eq!(journal[1]["endpoint"], "swap/approve/spender");
+        std::fs::remove_file(dir.join("journal.json")).unwrap();
+
+        // Replay doesn't send anything, even to the recorded base URL
+        let replaying =
+            new_with_default_http("mock".to_string(), SupportedNetworks::Ethereum).with_fixtures(Fixtures::replay(&dir));
+        for _ in 0..3 {
+            let router = replaying.get_router_address().await.unwrap();
+            assert_eq!(router.address, "0x1111111111111111111111111111111111111111");
+        }
+        assert_eq!(server.requests().len(), 2);
+
+        let other_chain = replaying.with_chain(SupportedNetworks::Polygon);
+        assert!(matches!(
+            other_chain.get_router_address().await,
+            Err(OneInchError::Api { status_code: 404, .. })
+        ));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
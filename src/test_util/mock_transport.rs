@@ -0,0 +1,174 @@
+use crate::client::{new_with_default_http, OneInchClient, SupportedNetworks};
+use reqwest::{Method, Request, Response};
+use serde_json::json;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+/// Transport answering requests of the client with canned responses instead
+/// of sending them, so swap logic can be unit tested without an API key or
+/// network.
+///
+/// Responses are canned per endpoint, like `swap/quote` or `swap/swap`.
+/// Requests of endpoints without a canned response get 404 in the format of
+/// the API errors. Everything else of the client, like rate limiting,
+/// journaling and price impact checks, works as with real requests.
+///
+/// ```
+/// use one_inch::{client::SupportedNetworks, swap::QuoteDetailsBuilder, test_util::MockTransport};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let client = MockTransport::new().quote("1000").client(SupportedNetworks::Ethereum);
+/// let details = QuoteDetailsBuilder::new()
+///     .src("0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee".to_string())
+///     .dst("0x6b175474e89094c44da98b954eedeac495271d0f".to_string())
+///     .amount("1".to_string())
+///     .build()
+///     .unwrap();
+/// assert_eq!(client.quote(details).await.unwrap().to_amount, "1000");
+/// assert_eq!(client.mock_transport().unwrap().requests()[0].endpoint, "swap/quote");
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct MockTransport {
+    responses: Mutex<HashMap<&'static str, (u16, String)>>,
+    requests: Mutex<Vec<MockRequest>>,
+}
+
+/// Request answered by the [`MockTransport`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MockRequest {
+    pub endpoint: &'static str,
+    pub method: Method,
+    pub url: String,
+}
+
+impl MockTransport {
+    pub fn new() -> MockTransport {
+        MockTransport::default()
+    }
+
+    /// Responds to requests of the endpoint with the status and JSON body.
+    pub fn respond(self, endpoint: &'static str, status: u16, body: impl Into<String>) -> MockTransport {
+        self.responses.lock().unwrap().insert(endpoint, (status, body.into()));
+        self
+    }
+
+    /// Responds to quotes with `QuoteResponse` of the destination amount.
+    pub fn quote(self, to_amount: &str) -> MockTransport {
+        let body = json!({ "toAmount": to_amount, "dstAmount": to_amount });
+        self.respond("swap/quote", 200, body.to_string())
+    }
+
+    /// Responds to swaps, of both API versions, with `SwapResponse` of the
+    /// destination amount, whose transaction calls `to` with `data`.
+    pub fn swap(self, to_amount: &str, to: &str, data: &str) -> MockTransport {
+        let body = json!({
+            "toAmount": to_amount,
+            "dstAmount": to_amount,
+            "tx": {
+                "from": "0x0000000000000000000000000000000000000000",
+                "to": to,
+                "data": data,
+                "value": "0",
+                "gasPrice": "1",
+                "gas": 250000,
+            },
+        });
+        self.respond("swap/swap", 200, body.to_string())
+    }
+
+    /// Returns requests answered so far, in order.
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    /// Creates client of the network answered by the transport, with a dummy
+    /// API key.
+    pub fn client(self, network_id: SupportedNetworks) -> OneInchClient {
+        new_with_default_http("mock".to_string(), network_id).with_transport(self)
+    }
+
+    pub(crate) fn answer(&self, endpoint: &'static str, request: Request) -> Response {
+        self.requests.lock().unwrap().push(MockRequest {
+            endpoint,
+            method: request.method().clone(),
+            url: request.url().to_string(),
+        });
+
+        let (status, body) = self.responses.lock().unwrap().get(endpoint).cloned().unwrap_or_else(|| {
+            let body = json!({
+                "statusCode": 404,
+                "error": "Not Found",
+                "description": format!("no canned response for endpoint {}", endpoint),
+                "requestId": "mock",
+            });
+            (404, body.to_string())
+        });
+
+        http::Response::builder()
+            .status(status)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(body)
+            .expect("canned response is valid")
+            .into()
+    }
+}
+
+impl OneInchClient {
+    /// Answers requests of the client and its clones with the mock transport
+    /// instead of sending them. Pass `Arc<MockTransport>` to keep a handle
+    /// for inspecting the requests.
+    pub fn with_transport(mut self, transport: impl Into<Arc<MockTransport>>) -> OneInchClient {
+        self.transport = Some(transport.into());
+        self
+    }
+
+    /// Returns the mock transport answering requests of the client, if any.
+    pub fn mock_transport(&self) -> Option<&Arc<MockTransport>> {
+        self.transport.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{error::OneInchError, swap::SwapDetailsV6Builder};
+
+    #[tokio::test]
+    async fn test_swaps_are_answered_offline() {
+        let client = MockTransport::new().swap("42", "0x1111", "0xabcd").client(SupportedNetworks::Polygon);
+        let details = SwapDetailsV6Builder::new()
+            .src("0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee".into())
+            .dst("0x6b175474e89094c44da98b954eedeac495271d0f".into())
+            .amount("1000".into())
+            .eoa("0x2222222222222222222222222222222222222222".into())
+            .slippage(1)
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let swap = client.swap_v6(details).await.unwrap();
+        assert_eq!((swap.dst_amount.as_str(), swap.transaction.data.as_str()), ("42", "0xabcd"));
+
+        match client.get_router_address().await {
+            Err(OneInchError::Api { status_code: 404, description, .. }) => {
+                assert!(description.unwrap().contains("no canned response"))
+            }
+            other => panic!("unexpected result: {:?}", other.map(|router| router.address)),
+        }
+
+        let requests = client.mock_transport().unwrap().requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].endpoint, "swap/swap");
+        assert!(requests[0].url.contains("/v6.0/137/swap"));
+
+        // Shared handle of the transport
+        let transport = Arc::new(MockTransport::new().quote("1"));
+        let client = new_with_default_http("mock".into(), SupportedNetworks::Ethereum).with_transport(transport.clone());
+        assert!(client.get_router_address().await.is_err());
+        assert_eq!(transport.requests()[0].endpoint, "swap/approve/spender");
+    }
+}
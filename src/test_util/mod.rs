@@ -0,0 +1,5 @@
+mod fixtures;
+mod mock_transport;
+
+pub use fixtures::*;
+pub use mock_transport::*;
@@ -0,0 +1,73 @@
+use std::{collections::HashMap, time::Duration};
+
+/// Timeouts of the requests, per endpoint. Timeout covers the whole request,
+/// from sending it to reading the response body.
+///
+/// Endpoints are identified by names like `swap/quote` or `swap/tokens`, the
+/// same as in [`ResponseLimits`](crate::response::ResponseLimits).
+///
+/// ```
+/// use one_inch::timeout::Timeouts;
+/// use std::time::Duration;
+///
+/// // Quotes go stale quickly, while tokens list is large.
+/// let timeouts = Timeouts::new()
+///     .default_timeout(Duration::from_secs(10))
+///     .endpoint_timeout("swap/quote", Duration::from_secs(2))
+///     .endpoint_timeout("swap/tokens", Duration::from_secs(30));
+/// assert_eq!(timeouts.timeout_for("swap/quote"), Some(Duration::from_secs(2)));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Timeouts {
+    default: Option<Duration>,
+    per_endpoint: HashMap<String, Duration>,
+    call: Option<Duration>,
+}
+
+impl Timeouts {
+    /// Creates timeouts leaving requests to the HTTP client defaults.
+    pub fn new() -> Timeouts {
+        Timeouts::default()
+    }
+
+    /// Sets timeout of the requests to all the endpoints without their own
+    /// timeout.
+    pub fn default_timeout(mut self, timeout: Duration) -> Timeouts {
+        self.default = Some(timeout);
+        self
+    }
+
+    /// Sets timeout of the requests to the specific endpoint.
+    pub fn endpoint_timeout(mut self, endpoint: impl Into<String>, timeout: Duration) -> Timeouts {
+        self.per_endpoint.insert(endpoint.into(), timeout);
+        self
+    }
+
+    /// Returns timeout applied to the requests to the endpoint, if any.
+    pub fn timeout_for(&self, endpoint: &str) -> Option<Duration> {
+        self.call.or_else(|| self.per_endpoint.get(endpoint).copied()).or(self.default)
+    }
+
+    // Overrides timeouts of all the endpoints, for the client of a single
+    // call.
+    pub(crate) fn with_call_timeout(mut self, timeout: Duration) -> Timeouts {
+        self.call = Some(timeout);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_call_timeout_takes_precedence() {
+        let timeouts =
+            Timeouts::new().default_timeout(Duration::from_secs(10)).endpoint_timeout("swap/quote", Duration::from_secs(2));
+        assert_eq!(timeouts.timeout_for("swap/tokens"), Some(Duration::from_secs(10)));
+
+        let timeouts = timeouts.with_call_timeout(Duration::from_millis(500));
+        assert_eq!(timeouts.timeout_for("swap/quote"), Some(Duration::from_millis(500)));
+        assert_eq!(Timeouts::new().timeout_for("swap/quote"), None);
+    }
+}
@@ -0,0 +1,46 @@
+use crate::{
+    client::OneInchClient,
+    consts::TOKEN_DETAILS_API_VERSION,
+    error::OneInchError,
+    token_details::{PriceChangeInterval, TokenDetailsResponse, TokenPriceChangeResponse},
+};
+use reqwest::Url;
+
+impl OneInchClient {
+    /// Performs request to get description and market data of the token on
+    /// the client's network. Native currency of the network is requested if
+    /// `address` is `None`.
+    pub async fn get_token_details(&self, address: Option<String>) -> Result<TokenDetailsResponse, OneInchError> {
+        let mut url = format!(
+            "{}/token-details/{}/details/{}",
+            self.base_url(),
+            TOKEN_DETAILS_API_VERSION,
+            self.network_id
+        );
+        if let Some(address) = address {
+            url = format!("{}/{}", url, address);
+        }
+
+        self.request_json("token-details/details", self.http_client.get(url)).await
+    }
+
+    /// Performs request to get change of the token price over the interval.
+    pub async fn get_token_price_change(
+        &self,
+        address: String,
+        interval: PriceChangeInterval,
+    ) -> Result<TokenPriceChangeResponse, OneInchError> {
+        let url = format!(
+            "{}/token-details/{}/prices/change/{}/{}",
+            self.base_url(),
+            TOKEN_DETAILS_API_VERSION,
+            self.network_id,
+            address
+        );
+
+        let url_with_params = Url::parse_with_params(&url, [("interval", interval.to_string())])
+            .map_err(|e| OneInchError::Validation(e.to_string()))?;
+
+        self.request_json("token-details/prices/change", self.http_client.get(url_with_params)).await
+    }
+}
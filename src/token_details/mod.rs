@@ -0,0 +1,4 @@
+mod details;
+mod types;
+
+pub use types::*;
@@ -0,0 +1,112 @@
+use serde::Deserialize;
+use strum_macros::Display;
+
+/// Link to the token's page on a social network.
+#[derive(Deserialize, Debug, Clone)]
+pub struct SocialLink {
+    pub name: String,
+    pub url: String,
+    pub handle: Option<String>,
+}
+
+/// Descriptive information about the token and its project.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenAssets {
+    pub name: Option<String>,
+    pub website: Option<String>,
+    pub source_code: Option<String>,
+    pub white_paper: Option<String>,
+    pub description: Option<String>,
+    pub short_description: Option<String>,
+    pub research: Option<String>,
+    pub explorer: Option<String>,
+    #[serde(default, rename = "social_links")]
+    pub social_links: Vec<SocialLink>,
+}
+
+/// Market data of the token, as provided by the market data provider.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenMarketData {
+    /// Name of the market data provider, like `coingecko`.
+    pub provider: Option<String>,
+    #[serde(rename = "providerURL")]
+    pub provider_url: Option<String>,
+    /// Trading volume in USD over the last 24 hours.
+    pub vol24: Option<f64>,
+    /// Market capitalization in USD.
+    pub market_cap: Option<f64>,
+    pub circulating_supply: Option<f64>,
+    pub total_supply: Option<f64>,
+}
+
+/// Represents the struct we receive after requesting token details.
+#[derive(Deserialize, Debug, Clone)]
+pub struct TokenDetailsResponse {
+    pub assets: Option<TokenAssets>,
+    pub details: Option<TokenMarketData>,
+}
+
+/// Interval the token price change is calculated over.
+#[derive(Debug, Display, Copy, Clone)]
+pub enum PriceChangeInterval {
+    #[strum(serialize = "5m")]
+    FiveMinutes,
+    #[strum(serialize = "10m")]
+    TenMinutes,
+    #[strum(serialize = "1h")]
+    Hour,
+    #[strum(serialize = "4h")]
+    FourHours,
+    #[strum(serialize = "24h")]
+    Day,
+    #[strum(serialize = "7d")]
+    Week,
+    #[strum(serialize = "30d")]
+    Month,
+    #[strum(serialize = "1y")]
+    Year,
+}
+
+/// Represents the struct we receive after requesting token price change.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenPriceChangeResponse {
+    /// Absolute change of the price in USD.
+    pub in_usd: f64,
+    /// Relative change of the price in percents.
+    pub in_percent: f64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_details_deserialization() {
+        let body = r#"{
+            "assets": {
+                "name": "1inch",
+                "website": "https://1inch.io",
+                "sourceCode": "https://github.com/1inch",
+                "shortDescription": "DEX aggregator",
+                "social_links": [{"name": "twitter", "url": "https://twitter.com/1inch", "handle": "1inch"}]
+            },
+            "details": {
+                "provider": "coingecko",
+                "providerURL": "https://www.coingecko.com/en/coins/1inch",
+                "vol24": 31877470.0,
+                "marketCap": 451356340.5,
+                "circulatingSupply": 1270000000,
+                "totalSupply": 1500000000
+            }
+        }"#;
+
+        let response: TokenDetailsResponse = serde_json::from_str(body).unwrap();
+
+        assert_eq!(response.assets.unwrap().social_links[0].handle.as_deref(), Some("1inch"));
+        assert_eq!(response.details.unwrap().market_cap, Some(451356340.5));
+        assert_eq!(PriceChangeInterval::Day.to_string(), "24h");
+    }
+}
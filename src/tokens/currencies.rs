@@ -1,9 +1,6 @@
 use serde::Deserialize;
 
-use crate::{
-    client::OneInchClient,
-    consts::{BASIC_URL, SPOT_PRICE_API_VERSION},
-};
+use crate::{client::OneInchClient, consts::SPOT_PRICE_API_VERSION};
 use std::error::Error;
 
 /// `CurrenciesResponse` is a struct that defines a list of supported currencies
@@ -19,15 +16,15 @@ pub struct CurrenciesResponse {
 impl OneInchClient {
     /// Get current list of currencies
     pub async fn get_custom_currencies(&self) -> Result<CurrenciesResponse, Box<dyn Error>> {
-        let url = format!("{}/price/{}/{}/currencies", BASIC_URL, SPOT_PRICE_API_VERSION, self.network_id);
-        let request_result = self.http_client.get(url).header("Authorization", &self.token).send().await;
+        let url = format!("{}/price/{}/{}/currencies", self.base_url(), SPOT_PRICE_API_VERSION, self.network_id);
+        let request_result = self.send("price/currencies", self.http_client.get(url)).await;
 
         let response = request_result
             .map_err(|e| Box::new(e) as Box<dyn Error>)?
             .error_for_status()
             .map_err(|e| Box::new(e) as Box<dyn Error>)?;
 
-        let currencies_list_response: CurrenciesResponse = response.json().await.map_err(|e| Box::new(e) as Box<dyn Error>)?;
+        let currencies_list_response: CurrenciesResponse = self.read_json("price/currencies", response).await?;
 
         Ok(currencies_list_response)
     }
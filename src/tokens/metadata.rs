@@ -0,0 +1,334 @@
+use crate::{client::OneInchClient, common::token::TokenInfo, consts::TOKENS_API_VERSION};
+use serde::Deserialize;
+use strum_macros::Display;
+use thiserror::Error;
+
+/// Source the token metadata was taken from.
+#[derive(Debug, Display, Copy, Clone, Eq, PartialEq)]
+pub enum TokenMetadataSource {
+    /// Tokens list of the swap API.
+    SwapTokensList,
+    /// `symbol()` and `decimals()` of the token contract.
+    OnChain,
+    /// Token info of the Token API.
+    TokenApi,
+}
+
+/// Metadata of the token as reported by one of the sources.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct TokenMetadataObservation {
+    pub source: TokenMetadataSource,
+    pub symbol: String,
+    pub decimals: u8,
+}
+
+impl TokenMetadataObservation {
+    pub fn new(source: TokenMetadataSource, token: &TokenInfo) -> TokenMetadataObservation {
+        TokenMetadataObservation { source, symbol: token.symbol.clone(), decimals: token.decimals }
+    }
+}
+
+// Token of the Token API, which may lack logo and tags.
+#[derive(Deserialize)]
+struct TokenApiToken {
+    address: String,
+    symbol: String,
+    name: String,
+    decimals: u8,
+    #[serde(rename = "logoURI", default)]
+    logo_uri: Option<String>,
+    #[serde(default)]
+    tags: Vec<String>,
+}
+
+impl From<TokenApiToken> for TokenInfo {
+    fn from(token: TokenApiToken) -> TokenInfo {
+        TokenInfo {
+            address: token.address.to_lowercase(),
+            symbol: token.symbol,
+            name: token.name,
+            decimals: token.decimals,
+            logo_uri: token.logo_uri.unwrap_or_default(),
+            domain_version: None,
+            eip2612: None,
+            is_fot: None,
+            tags: token.tags,
+        }
+    }
+}
+
+/// Field of the token metadata sources disagree on.
+#[derive(Debug, Display, Copy, Clone, Eq, PartialEq)]
+pub enum TokenMetadataField {
+    Decimals,
+    Symbol,
+}
+
+/// Disagreement of the sources on the field of the token metadata.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct TokenMetadataMismatch {
+    pub field: TokenMetadataField,
+    /// Value of the field reported by every source.
+    pub values: Vec<(TokenMetadataSource, String)>,
+}
+
+/// Result of the consistency check of the token metadata across sources.
+#[derive(Debug, Clone)]
+pub struct TokenMetadataReport {
+    pub address: String,
+    pub observations: Vec<TokenMetadataObservation>,
+    pub mismatches: Vec<TokenMetadataMismatch>,
+}
+
+impl TokenMetadataReport {
+    /// Returns true if the metadata was reported by at least two sources, so
+    /// there was something to compare.
+    pub fn is_conclusive(&self) -> bool {
+        self.observations.len() >= 2
+    }
+
+    /// Returns true if at least two sources were compared and all of them
+    /// agree on decimals and symbol.
+    pub fn is_consistent(&self) -> bool {
+        self.is_conclusive() && self.mismatches.is_empty()
+    }
+
+    /// Returns decimals of the token if at least two sources were compared and
+    /// all of them agree on them. Amounts scaled by wrong decimals are off by
+    /// orders of magnitude, so there is no fallback to any of the sources.
+    pub fn decimals(&self) -> Option<u8> {
+        if !self.is_conclusive() {
+            return None;
+        }
+        let first = self.observations.first()?;
+        self.observations.iter().all(|o| o.decimals == first.decimals).then_some(first.decimals)
+    }
+}
+
+/// Error of the checked token metadata lookup.
+#[derive(Error, Debug)]
+pub enum TokenMetadataError {
+    /// Sources disagree on the metadata of the token.
+    #[error("Token {address} metadata mismatch: {}", describe(.mismatches))]
+    Mismatch { address: String, mismatches: Vec<TokenMetadataMismatch> },
+    /// Metadata of the token was reported by less than two sources, so it
+    /// couldn't be cross-checked.
+    #[error("Token {address} metadata can't be cross-checked, reported only by {sources:?}")]
+    Inconclusive { address: String, sources: Vec<TokenMetadataSource> },
+    /// None of the sources know the token, or all of them failed.
+    #[error("No metadata found for token {address}")]
+    NotFound { address: String },
+}
+
+fn describe(mismatches: &[TokenMetadataMismatch]) -> String {
+    mismatches
+        .iter()
+        .map(|mismatch| {
+            let values: Vec<String> = mismatch.values.iter().map(|(source, value)| format!("{}={}", source, value)).collect();
+            format!("{} ({})", mismatch.field, values.join(", "))
+        })
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// Compares metadata of the token reported by different sources, flagging
+/// disagreement on decimals or symbol. Symbols are compared ignoring case and
+/// surrounding whitespace.
+pub fn check_token_metadata(address: &str, observations: Vec<TokenMetadataObservation>) -> TokenMetadataReport {
+    let mut mismatches = vec![];
+
+    let field_mismatch = |field, value: fn(&TokenMetadataObservation) -> String| {
+        let values: Vec<(TokenMetadataSource, String)> = observations.iter().map(|o| (o.source, value(o))).collect();
+        let first = values.first().map(|(_, value)| value.clone());
+        values.iter().any(|(_, value)| Some(value) != first.as_ref()).then_some(TokenMetadataMismatch { field, values })
+    };
+
+    mismatches.extend(field_mismatch(TokenMetadataField::Decimals, |o| o.decimals.to_string()));
+    mismatches.extend(field_mismatch(TokenMetadataField::Symbol, |o| o.symbol.trim().to_lowercase()));
+
+    TokenMetadataReport { address: address.to_lowercase(), observations, mismatches }
+}
+
+impl OneInchClient {
+    /// Checks consistency of the token metadata between 1inch tokens list,
+    /// Token API and the token contract. Sources which don't know the token,
+    /// or fail, are left out; report of less than two sources is
+    /// [inconclusive](TokenMetadataReport::is_conclusive).
+    pub async fn check_token_metadata(&self, address: String) -> Result<TokenMetadataReport, TokenMetadataError> {
+        Ok(self.token_metadata_sources(address).await?.0)
+    }
+
+    /// Get information about the token like
+    /// [`get_token_info`](OneInchClient::get_token_info), failing with
+    /// [`TokenMetadataError::Mismatch`] if the sources disagree on its
+    /// decimals or symbol, and with [`TokenMetadataError::Inconclusive`] if
+    /// less than two sources report it.
+    pub async fn get_token_info_checked(&self, address: String) -> Result<TokenInfo, TokenMetadataError> {
+        let (report, token) = self.token_metadata_sources(address).await?;
+        if !report.is_conclusive() {
+            return Err(TokenMetadataError::Inconclusive {
+                address: report.address,
+                sources: report.observations.iter().map(|o| o.source).collect(),
+            });
+        }
+        if !report.is_consistent() {
+            return Err(TokenMetadataError::Mismatch { address: report.address, mismatches: report.mismatches });
+        }
+
+        Ok(token)
+    }
+
+    // Collects metadata from all sources, returning the report along with the
+    // token info of the most complete source.
+    async fn token_metadata_sources(&self, address: String) -> Result<(TokenMetadataReport, TokenInfo), TokenMetadataError> {
+        let mut observations = vec![];
+
+        let listed = match self.get_tokens_list().await {
+            Ok(mut tokens_list) => tokens_list.tokens.remove(&address.to_lowercase()),
+            Err(e) => {
+                tracing::warn!(address, "token metadata can't be read from tokens list: {}", e);
+                None
+            }
+        };
+        if let Some(token) = &listed {
+            observations.push(TokenMetadataObservation::new(TokenMetadataSource::SwapTokensList, token));
+        }
+
+        let url = format!("{}/token/{}/{}/custom/{}", self.base_url(), TOKENS_API_VERSION, self.network_id, address);
+        let token_api = match self.request_json::<TokenApiToken>("token/custom", self.http_client.get(url)).await {
+            Ok(token) => {
+                let token = TokenInfo::from(token);
+                observations.push(TokenMetadataObservation::new(TokenMetadataSource::TokenApi, &token));
+                Some(token)
+            }
+            Err(e) => {
+                tracing::warn!(address, "token metadata can't be read from Token API: {}", e);
+                None
+            }
+        };
+
+        let onchain = match self.get_token_info_onchain(address.clone()).await {
+            Ok(token) => {
+                observations.push(TokenMetadataObservation::new(TokenMetadataSource::OnChain, &token));
+                Some(token)
+            }
+            Err(e) => {
+                tracing::warn!(address, "token metadata can't be read on-chain: {}", e);
+                None
+            }
+        };
+
+        let token = listed.or(token_api).or(onchain).ok_or_else(|| TokenMetadataError::NotFound { address: address.clone() })?;
+
+        let report = check_token_metadata(&address, observations);
+        if !report.is_conclusive() {
+            tracing::warn!(address, "token metadata can't be cross-checked, less than two sources report it");
+        }
+        for mismatch in &report.mismatches {
+            tracing::warn!(address, field = %mismatch.field, "token metadata mismatch: {:?}", mismatch.values);
+        }
+
+        Ok((report, token))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::{new_with_base_url, SupportedNetworks},
+        utils::{assert_send, mock_server::MockServer},
+    };
+
+    fn observation(source: TokenMetadataSource, symbol: &str, decimals: u8) -> TokenMetadataObservation {
+        TokenMetadataObservation { source, symbol: symbol.to_string(), decimals }
+    }
+
+    #[test]
+    fn test_decimals_mismatch_is_flagged() {
+        let report = check_token_metadata(
+            "0xA0b8",
+            vec![
+                observation(TokenMetadataSource::SwapTokensList, "USDC", 6),
+                observation(TokenMetadataSource::OnChain, "usdc ", 6),
+            ],
+        );
+        assert!(report.is_consistent());
+        assert_eq!(report.decimals(), Some(6));
+
+        let report = check_token_metadata(
+            "0xA0b8",
+            vec![
+                observation(TokenMetadataSource::SwapTokensList, "USDC", 6),
+                observation(TokenMetadataSource::OnChain, "USDC", 9),
+            ],
+        );
+        assert_eq!(report.mismatches.len(), 1);
+        assert_eq!(report.mismatches[0].field, TokenMetadataField::Decimals);
+        assert_eq!(report.decimals(), None);
+
+        let error = TokenMetadataError::Mismatch { address: report.address, mismatches: report.mismatches };
+        assert_eq!(
+            error.to_string(),
+            "Token 0xa0b8 metadata mismatch: Decimals (SwapTokensList=6, OnChain=9)"
+        );
+    }
+
+    #[test]
+    fn test_single_source_is_inconclusive() {
+        let report = check_token_metadata("0xA0b8", vec![observation(TokenMetadataSource::TokenApi, "USDC", 6)]);
+        assert!(report.mismatches.is_empty());
+        assert!(!report.is_conclusive());
+        assert!(!report.is_consistent());
+        assert_eq!(report.decimals(), None);
+    }
+
+    #[tokio::test]
+    async fn test_token_api_is_a_metadata_source() {
+        // Tokens list doesn't know the token and the contract call reverts
+        const RESPONSES: &[&str] = &[
+            r#"{"tokens": {}}"#,
+            r#"{"address": "0xa0b8", "symbol": "USDC", "name": "USD Coin", "decimals": 6}"#,
+            r#"{"jsonrpc": "2.0", "id": 1, "error": {"code": 3, "message": "execution reverted"}}"#,
+        ];
+        let server = MockServer::start_sequence(RESPONSES).await;
+        let client = new_with_base_url("token".to_string(), SupportedNetworks::Ethereum, &server.base_url);
+
+        let report = client.check_token_metadata("0xa0b8".to_string()).await.unwrap();
+        assert_eq!(report.observations, vec![observation(TokenMetadataSource::TokenApi, "USDC", 6)]);
+        assert!(!report.is_conclusive());
+        assert!(server.requests()[1].starts_with("/token/v1.2/1/custom/0xa0b8"));
+
+        let server = MockServer::start_sequence(RESPONSES).await;
+        let client = new_with_base_url("token".to_string(), SupportedNetworks::Ethereum, &server.base_url);
+        let error = client.get_token_info_checked("0xa0b8".to_string()).await.unwrap_err();
+        assert!(matches!(
+            error,
+            TokenMetadataError::Inconclusive { sources, .. } if sources == [TokenMetadataSource::TokenApi]
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_failed_tokens_list_is_skipped() {
+        const ROUTES: &[(&str, &str)] = &[
+            ("/token/", r#"{"address": "0xa0b8", "symbol": "USDC", "name": "USD Coin", "decimals": 6}"#),
+            (
+                "/web3/",
+                r#"{"jsonrpc": "2.0", "id": 1, "error": {"code": 3, "message": "execution reverted"}}"#,
+            ),
+        ];
+        let server = MockServer::start_routes(ROUTES).await;
+        let client = new_with_base_url("token".to_string(), SupportedNetworks::Ethereum, &server.base_url);
+
+        let report = client.check_token_metadata("0xa0b8".to_string()).await.unwrap();
+        assert_eq!(report.observations, vec![observation(TokenMetadataSource::TokenApi, "USDC", 6)]);
+        assert!(server.requests()[0].starts_with("/swap/"));
+    }
+
+    #[test]
+    fn test_token_metadata_futures_are_send() {
+        let client = new_with_base_url("token".to_string(), SupportedNetworks::Ethereum, "http://localhost");
+        assert_send(client.check_token_metadata("0xa0b8".to_string()));
+        assert_send(client.get_token_info_checked("0xa0b8".to_string()));
+    }
+}
@@ -1,2 +1,4 @@
 pub mod currencies;
+pub mod metadata;
+pub mod token_list;
 pub mod tokens_price;
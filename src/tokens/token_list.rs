@@ -0,0 +1,287 @@
+use crate::{
+    client::OneInchClient,
+    common::{tags::TokenTag, token::TokenInfo},
+    utils::{
+        iso8601,
+        runtime::{SystemTime, UNIX_EPOCH},
+    },
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    error::Error,
+};
+
+// Limits of the Token Lists schema.
+const MAX_LIST_NAME_LEN: usize = 30;
+const MAX_TOKEN_NAME_LEN: usize = 40;
+const MAX_SYMBOL_LEN: usize = 20;
+const MAX_TAG_ID_LEN: usize = 10;
+const MAX_TAG_NAME_LEN: usize = 20;
+const MAX_TAG_DESCRIPTION_LEN: usize = 200;
+const MAX_TOKEN_TAGS: usize = 10;
+
+/// Token list in the [Token Lists](https://tokenlists.org) format, for tools
+/// which consume only it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenList {
+    pub name: String,
+    /// Time the list was exported at, in ISO 8601.
+    pub timestamp: String,
+    pub version: TokenListVersion,
+    pub tokens: Vec<TokenListEntry>,
+    /// Definitions of the tags the tokens refer to by id.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub tags: BTreeMap<String, TokenListTag>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct TokenListVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenListEntry {
+    pub chain_id: u32,
+    pub address: String,
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+    #[serde(rename = "logoURI", skip_serializing_if = "Option::is_none")]
+    pub logo_uri: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenListTag {
+    pub name: String,
+    pub description: String,
+}
+
+impl TokenList {
+    /// Creates empty list of the version 1.0.0, stamped with the current time.
+    /// Name is cut down to the word characters and spaces, up to 30 of them,
+    /// the schema allows.
+    pub fn new(name: impl Into<String>) -> TokenList {
+        let name = sanitize(&name.into(), |c| is_word(c) || c == ' ', MAX_LIST_NAME_LEN);
+        TokenList {
+            name: name.unwrap_or_else(|| "Token list".to_string()),
+            timestamp: iso_timestamp(SystemTime::now()),
+            version: TokenListVersion { major: 1, minor: 0, patch: 0 },
+            tokens: vec![],
+            tags: BTreeMap::new(),
+        }
+    }
+
+    /// Adds 1inch tokens of the chain to the list. Tags become tag ids
+    /// allowed by the schema, like `peg_usd` for `PEG:USD`, and are defined in
+    /// the list's `tags`.
+    ///
+    /// Names and symbols are cut down to the characters and lengths the
+    /// schema allows, and tokens left without either are skipped.
+    pub fn add_tokens<'a>(&mut self, chain_id: u32, tokens: impl IntoIterator<Item = &'a TokenInfo>) {
+        for token in tokens {
+            let name = sanitize(&token.name, is_token_name_char, MAX_TOKEN_NAME_LEN);
+            let symbol = sanitize(&token.symbol, |c| !c.is_whitespace(), MAX_SYMBOL_LEN);
+            let (Some(name), Some(symbol)) = (name, symbol) else {
+                tracing::debug!(address = %token.address, "token without valid name or symbol is skipped");
+                continue;
+            };
+
+            let tags = token
+                .tags
+                .iter()
+                .filter_map(|raw| self.define_tag(raw))
+                .collect::<BTreeSet<String>>()
+                .into_iter()
+                .take(MAX_TOKEN_TAGS);
+
+            self.tokens.push(TokenListEntry {
+                chain_id,
+                address: token.address.clone(),
+                name,
+                symbol,
+                decimals: token.decimals,
+                logo_uri: Some(token.logo_uri.clone()).filter(|uri| !uri.is_empty()),
+                tags: tags.collect(),
+            });
+        }
+
+        self.tokens.sort_by_key(|entry| (entry.chain_id, entry.address.to_lowercase()));
+    }
+
+    // Defines the 1inch tag in the list, returning its id. Tags whose ids
+    // collide once truncated get numbered ids, like `stablecoi1`.
+    fn define_tag(&mut self, raw: &str) -> Option<String> {
+        let spaced = raw.replace(|c: char| !is_word(c), " ");
+        let name = sanitize(&spaced, |c| is_word(c) || c == ' ', MAX_TAG_NAME_LEN)?;
+        let description = sanitize(&format!("1inch tag {}", raw), is_tag_description_char, MAX_TAG_DESCRIPTION_LEN)?;
+        let base = tag_id(&TokenTag::from(raw));
+
+        for n in 0.. {
+            let id = match n {
+                0 => base.clone(),
+                n => {
+                    let suffix = n.to_string();
+                    format!("{}{}", &base[..base.len().min(MAX_TAG_ID_LEN - suffix.len())], suffix)
+                }
+            };
+            match self.tags.get(&id) {
+                Some(tag) if tag.name == name => return Some(id),
+                Some(_) => continue,
+                None => {
+                    self.tags.insert(id.clone(), TokenListTag { name, description });
+                    return Some(id);
+                }
+            }
+        }
+        None
+    }
+
+    /// Sets version of the list following the previously published one, as
+    /// the Token Lists spec says: major if tokens were removed, minor if only
+    /// added, patch if only their details changed.
+    pub fn stamp_version(&mut self, previous: &TokenList) {
+        let key = |entry: &TokenListEntry| (entry.chain_id, entry.address.to_lowercase());
+        let current: BTreeSet<_> = self.tokens.iter().map(key).collect();
+        let published: BTreeSet<_> = previous.tokens.iter().map(key).collect();
+
+        let version = previous.version;
+        self.version = if !published.is_subset(&current) {
+            TokenListVersion { major: version.major + 1, minor: 0, patch: 0 }
+        } else if current != published {
+            TokenListVersion { minor: version.minor + 1, patch: 0, ..version }
+        } else if self.tokens != previous.tokens || self.tags != previous.tags {
+            TokenListVersion { patch: version.patch + 1, ..version }
+        } else {
+            version
+        };
+    }
+}
+
+impl OneInchClient {
+    /// Exports tokens available for swapping on the client's network as the
+    /// token list of version 1.0.0. See
+    /// [`stamp_version`](TokenList::stamp_version) to continue the versions
+    /// of a published list.
+    pub async fn export_token_list(&self, name: impl Into<String>) -> Result<TokenList, Box<dyn Error>> {
+        let tokens = self.get_tokens_list().await?;
+
+        let mut list = TokenList::new(name);
+        list.add_tokens(self.network_id as u32, tokens.tokens.values());
+        Ok(list)
+    }
+}
+
+// Tag id of the schema: up to 10 word characters, possibly colliding for
+// long tags.
+fn tag_id(tag: &TokenTag) -> String {
+    let raw = match tag {
+        TokenTag::Native => "native".to_string(),
+        TokenTag::Tokens => "tokens".to_string(),
+        TokenTag::Staking => "staking".to_string(),
+        TokenTag::Peg(asset) => format!("peg_{}", asset),
+        TokenTag::Risk(flag) => format!("risk_{}", flag),
+        TokenTag::Other(other) => other.clone(),
+    };
+
+    raw.chars().map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_lowercase() } else { '_' }).take(MAX_TAG_ID_LEN).collect()
+}
+
+// Keeps only the allowed characters, up to `max_len` of them, without
+// surrounding spaces. Returns `None` if nothing is left.
+fn sanitize(value: &str, allowed: impl Fn(char) -> bool, max_len: usize) -> Option<String> {
+    let kept: String = value.chars().filter(|c| allowed(*c)).collect();
+    let kept: String = kept.trim().chars().take(max_len).collect();
+    let kept = kept.trim_end();
+    (!kept.is_empty()).then(|| kept.to_string())
+}
+
+// `\w` of the schema patterns.
+fn is_word(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+fn is_token_name_char(c: char) -> bool {
+    is_word(c) || " .'+-%/:&[]()".contains(c) || matches!(c, '\u{c0}'..='\u{d6}' | '\u{d8}'..='\u{f6}' | '\u{f8}'..='\u{ff}')
+}
+
+fn is_tag_description_char(c: char) -> bool {
+    is_word(c) || " .,:".contains(c)
+}
+
+// Formats time as ISO 8601 in UTC, like `2024-01-31T12:00:00Z`.
+fn iso_timestamp(time: SystemTime) -> String {
+    iso8601::format_timestamp(time.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn token(address: &str, tags: &[&str]) -> TokenInfo {
+        TokenInfo {
+            address: address.to_string(),
+            symbol: "USDC".to_string(),
+            name: "USD Coin".to_string(),
+            decimals: 6,
+            logo_uri: String::new(),
+            domain_version: None,
+            eip2612: None,
+            is_fot: None,
+            tags: tags.iter().map(|tag| tag.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_token_list_export() {
+        assert_eq!(iso_timestamp(UNIX_EPOCH + Duration::from_secs(1_706_702_400)), "2024-01-31T12:00:00Z");
+
+        let mut list = TokenList::new("1inch");
+        list.add_tokens(1, &[token("0xb", &["PEG:USD", "tokens"]), token("0xa", &[])]);
+        let json = serde_json::to_value(&list).unwrap();
+        assert_eq!(json["tokens"][0]["address"], "0xa");
+        assert_eq!(json["tokens"][1]["chainId"], 1);
+        assert_eq!(json["tokens"][1]["tags"], serde_json::json!(["peg_usd", "tokens"]));
+        assert_eq!(json["tags"]["peg_usd"]["name"], "PEG USD");
+        assert_eq!(json["tags"]["peg_usd"]["description"], "1inch tag PEG:USD");
+        assert!(json["tokens"][0].get("logoURI").is_none());
+
+        let mut next = list.clone();
+        next.add_tokens(10, &[token("0xc", &[])]);
+        next.stamp_version(&list);
+        assert_eq!(next.version, TokenListVersion { major: 1, minor: 1, patch: 0 });
+
+        let mut reduced = TokenList::new("1inch");
+        reduced.add_tokens(1, &[token("0xa", &[])]);
+        reduced.stamp_version(&next);
+        assert_eq!(reduced.version, TokenListVersion { major: 2, minor: 0, patch: 0 });
+    }
+
+    #[test]
+    fn test_token_list_follows_schema_limits() {
+        let mut list = TokenList::new("1inch: Ethereum tokens, exported by the SDK");
+        assert_eq!(list.name, "1inch Ethereum tokens exported");
+
+        let mut long = token("0xa", &["stablecoin_a", "stablecoin_b", "stablecoin_a"]);
+        long.name = format!("{} 🚀", "Very Long Token Name ".repeat(3));
+        long.symbol = "USD C".to_string();
+        let mut unnamed = token("0xb", &[]);
+        unnamed.name = "🚀🚀".to_string();
+        list.add_tokens(1, &[long, unnamed]);
+
+        assert_eq!(list.tokens.len(), 1);
+        let entry = &list.tokens[0];
+        assert_eq!(entry.name, "Very Long Token Name Very Long Token Nam");
+        assert_eq!(entry.symbol, "USDC");
+        assert_eq!(entry.tags, vec!["stablecoi1", "stablecoin"]);
+        assert_eq!(list.tags["stablecoin"].name, "stablecoin_a");
+        assert_eq!(list.tags["stablecoi1"].name, "stablecoin_b");
+    }
+}
@@ -1,12 +1,15 @@
 use crate::{
-    builder_setter, client,
+    builder_setter,
+    chunked::{fetch_chunked, ChunkedRequestError},
+    client,
     client::OneInchClient,
-    consts::{BASIC_URL, SPOT_PRICE_API_VERSION},
+    consts::{SPOT_PRICE_API_VERSION, SPOT_PRICE_MAX_ADDRESSES},
+    error::OneInchError,
     utils::builder::BasicBuilderError,
 };
 use reqwest::Url;
 use serde::{Deserialize, Serialize};
-use std::{collections::HashMap, error::Error};
+use std::collections::HashMap;
 
 /// Builder struct to create instance of
 /// [`TokensPricesRequestDetails`](crate::tokens::tokens_price::TokensPricesRequestDetails)
@@ -47,7 +50,7 @@ pub struct TokensPricesRequestDetails {
 /// for specified tokens. In fact response is just a hashmap where key is
 /// token`s address and value its price in selected currency. Note : 1inch
 /// always returns tokens addresses in lowercase.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Default)]
 pub struct TokenPricesResponse {
     #[serde(flatten)]
     pub prices: HashMap<String, String>,
@@ -55,37 +58,47 @@ pub struct TokenPricesResponse {
 
 impl OneInchClient {
     /// Performs request to get price of specified tokens in specified currency.
-    pub async fn get_tokens_price(&self, details: TokensPricesRequestDetails) -> Result<TokenPricesResponse, Box<dyn Error>> {
-        let base_url = format!("{}/price/{}/{}/", BASIC_URL, SPOT_PRICE_API_VERSION, self.network_id);
+    ///
+    /// Addresses are deduplicated and, if there are more than the API accepts
+    /// at once, requested in chunks whose prices are merged. If some chunks
+    /// fail, the error is [`ChunkedRequestError`] with prices of the rest.
+    pub async fn get_tokens_price(
+        &self,
+        details: TokensPricesRequestDetails,
+    ) -> Result<TokenPricesResponse, ChunkedRequestError<TokenPricesResponse>> {
+        let mut addresses: Vec<String> = details.addresses.iter().map(|addr| addr.to_lowercase()).collect();
+        addresses.sort();
+        addresses.dedup();
+
+        let currency = details.currency;
+        let prices = fetch_chunked(
+            addresses,
+            SPOT_PRICE_MAX_ADDRESSES,
+            |chunk| self.get_tokens_price_chunk(chunk, currency.clone()),
+            |merged: &mut TokenPricesResponse, chunk| merged.prices.extend(chunk.prices),
+        )
+        .await?;
+
+        Ok(prices)
+    }
 
-        let comma_separated_addresses = details
-            .addresses
-            .iter()
-            .map(|addr| addr.to_string())
-            .map(|addr| addr.to_lowercase())
-            .collect::<Vec<String>>()
-            .join(",");
+    pub(crate) async fn get_tokens_price_chunk(
+        &self,
+        addresses: Vec<String>,
+        currency: Option<client::SupportedCurrencies>,
+    ) -> Result<TokenPricesResponse, OneInchError> {
+        let base_url = format!("{}/price/{}/{}/", self.base_url(), SPOT_PRICE_API_VERSION, self.network_id);
 
-        let mut url_with_params = format!("{}{}", base_url, comma_separated_addresses);
+        let mut url_with_params = format!("{}{}", base_url, addresses.join(","));
 
         // Adding `currency` param if not None
-        if let Some(currency) = details.currency {
+        if let Some(currency) = currency {
             url_with_params = format!("{}?currency={}", url_with_params, currency);
         }
 
-        let url = Url::parse(&url_with_params).map_err(|e| Box::new(e) as Box<dyn Error>)?;
-
-        dbg!(&url.clone().to_string());
-
-        let request_result = self.http_client.get(url).header("Authorization", &self.token).send().await;
-
-        let response = request_result
-            .map_err(|e| Box::new(e) as Box<dyn Error>)?
-            .error_for_status()
-            .map_err(|e| Box::new(e) as Box<dyn Error>)?;
-
-        let tokens_prices_response: TokenPricesResponse = response.json().await.map_err(|e| Box::new(e) as Box<dyn Error>)?;
+        let url = Url::parse(&url_with_params).map_err(|e| OneInchError::Validation(e.to_string()))?;
 
-        Ok(tokens_prices_response)
+        // Goes through the response cache, so prices can be served stale
+        self.request_json("price/prices", self.http_client.get(url)).await
     }
 }
@@ -0,0 +1,4 @@
+mod trace;
+mod types;
+
+pub use types::*;
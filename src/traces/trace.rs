@@ -0,0 +1,32 @@
+use crate::{
+    client::OneInchClient,
+    consts::TRACES_API_VERSION,
+    traces::{TransactionTrace, TransactionTraceResponse},
+};
+use std::error::Error;
+
+impl OneInchClient {
+    /// Performs request to get call trace tree of the transaction included in
+    /// the block on the client's network.
+    pub async fn get_transaction_trace(&self, block: u64, tx_hash: String) -> Result<TransactionTrace, Box<dyn Error>> {
+        let url = format!(
+            "{}/traces/{}/chain/{}/block-trace/{}/tx-hash/{}",
+            self.base_url(),
+            TRACES_API_VERSION,
+            self.network_id,
+            block,
+            tx_hash
+        );
+
+        let request_result = self.send("traces/transaction", self.http_client.get(url)).await;
+
+        let response = request_result
+            .map_err(|e| Box::new(e) as Box<dyn Error>)?
+            .error_for_status()
+            .map_err(|e| Box::new(e) as Box<dyn Error>)?;
+
+        let trace: TransactionTraceResponse = self.read_json("traces/transaction", response).await?;
+
+        Ok(trace.transaction_trace)
+    }
+}
@@ -0,0 +1,112 @@
+use serde::Deserialize;
+
+/// Call of the transaction, along with calls it made in turn.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CallTrace {
+    /// Kind of the call: `CALL`, `DELEGATECALL`, `STATICCALL`, `CREATE`, etc.
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub from: String,
+    pub to: Option<String>,
+    pub value: Option<String>,
+    pub gas: Option<String>,
+    pub gas_used: Option<String>,
+    pub input: Option<String>,
+    pub output: Option<String>,
+    /// Error the call failed with, if it did.
+    pub error: Option<String>,
+    pub revert_reason: Option<String>,
+    #[serde(default)]
+    pub calls: Vec<CallTrace>,
+}
+
+impl CallTrace {
+    /// Returns true if the call failed.
+    pub fn is_failed(&self) -> bool {
+        self.error.is_some()
+    }
+
+    /// Returns the deepest failed call on the path of failed calls starting
+    /// from this one, which usually is the root cause of the revert. Returns
+    /// `None` if this call didn't fail.
+    pub fn root_cause(&self) -> Option<&CallTrace> {
+        if !self.is_failed() {
+            return None;
+        }
+
+        // Failure usually bubbles up from the last failed subcall
+        match self.calls.iter().rev().find(|c| c.is_failed()) {
+            Some(call) => call.root_cause(),
+            None => Some(self),
+        }
+    }
+
+    /// Returns this call and all nested calls in depth-first order, along with
+    /// their depth in the tree.
+    pub fn flatten(&self) -> Vec<(usize, &CallTrace)> {
+        let mut calls = vec![];
+        let mut stack = vec![(0, self)];
+
+        while let Some((depth, call)) = stack.pop() {
+            calls.push((depth, call));
+            stack.extend(call.calls.iter().rev().map(|c| (depth + 1, c)));
+        }
+
+        calls
+    }
+}
+
+/// Trace of the transaction: its top-level call tree along with transaction
+/// details.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionTrace {
+    pub tx_hash: Option<String>,
+    pub nonce: Option<String>,
+    pub gas_price: Option<String>,
+    pub status: Option<String>,
+    #[serde(flatten)]
+    pub call: CallTrace,
+}
+
+/// Represents the struct we receive after requesting transaction trace.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionTraceResponse {
+    pub transaction_trace: TransactionTrace,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trace_root_cause() {
+        let body = r#"{
+            "transactionTrace": {
+                "txHash": "0x01",
+                "type": "CALL",
+                "from": "0xa",
+                "to": "0xb",
+                "error": "execution reverted",
+                "calls": [
+                    {"type": "STATICCALL", "from": "0xb", "to": "0xc"},
+                    {"type": "DELEGATECALL", "from": "0xb", "to": "0xd", "error": "execution reverted", "calls": [
+                        {"type": "CALL", "from": "0xb", "to": "0xe", "error": "execution reverted", "revertReason": "Return amount is not enough"}
+                    ]}
+                ]
+            }
+        }"#;
+
+        let trace = serde_json::from_str::<TransactionTraceResponse>(body).unwrap().transaction_trace;
+
+        assert_eq!(trace.tx_hash.as_deref(), Some("0x01"));
+        let cause = trace.call.root_cause().unwrap();
+        assert_eq!(cause.to.as_deref(), Some("0xe"));
+        assert_eq!(cause.revert_reason.as_deref(), Some("Return amount is not enough"));
+
+        let path: Vec<(usize, &str)> = trace.call.flatten().iter().map(|(d, c)| (*d, c.to.as_deref().unwrap())).collect();
+        assert_eq!(path, vec![(0, "0xb"), (1, "0xc"), (1, "0xd"), (2, "0xe")]);
+    }
+}
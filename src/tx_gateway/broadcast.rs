@@ -0,0 +1,46 @@
+use crate::{
+    client::OneInchClient,
+    consts::TX_GATEWAY_API_VERSION,
+    error::OneInchError,
+    tx_gateway::{BroadcastRequest, BroadcastResponse},
+};
+
+impl OneInchClient {
+    /// Broadcasts signed transaction to the public mempool of the client's
+    /// network via Transaction Gateway API and returns its hash. Transaction
+    /// of the swap response should be signed by the swap's origin first.
+    pub async fn broadcast_public_tx(&self, raw_tx: String) -> Result<String, OneInchError> {
+        let url = format!("{}/tx-gateway/{}/{}/broadcast", self.base_url(), TX_GATEWAY_API_VERSION, self.network_id);
+
+        let body = BroadcastRequest { raw_transaction: raw_tx };
+
+        let broadcast: BroadcastResponse =
+            self.request_json("tx-gateway/broadcast", self.http_client.post(url).json(&body)).await?;
+
+        Ok(broadcast.transaction_hash)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::{new_with_base_url, SupportedNetworks},
+        utils::mock_server::MockServer,
+    };
+
+    #[tokio::test]
+    async fn test_rejected_broadcast_keeps_api_description() {
+        let server = MockServer::start_with_status(
+            400,
+            r#"{"error": "Bad Request", "description": "nonce too low", "statusCode": 400, "requestId": "id"}"#,
+        )
+        .await;
+        let client = new_with_base_url("token".to_string(), SupportedNetworks::Ethereum, &server.base_url);
+
+        let error = client.broadcast_public_tx("0x02f8".to_string()).await.unwrap_err();
+        assert!(matches!(&error, OneInchError::Api { description: Some(description), .. } if description == "nonce too low"));
+        assert_eq!(error.request_id(), Some("id"));
+        assert!(server.requests()[0].starts_with("/tx-gateway/v1.1/1/broadcast"));
+    }
+}
@@ -0,0 +1,4 @@
+mod broadcast;
+mod types;
+
+pub use types::*;
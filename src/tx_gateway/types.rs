@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+/// Body of the broadcast request.
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BroadcastRequest {
+    /// Hex encoded signed transaction.
+    pub raw_transaction: String,
+}
+
+/// Represents the struct we receive after broadcasting the transaction.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BroadcastResponse {
+    pub transaction_hash: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_broadcast_request_serialization() {
+        let body = serde_json::to_value(BroadcastRequest { raw_transaction: "0x02f8".into() }).unwrap();
+        assert_eq!(body, serde_json::json!({ "rawTransaction": "0x02f8" }));
+    }
+}
@@ -0,0 +1,79 @@
+use reqwest::{header::HeaderMap, Response};
+use std::{
+    collections::HashSet,
+    sync::{Mutex, OnceLock},
+};
+
+// Client methods known to be deprecated together with their replacements.
+const DEPRECATED_METHODS: &[(&str, &str)] = &[("swap", "swap_v6")];
+
+// Keys of the warnings which have already been emitted by the process.
+static EMITTED: OnceLock<Mutex<HashSet<String>>> = OnceLock::new();
+
+// Returns true only the first time it's called with the key.
+fn first_time(key: String) -> bool {
+    EMITTED.get_or_init(|| Mutex::new(HashSet::new())).lock().unwrap().insert(key)
+}
+
+// Emits a one-time warning if the client method is known to be deprecated.
+pub(crate) fn warn_if_deprecated_method(method: &'static str) {
+    let Some((_, replacement)) = DEPRECATED_METHODS.iter().find(|(m, _)| *m == method) else {
+        return;
+    };
+
+    if first_time(format!("method:{}", method)) {
+        tracing::warn!(
+            method,
+            replacement,
+            "OneInchClient::{} uses deprecated API version, consider migrating to OneInchClient::{}",
+            method,
+            replacement
+        );
+    }
+}
+
+// Emits a one-time warning per endpoint if the server signals endpoint
+// deprecation via `Deprecation`/`Sunset` headers.
+pub(crate) fn warn_if_deprecated_response(endpoint: &'static str, response: &Response) {
+    if let Some((deprecation, sunset)) = deprecation_signals(response.headers()) {
+        if first_time(format!("endpoint:{}", endpoint)) {
+            tracing::warn!(
+                endpoint,
+                deprecation = deprecation.as_deref(),
+                sunset = sunset.as_deref(),
+                "1inch API signals that {} endpoint is deprecated",
+                endpoint
+            );
+        }
+    }
+}
+
+// Returns values of `Deprecation` and `Sunset` headers, if any of them is
+// present.
+fn deprecation_signals(headers: &HeaderMap) -> Option<(Option<String>, Option<String>)> {
+    let header = |name| headers.get(name).and_then(|v| v.to_str().ok()).map(|v| v.to_string());
+    let (deprecation, sunset) = (header("deprecation"), header("sunset"));
+
+    if deprecation.is_none() && sunset.is_none() {
+        return None;
+    }
+    Some((deprecation, sunset))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    #[test]
+    fn test_deprecation_signals_and_one_time_keys() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(deprecation_signals(&headers), None);
+
+        headers.insert("sunset", HeaderValue::from_static("Wed, 01 Jan 2025 00:00:00 GMT"));
+        assert_eq!(deprecation_signals(&headers), Some((None, Some("Wed, 01 Jan 2025 00:00:00 GMT".into()))));
+
+        assert!(first_time("test:key".into()));
+        assert!(!first_time("test:key".into()));
+    }
+}
@@ -0,0 +1,71 @@
+//! Integrity checks of the transaction fields in the responses, run during
+//! deserialization. Malformed field fails deserialization with the field name
+//! in the error, instead of surfacing later as a cryptic signer error.
+
+use num_bigint::BigUint;
+use serde::{de, Deserialize, Deserializer};
+
+// Max length of the calldata, by the 128KB transaction size limit of nodes.
+const MAX_CALLDATA_BYTES: usize = 128 * 1024;
+
+/// Checks that the calldata is `0x` prefixed hex of whole bytes, within the
+/// transaction size limit.
+pub(crate) fn check_calldata(data: &str) -> Result<(), String> {
+    let hex = data.strip_prefix("0x").ok_or("missing 0x prefix")?;
+    if hex.len() % 2 != 0 {
+        return Err("odd number of hex digits".to_string());
+    }
+    if hex.len() / 2 > MAX_CALLDATA_BYTES {
+        return Err(format!("{} bytes exceed the transaction size limit", hex.len() / 2));
+    }
+    if let Some(position) = hex.find(|c: char| !c.is_ascii_hexdigit()) {
+        return Err(format!("non-hex character at position {}", position + 2));
+    }
+    Ok(())
+}
+
+/// Checks that the value is a non-negative decimal integer.
+pub(crate) fn check_integer(value: &str) -> Result<(), String> {
+    value.parse::<BigUint>().map(|_| ()).map_err(|_| format!("{:?} is not an integer", value))
+}
+
+fn checked<'de, D: Deserializer<'de>>(
+    deserializer: D,
+    field: &str,
+    check: fn(&str) -> Result<(), String>,
+) -> Result<String, D::Error> {
+    let value = String::deserialize(deserializer)?;
+    check(&value).map_err(|reason| de::Error::custom(format!("invalid `{}`: {}", field, reason)))?;
+    Ok(value)
+}
+
+pub(crate) fn calldata<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    checked(deserializer, "data", check_calldata)
+}
+
+pub(crate) fn value<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    checked(deserializer, "value", check_integer)
+}
+
+pub(crate) fn gas_price<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    checked(deserializer, "gasPrice", check_integer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transaction_fields_checks() {
+        assert!(check_calldata("0x").is_ok());
+        assert!(check_calldata("0x12aB").is_ok());
+        assert_eq!(check_calldata("12ab"), Err("missing 0x prefix".to_string()));
+        assert_eq!(check_calldata("0x123"), Err("odd number of hex digits".to_string()));
+        assert_eq!(check_calldata("0x12zz"), Err("non-hex character at position 4".to_string()));
+        assert!(check_calldata(&format!("0x{}", "00".repeat(MAX_CALLDATA_BYTES + 1))).is_err());
+
+        assert!(check_integer("1000000000000000000000000").is_ok());
+        assert!(check_integer("-1").is_err());
+        assert!(check_integer("1.5").is_err());
+    }
+}
@@ -0,0 +1,98 @@
+//! Conversions between unix timestamps and ISO 8601 date-times, like
+//! `2024-01-31T12:00:00Z`, which some APIs return instead of timestamps.
+
+/// Formats unix timestamp as ISO 8601 in UTC, like `2024-01-31T12:00:00Z`.
+pub(crate) fn format_timestamp(secs: u64) -> String {
+    let (days, secs_of_day) = (secs / 86_400, secs % 86_400);
+
+    // Civil date from days since the epoch, see
+    // http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let z = days as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        secs_of_day / 3600,
+        secs_of_day % 3600 / 60,
+        secs_of_day % 60
+    )
+}
+
+/// Parses ISO 8601 date-time with fractional seconds and `Z` or `±HH:MM`
+/// offset, like `2024-01-31T12:00:00.000Z`, into unix timestamp. Returns
+/// `None` for malformed input and times before the epoch.
+pub(crate) fn parse_timestamp(value: &str) -> Option<u64> {
+    let (date, rest) = value.split_once(['T', ' '])?;
+    let (time, offset) = match rest.strip_suffix(['Z', 'z']) {
+        Some(time) => (time, 0),
+        None => {
+            let at = rest.rfind(['+', '-'])?;
+            let (hours, minutes) = rest[at + 1..].split_once(':')?;
+            let offset = number(hours, 2)? * 3600 + number(minutes, 2)? * 60;
+            (&rest[..at], if rest[at..].starts_with('-') { -offset } else { offset })
+        }
+    };
+
+    let mut date = date.split('-');
+    let (year, month, day) = (number(date.next()?, 4)?, number(date.next()?, 2)?, number(date.next()?, 2)?);
+    let time = time.split_once('.').map_or(time, |(time, _)| time).split(':').collect::<Vec<_>>();
+    let [hours, minutes, seconds] = time[..] else {
+        return None;
+    };
+    let (hours, minutes, seconds) = (number(hours, 2)?, number(minutes, 2)?, number(seconds, 2)?);
+    if date.next().is_some()
+        || !(1..=12).contains(&month)
+        || !(1..=31).contains(&day)
+        || hours > 23
+        || minutes > 59
+        || seconds > 60
+    {
+        return None;
+    }
+
+    // Days since the epoch from civil date, see
+    // http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let doy = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146_097 + doe - 719_468;
+
+    u64::try_from(days * 86_400 + hours * 3600 + minutes * 60 + seconds - offset).ok()
+}
+
+// Parses the number of exactly `digits` decimal digits.
+fn number(value: &str, digits: usize) -> Option<i64> {
+    (value.len() == digits && value.bytes().all(|b| b.is_ascii_digit())).then(|| value.parse().ok())?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_iso_timestamps() {
+        assert_eq!(format_timestamp(1_706_702_400), "2024-01-31T12:00:00Z");
+        assert_eq!(parse_timestamp("2024-01-31T12:00:00Z"), Some(1_706_702_400));
+        assert_eq!(parse_timestamp("2024-01-31T12:00:00.000Z"), Some(1_706_702_400));
+        assert_eq!(parse_timestamp("2024-01-31T14:30:00+02:30"), Some(1_706_702_400));
+        assert_eq!(parse_timestamp("2024-02-29T00:00:00Z"), Some(1_709_164_800));
+        assert_eq!(parse_timestamp(&format_timestamp(4_102_444_799)), Some(4_102_444_799));
+
+        assert_eq!(parse_timestamp("1700000000"), None);
+        assert_eq!(parse_timestamp("2024-13-01T00:00:00Z"), None);
+        assert_eq!(parse_timestamp("2024-01-31T12:00Z"), None);
+        assert_eq!(parse_timestamp("1969-12-31T23:59:59Z"), None);
+    }
+}
@@ -0,0 +1,31 @@
+//! Request metrics recorded via the `metrics` facade when the `metrics`
+//! feature is enabled, and nothing otherwise.
+
+use reqwest::Response;
+use std::time::Duration;
+
+/// Records the attempt to send request to the endpoint: its status, or the
+/// kind of the error if no response was received, and its latency.
+#[cfg(feature = "metrics")]
+pub(crate) fn record_response(endpoint: &'static str, result: &reqwest::Result<Response>, elapsed: Duration) {
+    match result {
+        Ok(response) => {
+            let status = response.status();
+            metrics::counter!("one_inch_requests_total", "endpoint" => endpoint, "status" => status.as_u16().to_string())
+                .increment(1);
+            if status.is_client_error() || status.is_server_error() {
+                metrics::counter!("one_inch_request_errors_total", "endpoint" => endpoint, "kind" => "status").increment(1);
+            }
+        }
+        Err(e) => {
+            let kind = if e.is_timeout() { "timeout" } else { "network" };
+            metrics::counter!("one_inch_requests_total", "endpoint" => endpoint, "status" => "none").increment(1);
+            metrics::counter!("one_inch_request_errors_total", "endpoint" => endpoint, "kind" => kind).increment(1);
+        }
+    }
+
+    metrics::histogram!("one_inch_request_duration_seconds", "endpoint" => endpoint).record(elapsed.as_secs_f64());
+}
+
+#[cfg(not(feature = "metrics"))]
+pub(crate) fn record_response(_endpoint: &'static str, _result: &reqwest::Result<Response>, _elapsed: Duration) {}
@@ -0,0 +1,156 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+};
+
+/// Minimal HTTP server for tests, responding to every request with the same
+/// JSON body (or bodies in sequence, or by path) and recording targets (path
+/// with query) and heads of the requests. Connections are served
+/// concurrently.
+pub(crate) struct MockServer {
+    pub(crate) base_url: String,
+    requests: Arc<Mutex<Vec<String>>>,
+    heads: Arc<Mutex<Vec<String>>>,
+    max_in_flight: Arc<AtomicUsize>,
+}
+
+impl MockServer {
+    pub(crate) async fn start(body: &'static str) -> MockServer {
+        MockServer::start_with_status(200, body).await
+    }
+
+    /// Starts the server responding with the status instead of 200.
+    pub(crate) async fn start_with_status(status: u16, body: &'static str) -> MockServer {
+        MockServer::start_with_headers(status, &[], body).await
+    }
+
+    /// Starts the server responding with the status and extra headers.
+    pub(crate) async fn start_with_headers(
+        status: u16,
+        headers: &'static [(&'static str, &'static str)],
+        body: &'static str,
+    ) -> MockServer {
+        MockServer::start_responding(headers, move |_, _| (status, body, Duration::ZERO)).await
+    }
+
+    /// Starts the server responding with the bodies in order of the requests,
+    /// repeating the last one once they run out.
+    pub(crate) async fn start_sequence(bodies: &'static [&'static str]) -> MockServer {
+        MockServer::start_responding(&[], move |index, _| (200, bodies[index.min(bodies.len() - 1)], Duration::ZERO)).await
+    }
+
+    /// Starts the server responding with the body picked by target of the
+    /// request after the delay, to make concurrent requests complete out of
+    /// order.
+    pub(crate) async fn start_delayed(respond: impl Fn(&str) -> (&'static str, Duration) + Send + Sync + 'static) -> MockServer {
+        MockServer::start_responding(&[], move |_, target| {
+            let (body, delay) = respond(target);
+            (200, body, delay)
+        })
+        .await
+    }
+
+    /// Starts the server responding with the body of the first route whose
+    /// path prefix the request's target starts with, and with 404 to requests
+    /// no route matches, so a call to the wrong endpoint fails.
+    pub(crate) async fn start_routes(routes: &'static [(&'static str, &'static str)]) -> MockServer {
+        MockServer::start_responding(&[], move |_, target| match routes.iter().find(|(prefix, _)| target.starts_with(prefix)) {
+            Some((_, body)) => (200, *body, Duration::ZERO),
+            None => (404, r#"{"error": "Not Found"}"#, Duration::ZERO),
+        })
+        .await
+    }
+
+    // Starts the server responding with the status and body picked by index
+    // and target of the request, after the delay.
+    async fn start_responding(
+        headers: &'static [(&'static str, &'static str)],
+        respond: impl Fn(usize, &str) -> (u16, &'static str, Duration) + Send + Sync + 'static,
+    ) -> MockServer {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(vec![]));
+        let heads = Arc::new(Mutex::new(vec![]));
+        let max_in_flight = Arc::new(AtomicUsize::new(0));
+
+        let (recorded, recorded_heads, recorded_max) = (requests.clone(), heads.clone(), max_in_flight.clone());
+        let (respond, in_flight) = (Arc::new(respond), Arc::new(AtomicUsize::new(0)));
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let (recorded, recorded_heads, recorded_max) = (recorded.clone(), recorded_heads.clone(), recorded_max.clone());
+                let (respond, in_flight) = (respond.clone(), in_flight.clone());
+                tokio::spawn(async move {
+                    let mut head = vec![];
+                    let mut buf = [0u8; 4096];
+                    while !head.windows(4).any(|w| w == b"\r\n\r\n") {
+                        match stream.read(&mut buf).await {
+                            Ok(0) | Err(_) => break,
+                            Ok(n) => head.extend_from_slice(&buf[..n]),
+                        }
+                    }
+
+                    let head = String::from_utf8_lossy(&head);
+                    let target = head.split_whitespace().nth(1).unwrap_or_default().to_string();
+                    let (status, body, delay) = {
+                        let mut recorded = recorded.lock().unwrap();
+                        let response = respond(recorded.len(), &target);
+                        recorded.push(target);
+                        recorded_heads.lock().unwrap().push(head.to_lowercase());
+                        response
+                    };
+
+                    let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                    recorded_max.fetch_max(current, Ordering::SeqCst);
+                    tokio::time::sleep(delay).await;
+                    in_flight.fetch_sub(1, Ordering::SeqCst);
+
+                    let extra: String = headers.iter().map(|(name, value)| format!("{}: {}\r\n", name, value)).collect();
+                    let response = format!(
+                        "HTTP/1.1 {} Mock\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n{}\r\n{}",
+                        status,
+                        body.len(),
+                        extra,
+                        body
+                    );
+                    let _ = stream.write_all(response.as_bytes()).await;
+                });
+            }
+        });
+
+        MockServer { base_url, requests, heads, max_in_flight }
+    }
+
+    /// Returns targets of the received requests.
+    pub(crate) fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    /// Returns the largest number of requests the server was answering at
+    /// once.
+    pub(crate) fn max_in_flight(&self) -> usize {
+        self.max_in_flight.load(Ordering::SeqCst)
+    }
+
+    /// Returns value of the header of the last request, if it was sent.
+    pub(crate) fn last_header(&self, name: &str) -> Option<String> {
+        let head = self.heads.lock().unwrap().last().cloned().expect("no requests received");
+        let prefix = format!("{}: ", name.to_lowercase());
+        head.lines().find_map(|line| line.strip_prefix(&prefix).map(str::to_string))
+    }
+
+    /// Returns query parameters of the last request, sorted by name.
+    pub(crate) fn last_query(&self) -> Vec<(String, String)> {
+        let target = self.requests().pop().expect("no requests received");
+        let url = reqwest::Url::parse(&format!("{}{}", self.base_url, target)).unwrap();
+        let mut params: Vec<(String, String)> = url.query_pairs().into_owned().collect();
+        params.sort();
+        params
+    }
+}
@@ -1,2 +1,16 @@
 pub mod builder;
+pub mod deprecation;
+pub(crate) mod integrity;
+pub(crate) mod iso8601;
+pub(crate) mod metrics;
+#[cfg(test)]
+pub(crate) mod mock_server;
+#[cfg(test)]
+mod param_coverage;
 pub mod params;
+pub(crate) mod runtime;
+
+// Fails compilation unless the value, like a future of a public method, is
+// `Send`, so it can be spawned onto a multi-threaded runtime.
+#[cfg(test)]
+pub(crate) fn assert_send<T: Send>(_: T) {}
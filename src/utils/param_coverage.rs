@@ -0,0 +1,364 @@
+//! Coverage matrix of the request parameters: request types of the swap,
+//! Fusion, Fusion+ and Orderbook APIs are built with all optional fields set,
+//! sent to the mock server, and their query strings are compared with the
+//! expected parameter names and values. Details are built with struct
+//! literals rather than builders, so a new field fails compilation here until
+//! it's covered.
+
+use crate::{
+    client::{new_with_base_url, OneInchClient, SupportedNetworks},
+    fusion::FusionQuoteDetails,
+    fusion_plus::CrossChainQuoteDetails,
+    orderbook::{AllOrdersRequestDetails, FeeInfoRequestDetails, OrderValidity, OrdersSortBy},
+    swap::{
+        approve::{AllowanceDetails, ApproveTranactionDetails},
+        QuoteDetails, SwapDetails, SwapDetailsV6,
+    },
+    utils::mock_server::MockServer,
+};
+
+const WALLET: &str = "0x1111111111111111111111111111111111111111";
+const RECEIVER: &str = "0x2222222222222222222222222222222222222222";
+
+const SWAP_RESPONSE: &str = r#"{"toAmount": "1", "dstAmount": "1", "tx": {"from": "a", "to": "b", "data": "0x", "value": "0", "gasPrice": "1", "gas": 21000}}"#;
+
+fn client(server: &MockServer) -> OneInchClient {
+    new_with_base_url("token".to_string(), SupportedNetworks::Ethereum, &server.base_url)
+}
+
+fn expected(params: &[(&str, &str)]) -> Vec<(String, String)> {
+    let mut params: Vec<(String, String)> = params.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+    params.sort();
+    params
+}
+
+#[tokio::test]
+async fn test_quote_params() {
+    let server = MockServer::start(r#"{"toAmount": "1"}"#).await;
+
+    let details = QuoteDetails {
+        src: "src".into(),
+        dst: "dst".into(),
+        amount: "1000".into(),
+        fee: Some(1),
+        protocols: Some("UNISWAP_V3".into()),
+        gas_price: Some("7".into()),
+        complexity_level: Some(2),
+        parts: Some(3),
+        main_route_parts: Some(4),
+        gas_limit: Some(5),
+        include_tokens_info: Some(true),
+        include_protocols: Some(false),
+        include_gas: Some(true),
+        connector_tokens: Some("connector".into()),
+    };
+    client(&server).quote(details).await.unwrap();
+
+    assert!(server.requests()[0].starts_with("/swap/v5.2/1/quote/"));
+    assert_eq!(
+        server.last_query(),
+        expected(&[
+            ("src", "src"),
+            ("dst", "dst"),
+            ("amount", "1000"),
+            ("fee", "1"),
+            ("protocols", "UNISWAP_V3"),
+            ("gasPrice", "7"),
+            ("complexityLevel", "2"),
+            ("parts", "3"),
+            ("mainRouteParts", "4"),
+            ("gasLimit", "5"),
+            ("includeTokensInfo", "true"),
+            ("includeProtocols", "false"),
+            ("includeGas", "true"),
+            ("connectorTokens", "connector"),
+        ])
+    );
+}
+
+#[tokio::test]
+async fn test_swap_params() {
+    let server = MockServer::start(SWAP_RESPONSE).await;
+
+    let details = SwapDetails {
+        src: "src".into(),
+        dst: "dst".into(),
+        amount: "1000".into(),
+        from: WALLET.into(),
+        slippage: 1,
+        fee: Some(1),
+        protocols: Some("UNISWAP_V3".into()),
+        gas_price: Some("7".into()),
+        gas_tier: None,
+        complexity_level: Some(2),
+        parts: Some(3),
+        main_route_parts: Some(4),
+        gas_limit: Some(5),
+        include_tokens_info: Some(true),
+        include_protocols: Some(false),
+        include_gas: Some(true),
+        connector_tokens: Some("connector".into()),
+        permit: Some("0xpermit".into()),
+        receiver: Some(RECEIVER.into()),
+        referrer: Some("0xreferrer".into()),
+        disable_estimate: Some(true),
+        allow_partial_fill: Some(false),
+    };
+    client(&server).swap(details).await.unwrap();
+
+    assert!(server.requests()[0].starts_with("/swap/v5.2/1/swap/"));
+    assert_eq!(
+        server.last_query(),
+        expected(&[
+            ("src", "src"),
+            ("dst", "dst"),
+            ("amount", "1000"),
+            ("from", WALLET),
+            ("slippage", "1"),
+            ("fee", "1"),
+            ("protocols", "UNISWAP_V3"),
+            ("gasPrice", "7"),
+            ("complexityLevel", "2"),
+            ("parts", "3"),
+            ("mainRouteParts", "4"),
+            ("gasLimit", "5"),
+            ("includeTokensInfo", "true"),
+            ("includeProtocols", "false"),
+            ("includeGas", "true"),
+            ("connectorTokens", "connector"),
+            ("permit", "0xpermit"),
+            ("receiver", RECEIVER),
+            ("referrer", "0xreferrer"),
+            ("disableEstimate", "true"),
+            ("allowPartialFill", "false"),
+        ])
+    );
+}
+
+#[tokio::test]
+async fn test_swap_v6_params() {
+    let server = MockServer::start(SWAP_RESPONSE).await;
+
+    let details = SwapDetailsV6 {
+        src: "src".into(),
+        dst: "dst".into(),
+        amount: "1000".into(),
+        from: WALLET.into(),
+        origin: RECEIVER.into(),
+        slippage: 1,
+        fee: Some(1),
+        protocols: Some("UNISWAP_V3".into()),
+        gas_price: Some("7".into()),
+        gas_tier: None,
+        complexity_level: Some(2),
+        parts: Some(3),
+        main_route_parts: Some(4),
+        gas_limit: Some(5),
+        include_tokens_info: Some(true),
+        include_protocols: Some(false),
+        include_gas: Some(true),
+        connector_tokens: Some("connector".into()),
+        permit: Some("0xpermit".into()),
+        receiver: Some(RECEIVER.into()),
+        referrer: Some("0xreferrer".into()),
+        disable_estimate: Some(true),
+        allow_partial_fill: Some(false),
+        use_permit2: Some(true),
+    };
+    client(&server).swap_v6(details).await.unwrap();
+
+    assert!(server.requests()[0].starts_with("/swap/v6.0/1/swap/"));
+    assert_eq!(
+        server.last_query(),
+        expected(&[
+            ("src", "src"),
+            ("dst", "dst"),
+            ("amount", "1000"),
+            ("from", WALLET),
+            ("origin", RECEIVER),
+            ("slippage", "1"),
+            ("fee", "1"),
+            ("protocols", "UNISWAP_V3"),
+            ("gasPrice", "7"),
+            ("complexityLevel", "2"),
+            ("parts", "3"),
+            ("mainRouteParts", "4"),
+            ("gasLimit", "5"),
+            ("includeTokensInfo", "true"),
+            ("includeProtocols", "false"),
+            ("includeGas", "true"),
+            ("connectorTokens", "connector"),
+            ("permit", "0xpermit"),
+            ("receiver", RECEIVER),
+            ("referrer", "0xreferrer"),
+            ("disableEstimate", "true"),
+            ("allowPartialFill", "false"),
+            ("usePermit2", "true"),
+        ])
+    );
+}
+
+#[tokio::test]
+async fn test_approve_params() {
+    let server = MockServer::start(r#"{"data": "0x", "gasPrice": "1", "to": "0xtoken", "value": "0"}"#).await;
+
+    let details = ApproveTranactionDetails { token_address: "0xtoken".into(), amount: Some("1000".into()) };
+    client(&server).approve(details).await.unwrap();
+
+    assert!(server.requests()[0].starts_with("/swap/v5.2/1/approve/transaction"));
+    assert_eq!(
+        server.last_query(),
+        expected(&[("chain", "1"), ("tokenAddress", "0xtoken"), ("amount", "1000")])
+    );
+}
+
+#[tokio::test]
+async fn test_allowance_params() {
+    let server = MockServer::start(r#"{"allowance": "0"}"#).await;
+
+    let details = AllowanceDetails { token_address: "0xtoken".into(), wallet_address: WALLET.into() };
+    client(&server).get_allowance(details).await.unwrap();
+
+    assert!(server.requests()[0].starts_with("/swap/v5.2/1/approve/allowance"));
+    assert_eq!(server.last_query(), expected(&[("tokenAddress", "0xtoken"), ("walletAddress", WALLET)]));
+}
+
+// Responses of the requests below aren't checked, only their query strings.
+
+#[tokio::test]
+async fn test_fusion_quote_params() {
+    let server = MockServer::start("{}").await;
+
+    let details = FusionQuoteDetails {
+        src: "src".into(),
+        dst: "dst".into(),
+        amount: "1000".into(),
+        wallet_address: WALLET.into(),
+        enable_estimate: Some(true),
+        fee: Some(1),
+        is_permit2: Some(false),
+        permit: Some("0xpermit".into()),
+        source: Some("sdk".into()),
+    };
+    let _ = client(&server).get_fusion_quote(details).await;
+
+    assert!(server.requests()[0].starts_with("/fusion/quoter/v2.0/1/quote/receive"));
+    assert_eq!(
+        server.last_query(),
+        expected(&[
+            ("fromTokenAddress", "src"),
+            ("toTokenAddress", "dst"),
+            ("amount", "1000"),
+            ("walletAddress", WALLET),
+            ("enableEstimate", "true"),
+            ("fee", "1"),
+            ("isPermit2", "false"),
+            ("permit", "0xpermit"),
+            ("source", "sdk"),
+        ])
+    );
+}
+
+#[tokio::test]
+async fn test_fusion_active_orders_params() {
+    let server = MockServer::start("{}").await;
+
+    let _ = client(&server).get_fusion_active_orders(Some(2), Some(50)).await;
+
+    assert!(server.requests()[0].starts_with("/fusion/orders/v2.0/1/order/active"));
+    assert_eq!(server.last_query(), expected(&[("page", "2"), ("limit", "50")]));
+}
+
+#[tokio::test]
+async fn test_cross_chain_quote_params() {
+    let server = MockServer::start("{}").await;
+
+    let details = CrossChainQuoteDetails {
+        src_chain: SupportedNetworks::Ethereum,
+        dst_chain: SupportedNetworks::Polygon,
+        src_token: "src".into(),
+        dst_token: "dst".into(),
+        amount: "1000".into(),
+        wallet_address: WALLET.into(),
+        enable_estimate: Some(true),
+        fee: Some(1),
+        is_permit2: Some(false),
+        permit: Some("0xpermit".into()),
+    };
+    let _ = client(&server).get_cross_chain_quote(details).await;
+
+    assert!(server.requests()[0].starts_with("/fusion-plus/quoter/v1.0/quote/receive"));
+    assert_eq!(
+        server.last_query(),
+        expected(&[
+            ("srcChain", "1"),
+            ("dstChain", "137"),
+            ("srcTokenAddress", "src"),
+            ("dstTokenAddress", "dst"),
+            ("amount", "1000"),
+            ("walletAddress", WALLET),
+            ("enableEstimate", "true"),
+            ("fee", "1"),
+            ("isPermit2", "false"),
+            ("permit", "0xpermit"),
+        ])
+    );
+}
+
+#[tokio::test]
+async fn test_orders_by_maker_params() {
+    let server = MockServer::start("{}").await;
+
+    let statuses = vec![OrderValidity::Valid, OrderValidity::Invalid];
+    let _ = client(&server).get_orders_by_maker(WALLET.into(), Some(2), Some(50), statuses).await;
+
+    assert!(server.requests()[0].starts_with(&format!("/orderbook/v4.0/1/address/{}", WALLET)));
+    assert_eq!(server.last_query(), expected(&[("page", "2"), ("limit", "50"), ("statuses", "1,3")]));
+}
+
+#[tokio::test]
+async fn test_all_orders_params() {
+    let server = MockServer::start("{}").await;
+
+    let details = AllOrdersRequestDetails {
+        page: Some(2),
+        limit: Some(50),
+        statuses: vec![OrderValidity::TemporarilyInvalid],
+        sort_by: Some(OrdersSortBy::MakerRate),
+        maker_asset: Some("maker".into()),
+        taker_asset: Some("taker".into()),
+    };
+    let _ = client(&server).get_all_orders(details).await;
+
+    assert!(server.requests()[0].starts_with("/orderbook/v4.0/1/all"));
+    assert_eq!(
+        server.last_query(),
+        expected(&[
+            ("page", "2"),
+            ("limit", "50"),
+            ("statuses", "2"),
+            ("sortBy", "makerRate"),
+            ("makerAsset", "maker"),
+            ("takerAsset", "taker"),
+        ])
+    );
+}
+
+#[tokio::test]
+async fn test_orderbook_fee_info_params() {
+    let server = MockServer::start("{}").await;
+
+    let details = FeeInfoRequestDetails {
+        maker_asset: "maker".into(),
+        taker_asset: "taker".into(),
+        maker_amount: "1000".into(),
+        taker_amount: "2000".into(),
+    };
+    let _ = client(&server).get_orderbook_fee_info(details).await;
+
+    assert!(server.requests()[0].starts_with("/orderbook/v4.0/1/fee-info"));
+    assert_eq!(
+        server.last_query(),
+        expected(&[("makerAsset", "maker"), ("takerAsset", "taker"), ("makerAmount", "1000"), ("takerAmount", "2000")])
+    );
+}
@@ -0,0 +1,81 @@
+//! Platform specifics of the async runtime. Natively timers are tokio's, while
+//! in the browser (`wasm32`) they are timers of the JS host, and the clock is
+//! read from it too, since `std::time` panics there.
+
+use futures::Stream;
+use reqwest::Response;
+use std::{future::Future, time::Duration};
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) use std::time::{Instant, SystemTime, UNIX_EPOCH};
+#[cfg(target_arch = "wasm32")]
+pub(crate) use web_time::{Instant, SystemTime, UNIX_EPOCH};
+
+/// Error of the future which didn't complete in time.
+#[derive(Debug)]
+pub(crate) struct Elapsed;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) async fn sleep(duration: Duration) {
+    tokio::time::sleep(duration).await
+}
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) async fn sleep(duration: Duration) {
+    gloo_timers::future::sleep(duration).await
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) async fn timeout<F: Future>(duration: Duration, future: F) -> Result<F::Output, Elapsed> {
+    tokio::time::timeout(duration, future).await.map_err(|_| Elapsed)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) async fn timeout<F: Future>(duration: Duration, future: F) -> Result<F::Output, Elapsed> {
+    use futures::future::{select, Either};
+
+    match select(Box::pin(future), Box::pin(sleep(duration))).await {
+        Either::Left((output, _)) => Ok(output),
+        Either::Right(_) => Err(Elapsed),
+    }
+}
+
+/// Runs the task in the background. Natively it needs a tokio runtime, and the
+/// task is dropped if there is none.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn spawn(task: impl Future<Output = ()> + Send + 'static) {
+    match tokio::runtime::Handle::try_current() {
+        Ok(runtime) => drop(runtime.spawn(task)),
+        Err(_) => tracing::debug!("no runtime to run the background task on"),
+    }
+}
+
+/// Runs the task in the background.
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn spawn(task: impl Future<Output = ()> + 'static) {
+    wasm_bindgen_futures::spawn_local(task)
+}
+
+/// Returns chunks of the response body as they are received.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn body_chunks(response: Response) -> impl Stream<Item = reqwest::Result<impl AsRef<[u8]>>> {
+    futures::stream::unfold(response, |mut response| async move {
+        let chunk = response.chunk().await.transpose()?;
+        Some((chunk, response))
+    })
+}
+
+/// Returns chunks of the response body as they are received.
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn body_chunks(response: Response) -> impl Stream<Item = reqwest::Result<impl AsRef<[u8]>>> {
+    response.bytes_stream()
+}
+
+/// Returns true if the request failed to connect to the server. Browser
+/// doesn't tell connection failures apart, so any failed fetch counts.
+pub(crate) fn is_connect(e: &reqwest::Error) -> bool {
+    #[cfg(not(target_arch = "wasm32"))]
+    return e.is_connect();
+    #[cfg(target_arch = "wasm32")]
+    return e.is_request();
+}
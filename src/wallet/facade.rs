@@ -0,0 +1,262 @@
+use crate::{
+    client::OneInchClient,
+    common::page::Page,
+    consts::SWAP_V6_API_VERSION,
+    error::OneInchError,
+    execution::{ExecutionError, PreparedSwap, SignerError, TransactionSigner},
+    history::HistoryEvent,
+    swap::{approve::AllowanceDetails, parse_amount, SwapDetailsBuilderError, SwapDetailsV6Builder},
+    wallet::{WalletUpdate, WatchWalletConfig},
+    web3::Web3Error,
+};
+use futures::Stream;
+use num_bigint::BigUint;
+use std::{error::Error, sync::Arc, time::Duration};
+use thiserror::Error;
+
+/// Enumerates errors of the wallet facade calls.
+#[derive(Error, Debug)]
+pub enum WalletError {
+    /// Transaction had to be signed, but the wallet was created without a
+    /// signer.
+    #[error("Wallet {0} has no signer")]
+    NoSigner(String),
+
+    /// Swap parameters of the wallet are invalid, like too high slippage.
+    #[error(transparent)]
+    InvalidSwap(#[from] SwapDetailsBuilderError),
+
+    /// Prepared swap can't be executed by the wallet, e.g. it has expired or
+    /// is of another sender.
+    #[error(transparent)]
+    Execution(#[from] ExecutionError),
+
+    /// Request to the API failed.
+    #[error(transparent)]
+    Request(#[from] OneInchError),
+
+    /// RPC call to the node failed.
+    #[error(transparent)]
+    Rpc(#[from] Web3Error),
+
+    /// Signer failed to sign the transaction.
+    #[error("Signing failed: {0}")]
+    Signer(SignerError),
+}
+
+/// Wallet bound to the client: an address, and optionally a signer, so calls
+/// don't need the address threaded through them.
+///
+/// Swaps are built for the v6 router with the wallet as both sender and
+/// origin, with 1% slippage and 60s validity unless configured otherwise.
+#[derive(Clone)]
+pub struct Wallet {
+    client: OneInchClient,
+    address: String,
+    signer: Option<Arc<dyn TransactionSigner>>,
+    slippage: usize,
+    swap_valid_for: Duration,
+}
+
+impl OneInchClient {
+    /// Returns the wallet facade for the address, sharing settings of the
+    /// client.
+    pub fn wallet(&self, address: impl Into<String>) -> Wallet {
+        Wallet {
+            client: self.clone(),
+            address: address.into(),
+            signer: None,
+            slippage: 1,
+            swap_valid_for: Duration::from_secs(60),
+        }
+    }
+}
+
+impl Wallet {
+    /// Sets the signer the wallet's swaps are signed with.
+    pub fn with_signer(mut self, signer: impl TransactionSigner + 'static) -> Wallet {
+        self.signer = Some(Arc::new(signer));
+        self
+    }
+
+    /// Sets slippage of the wallet's swaps, in percents.
+    pub fn with_slippage(mut self, slippage: usize) -> Wallet {
+        self.slippage = slippage;
+        self
+    }
+
+    /// Sets the period prepared swaps are valid for.
+    pub fn with_swap_validity(mut self, valid_for: Duration) -> Wallet {
+        self.swap_valid_for = valid_for;
+        self
+    }
+
+    pub fn address(&self) -> &str {
+        &self.address
+    }
+
+    pub fn client(&self) -> &OneInchClient {
+        &self.client
+    }
+
+    /// Returns true if the wallet can sign its swaps.
+    pub fn has_signer(&self) -> bool {
+        self.signer.is_some()
+    }
+
+    /// Returns balance of the token held by the wallet, in minimal units.
+    pub async fn balance_of(&self, token: &str) -> Result<BigUint, OneInchError> {
+        let balances = self.client.get_wallet_balances(self.address.clone()).await?;
+
+        match balances.balances.get(&token.to_lowercase()) {
+            Some(balance) => parse_amount(balance),
+            None => Ok(BigUint::default()),
+        }
+    }
+
+    /// Returns amount of the token the v6 router is allowed to spend from the
+    /// wallet.
+    pub async fn allowance(&self, token: &str) -> Result<BigUint, OneInchError> {
+        let details = AllowanceDetails { token_address: token.to_string(), wallet_address: self.address.clone() };
+        let allowance = self.client.get_router_allowance(SWAP_V6_API_VERSION, details).await?;
+
+        parse_amount(&allowance.allowance)
+    }
+
+    /// Builds the swap of `amount` of `src` into `dst` for the wallet, ready to
+    /// be signed.
+    pub async fn swap(&self, src: &str, dst: &str, amount: &str) -> Result<PreparedSwap, WalletError> {
+        self.build_swap(src, dst, amount, None).await
+    }
+
+    /// Builds the swap sending `dst` tokens to the `receiver` instead of the
+    /// wallet. Receiver is verified if the client was configured with
+    /// [`with_receiver_verification`](OneInchClient::with_receiver_verification),
+    /// see [`receiver_kind`](PreparedSwap::receiver_kind).
+    pub async fn swap_to(&self, src: &str, dst: &str, amount: &str, receiver: &str) -> Result<PreparedSwap, WalletError> {
+        self.build_swap(src, dst, amount, Some(receiver.to_string())).await
+    }
+
+    async fn build_swap(
+        &self,
+        src: &str,
+        dst: &str,
+        amount: &str,
+        receiver: Option<String>,
+    ) -> Result<PreparedSwap, WalletError> {
+        let mut builder = SwapDetailsV6Builder::new()
+            .src(src.to_string())
+            .dst(dst.to_string())
+            .amount(amount.to_string())
+            .eoa(self.address.clone());
+        if let Some(receiver) = receiver {
+            builder = builder.receiver(receiver);
+        }
+        let details = builder.slippage(self.slippage)?.build()?;
+
+        Ok(self.client.prepare_swap(details, self.swap_valid_for).await?)
+    }
+
+    /// Builds the swap, signs it with the wallet's signer and broadcasts it.
+    /// Returns hash of the transaction.
+    pub async fn execute_swap(&self, src: &str, dst: &str, amount: &str) -> Result<String, WalletError> {
+        self.signer()?;
+
+        let prepared = self.swap(src, dst, amount).await?;
+        self.execute(&prepared).await
+    }
+
+    /// Signs the swap prepared by the wallet, like one of
+    /// [`swap_to`](Wallet::swap_to) whose receiver was checked, with the
+    /// wallet's signer and broadcasts it. Returns hash of the transaction.
+    pub async fn execute(&self, prepared: &PreparedSwap) -> Result<String, WalletError> {
+        let signer = self.signer()?;
+        prepared.transaction_for(&self.address)?;
+
+        let nonce = self.client.get_nonce(&self.address).await?;
+        let raw_tx = signer.sign_swap(prepared, nonce).await.map_err(WalletError::Signer)?;
+
+        Ok(self.client.broadcast_public_tx(raw_tx).await?)
+    }
+
+    fn signer(&self) -> Result<&Arc<dyn TransactionSigner>, WalletError> {
+        self.signer.as_ref().ok_or_else(|| WalletError::NoSigner(self.address.clone()))
+    }
+
+    /// Returns the latest history events of the wallet.
+    pub async fn history(&self, limit: Option<u32>) -> Result<Page<HistoryEvent>, WalletError> {
+        Ok(self.client.get_history_events(self.address.clone(), limit).await?)
+    }
+
+    /// Watches the wallet, see
+    /// [`watch_wallet_with`](OneInchClient::watch_wallet_with).
+    pub fn watch(&self, config: WatchWalletConfig) -> impl Stream<Item = Result<WalletUpdate, Box<dyn Error + Send + Sync>>> {
+        self.client.watch_wallet_with(self.address.clone(), config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::{new_with_base_url, SupportedNetworks},
+        execution::ReceiverKind,
+        utils::{assert_send, mock_server::MockServer},
+    };
+
+    #[test]
+    fn test_wallet_futures_are_send() {
+        let client = new_with_base_url("token".to_string(), SupportedNetworks::Ethereum, "http://localhost");
+        let wallet = client.wallet("0x1111111111111111111111111111111111111111");
+        assert_send(wallet.execute_swap("0xsrc", "0xdst", "1"));
+        assert_send(wallet.history(None));
+        assert_send(wallet.watch(WatchWalletConfig::default()));
+    }
+
+    #[tokio::test]
+    async fn test_wallet_calls_are_bound_to_address() {
+        let server = MockServer::start_routes(&[
+            (
+                "/balance/v1.2/1/balances/0x1111111111111111111111111111111111111111",
+                r#"{"0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48": "1500"}"#,
+            ),
+            ("/swap/v6.0/1/approve/allowance", r#"{"allowance": "7"}"#),
+        ])
+        .await;
+        let client = new_with_base_url("token".to_string(), SupportedNetworks::Ethereum, &server.base_url);
+        let wallet = client.wallet("0x1111111111111111111111111111111111111111");
+
+        let balance = wallet.balance_of("0xA0b86991c6218b36c1d19d4a2e9eb0ce3606eb48").await.unwrap();
+        assert_eq!(balance, BigUint::from(1500u32));
+
+        assert_eq!(wallet.allowance("0xtoken").await.unwrap(), BigUint::from(7u32));
+        assert!(server.requests()[1].contains("walletAddress=0x1111111111111111111111111111111111111111"));
+
+        let error = wallet.execute_swap("0xsrc", "0xdst", "1").await.unwrap_err();
+        assert!(matches!(error, WalletError::NoSigner(address) if address == wallet.address()));
+    }
+
+    #[tokio::test]
+    async fn test_wallet_swap_receiver_is_verified() {
+        let server = MockServer::start_routes(&[
+            ("/swap/v6.0/1/swap", r#"{"dstAmount": "1", "tx": {"from": "a", "to": "b", "data": "0x", "value": "0", "gasPrice": "1", "gas": 21000}}"#),
+            ("/web3/1", r#"{"jsonrpc": "2.0", "id": 1, "result": "0x6080604052"}"#),
+        ])
+        .await;
+        let client = new_with_base_url("token".to_string(), SupportedNetworks::Ethereum, &server.base_url)
+            .with_receiver_verification(true);
+        let wallet = client.wallet("0x1111111111111111111111111111111111111111");
+
+        let prepared = wallet.swap("0xsrc", "0xdst", "1").await.unwrap();
+        assert_eq!(prepared.receiver_kind, None);
+
+        let prepared = wallet.swap_to("0xsrc", "0xdst", "1", "0x3333333333333333333333333333333333333333").await.unwrap();
+        assert_eq!(prepared.details.receiver.as_deref(), Some("0x3333333333333333333333333333333333333333"));
+        assert_eq!(prepared.receiver_kind, Some(ReceiverKind::Contract));
+        assert!(matches!(wallet.execute(&prepared).await, Err(WalletError::NoSigner(_))));
+        assert!(matches!(
+            wallet.with_slippage(51).swap("0xsrc", "0xdst", "1").await,
+            Err(WalletError::InvalidSwap(_))
+        ));
+    }
+}
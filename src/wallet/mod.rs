@@ -0,0 +1,6 @@
+mod facade;
+mod types;
+mod watch;
+
+pub use facade::*;
+pub use types::*;
@@ -0,0 +1,246 @@
+use crate::{
+    client::SupportedCurrencies,
+    common::enrichment::{EnrichmentMode, EnrichmentWarning},
+    history::HistoryEvent,
+};
+use std::{
+    collections::{HashMap, HashSet},
+    time::Duration,
+};
+
+/// Configuration of the wallet watch.
+#[derive(Debug, Clone)]
+pub struct WatchWalletConfig {
+    /// Interval between polls of the wallet state.
+    pub interval: Duration,
+    /// Currency prices of the held tokens are tracked in.
+    pub currency: SupportedCurrencies,
+    /// Minimal relative price change (0.01 is 1%) since the last reported
+    /// price, which is reported as a price move.
+    pub min_price_change: f64,
+    /// Amount of the latest history events requested on every poll.
+    pub history_limit: u32,
+    /// Whether failed polls of prices and history fail the whole poll, or
+    /// are reported as [warnings](WalletUpdate::warnings). Balances are the
+    /// core of the watch and always fail the poll.
+    pub enrichment: EnrichmentMode,
+}
+
+impl Default for WatchWalletConfig {
+    fn default() -> Self {
+        WatchWalletConfig {
+            interval: Duration::from_secs(30),
+            currency: SupportedCurrencies::USD,
+            min_price_change: 0.01,
+            history_limit: 20,
+            enrichment: EnrichmentMode::Strict,
+        }
+    }
+}
+
+/// Change of the token balance, in minimal units.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BalanceChange {
+    pub token: String,
+    pub previous: String,
+    pub current: String,
+}
+
+/// Change of the price of the held token.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PriceMove {
+    pub token: String,
+    pub previous: f64,
+    pub current: f64,
+}
+
+impl PriceMove {
+    /// Returns relative change of the price, e.g. `-0.05` for 5% drop.
+    /// Change from the zero price is infinite, unless the price is still zero.
+    pub fn change(&self) -> f64 {
+        relative_change(self.previous, self.current)
+    }
+}
+
+fn relative_change(previous: f64, current: f64) -> f64 {
+    match previous == 0.0 {
+        true if current == 0.0 => 0.0,
+        true => f64::INFINITY.copysign(current),
+        false => (current - previous) / previous,
+    }
+}
+
+/// Consolidated update of the wallet state since the previous one.
+#[derive(Debug, Clone)]
+pub struct WalletUpdate {
+    pub address: String,
+    pub balance_changes: Vec<BalanceChange>,
+    /// New history events, from the newest to the oldest.
+    pub new_events: Vec<HistoryEvent>,
+    pub price_moves: Vec<PriceMove>,
+    /// Enrichment missing from the update in soft-fail mode. Updates with
+    /// warnings are yielded even if nothing has changed.
+    pub warnings: Vec<EnrichmentWarning>,
+}
+
+impl WalletUpdate {
+    /// Returns true if nothing has changed.
+    pub fn is_empty(&self) -> bool {
+        self.balance_changes.is_empty() && self.new_events.is_empty() && self.price_moves.is_empty()
+    }
+}
+
+// State of the wallet fetched by a single poll.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct WalletSnapshot {
+    pub(crate) balances: HashMap<String, String>,
+    // `None` if the enrichment failed in soft-fail mode
+    pub(crate) events: Option<Vec<HistoryEvent>>,
+    pub(crate) prices: Option<HashMap<String, f64>>,
+    pub(crate) warnings: Vec<EnrichmentWarning>,
+}
+
+// Last known state of the wallet updates are computed against. Prices are the
+// last reported ones, so slow drifts are reported too once they accumulate.
+#[derive(Debug)]
+pub(crate) struct WalletState {
+    balances: HashMap<String, String>,
+    // `None` until history is fetched for the first time
+    event_ids: Option<HashSet<String>>,
+    prices: HashMap<String, f64>,
+}
+
+impl WalletState {
+    pub(crate) fn new(snapshot: WalletSnapshot) -> WalletState {
+        WalletState {
+            balances: snapshot.balances,
+            event_ids: snapshot.events.map(|events| events.into_iter().map(|e| e.id).collect()),
+            prices: snapshot.prices.unwrap_or_default(),
+        }
+    }
+
+    // Applies the snapshot returning what changed since the previous one.
+    pub(crate) fn apply(&mut self, address: &str, snapshot: WalletSnapshot, min_price_change: f64) -> WalletUpdate {
+        let zero = "0".to_string();
+        let tokens: HashSet<&String> = self.balances.keys().chain(snapshot.balances.keys()).collect();
+        let mut balance_changes: Vec<BalanceChange> = tokens
+            .into_iter()
+            .filter_map(|token| {
+                let previous = self.balances.get(token).unwrap_or(&zero);
+                let current = snapshot.balances.get(token).unwrap_or(&zero);
+                (previous != current).then(|| BalanceChange {
+                    token: token.clone(),
+                    previous: previous.clone(),
+                    current: current.clone(),
+                })
+            })
+            .collect();
+        balance_changes.sort_by(|a, b| a.token.cmp(&b.token));
+
+        // History fetched for the first time is a baseline like the first poll
+        let new_events = match (&snapshot.events, &self.event_ids) {
+            (Some(events), Some(seen)) => events.iter().filter(|e| !seen.contains(&e.id)).cloned().collect(),
+            _ => vec![],
+        };
+
+        let mut price_moves = vec![];
+        for (token, current) in snapshot.prices.unwrap_or_default() {
+            match self.prices.get(&token) {
+                Some(previous) if relative_change(*previous, current).abs() < min_price_change => continue,
+                Some(previous) => price_moves.push(PriceMove { token: token.clone(), previous: *previous, current }),
+                None => {}
+            }
+            self.prices.insert(token, current);
+        }
+        price_moves.sort_by(|a, b| a.token.cmp(&b.token));
+
+        self.balances = snapshot.balances;
+        // Missing history doesn't forget the seen events
+        if let Some(events) = snapshot.events {
+            self.event_ids = Some(events.into_iter().map(|e| e.id).collect());
+        }
+
+        WalletUpdate { address: address.to_string(), balance_changes, new_events, price_moves, warnings: snapshot.warnings }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::history::HistoryEventDetails;
+
+    fn snapshot(balance: &str, price: f64) -> WalletSnapshot {
+        WalletSnapshot {
+            balances: HashMap::from([("0xa0b8".to_string(), balance.to_string())]),
+            events: Some(vec![]),
+            prices: Some(HashMap::from([("0xa0b8".to_string(), price)])),
+            warnings: vec![],
+        }
+    }
+
+    #[test]
+    fn test_wallet_state_reports_changes_since_last_report() {
+        let mut state = WalletState::new(snapshot("100", 1.0));
+
+        let update = state.apply("0x01", snapshot("100", 1.005), 0.01);
+        assert!(update.is_empty());
+
+        // Drift accumulates against the last reported price
+        let update = state.apply("0x01", snapshot("50", 1.011), 0.01);
+        assert_eq!(
+            update.balance_changes,
+            vec![BalanceChange { token: "0xa0b8".into(), previous: "100".into(), current: "50".into() }]
+        );
+        assert_eq!(
+            update.price_moves,
+            vec![PriceMove { token: "0xa0b8".into(), previous: 1.0, current: 1.011 }]
+        );
+
+        let update = state.apply("0x01", WalletSnapshot::default(), 0.01);
+        assert_eq!(update.balance_changes[0].current, "0");
+    }
+
+    #[test]
+    fn test_price_moves_from_zero() {
+        let mut state = WalletState::new(snapshot("100", 0.0));
+
+        let update = state.apply("0x01", snapshot("100", 0.0), 0.01);
+        assert!(update.price_moves.is_empty());
+
+        let update = state.apply("0x01", snapshot("100", 2.0), 0.01);
+        assert_eq!(update.price_moves.len(), 1);
+        assert_eq!(update.price_moves[0].change(), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_history_missing_from_baseline_is_seeded_silently() {
+        let event = |id: &str| HistoryEvent {
+            id: id.to_string(),
+            address: "0x01".into(),
+            time_ms: 0,
+            details: HistoryEventDetails {
+                tx_hash: "0x".into(),
+                chain_id: 1,
+                block_number: 1,
+                block_time_sec: 0,
+                status: "completed".into(),
+                kind: "Transfer".into(),
+                from_address: "0x01".into(),
+                to_address: "0x02".into(),
+                token_actions: vec![],
+            },
+        };
+        let mut state = WalletState::new(WalletSnapshot { events: None, ..snapshot("100", 1.0) });
+
+        let update = state.apply("0x01", WalletSnapshot { events: Some(vec![event("1")]), ..snapshot("100", 1.0) }, 0.01);
+        assert!(update.new_events.is_empty());
+
+        let update = state.apply(
+            "0x01",
+            WalletSnapshot { events: Some(vec![event("2"), event("1")]), ..snapshot("100", 1.0) },
+            0.01,
+        );
+        assert_eq!(update.new_events.len(), 1);
+        assert_eq!(update.new_events[0].id, "2");
+    }
+}
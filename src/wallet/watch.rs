@@ -0,0 +1,96 @@
+use crate::{
+    client::OneInchClient,
+    jitter::BackgroundTask,
+    tokens::tokens_price::TokensPricesRequestDetails,
+    wallet::{WalletSnapshot, WalletState, WalletUpdate, WatchWalletConfig},
+};
+use futures::{stream, Stream};
+use std::{error::Error, time::Duration};
+
+struct Watch {
+    client: OneInchClient,
+    address: String,
+    config: WatchWalletConfig,
+    state: Option<WalletState>,
+    polled: bool,
+}
+
+impl OneInchClient {
+    /// Watches the wallet with default configuration. See
+    /// [`watch_wallet_with`](OneInchClient::watch_wallet_with).
+    pub fn watch_wallet(&self, address: String) -> impl Stream<Item = Result<WalletUpdate, Box<dyn Error + Send + Sync>>> {
+        self.watch_wallet_with(address, WatchWalletConfig::default())
+    }
+
+    /// Returns a stream which periodically polls balances, history and prices
+    /// of the held tokens and yields consolidated updates whenever any of them
+    /// changes. The first poll is a baseline and yields only its warnings, if
+    /// any. Failed polls are yielded as errors and watching goes on.
+    pub fn watch_wallet_with(
+        &self,
+        address: String,
+        config: WatchWalletConfig,
+    ) -> impl Stream<Item = Result<WalletUpdate, Box<dyn Error + Send + Sync>>> {
+        let watch = Watch { client: self.clone(), address, config, state: None, polled: false };
+
+        stream::unfold(watch, |mut watch| async move {
+            loop {
+                let interval = if watch.polled { watch.config.interval } else { Duration::ZERO };
+                watch.client.jitter.sleep(BackgroundTask::HistorySync, interval).await;
+                watch.polled = true;
+
+                let mut snapshot = match watch.client.wallet_snapshot(&watch.address, &watch.config).await {
+                    Ok(snapshot) => snapshot,
+                    Err(e) => return Some((Err(e), watch)),
+                };
+
+                match &mut watch.state {
+                    None => {
+                        let warnings = std::mem::take(&mut snapshot.warnings);
+                        watch.state = Some(WalletState::new(snapshot));
+                        if !warnings.is_empty() {
+                            let update = WalletUpdate {
+                                address: watch.address.clone(),
+                                balance_changes: vec![],
+                                new_events: vec![],
+                                price_moves: vec![],
+                                warnings,
+                            };
+                            return Some((Ok(update), watch));
+                        }
+                    }
+                    Some(state) => {
+                        let update = state.apply(&watch.address, snapshot, watch.config.min_price_change);
+                        if !update.is_empty() || !update.warnings.is_empty() {
+                            return Some((Ok(update), watch));
+                        }
+                    }
+                }
+            }
+        })
+    }
+
+    async fn wallet_snapshot(
+        &self,
+        address: &str,
+        config: &WatchWalletConfig,
+    ) -> Result<WalletSnapshot, Box<dyn Error + Send + Sync>> {
+        let balances = self.get_wallet_balances(address.to_string()).await?;
+        let held: Vec<String> = balances.non_zero().map(|(token, _)| token.clone()).collect();
+        let mut warnings = vec![];
+
+        let mut prices = Some(Default::default());
+        if !held.is_empty() {
+            let details = TokensPricesRequestDetails { addresses: held, currency: Some(config.currency.clone()) };
+            let response = self.get_tokens_price(details).await;
+            prices = config.enrichment.enrich("prices", response, &mut warnings)?.map(|response| {
+                response.prices.into_iter().filter_map(|(token, price)| Some((token, price.parse().ok()?))).collect()
+            });
+        }
+
+        let events = self.get_history_events(address.to_string(), Some(config.history_limit)).await;
+        let events = config.enrichment.enrich("history", events, &mut warnings)?.map(|events| events.items);
+
+        Ok(WalletSnapshot { balances: balances.balances, events, prices, warnings })
+    }
+}
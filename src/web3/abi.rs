@@ -0,0 +1,183 @@
+// Minimal ABI decoding of the values returned by `eth_call`.
+
+use crate::web3::{RevertReason, Web3Error};
+use num_bigint::BigUint;
+
+// Decodes hex encoded return data into bytes.
+pub(crate) fn decode_hex(data: &str) -> Result<Vec<u8>, Web3Error> {
+    hex::decode(data.strip_prefix("0x").unwrap_or(data)).map_err(|e| Web3Error::InvalidResponse(e.to_string()))
+}
+
+// Decodes return value of `uint8` type, like `decimals()` returns.
+pub(crate) fn decode_uint8(data: &str) -> Result<u8, Web3Error> {
+    let bytes = decode_hex(data)?;
+    if bytes.len() < 32 || bytes[..31].iter().any(|b| *b != 0) {
+        return Err(Web3Error::InvalidResponse(format!("{} is not uint8", data)));
+    }
+    Ok(bytes[31])
+}
+
+// Decodes return value of `string` type. Some old tokens (like MKR) return
+// `bytes32` instead of a string, so it's supported as well.
+pub(crate) fn decode_string(data: &str) -> Result<String, Web3Error> {
+    let bytes = decode_hex(data)?;
+    let invalid = || Web3Error::InvalidResponse(format!("{} is not a string", data));
+
+    if bytes.len() == 32 {
+        let end = bytes.iter().position(|b| *b == 0).unwrap_or(32);
+        return String::from_utf8(bytes[..end].to_vec()).map_err(|_| invalid());
+    }
+
+    if bytes.len() < 64 {
+        return Err(invalid());
+    }
+
+    // Offset and length come from the contract (or the node, for revert
+    // data), so they may point anywhere
+    let offset = read_usize(&bytes[..32]).ok_or_else(invalid)?;
+    let start = offset.checked_add(32).ok_or_else(invalid)?;
+    let length = read_usize(bytes.get(offset..start).ok_or_else(invalid)?).ok_or_else(invalid)?;
+    let end = start.checked_add(length).ok_or_else(invalid)?;
+    let value = bytes.get(start..end).ok_or_else(invalid)?;
+
+    String::from_utf8(value.to_vec()).map_err(|_| invalid())
+}
+
+// Decodes revert data of the call: `Error(string)` and `Panic(uint256)` of
+// Solidity, or a custom error. Empty data has no reason.
+pub(crate) fn decode_revert(data: &[u8]) -> Option<RevertReason> {
+    let (selector, payload) = (data.get(..4)?, &data[4..]);
+    match selector {
+        [0x08, 0xc3, 0x79, 0xa0] => decode_string(&hex::encode(payload)).ok().map(RevertReason::Error),
+        [0x4e, 0x48, 0x7b, 0x71] => {
+            let code = decode_uint256(&hex::encode(payload)).ok()?;
+            Some(RevertReason::Panic(u64::try_from(&code).ok()?))
+        }
+        selector => Some(RevertReason::Custom { selector: selector.try_into().ok()?, data: payload.to_vec() }),
+    }
+}
+
+// Decodes return value of `uint256` type, like `balanceOf()` returns.
+pub(crate) fn decode_uint256(data: &str) -> Result<BigUint, Web3Error> {
+    let bytes = decode_hex(data)?;
+    if bytes.len() < 32 {
+        return Err(Web3Error::InvalidResponse(format!("{} is not uint256", data)));
+    }
+    Ok(BigUint::from_bytes_be(&bytes[..32]))
+}
+
+// Decodes JSON-RPC quantity, a `0x` prefixed hex number without leading
+// zeroes.
+pub(crate) fn decode_quantity(value: &str) -> Result<BigUint, Web3Error> {
+    let digits = value.strip_prefix("0x").ok_or_else(|| Web3Error::InvalidResponse(format!("{} is not a quantity", value)))?;
+    if digits.is_empty() {
+        return Ok(BigUint::default());
+    }
+    BigUint::parse_bytes(digits.as_bytes(), 16).ok_or_else(|| Web3Error::InvalidResponse(format!("{} is not a quantity", value)))
+}
+
+// Decodes JSON-RPC quantity which fits into u64, like block number or nonce.
+pub(crate) fn decode_u64_quantity(value: &str) -> Result<u64, Web3Error> {
+    let quantity = decode_quantity(value)?;
+    u64::try_from(&quantity).map_err(|_| Web3Error::InvalidResponse(format!("{} doesn't fit into u64", value)))
+}
+
+// Encodes address as an ABI word, to be passed as a call argument.
+pub(crate) fn encode_address(address: &str) -> Result<String, Web3Error> {
+    let invalid = || Web3Error::InvalidAddress(address.to_string());
+    let bytes = decode_hex(address).map_err(|_| invalid())?;
+    if bytes.len() != 20 {
+        return Err(invalid());
+    }
+    Ok(format!("{:0>64}", hex::encode(bytes)))
+}
+
+fn read_usize(word: &[u8]) -> Option<usize> {
+    if word[..24].iter().any(|b| *b != 0) {
+        return None;
+    }
+    usize::try_from(u64::from_be_bytes(word[24..32].try_into().ok()?)).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::web3::RpcErrorKind;
+
+    #[test]
+    fn test_decode_token_metadata() {
+        let symbol = "0x0000000000000000000000000000000000000000000000000000000000000020\
+                      0000000000000000000000000000000000000000000000000000000000000004\
+                      5553445400000000000000000000000000000000000000000000000000000000";
+        assert_eq!(decode_string(symbol).unwrap(), "USDT");
+
+        let bytes32_symbol = "0x4d4b520000000000000000000000000000000000000000000000000000000000";
+        assert_eq!(decode_string(bytes32_symbol).unwrap(), "MKR");
+
+        let decimals = "0x0000000000000000000000000000000000000000000000000000000000000012";
+        assert_eq!(decode_uint8(decimals).unwrap(), 18);
+
+        assert!(decode_string("0x").is_err());
+
+        // Offset and length overflowing the data
+        let hostile = "0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff\
+                       0000000000000000000000000000000000000000000000000000000000000004";
+        assert!(decode_string(hostile).is_err());
+        let hostile = "0x000000000000000000000000000000000000000000000000ffffffffffffffe0\
+                       0000000000000000000000000000000000000000000000000000000000000004";
+        assert!(decode_string(hostile).is_err());
+        let hostile = "0x0000000000000000000000000000000000000000000000000000000000000020\
+                       000000000000000000000000000000000000000000000000ffffffffffffffff";
+        assert!(decode_string(hostile).is_err());
+        let revert = [&[0x08, 0xc3, 0x79, 0xa0], hex::decode(&hostile[2..]).unwrap().as_slice()].concat();
+        assert_eq!(decode_revert(&revert), None);
+    }
+
+    #[test]
+    fn test_decode_quantities() {
+        assert_eq!(decode_u64_quantity("0x1b4").unwrap(), 436);
+        assert_eq!(decode_u64_quantity("0x0").unwrap(), 0);
+        assert_eq!(decode_quantity("0xde0b6b3a7640000").unwrap(), BigUint::from(10u64.pow(18)));
+        assert!(decode_quantity("1b4").is_err());
+        assert!(decode_u64_quantity("0x10000000000000000").is_err());
+
+        let word = encode_address("0xdAC17F958D2ee523a2206206994597C13D831ec7").unwrap();
+        assert_eq!(word, "000000000000000000000000dac17f958d2ee523a2206206994597c13d831ec7");
+        assert!(matches!(encode_address("0x1234"), Err(Web3Error::InvalidAddress(address)) if address == "0x1234"));
+        assert!(matches!(encode_address("0xzz"), Err(Web3Error::InvalidAddress(_))));
+    }
+
+    #[test]
+    fn test_revert_reasons_are_decoded() {
+        let error = Web3Error::Rpc {
+            code: 3,
+            message: "execution reverted: Not enough balance".into(),
+            data: Some(serde_json::json!(
+                "0x08c379a0\
+                 0000000000000000000000000000000000000000000000000000000000000020\
+                 0000000000000000000000000000000000000000000000000000000000000012\
+                 4e6f7420656e6f7567682062616c616e63650000000000000000000000000000"
+            )),
+        };
+        assert_eq!(error.kind(), Some(RpcErrorKind::Reverted));
+        assert_eq!(error.revert_reason(), Some(RevertReason::Error("Not enough balance".into())));
+
+        // Nested data and a panic of the arithmetic overflow
+        let error = Web3Error::Rpc {
+            code: -32603,
+            message: "execution reverted".into(),
+            data: Some(serde_json::json!({
+                "data": "0x4e487b710000000000000000000000000000000000000000000000000000000000000011"
+            })),
+        };
+        assert_eq!(error.revert_reason(), Some(RevertReason::Panic(0x11)));
+
+        let error = Web3Error::Rpc { code: -32005, message: "limit exceeded".into(), data: Some(serde_json::json!("0x01")) };
+        assert!(error.is_transient());
+        assert_eq!(error.revert_data(), None);
+        assert_eq!(
+            decode_revert(&[0xde, 0xad, 0xbe, 0xef]),
+            Some(RevertReason::Custom { selector: [0xde, 0xad, 0xbe, 0xef], data: vec![] })
+        );
+    }
+}
@@ -0,0 +1,65 @@
+use crate::{
+    client::OneInchClient,
+    web3::{
+        abi::{decode_quantity, decode_u64_quantity, decode_uint256, encode_address},
+        TransactionReceipt, Web3Error,
+    },
+};
+use num_bigint::BigUint;
+use serde::Deserialize;
+
+// Selector of the ERC-20 `balanceOf(address)` function.
+const BALANCE_OF_SELECTOR: &str = "0x70a08231";
+
+impl OneInchClient {
+    /// Returns number of the latest block.
+    pub async fn get_block_number(&self) -> Result<u64, Web3Error> {
+        let block: String = self.rpc_call("eth_blockNumber", serde_json::json!([])).await?;
+        decode_u64_quantity(&block)
+    }
+
+    /// Returns balance of the address in the native currency, in wei.
+    pub async fn get_native_balance(&self, address: &str) -> Result<BigUint, Web3Error> {
+        let balance: String = self.rpc_call("eth_getBalance", serde_json::json!([address, "latest"])).await?;
+        decode_quantity(&balance)
+    }
+
+    /// Returns balance of the address in the ERC-20 token, in token's minimal
+    /// units.
+    pub async fn get_token_balance(&self, token: &str, address: &str) -> Result<BigUint, Web3Error> {
+        let data = format!("{}{}", BALANCE_OF_SELECTOR, encode_address(address)?);
+        decode_uint256(&self.eth_call(token, &data).await?)
+    }
+
+    /// Returns nonce the next transaction of the address should be sent with,
+    /// taking pending transactions into account.
+    pub async fn get_nonce(&self, address: &str) -> Result<u64, Web3Error> {
+        let nonce: String = self.rpc_call("eth_getTransactionCount", serde_json::json!([address, "pending"])).await?;
+        decode_u64_quantity(&nonce)
+    }
+
+    /// Returns hex encoded code deployed at the address, `0x` for externally
+    /// owned accounts.
+    pub async fn get_code(&self, address: &str) -> Result<String, Web3Error> {
+        self.rpc_call("eth_getCode", serde_json::json!([address, "latest"])).await
+    }
+
+    /// Returns receipt of the transaction, or `None` if it's not mined yet.
+    pub async fn get_transaction_receipt(&self, tx_hash: &str) -> Result<Option<TransactionReceipt>, Web3Error> {
+        self.rpc_call_optional("eth_getTransactionReceipt", serde_json::json!([tx_hash])).await
+    }
+
+    /// Returns hashes of the transactions included in the block, in their
+    /// order in the block.
+    pub async fn get_block_transaction_hashes(&self, block: u64) -> Result<Vec<String>, Web3Error> {
+        let block: BlockTransactionHashes =
+            self.rpc_call("eth_getBlockByNumber", serde_json::json!([format!("{:#x}", block), false])).await?;
+        Ok(block.transactions)
+    }
+}
+
+// Block as `eth_getBlockByNumber` returns it without full transactions.
+#[derive(Deserialize)]
+struct BlockTransactionHashes {
+    transactions: Vec<String>,
+}
@@ -0,0 +1,181 @@
+use crate::{
+    client::{OneInchClient, SupportedNetworks},
+    events::ExecutionEvent,
+    web3::{abi::decode_u64_quantity, TransactionReceipt, TransactionStatus, Web3Error},
+};
+use std::{
+    collections::{HashMap, VecDeque},
+    error::Error,
+    sync::Mutex,
+};
+use thiserror::Error;
+
+/// Amount of confirmations (the including block counts as the first one) after
+/// which a transaction is considered final, per chain.
+///
+/// Defaults are approximations: about two epochs for Ethereum and the usual
+/// reorg depth for other chains. Rollups (Optimism, Base, Arbitrum, zkSync)
+/// have no default, since their blocks are final only once the batch
+/// including them is finalized on Ethereum, which no fixed amount of the
+/// rollup's blocks reflects. Set them (and tune the rest for your risk
+/// model) with [`with_confirmations`](FinalityConfig::with_confirmations).
+#[derive(Debug, Clone, Default)]
+pub struct FinalityConfig {
+    overrides: HashMap<u32, u64>,
+}
+
+impl FinalityConfig {
+    pub fn new() -> FinalityConfig {
+        FinalityConfig::default()
+    }
+
+    /// Sets amount of confirmations considered final on the chain.
+    pub fn with_confirmations(mut self, chain: SupportedNetworks, confirmations: u64) -> FinalityConfig {
+        self.overrides.insert(chain as u32, confirmations.max(1));
+        self
+    }
+
+    /// Returns amount of confirmations considered final on the chain, `None`
+    /// for rollups without configured confirmations.
+    pub fn confirmations(&self, chain: SupportedNetworks) -> Option<u64> {
+        if let Some(confirmations) = self.overrides.get(&(chain as u32)) {
+            return Some(*confirmations);
+        }
+
+        match chain {
+            SupportedNetworks::Ethereum | SupportedNetworks::Gnosis => Some(64),
+            SupportedNetworks::Polygon => Some(256),
+            SupportedNetworks::BSC => Some(15),
+            SupportedNetworks::Aurora => Some(3),
+            // Chains with instant finality
+            SupportedNetworks::Fantom | SupportedNetworks::Klaytn | SupportedNetworks::Avalanche => Some(1),
+            // Finality of rollups follows Ethereum, not their own blocks
+            SupportedNetworks::Optimism | SupportedNetworks::Base | SupportedNetworks::Arbitrum | SupportedNetworks::ZkSync => {
+                None
+            }
+        }
+    }
+}
+
+/// Enumerates errors of the transaction finality.
+#[derive(Error, Debug)]
+pub enum FinalityError {
+    /// Chain has no default amount of confirmations, it should be set with
+    /// [`FinalityConfig::with_confirmations`].
+    #[error("Finality of {0} isn't configured")]
+    NotConfigured(SupportedNetworks),
+}
+
+// Transactions whose finality was emitted, so that polling their status
+// emits it only once. The oldest ones are forgotten beyond the capacity.
+#[derive(Debug, Default)]
+pub(crate) struct EmittedFinality(Mutex<VecDeque<(u32, String)>>);
+
+impl EmittedFinality {
+    const CAPACITY: usize = 1024;
+
+    // Remembers the transaction, returning false if it was already emitted.
+    fn insert(&self, chain_id: u32, tx_hash: &str) -> bool {
+        let mut emitted = self.0.lock().unwrap();
+        let tx_hash = tx_hash.to_lowercase();
+        if emitted.iter().any(|(chain, hash)| *chain == chain_id && *hash == tx_hash) {
+            return false;
+        }
+        if emitted.len() == Self::CAPACITY {
+            emitted.pop_front();
+        }
+        emitted.push_back((chain_id, tx_hash));
+        true
+    }
+}
+
+impl TransactionStatus {
+    /// Derives status of the transaction from its receipt (`None` if it's not
+    /// mined yet), number of the latest block and amount of confirmations
+    /// considered final.
+    pub fn from_receipt(receipt: Option<&TransactionReceipt>, latest_block: u64, confirmations: u64) -> Result<Self, Web3Error> {
+        let Some(receipt) = receipt else {
+            return Ok(TransactionStatus::Pending);
+        };
+
+        let block = decode_u64_quantity(&receipt.block_number)?;
+        let success = receipt.is_success();
+        let received = latest_block.saturating_sub(block) + 1;
+
+        match received >= confirmations {
+            true => Ok(TransactionStatus::Final { block, success }),
+            false => Ok(TransactionStatus::Included { block, confirmations: received, success }),
+        }
+    }
+}
+
+impl OneInchClient {
+    /// Returns status of the transaction on the client's network, taking
+    /// finality configuration of the network into account. Fails with
+    /// [`FinalityError::NotConfigured`] on rollups whose confirmations aren't
+    /// configured. [`Confirmed`](ExecutionEvent::Confirmed) or
+    /// [`Failed`](ExecutionEvent::Failed) is emitted once the transaction
+    /// becomes final, not on every later check.
+    pub async fn get_transaction_status(&self, tx_hash: &str) -> Result<TransactionStatus, Box<dyn Error>> {
+        let confirmations = self.finality.confirmations(self.network_id).ok_or(FinalityError::NotConfigured(self.network_id))?;
+
+        let receipt = self.get_transaction_receipt(tx_hash).await?;
+        if receipt.is_none() {
+            return Ok(TransactionStatus::Pending);
+        }
+
+        let latest_block = self.get_block_number().await?;
+
+        let status = TransactionStatus::from_receipt(receipt.as_ref(), latest_block, confirmations)?;
+        let chain_id = self.network_id as u32;
+        if let TransactionStatus::Final { block, success } = status {
+            if !self.emitted_finality.insert(chain_id, tx_hash) {
+                return Ok(status);
+            }
+            let tx_hash = tx_hash.to_string();
+            self.emit(|| match success {
+                Some(false) => ExecutionEvent::Failed {
+                    chain_id,
+                    sender: None,
+                    tx_hash: Some(tx_hash),
+                    error: format!("transaction reverted in block {}", block),
+                },
+                _ => ExecutionEvent::Confirmed { chain_id, tx_hash, block, success },
+            })
+            .await;
+        }
+
+        Ok(status)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transaction_status_by_confirmations() {
+        let receipt: TransactionReceipt = serde_json::from_str(
+            r#"{"transactionHash": "0x01", "blockHash": "0x02", "blockNumber": "0x64", "from": "0x03", "to": "0x04",
+                "gasUsed": "0x5208", "status": "0x1", "logs": []}"#,
+        )
+        .unwrap();
+
+        assert_eq!(TransactionStatus::from_receipt(None, 100, 3).unwrap(), TransactionStatus::Pending);
+        assert_eq!(
+            TransactionStatus::from_receipt(Some(&receipt), 101, 3).unwrap(),
+            TransactionStatus::Included { block: 100, confirmations: 2, success: Some(true) }
+        );
+        assert_eq!(
+            TransactionStatus::from_receipt(Some(&receipt), 102, 3).unwrap(),
+            TransactionStatus::Final { block: 100, success: Some(true) }
+        );
+
+        let finality = FinalityConfig::new().with_confirmations(SupportedNetworks::Polygon, 32);
+        assert_eq!(finality.confirmations(SupportedNetworks::Polygon), Some(32));
+        assert_eq!(finality.confirmations(SupportedNetworks::Ethereum), Some(64));
+        assert_eq!(finality.confirmations(SupportedNetworks::Arbitrum), None);
+        let finality = finality.with_confirmations(SupportedNetworks::Arbitrum, 20_000);
+        assert_eq!(finality.confirmations(SupportedNetworks::Arbitrum), Some(20_000));
+    }
+}
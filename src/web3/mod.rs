@@ -0,0 +1,11 @@
+pub(crate) mod abi;
+mod eth;
+mod finality;
+mod policy;
+mod rpc;
+mod token_info;
+mod types;
+
+pub use finality::*;
+pub use policy::*;
+pub use types::*;
@@ -0,0 +1,134 @@
+use crate::{client::OneInchClient, retry::RetryPolicy};
+use std::{collections::HashMap, time::Duration};
+
+/// Read-only methods besides the `eth_get*` lookups, which are safe to retry.
+/// Anything else, like sending a transaction which may have been accepted by
+/// the node despite the failure, is never retried.
+const READ_ONLY_METHODS: &[&str] = &[
+    "eth_blockNumber",
+    "eth_call",
+    "eth_chainId",
+    "eth_estimateGas",
+    "eth_feeHistory",
+    "eth_gasPrice",
+    "eth_maxPriorityFeePerGas",
+    "eth_syncing",
+    "net_version",
+    "web3_clientVersion",
+];
+
+// Returns true if the method only reads the chain state. Polling a filter
+// consumes its changes, so it's not a plain lookup.
+fn is_read_only(method: &str) -> bool {
+    READ_ONLY_METHODS.contains(&method) || (method.starts_with("eth_get") && method != "eth_getFilterChanges")
+}
+
+/// Timeouts and retries of the JSON-RPC calls through the Web3 RPC endpoint,
+/// configured apart from the REST endpoints: node calls like `eth_call` of a
+/// heavy contract or receipt lookups have their own latency, and being POST
+/// requests they aren't retried by the client's
+/// [`RetryPolicy`](OneInchClient::with_retry_policy).
+///
+/// Only read-only methods are retried, after transport failures, 5xx
+/// responses and transient JSON-RPC errors, like the node's rate limit.
+///
+/// ```
+/// use one_inch::{retry::RetryPolicy, web3::Web3Policy};
+/// use std::time::Duration;
+///
+/// let policy = Web3Policy::new()
+///     .timeout(Duration::from_secs(5))
+///     .method_timeout("eth_call", Duration::from_secs(15))
+///     .retry_policy(RetryPolicy::new(3));
+/// assert_eq!(policy.timeout_for("eth_call"), Some(Duration::from_secs(15)));
+/// assert!(policy.retry_policy_for("eth_getTransactionReceipt").is_some());
+/// assert!(policy.retry_policy_for("eth_sendRawTransaction").is_none());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Web3Policy {
+    timeout: Option<Duration>,
+    method_timeouts: HashMap<String, Duration>,
+    retry_policy: Option<RetryPolicy>,
+}
+
+impl Web3Policy {
+    /// Creates policy leaving timeouts to the client's
+    /// [`Timeouts`](crate::timeout::Timeouts) of the `web3` endpoint and
+    /// retrying nothing.
+    pub fn new() -> Web3Policy {
+        Web3Policy::default()
+    }
+
+    /// Sets timeout of the calls of all the methods without their own
+    /// timeout. It overrides the client's timeouts of the REST endpoints.
+    pub fn timeout(mut self, timeout: Duration) -> Web3Policy {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets timeout of the calls of the specific method, like `eth_call`.
+    pub fn method_timeout(mut self, method: impl Into<String>, timeout: Duration) -> Web3Policy {
+        self.method_timeouts.insert(method.into(), timeout);
+        self
+    }
+
+    /// Sets policy of retrying read-only calls.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Web3Policy {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Returns timeout of the calls of the method, if any.
+    pub fn timeout_for(&self, method: &str) -> Option<Duration> {
+        self.method_timeouts.get(method).copied().or(self.timeout)
+    }
+
+    /// Returns retry policy of the method's calls, `None` if they aren't
+    /// retried.
+    pub fn retry_policy_for(&self, method: &str) -> Option<&RetryPolicy> {
+        self.retry_policy.as_ref().filter(|_| is_read_only(method))
+    }
+}
+
+impl OneInchClient {
+    /// Sets timeouts and retries of the JSON-RPC calls.
+    pub fn with_web3_policy(mut self, policy: Web3Policy) -> OneInchClient {
+        self.web3_policy = policy;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::{new_with_base_url, SupportedNetworks},
+        utils::mock_server::MockServer,
+        web3::Web3Error,
+    };
+
+    #[tokio::test]
+    async fn test_only_read_only_calls_are_retried() {
+        let server =
+            MockServer::start(r#"{"jsonrpc": "2.0", "id": 1, "error": {"code": -32005, "message": "limit exceeded"}}"#).await;
+        let policy = Web3Policy::new().retry_policy(RetryPolicy::new(2).with_base_delay(Duration::from_millis(1)));
+        let client = new_with_base_url("token".into(), SupportedNetworks::Ethereum, &server.base_url).with_web3_policy(policy);
+
+        let error = client.get_block_number().await.unwrap_err();
+        assert!(matches!(error, Web3Error::Rpc { code: -32005, .. }));
+        assert!(error.is_transient());
+        assert_eq!(server.requests().len(), 3);
+
+        let sent = client.rpc_call::<String>("eth_sendRawTransaction", serde_json::json!(["0x01"])).await;
+        assert!(sent.is_err());
+        assert_eq!(server.requests().len(), 4);
+
+        // Methods not known to be read-only aren't retried either
+        for method in ["eth_sendUserOperation", "personal_sign", "eth_getFilterChanges"] {
+            assert!(client.web3_policy.retry_policy_for(method).is_none(), "{}", method);
+        }
+        for method in ["eth_call", "eth_getLogs", "eth_estimateGas"] {
+            assert!(client.web3_policy.retry_policy_for(method).is_some(), "{}", method);
+        }
+    }
+}
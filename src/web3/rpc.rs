@@ -0,0 +1,99 @@
+use crate::{
+    client::OneInchClient,
+    error::OneInchError,
+    utils::runtime,
+    web3::{JsonRpcRequest, JsonRpcResponse, Web3Error},
+};
+use serde::de::DeserializeOwned;
+
+impl OneInchClient {
+    /// Performs JSON-RPC call through 1inch Web3 RPC endpoint of the client's
+    /// network.
+    pub async fn rpc_call<T: DeserializeOwned>(&self, method: &str, params: serde_json::Value) -> Result<T, Web3Error> {
+        self.rpc_call_optional(method, params)
+            .await?
+            .ok_or_else(|| Web3Error::InvalidResponse(format!("{} returned no result", method)))
+    }
+
+    /// Performs JSON-RPC call whose result may be null, like a lookup of the
+    /// not yet mined transaction. Call is timed out and retried according to
+    /// the client's [`Web3Policy`](crate::web3::Web3Policy).
+    pub async fn rpc_call_optional<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        params: serde_json::Value,
+    ) -> Result<Option<T>, Web3Error> {
+        let client = match self.web3_policy.timeout_for(method) {
+            Some(timeout) => self.with_call_timeout(timeout),
+            None => self.clone(),
+        };
+        let Some(retry_policy) = self.web3_policy.retry_policy_for(method) else {
+            return client.rpc_call_once(method, &params).await;
+        };
+
+        let mut attempt = 0;
+        loop {
+            let delay = match client.rpc_call_once(method, &params).await {
+                Err(e) if attempt < retry_policy.max_retries() && e.is_transient() => {
+                    let delay = retry_policy.delay(attempt);
+                    tracing::debug!(method, attempt, ?delay, "retrying RPC call after transient failure: {}", e);
+                    delay
+                }
+                result => return result,
+            };
+            runtime::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    async fn rpc_call_once<T: DeserializeOwned>(&self, method: &str, params: &serde_json::Value) -> Result<Option<T>, Web3Error> {
+        let url = format!("{}/web3/{}", self.base_url(), self.network_id);
+
+        let body = JsonRpcRequest { jsonrpc: "2.0", id: 1, method, params: params.clone() };
+
+        let response = self
+            .send("web3", self.http_client.post(url).json(&body))
+            .await
+            .map_err(OneInchError::from)?
+            .error_for_status()
+            .map_err(OneInchError::from)?;
+
+        let rpc_response: JsonRpcResponse<T> = self.read_json("web3", response).await?;
+
+        match rpc_response.error {
+            Some(err) => Err(Web3Error::Rpc { code: err.code, message: err.message, data: err.data }),
+            None => Ok(rpc_response.result),
+        }
+    }
+
+    /// Executes a message call without creating a transaction (`eth_call`) at
+    /// the latest block. Returns hex encoded result. Reverted call fails with
+    /// [`Web3Error::Rpc`], whose [reason](Web3Error::revert_reason) is decoded
+    /// from the revert data.
+    pub async fn eth_call(&self, to: &str, data: &str) -> Result<String, Web3Error> {
+        self.rpc_call("eth_call", serde_json::json!([{ "to": to, "data": data }, "latest"])).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        client::{new_with_base_url, SupportedNetworks},
+        utils::assert_send,
+        web3::TransactionReceipt,
+    };
+
+    #[test]
+    fn test_rpc_futures_are_send() {
+        let client = new_with_base_url("token".to_string(), SupportedNetworks::Ethereum, "http://localhost");
+        assert_send(client.rpc_call::<String>("eth_chainId", serde_json::json!([])));
+        assert_send(client.eth_call("0xtoken", "0x"));
+        assert_send(client.get_nonce("0xwallet"));
+        assert_send(client.get_code("0xwallet"));
+        assert_send(client.get_block_number());
+        assert_send(client.get_transaction_receipt("0xhash"));
+        assert_send(client.rpc_call_optional::<TransactionReceipt>("eth_getTransactionReceipt", serde_json::json!([])));
+        assert_send(client.get_token_info("0xtoken".to_string()));
+        assert_send(client.get_transaction_status("0xhash"));
+    }
+}
@@ -0,0 +1,46 @@
+use crate::{
+    client::OneInchClient,
+    common::token::TokenInfo,
+    web3::abi::{decode_string, decode_uint8},
+};
+use std::error::Error;
+
+// Selectors of the ERC-20 metadata functions.
+const NAME_SELECTOR: &str = "0x06fdde03";
+const SYMBOL_SELECTOR: &str = "0x95d89b41";
+const DECIMALS_SELECTOR: &str = "0x313ce567";
+
+impl OneInchClient {
+    /// Reads `name()`, `symbol()` and `decimals()` of the token contract via
+    /// Web3 RPC and synthesizes `TokenInfo` from them. Fails if any of them
+    /// can't be read, fields which aren't on-chain (like logo) are left empty.
+    pub async fn get_token_info_onchain(&self, address: String) -> Result<TokenInfo, Box<dyn Error>> {
+        let name = decode_string(&self.eth_call(&address, NAME_SELECTOR).await?)?;
+        let symbol = decode_string(&self.eth_call(&address, SYMBOL_SELECTOR).await?)?;
+        let decimals = decode_uint8(&self.eth_call(&address, DECIMALS_SELECTOR).await?)?;
+
+        Ok(TokenInfo {
+            address: address.to_lowercase(),
+            symbol,
+            name,
+            decimals,
+            logo_uri: String::new(),
+            domain_version: None,
+            eip2612: None,
+            is_fot: None,
+            tags: vec![],
+        })
+    }
+
+    /// Get information about the token. It's taken from 1inch token list if
+    /// token is there, otherwise it falls back to reading token metadata
+    /// on-chain, since long-tail tokens usually have no metadata in 1inch.
+    pub async fn get_token_info(&self, address: String) -> Result<TokenInfo, Box<dyn Error>> {
+        let mut tokens_list = self.get_tokens_list().await?;
+
+        match tokens_list.tokens.remove(&address.to_lowercase()) {
+            Some(token_info) => Ok(token_info),
+            None => self.get_token_info_onchain(address).await,
+        }
+    }
+}
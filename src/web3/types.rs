@@ -0,0 +1,206 @@
+use crate::{
+    error::OneInchError,
+    utils::runtime,
+    web3::abi::{decode_hex, decode_revert},
+};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// JSON-RPC request body as Web3 RPC endpoint accepts it.
+#[derive(Serialize, Debug)]
+pub struct JsonRpcRequest<'a> {
+    pub jsonrpc: &'static str,
+    pub id: u64,
+    pub method: &'a str,
+    pub params: serde_json::Value,
+}
+
+/// JSON-RPC response body, containing either result or error.
+#[derive(Deserialize, Debug)]
+pub struct JsonRpcResponse<T> {
+    pub result: Option<T>,
+    pub error: Option<JsonRpcError>,
+}
+
+/// Error object of the JSON-RPC response.
+#[derive(Deserialize, Debug, Clone)]
+pub struct JsonRpcError {
+    pub code: i64,
+    pub message: String,
+    pub data: Option<serde_json::Value>,
+}
+
+/// Represents errors that can occur while performing JSON-RPC calls through
+/// 1inch Web3 RPC endpoint.
+#[derive(Error, Debug)]
+pub enum Web3Error {
+    /// Node responded with JSON-RPC error.
+    #[error("RPC error {code}: {message}")]
+    Rpc { code: i64, message: String, data: Option<serde_json::Value> },
+
+    /// Response contains neither result nor error, or result can't be
+    /// decoded.
+    #[error("Invalid RPC response: {0}")]
+    InvalidResponse(String),
+
+    /// Call argument is not a valid hex encoded 20 bytes address.
+    #[error("Invalid address: {0}")]
+    InvalidAddress(String),
+
+    /// Request of the call failed, or its response couldn't be read, so no
+    /// JSON-RPC response was received.
+    #[error(transparent)]
+    Request(#[from] OneInchError),
+}
+
+/// Kind of the JSON-RPC error, by its code as the JSON-RPC spec and EIP-1474
+/// define them.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum RpcErrorKind {
+    /// Call reverted, see [`Web3Error::revert_reason`].
+    Reverted,
+    Parse,
+    InvalidRequest,
+    MethodNotFound,
+    InvalidParams,
+    Internal,
+    /// Missing or invalid parameters, like too low nonce or insufficient
+    /// funds. Geth reports most transaction errors with this code.
+    InvalidInput,
+    ResourceNotFound,
+    /// Requested resource isn't available yet, like a block being synced.
+    ResourceUnavailable,
+    TransactionRejected,
+    MethodNotSupported,
+    /// Request exceeds the node's limit, like its rate limit.
+    LimitExceeded,
+    Other(i64),
+}
+
+/// Reason of the reverted call, decoded from its revert data.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum RevertReason {
+    /// `require(condition, "message")` or `revert("message")`.
+    Error(String),
+    /// `Panic(uint256)` of the failed assertion, overflow and alike.
+    Panic(u64),
+    /// Custom error of the contract, identified by its selector.
+    Custom { selector: [u8; 4], data: Vec<u8> },
+}
+
+impl Web3Error {
+    /// Returns kind of the JSON-RPC error, `None` if the node didn't respond
+    /// with one.
+    pub fn kind(&self) -> Option<RpcErrorKind> {
+        let Web3Error::Rpc { code, message, .. } = self else {
+            return None;
+        };
+
+        // Some nodes report reverts with generic codes, so the message is
+        // checked too
+        if *code == 3 || message.starts_with("execution reverted") {
+            return Some(RpcErrorKind::Reverted);
+        }
+
+        Some(match code {
+            -32700 => RpcErrorKind::Parse,
+            -32600 => RpcErrorKind::InvalidRequest,
+            -32601 => RpcErrorKind::MethodNotFound,
+            -32602 => RpcErrorKind::InvalidParams,
+            -32603 => RpcErrorKind::Internal,
+            -32000 => RpcErrorKind::InvalidInput,
+            -32001 => RpcErrorKind::ResourceNotFound,
+            -32002 => RpcErrorKind::ResourceUnavailable,
+            -32003 => RpcErrorKind::TransactionRejected,
+            -32004 => RpcErrorKind::MethodNotSupported,
+            -32005 => RpcErrorKind::LimitExceeded,
+            code => RpcErrorKind::Other(*code),
+        })
+    }
+
+    /// Returns true if the same call may succeed later, like after the node's
+    /// rate limit, a timeout or a server error.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Web3Error::Request(OneInchError::Network(e)) => {
+                e.is_timeout() || runtime::is_connect(e) || e.status().is_some_and(|status| status.is_server_error())
+            }
+            Web3Error::Request(OneInchError::RateLimited { .. } | OneInchError::UpstreamUnavailable { .. }) => true,
+            Web3Error::Request(OneInchError::Api { status_code, .. }) => *status_code >= 500,
+            _ => matches!(self.kind(), Some(RpcErrorKind::ResourceUnavailable | RpcErrorKind::LimitExceeded)),
+        }
+    }
+
+    /// Returns revert data of the reverted call. Nodes put it into `data` of
+    /// the error either as a hex string or nested into an object.
+    pub fn revert_data(&self) -> Option<Vec<u8>> {
+        let Web3Error::Rpc { data: Some(data), .. } = self else {
+            return None;
+        };
+        if self.kind() != Some(RpcErrorKind::Reverted) {
+            return None;
+        }
+
+        let data = match data {
+            serde_json::Value::Object(object) => object.get("data")?,
+            data => data,
+        };
+        decode_hex(data.as_str()?).ok()
+    }
+
+    /// Returns decoded reason of the reverted call, `None` if the call
+    /// wasn't reverted or reverted without data.
+    pub fn revert_reason(&self) -> Option<RevertReason> {
+        decode_revert(&self.revert_data()?)
+    }
+}
+
+/// Log emitted by the transaction.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionLog {
+    pub address: String,
+    pub topics: Vec<String>,
+    pub data: String,
+}
+
+/// Receipt of the mined transaction, as `eth_getTransactionReceipt` returns
+/// it. Numbers are left as hex encoded quantities.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionReceipt {
+    pub transaction_hash: String,
+    pub block_hash: String,
+    pub block_number: String,
+    pub transaction_index: Option<String>,
+    pub from: String,
+    pub to: Option<String>,
+    pub contract_address: Option<String>,
+    pub gas_used: String,
+    pub effective_gas_price: Option<String>,
+    /// `0x1` for successful transaction and `0x0` for reverted one. Missing
+    /// for pre-Byzantium transactions.
+    pub status: Option<String>,
+    #[serde(default)]
+    pub logs: Vec<TransactionLog>,
+}
+
+impl TransactionReceipt {
+    /// Returns true if the transaction succeeded, or `None` if the receipt
+    /// has no status.
+    pub fn is_success(&self) -> Option<bool> {
+        self.status.as_deref().map(|status| status == "0x1")
+    }
+}
+
+/// Status of the transaction with respect to chain finality.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum TransactionStatus {
+    /// Transaction isn't mined yet.
+    Pending,
+    /// Transaction is included in a block, but may still be reorganized out.
+    /// `success` is `None` if the receipt has no status.
+    Included { block: u64, confirmations: u64, success: Option<bool> },
+    /// Transaction has enough confirmations to be considered final.
+    Final { block: u64, success: Option<bool> },
+}